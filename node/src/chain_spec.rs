@@ -1,4 +1,6 @@
-use node_template_runtime::{AccountId, RuntimeGenesisConfig, Signature, WASM_BINARY};
+use node_template_runtime::{
+	AccountId, Balance, BlockNumber, RuntimeGenesisConfig, Signature, DAYS, WASM_BINARY,
+};
 use sc_service::ChainType;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
@@ -53,6 +55,8 @@ pub fn development_config() -> Result<ChainSpec, String> {
 			get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
 			get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
 		],
+		// Vested allocations
+		vec![],
 		true,
 	))
 	.build())
@@ -86,6 +90,11 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 			get_account_id_from_seed::<sr25519::Public>("Eve//stash"),
 			get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
 		],
+		// Vested allocations: Eve and Ferdie unlock all but a quarter of theirs over 90 days.
+		vec![
+			(get_account_id_from_seed::<sr25519::Public>("Eve"), 0, 90 * DAYS, 1 << 58),
+			(get_account_id_from_seed::<sr25519::Public>("Ferdie"), 0, 90 * DAYS, 1 << 58),
+		],
 		true,
 	))
 	.build())
@@ -96,6 +105,7 @@ fn testnet_genesis(
 	initial_authorities: Vec<(AuraId, GrandpaId)>,
 	root_key: AccountId,
 	endowed_accounts: Vec<AccountId>,
+	vested_accounts: Vec<(AccountId, BlockNumber, BlockNumber, Balance)>,
 	_enable_println: bool,
 ) -> serde_json::Value {
 	serde_json::json!({
@@ -109,9 +119,26 @@ fn testnet_genesis(
 		"grandpa": {
 			"authorities": initial_authorities.iter().map(|x| (x.1.clone(), 1)).collect::<Vec<_>>(),
 		},
+		"vesting": {
+			// Pre-funded accounts whose balance, but for the liquid part, unlocks linearly from
+			// the starting block over the given number of blocks.
+			"vesting": vested_accounts,
+		},
 		"sudo": {
 			// Assign network admin rights.
 			"key": Some(root_key),
 		},
+		"jurorMembership": {
+			// Let the pre-funded accounts decide vulntoken escrow disputes.
+			"members": endowed_accounts.iter().take(3).cloned().collect::<Vec<_>>(),
+		},
+		"creatorMembership": {
+			// Let searches for verified creators find the pre-funded accounts' collectibles.
+			"members": endowed_accounts.iter().take(3).cloned().collect::<Vec<_>>(),
+		},
+		"bridgeRelayers": {
+			// The first pre-funded account relays for the Ethereum bridge.
+			"members": endowed_accounts.iter().take(1).cloned().collect::<Vec<_>>(),
+		},
 	})
 }
@@ -7,6 +7,11 @@ pub struct Cli {
 
 	#[clap(flatten)]
 	pub run: RunCmd,
+
+	/// Serve a read-only HTTP/JSON view of vulntoken collectibles and listings at this address,
+	/// e.g. `127.0.0.1:8080`.
+	#[arg(long, value_name = "ADDR")]
+	pub rest_gateway: Option<std::net::SocketAddr>,
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -14,11 +19,15 @@ pub struct Cli {
 pub enum Subcommand {
 	/// Key management cli utilities
 	#[command(subcommand)]
-	Key(sc_cli::KeySubcommand),
+	Key(crate::keys::KeySubcommand),
 
 	/// Build a chain specification.
 	BuildSpec(sc_cli::BuildSpecCmd),
 
+	/// Build a raw chain specification from `dev`, `local` or a chain spec file, replacing its
+	/// authorities, endowed accounts and genesis collectibles.
+	ChainSpecBuilder(crate::spec_builder::ChainSpecBuilderCmd),
+
 	/// Validate blocks.
 	CheckBlock(sc_cli::CheckBlockCmd),
 
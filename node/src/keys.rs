@@ -0,0 +1,81 @@
+//! Key management subcommands: the standard `key` utilities plus a helper that fills a
+//! validator's keystore with development keys.
+
+use node_template_runtime::vulntoken;
+use sc_cli::{KeystoreParams, SharedParams, SubstrateCli};
+use sc_keystore::LocalKeystore;
+use sc_service::{config::KeystoreConfig, BasePath};
+use sp_core::crypto::key_types::{AURA, GRANDPA};
+use sp_keyring::Sr25519Keyring;
+use sp_keystore::Keystore;
+
+/// Key management cli utilities
+#[derive(Debug, clap::Subcommand)]
+pub enum KeySubcommand {
+	/// The standard key utilities: `insert`, `generate-node-key`, `inspect` and the rest.
+	#[command(flatten)]
+	Key(sc_cli::KeySubcommand),
+
+	/// Insert the Aura, GRANDPA and vulntoken pinning keys of a development account into the
+	/// keystore, so a local multi-node network can be started without inserting each key.
+	Bootstrap(BootstrapCmd),
+}
+
+impl KeySubcommand {
+	/// Run the subcommand.
+	pub fn run<C: SubstrateCli>(&self, cli: &C) -> sc_cli::Result<()> {
+		match self {
+			KeySubcommand::Key(cmd) => cmd.run(cli),
+			KeySubcommand::Bootstrap(cmd) => cmd.run(cli),
+		}
+	}
+}
+
+/// The `key bootstrap` command.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct BootstrapCmd {
+	/// The development account whose keys to insert, e.g. `alice` or `bob`.
+	#[arg(long)]
+	pub validator: String,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub keystore_params: KeystoreParams,
+}
+
+impl BootstrapCmd {
+	/// Run the command.
+	pub fn run<C: SubstrateCli>(&self, cli: &C) -> sc_cli::Result<()> {
+		let validator = Sr25519Keyring::iter()
+			.find(|keyring| format!("{keyring:?}").eq_ignore_ascii_case(&self.validator))
+			.ok_or_else(|| {
+				sc_cli::Error::Input(format!("`{}` is not a development account", self.validator))
+			})?;
+		let seed = validator.to_seed();
+
+		let base_path = self
+			.shared_params
+			.base_path()?
+			.unwrap_or_else(|| BasePath::from_project("", "", &C::executable_name()));
+		let chain_spec = cli.load_spec(&self.shared_params.chain_id(self.shared_params.is_dev()))?;
+		let config_dir = base_path.config_dir(chain_spec.id());
+		let KeystoreConfig::Path { path, password } =
+			self.keystore_params.keystore_config(&config_dir)?
+		else {
+			unreachable!("keystore_config always returns path and password; qed")
+		};
+		let keystore = LocalKeystore::open(path, password)?;
+
+		let inserted = keystore
+			.sr25519_generate_new(AURA, Some(&seed))
+			.and_then(|_| keystore.ed25519_generate_new(GRANDPA, Some(&seed)))
+			.and_then(|_| keystore.sr25519_generate_new(vulntoken::KEY_TYPE, Some(&seed)));
+		inserted.map_err(|_| sc_cli::Error::KeystoreOperation)?;
+		println!("Inserted the aura, gran and vtok keys of {validator:?}");
+		Ok(())
+	}
+}
@@ -5,8 +5,11 @@ mod benchmarking;
 mod chain_spec;
 mod cli;
 mod command;
+mod keys;
+mod rest;
 mod rpc;
 mod service;
+mod spec_builder;
 
 fn main() -> sc_cli::Result<()> {
 	command::run()
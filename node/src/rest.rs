@@ -0,0 +1,173 @@
+//! A read-only HTTP/JSON gateway to vulntoken state, enabled with `--rest-gateway <ADDR>`, so
+//! collectibles can be looked up with `curl` instead of JSON-RPC and SCALE tooling. Every request
+//! is answered from the best block through the vulntoken runtime API.
+//!
+//! - `GET /collectibles/<id>`: the collectible, or 404 if it does not exist.
+//! - `GET /accounts/<ss58 address>/collectibles`: the collectibles the account holds.
+//! - `GET /listings?start_after=<id>&limit=<n>`: a page of fixed-price listings in id order.
+
+use std::{convert::Infallible, future::Future, net::SocketAddr, sync::Arc};
+
+use hyper::{
+	header::CONTENT_TYPE,
+	service::{make_service_fn, service_fn},
+	Body, Method, Request, Response, Server, StatusCode,
+};
+use node_template_runtime::{opaque::Block, vulntoken, AccountId, Balance};
+use serde::Serialize;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::crypto::Ss58Codec;
+use vulntoken_runtime_api::VulntokenApi as VulntokenRuntimeApi;
+
+/// Largest page of listings the gateway returns, whatever the request asks for.
+const MAX_LISTINGS: u32 = 100;
+
+/// A collectible as the gateway serves it.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Collectible {
+	id: u64,
+	owner: AccountId,
+	creator: AccountId,
+	color: String,
+	/// The fixed price it is listed at, if it is listed.
+	price: Option<Balance>,
+	/// The license it was minted with, if any.
+	license: Option<String>,
+	/// The metadata schema version its creator publishes under.
+	metadata_version: u16,
+	/// The collection it was minted into, if any.
+	collection_id: Option<u32>,
+}
+
+impl From<vulntoken::CollectibleView<AccountId, Balance>> for Collectible {
+	fn from(view: vulntoken::CollectibleView<AccountId, Balance>) -> Self {
+		Collectible {
+			id: view.unique_id,
+			owner: view.owner,
+			creator: view.creator,
+			color: format!("{:?}", view.color),
+			price: view.price,
+			license: view.license.map(|license| String::from_utf8_lossy(&license).into_owned()),
+			metadata_version: view.metadata_version,
+			collection_id: view.collection_id,
+		}
+	}
+}
+
+/// A fixed-price listing as the gateway serves it.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Listing {
+	id: u64,
+	color: String,
+	price: Balance,
+}
+
+/// Bind the gateway to `addr`. The returned future serves requests until the node stops.
+pub fn serve<C>(
+	addr: SocketAddr,
+	client: Arc<C>,
+) -> Result<impl Future<Output = ()>, hyper::Error>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: VulntokenRuntimeApi<Block, AccountId, Balance>,
+{
+	let make_service = make_service_fn(move |_| {
+		let client = client.clone();
+		async move {
+			Ok::<_, Infallible>(service_fn(move |request| {
+				let response = respond(&*client, &request);
+				async move { Ok::<_, Infallible>(response) }
+			}))
+		}
+	});
+	let server = Server::try_bind(&addr)?.serve(make_service);
+	log::info!("Vulntoken REST gateway listening on http://{}", server.local_addr());
+	Ok(async move {
+		if let Err(e) = server.await {
+			log::error!("Vulntoken REST gateway failed: {e}");
+		}
+	})
+}
+
+fn respond<C>(client: &C, request: &Request<Body>) -> Response<Body>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: VulntokenRuntimeApi<Block, AccountId, Balance>,
+{
+	if request.method() != Method::GET {
+		return status(StatusCode::METHOD_NOT_ALLOWED)
+	}
+	let path = request.uri().path().trim_matches('/').split('/').collect::<Vec<_>>();
+	let api = client.runtime_api();
+	let at = client.info().best_hash;
+	let response = match path[..] {
+		["collectibles", id] => {
+			let Ok(id) = id.parse() else { return status(StatusCode::BAD_REQUEST) };
+			api.collectible(at, id).map(|found| match found {
+				Some(collectible) => json(&Collectible::from(collectible)),
+				None => status(StatusCode::NOT_FOUND),
+			})
+		},
+		["accounts", account, "collectibles"] => {
+			let Ok(owner) = AccountId::from_ss58check(account) else {
+				return status(StatusCode::BAD_REQUEST)
+			};
+			api.collectibles_of(at, owner).and_then(|ids| {
+				let found = ids
+					.into_iter()
+					.filter_map(|id| api.collectible(at, id).transpose())
+					.map(|collectible| collectible.map(Collectible::from))
+					.collect::<Result<Vec<_>, _>>()?;
+				Ok(json(&found))
+			})
+		},
+		["listings"] => {
+			let Some((start_after, limit)) = page(request.uri().query().unwrap_or_default()) else {
+				return status(StatusCode::BAD_REQUEST)
+			};
+			let filter = vulntoken::CollectibleFilter { for_sale_only: true, ..Default::default() };
+			api.find_collectibles(at, filter, start_after, limit.min(MAX_LISTINGS)).map(|found| {
+				let listings = found
+					.into_iter()
+					.filter_map(|(id, color, price)| {
+						Some(Listing { id, color: format!("{color:?}"), price: price? })
+					})
+					.collect::<Vec<_>>();
+				json(&listings)
+			})
+		},
+		_ => return status(StatusCode::NOT_FOUND),
+	};
+	response.unwrap_or_else(|e| {
+		log::warn!("Vulntoken REST gateway request failed: {e}");
+		status(StatusCode::INTERNAL_SERVER_ERROR)
+	})
+}
+
+/// The `start_after` and `limit` of a `/listings` query string.
+fn page(query: &str) -> Option<(Option<u64>, u32)> {
+	let (mut start_after, mut limit) = (None, MAX_LISTINGS);
+	for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+		match pair.split_once('=')? {
+			("start_after", id) => start_after = Some(id.parse().ok()?),
+			("limit", n) => limit = n.parse().ok()?,
+			_ => return None,
+		}
+	}
+	Some((start_after, limit))
+}
+
+fn json<T: Serialize>(body: &T) -> Response<Body> {
+	let body = serde_json::to_vec(body).expect("gateway responses serialize; qed");
+	Response::builder()
+		.header(CONTENT_TYPE, "application/json")
+		.body(Body::from(body))
+		.expect("static header is valid; qed")
+}
+
+fn status(status: StatusCode) -> Response<Body> {
+	Response::builder().status(status).body(Body::empty()).expect("no headers to reject; qed")
+}
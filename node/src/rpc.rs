@@ -5,48 +5,82 @@
 
 #![warn(missing_docs)]
 
+pub mod fees;
+pub mod vulntoken;
+
 use std::sync::Arc;
 
 use jsonrpsee::RpcModule;
-use node_template_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use node_template_runtime::{opaque::Block, AccountId, Balance, Nonce, RuntimeCall};
+use sc_client_api::{
+	Backend, BlockBackend, BlockchainEvents, ExecutorProvider, ProofProvider, StorageProvider,
+};
+use sc_rpc::SubscriptionTaskExecutor;
 use sc_transaction_pool_api::TransactionPool;
-use sp_api::ProvideRuntimeApi;
+use sp_api::{CallApiAt, ProvideRuntimeApi};
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
 
 pub use sc_rpc_api::DenyUnsafe;
 
 /// Full client dependencies.
-pub struct FullDeps<C, P> {
+pub struct FullDeps<C, P, BE> {
 	/// The client instance to use.
 	pub client: Arc<C>,
+	/// The backend the client reads state from.
+	pub backend: Arc<BE>,
+	/// Whether the node keeps every block and its state, so it can serve the `archive` methods.
+	pub archive: bool,
 	/// Transaction pool instance.
 	pub pool: Arc<P>,
 	/// Whether to deny unsafe calls
 	pub deny_unsafe: DenyUnsafe,
+	/// Executor for subscription tasks.
+	pub subscription_executor: SubscriptionTaskExecutor,
 }
 
 /// Instantiate all full RPC extensions.
-pub fn create_full<C, P>(
-	deps: FullDeps<C, P>,
+pub fn create_full<C, P, BE>(
+	deps: FullDeps<C, P, BE>,
 ) -> Result<RpcModule<()>, Box<dyn std::error::Error + Send + Sync>>
 where
+	BE: Backend<Block> + 'static,
 	C: ProvideRuntimeApi<Block>,
 	C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError> + 'static,
+	C: BlockchainEvents<Block> + StorageProvider<Block, BE>,
+	C: BlockBackend<Block> + ExecutorProvider<Block> + CallApiAt<Block> + ProofProvider<Block>,
 	C: Send + Sync + 'static,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+	C::Api: pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi<
+		Block,
+		Balance,
+		RuntimeCall,
+	>,
 	C::Api: BlockBuilder<Block>,
+	C::Api: vulntoken_runtime_api::VulntokenApi<Block, AccountId, Balance>,
 	P: TransactionPool + 'static,
 {
+	use fees::{CallFees, CallFeesApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
+	use sc_rpc_spec_v2::archive::{Archive, ArchiveApiServer, ArchiveConfig};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
+	use vulntoken::{Vulntoken, VulntokenApiServer};
 
 	let mut module = RpcModule::new(());
-	let FullDeps { client, pool, deny_unsafe } = deps;
+	let FullDeps { client, backend, archive, pool, deny_unsafe, subscription_executor } = deps;
 
 	module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
-	module.merge(TransactionPayment::new(client).into_rpc())?;
+	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(CallFees::new(client.clone()).into_rpc())?;
+	// `sc-service` already serves the `chainHead`, `transaction` and `chainSpec` families of the
+	// new JSON-RPC spec. `archive` answers about any block, so only archive nodes serve it.
+	if archive {
+		let genesis_hash = client.block_hash(0).ok().flatten().expect("Genesis block exists; qed");
+		let archive = Archive::new(client.clone(), backend, genesis_hash, ArchiveConfig::default());
+		module.merge(archive.into_rpc())?;
+	}
+	module.merge(Vulntoken::<C, BE>::new(client, subscription_executor).into_rpc())?;
 
 	// Extend this RPC with a custom API by using the following syntax.
 	// `YourRpcStruct` should have a reference to a client, which is needed
@@ -0,0 +1,111 @@
+//! Fee estimates for calls that have not been signed yet.
+//!
+//! `payment_queryInfo` and `payment_queryFeeDetails` estimate the fee of a signed extrinsic, so a
+//! wallet would have to ask for a signature before it could show what signing costs. These
+//! methods take the SCALE-encoded call instead, along with the length of the extrinsic it will be
+//! sent in. Without a length, the call's own length is used, which leaves out the signature and
+//! signed extensions and so underestimates the length fee slightly.
+
+use std::sync::Arc;
+
+use codec::Decode;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::ErrorObjectOwned};
+use node_template_runtime::{opaque::Block, Balance, Hash, RuntimeCall};
+use pallet_transaction_payment::{FeeDetails, InclusionFee, RuntimeDispatchInfo};
+use pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_rpc::number::NumberOrHex;
+
+/// Fee estimate RPC methods for unsigned calls.
+#[rpc(server)]
+pub trait CallFeesApi {
+	/// The weight, dispatch class and fee of `call`, sent in an extrinsic of `len` bytes, at block
+	/// `at` or the best block.
+	#[method(name = "payment_queryCallInfo")]
+	fn query_call_info(
+		&self,
+		call: Bytes,
+		len: Option<u32>,
+		at: Option<Hash>,
+	) -> RpcResult<RuntimeDispatchInfo<Balance>>;
+
+	/// The fee of `call`, sent in an extrinsic of `len` bytes, broken down into its base, length
+	/// and weight parts, at block `at` or the best block.
+	#[method(name = "payment_queryCallFeeDetails")]
+	fn query_call_fee_details(
+		&self,
+		call: Bytes,
+		len: Option<u32>,
+		at: Option<Hash>,
+	) -> RpcResult<FeeDetails<NumberOrHex>>;
+}
+
+/// Implements [`CallFeesApiServer`] with the runtime's `TransactionPaymentCallApi`.
+pub struct CallFees<C> {
+	client: Arc<C>,
+}
+
+impl<C> CallFees<C> {
+	/// Create a new instance of the call fee RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+}
+
+impl<C> CallFeesApiServer for CallFees<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: TransactionPaymentCallApi<Block, Balance, RuntimeCall>,
+{
+	fn query_call_info(
+		&self,
+		call: Bytes,
+		len: Option<u32>,
+		at: Option<Hash>,
+	) -> RpcResult<RuntimeDispatchInfo<Balance>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let (call, len) = decode_call(call, len)?;
+		self.client
+			.runtime_api()
+			.query_call_info(at, call, len)
+			.map_err(|error| query_failed(&error))
+	}
+
+	fn query_call_fee_details(
+		&self,
+		call: Bytes,
+		len: Option<u32>,
+		at: Option<Hash>,
+	) -> RpcResult<FeeDetails<NumberOrHex>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let (call, len) = decode_call(call, len)?;
+		let details = self
+			.client
+			.runtime_api()
+			.query_call_fee_details(at, call, len)
+			.map_err(|error| query_failed(&error))?;
+		Ok(FeeDetails {
+			inclusion_fee: details.inclusion_fee.map(|fee| InclusionFee {
+				base_fee: fee.base_fee.into(),
+				len_fee: fee.len_fee.into(),
+				adjusted_weight_fee: fee.adjusted_weight_fee.into(),
+			}),
+			tip: Default::default(),
+		})
+	}
+}
+
+/// Decode a runtime call, and the length of the extrinsic to estimate it in.
+fn decode_call(call: Bytes, len: Option<u32>) -> RpcResult<(RuntimeCall, u32)> {
+	let len = len.unwrap_or(call.len() as u32);
+	let call = RuntimeCall::decode(&mut &call[..]).map_err(|error| {
+		ErrorObjectOwned::owned(1, "Unable to decode the call", Some(error.to_string()))
+	})?;
+	Ok((call, len))
+}
+
+fn query_failed(error: &dyn std::fmt::Display) -> ErrorObjectOwned {
+	ErrorObjectOwned::owned(2, "Unable to query the fee of the call", Some(error.to_string()))
+}
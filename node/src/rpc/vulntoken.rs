@@ -0,0 +1,449 @@
+//! RPC methods for the vulntoken pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::{Decode, Encode};
+use futures::{stream, FutureExt, StreamExt};
+use jsonrpsee::{
+	core::RpcResult, proc_macros::rpc, types::ErrorObjectOwned, PendingSubscriptionSink,
+};
+use node_template_runtime::{
+	opaque::Block, vulntoken, AccountId, Balance, Hash, Runtime, RuntimeError, RuntimeEvent,
+};
+use sc_client_api::{Backend, BlockchainEvents, ProofProvider, StorageProvider};
+use sc_rpc::{utils::pipe_from_stream, SubscriptionTaskExecutor};
+use serde::Serialize;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{storage::StorageKey, twox_128, twox_64, Bytes};
+use sp_runtime::DispatchError;
+use vulntoken_runtime_api::VulntokenApi as VulntokenRuntimeApi;
+
+/// Largest page of listings `vulntoken_collectiblesForSale` returns, whatever the call asks for.
+const MAX_LISTINGS: u32 = 100;
+
+/// A collectible as it stands at a block.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectibleDetails {
+	pub unique_id: u64,
+	pub owner: AccountId,
+	pub creator: AccountId,
+	pub color: String,
+	/// The fixed price it is listed at, if it is listed.
+	pub price: Option<Balance>,
+	/// The license it was minted with, if any.
+	pub license: Option<String>,
+	/// The metadata schema version its creator publishes under.
+	pub metadata_version: u16,
+	/// The collection it was minted into, if any.
+	pub collection_id: Option<u32>,
+}
+
+impl From<vulntoken::CollectibleView<AccountId, Balance>> for CollectibleDetails {
+	fn from(view: vulntoken::CollectibleView<AccountId, Balance>) -> Self {
+		CollectibleDetails {
+			unique_id: view.unique_id,
+			owner: view.owner,
+			creator: view.creator,
+			color: format!("{:?}", view.color),
+			price: view.price,
+			license: view.license.map(|license| String::from_utf8_lossy(&license).into_owned()),
+			metadata_version: view.metadata_version,
+			collection_id: view.collection_id,
+		}
+	}
+}
+
+/// A collectible put up for sale at a fixed price.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Listing {
+	/// Block in which the collectible was listed.
+	pub block: Hash,
+	pub collectible: u64,
+	pub price: Balance,
+	pub seller: AccountId,
+}
+
+/// Something that happened to a single collectible.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum ItemActivity {
+	/// The collectible changed hands without a sale.
+	#[serde(rename_all = "camelCase")]
+	Transfer { block: Hash, from: AccountId, to: AccountId },
+	/// The collectible was listed at, or taken off the market with, `price: None`.
+	#[serde(rename_all = "camelCase")]
+	PriceChanged { block: Hash, price: Option<Balance> },
+	/// The collectible was bought.
+	#[serde(rename_all = "camelCase")]
+	Sold { block: Hash, seller: AccountId, buyer: AccountId, price: Balance },
+}
+
+/// What buying a collectible would do, found by running the purchase against a block's state.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase", tag = "outcome")]
+pub enum DryRunBuy {
+	/// The purchase would go through and pay out like this.
+	#[serde(rename_all = "camelCase")]
+	Settled {
+		seller: AccountId,
+		price: Balance,
+		/// The part of the price the seller receives, after any royalty and charity share.
+		seller_share: Balance,
+		/// The royalty paid to the collectible's creator.
+		royalty: Balance,
+		/// The marketplace fee.
+		fee: Balance,
+		/// Whoever referred the buyer, and what they are paid.
+		referrer: Option<(AccountId, Balance)>,
+		/// The beneficiary of a listing that gives to charity, and what they are paid.
+		charity: Option<(AccountId, Balance)>,
+	},
+	/// The purchase would fail. Pallet errors are named as `Pallet(Variant)`.
+	#[serde(rename_all = "camelCase")]
+	Failed { error: String },
+}
+
+/// A storage proof of a collectible's entry, which can be checked against the state root of the
+/// block's header without trusting the node that served it.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectibleProof {
+	/// Block whose state root the proof is against.
+	pub at: Hash,
+	/// The proven storage keys. Their values are SCALE-encoded collectibles, or absent if the
+	/// collectible does not exist.
+	pub keys: Vec<Bytes>,
+	/// Trie nodes proving the values of `keys`.
+	pub proof: Vec<Bytes>,
+}
+
+/// Vulntoken RPC methods.
+#[rpc(server)]
+pub trait VulntokenApi {
+	/// Stream every collectible listed for sale in newly imported blocks.
+	#[subscription(
+		name = "vulntoken_subscribeListings" => "vulntoken_listing",
+		unsubscribe = "vulntoken_unsubscribeListings",
+		item = Listing
+	)]
+	fn subscribe_listings(&self);
+
+	/// Stream the transfers, price changes and sales of one collectible as blocks are
+	/// finalized.
+	#[subscription(
+		name = "vulntoken_subscribeItem" => "vulntoken_itemActivity",
+		unsubscribe = "vulntoken_unsubscribeItem",
+		item = ItemActivity
+	)]
+	fn subscribe_item(&self, unique_id: u64);
+
+	/// Buy collectible `unique_id` as `account`, for at most `max_price`, against the state of
+	/// block `at` or the best block, without submitting anything.
+	#[method(name = "vulntoken_dryRunBuy")]
+	fn dry_run_buy(
+		&self,
+		account: AccountId,
+		unique_id: u64,
+		max_price: Balance,
+		at: Option<Hash>,
+	) -> RpcResult<DryRunBuy>;
+
+	/// Prove who owns collectible `unique_id`, or that it does not exist, at block `at` or the
+	/// best block.
+	#[method(name = "vulntoken_proveCollectible")]
+	fn prove_collectible(&self, unique_id: u64, at: Option<Hash>) -> RpcResult<CollectibleProof>;
+
+	/// Collectible `unique_id` at block `at` or the best block, if it exists.
+	#[method(name = "vulntoken_collectible")]
+	fn collectible(
+		&self,
+		unique_id: u64,
+		at: Option<Hash>,
+	) -> RpcResult<Option<CollectibleDetails>>;
+
+	/// The collectibles `account` holds at block `at` or the best block.
+	#[method(name = "vulntoken_ownedCollectibles")]
+	fn owned_collectibles(
+		&self,
+		account: AccountId,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<CollectibleDetails>>;
+
+	/// A page of at most `limit` collectibles listed at a fixed price, in ascending id order
+	/// after `start_after`, at block `at` or the best block.
+	#[method(name = "vulntoken_collectiblesForSale")]
+	fn collectibles_for_sale(
+		&self,
+		start_after: Option<u64>,
+		limit: Option<u32>,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<CollectibleDetails>>;
+}
+
+/// Implements [`VulntokenApiServer`] by decoding the pallet's events and storage.
+pub struct Vulntoken<C, BE> {
+	client: Arc<C>,
+	executor: SubscriptionTaskExecutor,
+	_backend: PhantomData<BE>,
+}
+
+impl<C, BE> Vulntoken<C, BE> {
+	/// Create a new instance of the vulntoken RPC handler.
+	pub fn new(client: Arc<C>, executor: SubscriptionTaskExecutor) -> Self {
+		Self { client, executor, _backend: PhantomData }
+	}
+}
+
+impl<C, BE> VulntokenApiServer for Vulntoken<C, BE>
+where
+	BE: Backend<Block> + 'static,
+	C: BlockchainEvents<Block> + StorageProvider<Block, BE> + Send + Sync + 'static,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + ProofProvider<Block>,
+	C::Api: VulntokenRuntimeApi<Block, AccountId, Balance>,
+{
+	fn subscribe_listings(&self, pending: PendingSubscriptionSink) {
+		let client = self.client.clone();
+		let listings = self.client.import_notification_stream().flat_map(move |notification| {
+			stream::iter(block_listings(&*client, notification.hash))
+		});
+		self.executor.spawn(
+			"vulntoken-rpc-subscription",
+			Some("rpc"),
+			pipe_from_stream(pending, listings.boxed()).boxed(),
+		);
+	}
+
+	fn subscribe_item(&self, pending: PendingSubscriptionSink, unique_id: u64) {
+		let client = self.client.clone();
+		let activity = self.client.finality_notification_stream().flat_map(move |notification| {
+			// Blocks finalized implicitly by this notification come first, oldest to newest.
+			let activity = notification
+				.tree_route
+				.iter()
+				.chain(std::iter::once(&notification.hash))
+				.flat_map(|block| item_activity(&*client, *block, unique_id))
+				.collect::<Vec<_>>();
+			stream::iter(activity)
+		});
+		self.executor.spawn(
+			"vulntoken-rpc-subscription",
+			Some("rpc"),
+			pipe_from_stream(pending, activity.boxed()).boxed(),
+		);
+	}
+
+	fn dry_run_buy(
+		&self,
+		account: AccountId,
+		unique_id: u64,
+		max_price: Balance,
+		at: Option<Hash>,
+	) -> RpcResult<DryRunBuy> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let outcome = self
+			.client
+			.runtime_api()
+			.dry_run_buy(at, account, unique_id, max_price)
+			.map_err(|error| {
+				let message = "Unable to dry-run the purchase";
+				ErrorObjectOwned::owned(1, message, Some(error.to_string()))
+			})?;
+		Ok(match outcome {
+			Ok(quote) => DryRunBuy::Settled {
+				seller: quote.seller,
+				price: quote.price,
+				seller_share: quote.split.seller -
+					quote.royalty - quote.charity.as_ref().map_or(0, |(_, amount)| *amount),
+				royalty: quote.split.creator + quote.royalty,
+				fee: quote.split.fee,
+				referrer: quote.split.referrer,
+				charity: quote.charity,
+			},
+			Err(error) => DryRunBuy::Failed { error: describe_error(error) },
+		})
+	}
+
+	fn prove_collectible(&self, unique_id: u64, at: Option<Hash>) -> RpcResult<CollectibleProof> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let failed = |error: &dyn std::fmt::Display| {
+			let message = "Unable to prove the collectible";
+			ErrorObjectOwned::owned(1, message, Some(error.to_string()))
+		};
+		let keys = self
+			.client
+			.runtime_api()
+			.prove_collectible(at, unique_id)
+			.map_err(|error| failed(&error))?;
+		let proof = self
+			.client
+			.read_proof(at, &mut keys.iter().map(Vec::as_slice))
+			.map_err(|error| failed(&error))?;
+		Ok(CollectibleProof {
+			at,
+			keys: keys.into_iter().map(Bytes).collect(),
+			proof: proof.into_iter_nodes().map(Bytes).collect(),
+		})
+	}
+
+	fn collectible(
+		&self,
+		unique_id: u64,
+		at: Option<Hash>,
+	) -> RpcResult<Option<CollectibleDetails>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let found = self
+			.client
+			.runtime_api()
+			.collectible(at, unique_id)
+			.map_err(|error| query_failed(&error))?;
+		Ok(found.map(CollectibleDetails::from))
+	}
+
+	fn owned_collectibles(
+		&self,
+		account: AccountId,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<CollectibleDetails>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let ids = self
+			.client
+			.runtime_api()
+			.collectibles_of(at, account)
+			.map_err(|error| query_failed(&error))?;
+		details(&*self.client, at, ids)
+	}
+
+	fn collectibles_for_sale(
+		&self,
+		start_after: Option<u64>,
+		limit: Option<u32>,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<CollectibleDetails>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let filter = vulntoken::CollectibleFilter { for_sale_only: true, ..Default::default() };
+		let limit = limit.unwrap_or(MAX_LISTINGS).min(MAX_LISTINGS);
+		let found = self
+			.client
+			.runtime_api()
+			.find_collectibles(at, filter, start_after, limit)
+			.map_err(|error| query_failed(&error))?;
+		details(&*self.client, at, found.into_iter().map(|(id, _, _)| id))
+	}
+}
+
+/// The details of the collectibles `ids` at block `at`, skipping any that do not exist.
+fn details<C>(
+	client: &C,
+	at: Hash,
+	ids: impl IntoIterator<Item = u64>,
+) -> RpcResult<Vec<CollectibleDetails>>
+where
+	C: ProvideRuntimeApi<Block>,
+	C::Api: VulntokenRuntimeApi<Block, AccountId, Balance>,
+{
+	let api = client.runtime_api();
+	ids.into_iter()
+		.filter_map(|id| api.collectible(at, id).transpose())
+		.map(|found| found.map(CollectibleDetails::from).map_err(|error| query_failed(&error)))
+		.collect()
+}
+
+fn query_failed(error: &dyn std::fmt::Display) -> ErrorObjectOwned {
+	ErrorObjectOwned::owned(1, "Unable to query vulntoken state", Some(error.to_string()))
+}
+
+/// Name a dispatch error after the pallet error it decodes to, if it does.
+fn describe_error(error: DispatchError) -> String {
+	RuntimeError::from_dispatch_error(error)
+		.map_or_else(|| format!("{error:?}"), |error| format!("{error:?}"))
+}
+
+/// The storage key prefix of a storage item.
+fn storage_prefix(pallet: &str, item: &str) -> Vec<u8> {
+	[twox_128(pallet.as_bytes()), twox_128(item.as_bytes())].concat()
+}
+
+/// Read and decode a storage entry at `block`.
+fn read_storage<C, BE, V: Decode>(client: &C, block: Hash, key: Vec<u8>) -> Option<V>
+where
+	BE: Backend<Block>,
+	C: StorageProvider<Block, BE>,
+{
+	let data = client.storage(block, &StorageKey(key)).ok()??;
+	V::decode(&mut &data.0[..]).ok()
+}
+
+/// The vulntoken events deposited in `block`.
+fn block_events<C, BE>(client: &C, block: Hash) -> Vec<vulntoken::Event<Runtime>>
+where
+	BE: Backend<Block>,
+	C: StorageProvider<Block, BE>,
+{
+	let records: Vec<frame_system::EventRecord<RuntimeEvent, Hash>> =
+		read_storage(client, block, storage_prefix("System", "Events")).unwrap_or_default();
+	records
+		.into_iter()
+		.filter_map(|record| match record.event {
+			RuntimeEvent::VulnModule(event) => Some(event),
+			_ => None,
+		})
+		.collect()
+}
+
+/// A collectible as stored at `block`.
+fn collectible_at<C, BE>(
+	client: &C,
+	block: Hash,
+	unique_id: u64,
+) -> Option<vulntoken::Collectible<Runtime>>
+where
+	BE: Backend<Block>,
+	C: StorageProvider<Block, BE>,
+{
+	let id = unique_id.encode();
+	let key = [storage_prefix("VulnModule", "CollectibleMap"), twox_64(&id).to_vec(), id].concat();
+	read_storage(client, block, key)
+}
+
+/// The listings created in `block`.
+fn block_listings<C, BE>(client: &C, block: Hash) -> Vec<Listing>
+where
+	BE: Backend<Block>,
+	C: StorageProvider<Block, BE>,
+{
+	block_events(client, block)
+		.into_iter()
+		.filter_map(|event| match event {
+			vulntoken::Event::PriceSet { collectible, price: Some(price) } => {
+				let seller = collectible_at(client, block, collectible)?.owner;
+				Some(Listing { block, collectible, price, seller })
+			},
+			_ => None,
+		})
+		.collect()
+}
+
+/// The activity of collectible `unique_id` in `block`.
+fn item_activity<C, BE>(client: &C, block: Hash, unique_id: u64) -> Vec<ItemActivity>
+where
+	BE: Backend<Block>,
+	C: StorageProvider<Block, BE>,
+{
+	block_events(client, block)
+		.into_iter()
+		.filter_map(|event| match event {
+			vulntoken::Event::TransferSucceeded { from, to, collectible }
+				if collectible == unique_id =>
+				Some(ItemActivity::Transfer { block, from, to }),
+			vulntoken::Event::PriceSet { collectible, price } if collectible == unique_id =>
+				Some(ItemActivity::PriceChanged { block, price }),
+			vulntoken::Event::Sold { seller, buyer, collectible, price, .. }
+				if collectible == unique_id =>
+				Some(ItemActivity::Sold { block, seller, buyer, price }),
+			_ => None,
+		})
+		.collect()
+}
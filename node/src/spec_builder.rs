@@ -0,0 +1,125 @@
+//! The `chain-spec-builder` subcommand: a raw chain spec built from one of the node's chain specs
+//! with its authorities, endowed accounts and genesis collectibles replaced, so chains other than
+//! `dev` and `local` do not need hand-edited JSON. Genesis collectibles can come listed, for test
+//! networks that want a seeded marketplace.
+
+use crate::chain_spec::{authority_keys_from_seed, ChainSpec};
+use node_template_runtime::{vulntoken::Color, AccountId, Balance};
+use sc_cli::SubstrateCli;
+use sc_service::ChainSpec as _;
+use serde_json::{json, Value};
+use std::path::PathBuf;
+
+/// The `chain-spec-builder` command.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ChainSpecBuilderCmd {
+	/// The chain spec to start from: `dev`, `local` or the path to a chain spec file.
+	#[arg(long, default_value = "local")]
+	pub chain: String,
+
+	/// Seed of an initial authority, e.g. `Alice`. Repeat for each authority; replaces the
+	/// authorities of `--chain`.
+	#[arg(long = "authority", value_name = "SEED")]
+	pub authorities: Vec<String>,
+
+	/// SS58 address of an account to endow. Repeat for each account; replaces the endowed
+	/// accounts of `--chain`.
+	#[arg(long = "endow", value_name = "ADDRESS")]
+	pub endowed: Vec<AccountId>,
+
+	/// Balance of each account given with `--endow`.
+	#[arg(long, default_value_t = 1 << 60)]
+	pub balance: Balance,
+
+	/// A collectible minted at genesis, as the SS58 address of its owner, optionally followed by
+	/// its color (red by default) and a price to list it at, e.g. `ADDRESS:Blue:1000`. Repeat to
+	/// mint more.
+	#[arg(long = "collectible", value_name = "ADDRESS[:COLOR[:PRICE]]")]
+	pub collectibles: Vec<GenesisCollectible>,
+
+	/// Write the chain spec to this file instead of stdout.
+	#[arg(long)]
+	pub output: Option<PathBuf>,
+}
+
+/// A collectible given with `--collectible`.
+#[derive(Debug, Clone)]
+pub struct GenesisCollectible {
+	pub owner: AccountId,
+	pub color: Color,
+	pub price: Option<Balance>,
+}
+
+impl std::str::FromStr for GenesisCollectible {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut parts = s.split(':');
+		let owner = parts.next().unwrap_or_default();
+		let owner = owner.parse().map_err(|e| format!("invalid address `{owner}`: {e}"))?;
+		let color = match parts.next() {
+			Some(color) => serde_json::from_value(json!(color))
+				.map_err(|_| format!("unknown color `{color}`, expected e.g. `Red`"))?,
+			None => Color::Red,
+		};
+		let price = parts
+			.next()
+			.map(|price| price.parse().map_err(|e| format!("invalid price `{price}`: {e}")))
+			.transpose()?;
+		if parts.next().is_some() {
+			return Err(format!("`{s}` is not of the form ADDRESS[:COLOR[:PRICE]]"))
+		}
+		Ok(Self { owner, color, price })
+	}
+}
+
+impl ChainSpecBuilderCmd {
+	/// Run the command.
+	pub fn run<C: SubstrateCli>(&self, cli: &C) -> sc_cli::Result<()> {
+		let base = cli.load_spec(&self.chain)?.as_json(false)?;
+		let mut spec: Value = serde_json::from_str(&base).map_err(|e| e.to_string())?;
+		let genesis = spec
+			.pointer_mut("/genesis/runtimeGenesis/patch")
+			.ok_or("--chain must be a chain spec whose genesis is a runtime config patch")?;
+		self.apply(genesis);
+
+		let spec = serde_json::to_vec(&spec).map_err(|e| e.to_string())?;
+		let raw = ChainSpec::from_json_bytes(spec)?.as_json(true)?;
+		match &self.output {
+			Some(path) => std::fs::write(path, raw)?,
+			None => println!("{raw}"),
+		}
+		Ok(())
+	}
+
+	/// Replace the parts of the `genesis` config patch given on the command line.
+	fn apply(&self, genesis: &mut Value) {
+		if !self.authorities.is_empty() {
+			let keys = self
+				.authorities
+				.iter()
+				.map(|seed| authority_keys_from_seed(seed))
+				.collect::<Vec<_>>();
+			genesis["aura"]["authorities"] = json!(keys.iter().map(|k| &k.0).collect::<Vec<_>>());
+			genesis["grandpa"]["authorities"] =
+				json!(keys.iter().map(|k| (&k.1, 1)).collect::<Vec<_>>());
+		}
+		if !self.endowed.is_empty() {
+			let endowed = self.endowed.iter().map(|who| json!(who)).collect::<Vec<_>>();
+			genesis["balances"]["balances"] =
+				json!(endowed.iter().map(|who| (who, self.balance)).collect::<Vec<_>>());
+			// Vesting needs a balance to lock, so only endowed accounts keep their schedule.
+			if let Some(vesting) = genesis["vesting"]["vesting"].as_array_mut() {
+				vesting.retain(|schedule| endowed.contains(&schedule[0]));
+			}
+		}
+		if !self.collectibles.is_empty() {
+			let collectibles = self
+				.collectibles
+				.iter()
+				.map(|collectible| (&collectible.owner, collectible.color, collectible.price))
+				.collect::<Vec<_>>();
+			genesis["vulnModule"]["collectibles"] = json!(collectibles);
+		}
+	}
+}
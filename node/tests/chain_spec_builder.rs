@@ -0,0 +1,51 @@
+//! `chain-spec-builder` turning the local testnet spec into a raw spec with other authorities,
+//! endowed accounts and genesis collectibles.
+
+use codec::Encode;
+use sp_core::{
+	bytes::to_hex,
+	crypto::Ss58Codec,
+	hashing::{blake2_128, twox_128},
+};
+use sp_keyring::Sr25519Keyring::{Alice, Dave};
+use std::process::Command;
+
+fn hex(bytes: &[u8]) -> String {
+	to_hex(bytes, false)
+}
+
+#[test]
+fn builds_raw_specs_with_genesis_collectibles() {
+	let dir = tempfile::tempdir().unwrap();
+	let output = dir.path().join("spec.json");
+	let dave = Dave.to_account_id().to_ss58check();
+	let listed = format!("{dave}:Blue:1000");
+	let status = Command::new(env!("CARGO_BIN_EXE_node-template"))
+		.args(["chain-spec-builder", "--chain", "local", "--authority", "Alice"])
+		.args(["--endow", &dave, "--collectible", &dave, "--collectible", &listed, "--output"])
+		.arg(&output)
+		.status()
+		.expect("node binary runs");
+	assert!(status.success());
+
+	let spec: serde_json::Value =
+		serde_json::from_slice(&std::fs::read(output).unwrap()).unwrap();
+	let top = &spec["genesis"]["raw"]["top"];
+	let count = [twox_128(b"VulnModule"), twox_128(b"CollectiblesCount")].concat();
+	assert_eq!(top[hex(&count)], hex(&2u64.encode()));
+	let dave = Dave.to_account_id().encode();
+	let owned = [
+		&twox_128(b"VulnModule")[..],
+		&twox_128(b"OwnerOfCollectibles"),
+		&blake2_128(&dave),
+		&dave,
+	]
+	.concat();
+	assert_eq!(top[hex(&owned)], hex(&vec![0u64, 1].encode()));
+	// Only the second collectible was given a price.
+	let floor = [twox_128(b"VulnModule"), twox_128(b"FloorPrice")].concat();
+	assert_eq!(top[hex(&floor)], hex(&1000u128.encode()));
+
+	let authorities = [twox_128(b"Aura"), twox_128(b"Authorities")].concat();
+	assert_eq!(top[hex(&authorities)], hex(&vec![Alice.public()].encode()));
+}
@@ -0,0 +1,286 @@
+//! Harness for tests that run real `node-template` processes on the local testnet chain spec,
+//! where Alice and Bob are the Aura/GRANDPA authorities.
+
+use codec::{Decode, Encode};
+use jsonrpsee::{
+	core::client::ClientT,
+	http_client::{HttpClient, HttpClientBuilder},
+	rpc_params,
+};
+use node_template_runtime::{self as runtime, AccountId, RuntimeCall};
+use sp_core::{
+	crypto::Pair,
+	hashing::{blake2_128, twox_128},
+	Bytes, H256,
+};
+use sp_keyring::Sr25519Keyring;
+use sp_runtime::generic::Era;
+use std::{
+	fs::File,
+	future::Future,
+	net::TcpListener,
+	path::PathBuf,
+	process::{Child, Command, Stdio},
+	time::Duration,
+};
+use tempfile::TempDir;
+
+/// How long a node may take to come up, or the network to reach an expected state.
+pub const TIMEOUT: Duration = Duration::from_secs(180);
+
+pub struct Node {
+	pub name: &'static str,
+	base_path: PathBuf,
+	args: Vec<String>,
+	process: Option<Child>,
+	rpc_port: u16,
+	rpc: HttpClient,
+}
+
+impl Node {
+	/// Start `name` with its own database under `dir`. Authorities pass `--alice` or `--bob`
+	/// through `flags`.
+	fn start(dir: &TempDir, name: &'static str, node_key: u8, flags: &[String]) -> Node {
+		let base_path = dir.path().join(name);
+		let rpc_port = free_port();
+		let mut args = vec![
+			"--chain=local".to_string(),
+			format!("--base-path={}", base_path.display()),
+			format!("--port={}", free_port()),
+			format!("--rpc-port={rpc_port}"),
+			format!("--node-key={node_key:064x}"),
+			format!("--name={name}"),
+			"--no-telemetry".to_string(),
+			"--no-prometheus".to_string(),
+			"--no-mdns".to_string(),
+		];
+		args.extend_from_slice(flags);
+		let rpc = HttpClientBuilder::default()
+			.build(format!("http://127.0.0.1:{rpc_port}"))
+			.expect("the url is valid; qed");
+		let mut node = Node { name, base_path, args, process: None, rpc_port, rpc };
+		node.spawn();
+		node
+	}
+
+	fn spawn(&mut self) {
+		std::fs::create_dir_all(&self.base_path).unwrap();
+		let log = File::options()
+			.create(true)
+			.append(true)
+			.open(self.base_path.join("node.log"))
+			.unwrap();
+		let child = Command::new(env!("CARGO_BIN_EXE_node-template"))
+			.args(&self.args)
+			.stdout(Stdio::null())
+			.stderr(log)
+			.spawn()
+			.unwrap();
+		self.process = Some(child);
+	}
+
+	/// Kill the process without a clean shutdown, keeping its database.
+	pub fn kill(&mut self) {
+		if let Some(mut child) = self.process.take() {
+			let _ = child.kill();
+			let _ = child.wait();
+		}
+	}
+
+	/// Kill the node and start it again on the same database, ports and keys.
+	pub async fn restart(&mut self) {
+		self.kill();
+		self.spawn();
+		self.wait_ready().await;
+	}
+
+	pub async fn rpc<R: serde::de::DeserializeOwned>(
+		&self,
+		method: &str,
+		params: jsonrpsee::core::params::ArrayParams,
+	) -> R {
+		self.rpc.request(method, params).await.unwrap_or_else(|e| {
+			panic!("{} ({}): {method} failed: {e}", self.name, self.log_path().display())
+		})
+	}
+
+	pub async fn wait_ready(&self) {
+		let name = self.name;
+		let rpc_port = self.rpc_port;
+		eventually(&format!("{name} to serve RPC on port {rpc_port}"), || async {
+			self.rpc.request::<serde_json::Value, _>("system_health", rpc_params![]).await.is_ok()
+		})
+		.await;
+	}
+
+	pub async fn peer_id(&self) -> String {
+		self.rpc("system_localPeerId", rpc_params![]).await
+	}
+
+	pub async fn peers(&self) -> u64 {
+		let health: serde_json::Value = self.rpc("system_health", rpc_params![]).await;
+		health["peers"].as_u64().unwrap_or_default()
+	}
+
+	pub async fn finalized_number(&self) -> u64 {
+		let head: H256 = self.rpc("chain_getFinalizedHead", rpc_params![]).await;
+		let header: serde_json::Value = self.rpc("chain_getHeader", rpc_params![head]).await;
+		let number = header["number"].as_str().expect("header numbers are hex strings");
+		u64::from_str_radix(number.trim_start_matches("0x"), 16).unwrap()
+	}
+
+	/// The collectibles `who` owns in the latest finalized block.
+	pub async fn finalized_collectibles(&self, who: &AccountId) -> Vec<u64> {
+		let head: H256 = self.rpc("chain_getFinalizedHead", rpc_params![]).await;
+		let key = Bytes(owner_of_collectibles_key(who));
+		let value: Option<Bytes> = self.rpc("state_getStorage", rpc_params![key, head]).await;
+		value.map(|v| Vec::<u64>::decode(&mut &v[..]).unwrap()).unwrap_or_default()
+	}
+
+	/// Sign `call` as `signer` and submit it through this node.
+	pub async fn submit(&self, signer: Sr25519Keyring, call: impl Into<RuntimeCall>) -> H256 {
+		let account: AccountId = signer.to_account_id();
+		let nonce: u32 = self.rpc("system_accountNextIndex", rpc_params![account]).await;
+		let genesis: H256 = self.rpc("chain_getBlockHash", rpc_params![0]).await;
+		let xt = signed_extrinsic(signer, call.into(), nonce, genesis);
+		self.rpc("author_submitExtrinsic", rpc_params![Bytes(xt.encode())]).await
+	}
+
+	async fn bootnode(&self) -> String {
+		let port = self
+			.args
+			.iter()
+			.find_map(|arg| arg.strip_prefix("--port="))
+			.expect("every node is given a p2p port; qed");
+		format!("/ip4/127.0.0.1/tcp/{port}/p2p/{}", self.peer_id().await)
+	}
+
+	fn log_path(&self) -> PathBuf {
+		self.base_path.join("node.log")
+	}
+}
+
+impl Drop for Node {
+	fn drop(&mut self) {
+		self.kill();
+	}
+}
+
+/// Alice and Bob authoring and finalizing, and Charlie following as a plain full node.
+pub struct Network {
+	pub alice: Node,
+	pub bob: Node,
+	pub charlie: Node,
+	// Dropped last, after the nodes using it have been killed.
+	dir: TempDir,
+}
+
+impl Network {
+	pub async fn start() -> Network {
+		let dir = tempfile::tempdir().unwrap();
+		let alice = Node::start(&dir, "alice", 1, &["--alice".into()]);
+		alice.wait_ready().await;
+		let bootnode = format!("--bootnodes={}", alice.bootnode().await);
+		// Bob runs on the other database backend with small caches, so restarting him checks
+		// that those flags reach the client.
+		let bob_flags = [
+			"--bob".into(),
+			"--database=paritydb".into(),
+			"--db-cache=16".into(),
+			"--trie-cache-size=16777216".into(),
+			bootnode.clone(),
+		];
+		let bob = Node::start(&dir, "bob", 2, &bob_flags);
+		let charlie = Node::start(&dir, "charlie", 3, &[bootnode]);
+		bob.wait_ready().await;
+		charlie.wait_ready().await;
+		let network = Network { alice, bob, charlie, dir };
+		for node in network.nodes() {
+			eventually(&format!("{} to see both other nodes", node.name), || async {
+				node.peers().await >= 2
+			})
+			.await;
+		}
+		network
+	}
+
+	pub fn nodes(&self) -> [&Node; 3] {
+		[&self.alice, &self.bob, &self.charlie]
+	}
+
+	/// Start another plain full node that finds the network through Alice. Drop it before the
+	/// network.
+	pub async fn join(&self, name: &'static str, node_key: u8, flags: &[String]) -> Node {
+		let mut flags = flags.to_vec();
+		flags.push(format!("--bootnodes={}", self.alice.bootnode().await));
+		let node = Node::start(&self.dir, name, node_key, &flags);
+		node.wait_ready().await;
+		node
+	}
+}
+
+/// Poll `condition` until it holds, panicking after [`TIMEOUT`].
+pub async fn eventually<F, Fut>(what: &str, mut condition: F)
+where
+	F: FnMut() -> Fut,
+	Fut: Future<Output = bool>,
+{
+	let poll = async {
+		while !condition().await {
+			tokio::time::sleep(Duration::from_secs(1)).await;
+		}
+	};
+	if tokio::time::timeout(TIMEOUT, poll).await.is_err() {
+		panic!("timed out waiting for {what}");
+	}
+}
+
+fn free_port() -> u16 {
+	TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port()
+}
+
+pub fn owner_of_collectibles_key(who: &AccountId) -> Vec<u8> {
+	let account = who.encode();
+	let prefix = [twox_128(b"VulnModule"), twox_128(b"OwnerOfCollectibles")].concat();
+	[&prefix[..], &blake2_128(&account), &account].concat()
+}
+
+/// An immortal extrinsic, so it does not depend on which block the submitting node is at.
+fn signed_extrinsic(
+	signer: Sr25519Keyring,
+	call: RuntimeCall,
+	nonce: u32,
+	genesis: H256,
+) -> runtime::UncheckedExtrinsic {
+	let extra: runtime::SignedExtra = (
+		frame_system::CheckNonZeroSender::<runtime::Runtime>::new(),
+		frame_system::CheckSpecVersion::<runtime::Runtime>::new(),
+		frame_system::CheckTxVersion::<runtime::Runtime>::new(),
+		frame_system::CheckGenesis::<runtime::Runtime>::new(),
+		frame_system::CheckEra::<runtime::Runtime>::from(Era::Immortal),
+		frame_system::CheckNonce::<runtime::Runtime>::from(nonce),
+		frame_system::CheckWeight::<runtime::Runtime>::new(),
+		pallet_transaction_payment::ChargeTransactionPayment::<runtime::Runtime>::from(0),
+	);
+	let payload = runtime::SignedPayload::from_raw(
+		call.clone(),
+		extra.clone(),
+		(
+			(),
+			runtime::VERSION.spec_version,
+			runtime::VERSION.transaction_version,
+			genesis,
+			genesis,
+			(),
+			(),
+			(),
+		),
+	);
+	let signature = payload.using_encoded(|e| signer.pair().sign(e));
+	runtime::UncheckedExtrinsic::new_signed(
+		call,
+		signer.to_account_id().into(),
+		runtime::Signature::Sr25519(signature),
+		extra,
+	)
+}
@@ -0,0 +1,36 @@
+//! Fee estimates wallets show before asking for a signature.
+
+mod common;
+
+use codec::Encode;
+use common::Network;
+use jsonrpsee::rpc_params;
+use node_template_runtime::{vulntoken, Runtime, RuntimeCall};
+use serde_json::Value;
+use sp_core::Bytes;
+use sp_keyring::Sr25519Keyring::Dave;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn unsigned_calls_are_quoted_the_fee_they_will_pay() {
+	let network = Network::start().await;
+	let alice = &network.alice;
+	let call: RuntimeCall =
+		vulntoken::Call::<Runtime>::create_collectible { to: Dave.to_account_id() }.into();
+	let call = Bytes(call.encode());
+
+	let info: Value = alice.rpc("payment_queryCallInfo", rpc_params![&call, 150]).await;
+	let details: Value = alice.rpc("payment_queryCallFeeDetails", rpc_params![&call, 150]).await;
+	// Balances are too wide for JSON numbers, so the breakdown comes in hex.
+	let fee = |value: &Value| {
+		let hex = value.as_str().expect("balances are hex strings");
+		u128::from_str_radix(hex.trim_start_matches("0x"), 16).unwrap()
+	};
+	let partial_fee = info["partialFee"].as_str().unwrap().parse::<u128>().unwrap();
+	let inclusion = &details["inclusionFee"];
+	assert!(partial_fee > 0);
+	let parts = ["baseFee", "lenFee", "adjustedWeightFee"];
+	assert_eq!(partial_fee, parts.iter().map(|part| fee(&inclusion[*part])).sum::<u128>());
+	// A longer extrinsic pays a higher length fee.
+	let longer: Value = alice.rpc("payment_queryCallFeeDetails", rpc_params![&call, 300]).await;
+	assert!(fee(&longer["inclusionFee"]["lenFee"]) > fee(&inclusion["lenFee"]));
+}
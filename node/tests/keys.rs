@@ -0,0 +1,33 @@
+//! `key bootstrap` filling a keystore with the keys of a development account.
+
+use std::process::Command;
+
+fn bootstrap(validator: &str, base_path: &std::path::Path) -> bool {
+	Command::new(env!("CARGO_BIN_EXE_node-template"))
+		.args(["key", "bootstrap", "--chain", "local", "--validator", validator, "--base-path"])
+		.arg(base_path)
+		.status()
+		.expect("node binary runs")
+		.success()
+}
+
+#[test]
+fn bootstrap_inserts_aura_grandpa_and_pinning_keys() {
+	let base_path = tempfile::tempdir().unwrap();
+	assert!(bootstrap("alice", base_path.path()));
+
+	// Keystore files are named by the hex of the key type followed by the public key.
+	let keystore = base_path.path().join("chains/local_testnet/keystore");
+	let mut key_types = std::fs::read_dir(keystore)
+		.unwrap()
+		.map(|entry| entry.unwrap().file_name().to_string_lossy()[..8].to_owned())
+		.collect::<Vec<_>>();
+	key_types.sort();
+	assert_eq!(key_types, [hex("aura"), hex("gran"), hex("vtok")]);
+
+	assert!(!bootstrap("mallory", base_path.path()));
+}
+
+fn hex(key_type: &str) -> String {
+	key_type.bytes().map(|byte| format!("{byte:02x}")).collect()
+}
@@ -0,0 +1,41 @@
+//! Networking, consensus and the vulntoken pallet together: three nodes on the local testnet
+//! chain, one of them restarted mid-test.
+
+mod common;
+
+use common::{eventually, Network};
+use node_template_runtime::{vulntoken, Runtime};
+use sp_keyring::Sr25519Keyring::Dave;
+
+type VulnCall = vulntoken::Call<Runtime>;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn collectibles_propagate_finalize_and_survive_a_restart() {
+	let mut network = Network::start().await;
+	let dave = Dave.to_account_id();
+
+	// Charlie does not author, so the mint only lands if gossip carries it to an authority.
+	network.charlie.submit(Dave, VulnCall::create_collectible { to: dave.clone() }).await;
+	for node in network.nodes() {
+		eventually(&format!("{} to finalize the first mint", node.name), || async {
+			node.finalized_collectibles(&dave).await.len() == 1
+		})
+		.await;
+	}
+
+	// GRANDPA needs both authorities, so finality stalls while Bob is down and has to
+	// resume from his database once he is back.
+	let finalized_before = network.bob.finalized_number().await;
+	network.bob.restart().await;
+	assert!(network.bob.finalized_number().await >= finalized_before);
+	let bob = &network.bob;
+	eventually("bob to rejoin the network", || async { bob.peers().await >= 2 }).await;
+
+	bob.submit(Dave, VulnCall::create_collectible { to: dave.clone() }).await;
+	for node in network.nodes() {
+		eventually(&format!("{} to finalize the second mint", node.name), || async {
+			node.finalized_collectibles(&dave).await.len() == 2
+		})
+		.await;
+	}
+}
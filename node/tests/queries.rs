@@ -0,0 +1,37 @@
+//! Collectible queries frontends make over JSON-RPC instead of decoding storage themselves.
+
+mod common;
+
+use common::{eventually, Network};
+use jsonrpsee::rpc_params;
+use node_template_runtime::{vulntoken, Runtime};
+use serde_json::{json, Value};
+use sp_core::H256;
+use sp_keyring::Sr25519Keyring::Dave;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn collectibles_are_looked_up_by_id_owner_and_listing() {
+	let network = Network::start().await;
+	let alice = &network.alice;
+	let dave = Dave.to_account_id();
+	alice.submit(Dave, vulntoken::Call::<Runtime>::create_collectible { to: dave.clone() }).await;
+	eventually("dave to finalize the mint", || async {
+		alice.finalized_collectibles(&dave).await.len() == 1
+	})
+	.await;
+	let id = alice.finalized_collectibles(&dave).await[0];
+	let head: H256 = alice.rpc("chain_getFinalizedHead", rpc_params![]).await;
+
+	let owned: Value = alice.rpc("vulntoken_ownedCollectibles", rpc_params![&dave, head]).await;
+	assert_eq!(owned.as_array().unwrap().len(), 1);
+	assert_eq!(owned[0]["uniqueId"], json!(id));
+	assert_eq!(owned[0]["owner"], json!(dave));
+	let found: Value = alice.rpc("vulntoken_collectible", rpc_params![id, head]).await;
+	assert_eq!(found, owned[0]);
+	let missing: Value = alice.rpc("vulntoken_collectible", rpc_params![id + 1, head]).await;
+	assert_eq!(missing, Value::Null);
+	// Nothing was listed.
+	let for_sale: Value =
+		alice.rpc("vulntoken_collectiblesForSale", rpc_params![Value::Null, 10, head]).await;
+	assert_eq!(for_sale, json!([]));
+}
@@ -0,0 +1,85 @@
+//! The new JSON-RPC spec, which smoldot-based clients use to follow the chain and read storage,
+//! and the storage proofs light clients check collectible reads with.
+
+mod common;
+
+use codec::Decode;
+use common::{eventually, owner_of_collectibles_key, Network};
+use jsonrpsee::rpc_params;
+use node_template_runtime::{vulntoken, Runtime};
+use serde_json::{json, Value};
+use sp_core::{Bytes, H256};
+use sp_keyring::Sr25519Keyring::Dave;
+use sp_runtime::traits::BlakeTwo256;
+use sp_state_machine::{read_proof_check, StorageProof};
+
+type VulnCall = vulntoken::Call<Runtime>;
+
+fn serves(methods: &Value, method: &str) -> bool {
+	methods["methods"].as_array().unwrap().contains(&json!(method))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn archive_nodes_serve_collectible_storage_over_rpc_v2() {
+	let network = Network::start().await;
+	let flags = ["--state-pruning=archive".into(), "--blocks-pruning=archive".into()];
+	let archive = network.join("dave", 4, &flags).await;
+
+	let methods: Value = archive.rpc("rpc_methods", rpc_params![]).await;
+	let wanted = [
+		"chainHead_unstable_follow",
+		"chainHead_unstable_storage",
+		"archive_unstable_genesisHash",
+		"archive_unstable_storage",
+	];
+	for method in wanted {
+		assert!(serves(&methods, method), "{method} is not served");
+	}
+	// A node that prunes cannot answer for old blocks.
+	let pruned: Value = network.charlie.rpc("rpc_methods", rpc_params![]).await;
+	assert!(serves(&pruned, "chainHead_unstable_follow"));
+	assert!(!serves(&pruned, "archive_unstable_storage"));
+
+	let genesis: H256 = archive.rpc("chain_getBlockHash", rpc_params![0]).await;
+	let v2_genesis: H256 = archive.rpc("archive_unstable_genesisHash", rpc_params![]).await;
+	assert_eq!(v2_genesis, genesis);
+
+	let dave = Dave.to_account_id();
+	archive.submit(Dave, VulnCall::create_collectible { to: dave.clone() }).await;
+	eventually("dave to finalize the mint", || async {
+		archive.finalized_collectibles(&dave).await.len() == 1
+	})
+	.await;
+	let head: H256 = archive.rpc("chain_getFinalizedHead", rpc_params![]).await;
+	let items = json!([{ "key": Bytes(owner_of_collectibles_key(&dave)), "type": "value" }]);
+	let found: Value =
+		archive.rpc("archive_unstable_storage", rpc_params![head, items, Value::Null]).await;
+	let owned: Bytes = serde_json::from_value(found["result"][0]["value"].clone()).unwrap();
+	assert_eq!(Vec::<u64>::decode(&mut &owned[..]).unwrap().len(), 1);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn collectible_ownership_is_proven_against_the_state_root() {
+	let network = Network::start().await;
+	let alice = &network.alice;
+	let dave = Dave.to_account_id();
+	alice.submit(Dave, VulnCall::create_collectible { to: dave.clone() }).await;
+	eventually("dave to finalize the mint", || async {
+		alice.finalized_collectibles(&dave).await.len() == 1
+	})
+	.await;
+	let id = alice.finalized_collectibles(&dave).await[0];
+	let head: H256 = alice.rpc("chain_getFinalizedHead", rpc_params![]).await;
+	let header: Value = alice.rpc("chain_getHeader", rpc_params![head]).await;
+	let state_root: H256 = serde_json::from_value(header["stateRoot"].clone()).unwrap();
+
+	let proven: Value = alice.rpc("vulntoken_proveCollectible", rpc_params![id, head]).await;
+	let keys: Vec<Bytes> = serde_json::from_value(proven["keys"].clone()).unwrap();
+	let nodes: Vec<Bytes> = serde_json::from_value(proven["proof"].clone()).unwrap();
+	let proof = StorageProof::new(nodes.into_iter().map(|node| node.0));
+	let keys = keys.into_iter().map(|key| key.0).collect::<Vec<_>>();
+	let values = read_proof_check::<BlakeTwo256, _>(state_root, proof, &keys).unwrap();
+	let stored = values[&keys[0]].clone().expect("the collectible exists");
+	let collectible = vulntoken::Collectible::<Runtime>::decode(&mut &stored[..]).unwrap();
+	assert_eq!(collectible.owner, dave);
+}
@@ -0,0 +1,29 @@
+//! A node joining a running network late and warp syncing: it proves the finalized head from
+//! GRANDPA justifications and downloads the state there, instead of importing every block.
+
+mod common;
+
+use common::{eventually, Network};
+use node_template_runtime::{vulntoken, Runtime};
+use sp_keyring::Sr25519Keyring::Dave;
+
+type VulnCall = vulntoken::Call<Runtime>;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn late_joiners_warp_sync_to_the_finalized_state() {
+	let network = Network::start().await;
+	let dave = Dave.to_account_id();
+	network.alice.submit(Dave, VulnCall::create_collectible { to: dave.clone() }).await;
+	let alice = &network.alice;
+	eventually("alice to finalize the mint", || async {
+		alice.finalized_collectibles(&dave).await.len() == 1
+	})
+	.await;
+
+	// Warp sync starts once the joiner has a peer for each of the three running nodes.
+	let joiner = network.join("dave", 4, &["--sync=warp".into()]).await;
+	eventually("dave to warp sync past the mint", || async {
+		joiner.finalized_collectibles(&dave).await.len() == 1
+	})
+	.await;
+}
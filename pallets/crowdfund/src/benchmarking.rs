@@ -0,0 +1,103 @@
+//! Benchmarking setup for pallet-crowdfund
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+use frame_benchmarking::v2::*;
+use frame_support::traits::{
+	fungible::{Inspect, Mutate},
+	Get,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Saturating;
+use vulntoken::Marketplace;
+
+/// The smallest contribution, and at least the existential deposit.
+fn unit<T: Config>() -> BalanceOf<T> {
+	T::MinContribution::get().max(T::Currency::minimum_balance())
+}
+
+/// An item price of ten [`unit`]s.
+fn price<T: Config>() -> BalanceOf<T> {
+	unit::<T>().saturating_mul(10u32.into())
+}
+
+/// An account with a hundred [`unit`]s.
+fn funded<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let who = account(name, index, 0);
+	T::Currency::set_balance(&who, unit::<T>().saturating_mul(100u32.into()));
+	who
+}
+
+/// A campaign running for `MaxDuration` towards an item listed at [`price`], with a first
+/// contribution of one [`unit`].
+fn campaign<T: Config>() -> (T::AccountId, u64) {
+	let price = price::<T>();
+	let seller = funded::<T>("seller", 0);
+	let collectible = T::Marketplace::list(&seller, price);
+	let creator = funded::<T>("creator", 0);
+	Pallet::<T>::create_campaign(
+		RawOrigin::Signed(creator).into(),
+		collectible,
+		T::MaxDuration::get(),
+	)
+	.unwrap();
+	let contributor = funded::<T>("contributor", 0);
+	Pallet::<T>::contribute(RawOrigin::Signed(contributor.clone()).into(), 0, unit::<T>()).unwrap();
+	(contributor, collectible)
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn create_campaign() {
+		let collectible = T::Marketplace::list(&funded::<T>("seller", 0), price::<T>());
+		let caller = funded::<T>("creator", 0);
+
+		#[extrinsic_call]
+		create_campaign(RawOrigin::Signed(caller), collectible, T::MaxDuration::get());
+
+		assert!(Campaigns::<T>::contains_key(0));
+	}
+
+	// The worst case is a contributor's first contribution.
+	#[benchmark]
+	fn contribute() {
+		campaign::<T>();
+		let caller = funded::<T>("contributor", 1);
+		let amount = unit::<T>();
+
+		#[extrinsic_call]
+		contribute(RawOrigin::Signed(caller.clone()), 0, amount);
+
+		assert_eq!(Contributions::<T>::get(0, &caller), amount);
+	}
+
+	#[benchmark]
+	fn purchase() {
+		let (contributor, collectible) = campaign::<T>();
+		Pallet::<T>::contribute(RawOrigin::Signed(contributor.clone()).into(), 0, price::<T>())
+			.unwrap();
+
+		#[extrinsic_call]
+		purchase(RawOrigin::Signed(contributor), 0);
+
+		assert_eq!(T::Marketplace::owner(collectible), Some(Pallet::<T>::pool_account(0)));
+	}
+
+	// The worst case refunds the last contributor and closes the campaign.
+	#[benchmark]
+	fn refund() {
+		let (contributor, _) = campaign::<T>();
+		let end = Campaigns::<T>::get(0).unwrap().end;
+		frame_system::Pallet::<T>::set_block_number(end);
+
+		#[extrinsic_call]
+		refund(RawOrigin::Signed(contributor.clone()), 0, contributor.clone());
+
+		assert!(!Campaigns::<T>::contains_key(0));
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,326 @@
+//! # Crowdfund
+//!
+//! Group purchases of marketplace collectibles. A campaign targets one listed collectible and
+//! pools contributions in an account of its own until they cover the listing price; anybody
+//! can then buy the item for the pool, which keeps it and records each contributor's share.
+//! If the campaign ends before the item is bought, every contributor is refunded.
+//!
+//! The creator seeds the pool with the existential deposit, which keeps the pool account alive
+//! to own the item, and gets it back with the last refund of a failed campaign.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			fungible::{Inspect, Mutate},
+			tokens::Preservation,
+		},
+		PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::{
+		traits::{AccountIdConversion, Saturating, Zero},
+		Perbill,
+	};
+	use vulntoken::Marketplace;
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub type CampaignId = u32;
+
+	/// A group purchase of a single collectible.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Campaign<T: Config> {
+		pub creator: T::AccountId,
+		pub collectible: u64,
+		/// The listing price when the campaign started. Contributions stop here.
+		pub target: BalanceOf<T>,
+		/// Contributed so far.
+		pub raised: BalanceOf<T>,
+		/// Contributions and the purchase are accepted strictly before this block.
+		pub end: BlockNumberFor<T>,
+		/// Number of accounts with a contribution on record.
+		pub contributors: u32,
+		/// Whether the pool bought the item.
+		pub bought: bool,
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		type Currency: Mutate<Self::AccountId>;
+
+		/// Where campaigns buy their collectible.
+		type Marketplace: Marketplace<Self::AccountId, BalanceOf<Self>>;
+
+		/// Derives the pool account of each campaign.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Longest a campaign can run.
+		#[pallet::constant]
+		type MaxDuration: Get<BlockNumberFor<Self>>;
+
+		/// Smallest contribution accepted, unless it completes the target.
+		#[pallet::constant]
+		type MinContribution: Get<BalanceOf<Self>>;
+
+		/// A type representing the weights required by the dispatchables of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The id the next campaign is created with.
+	#[pallet::storage]
+	pub type NextCampaignId<T> = StorageValue<_, CampaignId, ValueQuery>;
+
+	/// Campaigns by id. Bought campaigns stay, as the record of the pool's item.
+	#[pallet::storage]
+	pub type Campaigns<T: Config> = StorageMap<_, Twox64Concat, CampaignId, Campaign<T>>;
+
+	/// What each account contributed to a campaign. Once the item is bought, these are the
+	/// contributors' shares of it.
+	#[pallet::storage]
+	pub type Contributions<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		CampaignId,
+		Blake2_128Concat,
+		T::AccountId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		CampaignCreated {
+			id: CampaignId,
+			creator: T::AccountId,
+			collectible: u64,
+			target: BalanceOf<T>,
+			end: BlockNumberFor<T>,
+		},
+		Contributed {
+			id: CampaignId,
+			who: T::AccountId,
+			amount: BalanceOf<T>,
+			raised: BalanceOf<T>,
+		},
+		/// The pool bought the collectible.
+		CampaignSucceeded { id: CampaignId, collectible: u64, price: BalanceOf<T> },
+		Refunded { id: CampaignId, who: T::AccountId, amount: BalanceOf<T> },
+		/// Every contributor of a failed campaign was refunded.
+		CampaignClosed { id: CampaignId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The collectible is not listed for sale.
+		NotForSale,
+		/// Campaigns must run for at least one block and at most `MaxDuration`.
+		InvalidDuration,
+		/// There is no campaign with this id.
+		NoCampaign,
+		/// The campaign's deadline has passed.
+		CampaignEnded,
+		/// The campaign is still running.
+		CampaignRunning,
+		/// The pool already bought the item.
+		AlreadyBought,
+		/// The campaign has raised its target.
+		FullyFunded,
+		/// The contribution is below `MinContribution`.
+		ContributionTooLow,
+		/// Not enough was raised to buy the item at its current price.
+		NotFunded,
+		/// The account has no contribution to refund.
+		NoContribution,
+		/// Campaign ids are exhausted.
+		Overflow,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Start raising the listing price of `collectible` for `duration` blocks, seeding the
+		/// pool with the existential deposit.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::create_campaign())]
+		pub fn create_campaign(
+			origin: OriginFor<T>,
+			collectible: u64,
+			duration: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let creator = ensure_signed(origin)?;
+			ensure!(
+				!duration.is_zero() && duration <= T::MaxDuration::get(),
+				Error::<T>::InvalidDuration
+			);
+			let target = T::Marketplace::price(collectible).ok_or(Error::<T>::NotForSale)?;
+			let id = NextCampaignId::<T>::get();
+			NextCampaignId::<T>::put(id.checked_add(1).ok_or(Error::<T>::Overflow)?);
+
+			T::Currency::transfer(
+				&creator,
+				&Self::pool_account(id),
+				T::Currency::minimum_balance(),
+				Preservation::Preserve,
+			)?;
+			let end = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+			Campaigns::<T>::insert(
+				id,
+				Campaign {
+					creator: creator.clone(),
+					collectible,
+					target,
+					raised: Zero::zero(),
+					end,
+					contributors: 0,
+					bought: false,
+				},
+			);
+			Self::deposit_event(Event::CampaignCreated { id, creator, collectible, target, end });
+			Ok(())
+		}
+
+		/// Contribute to a running campaign. Contributions past the target are cut down to it.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::contribute())]
+		pub fn contribute(
+			origin: OriginFor<T>,
+			id: CampaignId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut campaign = Self::running(id)?;
+			let missing = campaign.target.saturating_sub(campaign.raised);
+			ensure!(!missing.is_zero(), Error::<T>::FullyFunded);
+			let amount = amount.min(missing);
+			ensure!(
+				amount == missing || amount >= T::MinContribution::get(),
+				Error::<T>::ContributionTooLow
+			);
+
+			T::Currency::transfer(&who, &Self::pool_account(id), amount, Preservation::Preserve)?;
+			Contributions::<T>::mutate(id, &who, |contribution| {
+				if contribution.is_zero() {
+					campaign.contributors.saturating_inc();
+				}
+				contribution.saturating_accrue(amount);
+			});
+			campaign.raised.saturating_accrue(amount);
+			let raised = campaign.raised;
+			Campaigns::<T>::insert(id, campaign);
+			Self::deposit_event(Event::Contributed { id, who, amount, raised });
+			Ok(())
+		}
+
+		/// Buy the campaign's collectible for the pool, once enough was raised. Callable by
+		/// anyone. Anything raised above the price stays in the pool.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::purchase())]
+		pub fn purchase(origin: OriginFor<T>, id: CampaignId) -> DispatchResult {
+			ensure_signed(origin)?;
+			let mut campaign = Self::running(id)?;
+			let price =
+				T::Marketplace::price(campaign.collectible).ok_or(Error::<T>::NotForSale)?;
+			ensure!(price <= campaign.raised, Error::<T>::NotFunded);
+
+			T::Marketplace::buy(campaign.collectible, &Self::pool_account(id))?;
+			campaign.bought = true;
+			let collectible = campaign.collectible;
+			Campaigns::<T>::insert(id, campaign);
+			Self::deposit_event(Event::CampaignSucceeded { id, collectible, price });
+			Ok(())
+		}
+
+		/// Refund a contributor of a campaign that ended without buying its item. Callable by
+		/// anyone. The last refund returns the creator's seed and closes the campaign.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::refund())]
+		pub fn refund(
+			origin: OriginFor<T>,
+			id: CampaignId,
+			contributor: T::AccountId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let mut campaign = Campaigns::<T>::get(id).ok_or(Error::<T>::NoCampaign)?;
+			ensure!(!campaign.bought, Error::<T>::AlreadyBought);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= campaign.end,
+				Error::<T>::CampaignRunning
+			);
+			let amount = Contributions::<T>::take(id, &contributor);
+			// A campaign nobody contributed to closes on any refund.
+			ensure!(
+				!amount.is_zero() || campaign.contributors == 0,
+				Error::<T>::NoContribution
+			);
+
+			let pool = Self::pool_account(id);
+			if !amount.is_zero() {
+				T::Currency::transfer(&pool, &contributor, amount, Preservation::Expendable)?;
+				campaign.contributors.saturating_dec();
+				campaign.raised.saturating_reduce(amount);
+				Self::deposit_event(Event::Refunded { id, who: contributor, amount });
+			}
+
+			if campaign.contributors == 0 {
+				let seed = T::Currency::balance(&pool);
+				T::Currency::transfer(&pool, &campaign.creator, seed, Preservation::Expendable)?;
+				Campaigns::<T>::remove(id);
+				Self::deposit_event(Event::CampaignClosed { id });
+			} else {
+				Campaigns::<T>::insert(id, campaign);
+			}
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The account a campaign pools its contributions in, and that owns the item it buys.
+		pub fn pool_account(id: CampaignId) -> T::AccountId {
+			T::PalletId::get().into_sub_account_truncating(id)
+		}
+
+		/// A contributor's share of the item a campaign bought.
+		pub fn share_of(id: CampaignId, who: &T::AccountId) -> Option<Perbill> {
+			let campaign = Campaigns::<T>::get(id).filter(|campaign| campaign.bought)?;
+			let contribution = Contributions::<T>::get(id, who);
+			Some(Perbill::from_rational(contribution, campaign.raised))
+		}
+
+		/// The campaign, if it can still take contributions or buy its item.
+		fn running(id: CampaignId) -> Result<Campaign<T>, DispatchError> {
+			let campaign = Campaigns::<T>::get(id).ok_or(Error::<T>::NoCampaign)?;
+			ensure!(!campaign.bought, Error::<T>::AlreadyBought);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() < campaign.end,
+				Error::<T>::CampaignEnded
+			);
+			Ok(campaign)
+		}
+	}
+}
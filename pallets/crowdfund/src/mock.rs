@@ -0,0 +1,129 @@
+use crate as pallet_crowdfund;
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{fungible::Mutate, tokens::Preservation, ConstU16, ConstU32, ConstU64},
+	PalletId,
+};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage, DispatchError, DispatchResult,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Crowdfund: pallet_crowdfund,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ConstU16<42>;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = ();
+	type RuntimeFreezeReason = ();
+}
+
+parameter_types! {
+	pub const CrowdfundPalletId: PalletId = PalletId(*b"vn/crowd");
+	/// Listed items as `(id, owner, price)`.
+	pub static Listings: Vec<(u64, u64, Option<u64>)> = vec![(0, 9, Some(100))];
+}
+
+/// A marketplace selling the items in [`Listings`].
+pub struct TestMarketplace;
+impl vulntoken::Marketplace<u64, u64> for TestMarketplace {
+	fn price(unique_id: u64) -> Option<u64> {
+		Listings::get().into_iter().find(|(id, ..)| *id == unique_id)?.2
+	}
+
+	fn buy(unique_id: u64, buyer: &u64) -> DispatchResult {
+		let mut listings = Listings::get();
+		let (_, owner, price) = listings
+			.iter_mut()
+			.find(|(id, ..)| *id == unique_id)
+			.ok_or(DispatchError::Other("no such item"))?;
+		let amount = price.take().ok_or(DispatchError::Other("not for sale"))?;
+		Balances::transfer(buyer, owner, amount, Preservation::Preserve)?;
+		*owner = *buyer;
+		Listings::set(listings);
+		Ok(())
+	}
+
+	fn owner(unique_id: u64) -> Option<u64> {
+		Listings::get().into_iter().find(|(id, ..)| *id == unique_id).map(|(_, owner, _)| owner)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn list(seller: &u64, price: u64) -> u64 {
+		let mut listings = Listings::get();
+		let unique_id = listings.len() as u64;
+		listings.push((unique_id, *seller, Some(price)));
+		Listings::set(listings);
+		unique_id
+	}
+}
+
+impl pallet_crowdfund::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type Marketplace = TestMarketplace;
+	type PalletId = CrowdfundPalletId;
+	type MaxDuration = ConstU64<10>;
+	type MinContribution = ConstU64<10>;
+	type WeightInfo = ();
+}
+
+/// Accounts 1 to 3 start with 100 each. Item 0 is listed by account 9 at 100.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(1, 100), (2, 100), (3, 100)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	let mut ext: sp_io::TestExternalities = t.into();
+	// Go past genesis block so events get deposited.
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
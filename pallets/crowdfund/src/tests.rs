@@ -0,0 +1,94 @@
+use crate::{mock::*, Campaigns, Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::Perbill;
+use vulntoken::Marketplace;
+
+#[test]
+fn pool_buys_the_item_once_funded() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Crowdfund::create_campaign(RuntimeOrigin::signed(1), 0, 0),
+			Error::<Test>::InvalidDuration
+		);
+		assert_noop!(
+			Crowdfund::create_campaign(RuntimeOrigin::signed(1), 0, 11),
+			Error::<Test>::InvalidDuration
+		);
+		assert_noop!(
+			Crowdfund::create_campaign(RuntimeOrigin::signed(1), 1, 5),
+			Error::<Test>::NotForSale
+		);
+		assert_ok!(Crowdfund::create_campaign(RuntimeOrigin::signed(1), 0, 5));
+		System::assert_last_event(
+			Event::CampaignCreated { id: 0, creator: 1, collectible: 0, target: 100, end: 6 }
+				.into(),
+		);
+		let pool = Crowdfund::pool_account(0);
+		assert_eq!(Balances::free_balance(pool), 1);
+
+		assert_noop!(
+			Crowdfund::contribute(RuntimeOrigin::signed(2), 0, 5),
+			Error::<Test>::ContributionTooLow
+		);
+		assert_ok!(Crowdfund::contribute(RuntimeOrigin::signed(1), 0, 60));
+		assert_noop!(Crowdfund::purchase(RuntimeOrigin::signed(3), 0), Error::<Test>::NotFunded);
+		// Only what is missing from the target is taken.
+		assert_ok!(Crowdfund::contribute(RuntimeOrigin::signed(2), 0, 60));
+		System::assert_last_event(
+			Event::Contributed { id: 0, who: 2, amount: 40, raised: 100 }.into(),
+		);
+		assert_eq!(Balances::free_balance(2), 60);
+		assert_noop!(
+			Crowdfund::contribute(RuntimeOrigin::signed(3), 0, 10),
+			Error::<Test>::FullyFunded
+		);
+
+		assert_ok!(Crowdfund::purchase(RuntimeOrigin::signed(3), 0));
+		System::assert_last_event(
+			Event::CampaignSucceeded { id: 0, collectible: 0, price: 100 }.into(),
+		);
+		assert_eq!(TestMarketplace::owner(0), Some(pool));
+		assert_eq!(Balances::free_balance(9), 100);
+		assert_eq!(Balances::free_balance(pool), 1);
+		assert_eq!(Crowdfund::share_of(0, &1), Some(Perbill::from_percent(60)));
+		assert_eq!(Crowdfund::share_of(0, &2), Some(Perbill::from_percent(40)));
+		assert_noop!(
+			Crowdfund::refund(RuntimeOrigin::signed(1), 0, 1),
+			Error::<Test>::AlreadyBought
+		);
+	});
+}
+
+#[test]
+fn failed_campaign_refunds_contributors() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Crowdfund::create_campaign(RuntimeOrigin::signed(1), 0, 5));
+		assert_ok!(Crowdfund::contribute(RuntimeOrigin::signed(2), 0, 30));
+		assert_ok!(Crowdfund::contribute(RuntimeOrigin::signed(3), 0, 20));
+		assert_noop!(
+			Crowdfund::refund(RuntimeOrigin::signed(2), 0, 2),
+			Error::<Test>::CampaignRunning
+		);
+
+		System::set_block_number(6);
+		assert_noop!(
+			Crowdfund::contribute(RuntimeOrigin::signed(2), 0, 50),
+			Error::<Test>::CampaignEnded
+		);
+		assert_ok!(Crowdfund::refund(RuntimeOrigin::signed(1), 0, 2));
+		System::assert_last_event(Event::Refunded { id: 0, who: 2, amount: 30 }.into());
+		assert_eq!(Balances::free_balance(2), 100);
+		assert_noop!(
+			Crowdfund::refund(RuntimeOrigin::signed(1), 0, 2),
+			Error::<Test>::NoContribution
+		);
+
+		// The last refund returns the creator's seed.
+		assert_ok!(Crowdfund::refund(RuntimeOrigin::signed(1), 0, 3));
+		System::assert_last_event(Event::CampaignClosed { id: 0 }.into());
+		assert_eq!(Balances::free_balance(3), 100);
+		assert_eq!(Balances::free_balance(1), 100);
+		assert_eq!(Balances::free_balance(Crowdfund::pool_account(0)), 0);
+		assert!(Campaigns::<Test>::get(0).is_none());
+	});
+}
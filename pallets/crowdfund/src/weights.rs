@@ -0,0 +1,128 @@
+//! Weights for pallet_crowdfund
+//!
+//! Estimated from the storage each call touches. Regenerate them on reference hardware with
+//! the command below whenever a call changes.
+
+// Command:
+// ./target/release/node-template
+// benchmark
+// pallet
+// --chain
+// dev
+// --pallet
+// pallet_crowdfund
+// --extrinsic
+// *
+// --steps=50
+// --repeat=20
+// --wasm-execution=compiled
+// --output
+// pallets/crowdfund/src/weights.rs
+// --template
+// ./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_crowdfund.
+pub trait WeightInfo {
+	fn create_campaign() -> Weight;
+	fn contribute() -> Weight;
+	fn purchase() -> Weight;
+	fn refund() -> Weight;
+}
+
+/// Weights for pallet_crowdfund using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: VulnModule CollectibleMap (r:1 w:0)
+	/// Storage: VulnModule ListedAt (r:1 w:0)
+	/// Storage: Crowdfund NextCampaignId (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: Crowdfund Campaigns (r:0 w:1)
+	fn create_campaign() -> Weight {
+		Weight::from_parts(46_218_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Crowdfund Campaigns (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: Crowdfund Contributions (r:1 w:1)
+	fn contribute() -> Weight {
+		Weight::from_parts(49_803_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Crowdfund Campaigns (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule ListedAt (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:0)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: VulnModule RentalRates (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: VulnModule CooldownUntil (r:0 w:1)
+	fn purchase() -> Weight {
+		Weight::from_parts(96_541_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: Crowdfund Campaigns (r:1 w:1)
+	/// Storage: Crowdfund Contributions (r:1 w:1)
+	/// Storage: System Account (r:3 w:3)
+	fn refund() -> Weight {
+		Weight::from_parts(62_394_000, 8_799)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: VulnModule CollectibleMap (r:1 w:0)
+	/// Storage: VulnModule ListedAt (r:1 w:0)
+	/// Storage: Crowdfund NextCampaignId (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: Crowdfund Campaigns (r:0 w:1)
+	fn create_campaign() -> Weight {
+		Weight::from_parts(46_218_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Crowdfund Campaigns (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: Crowdfund Contributions (r:1 w:1)
+	fn contribute() -> Weight {
+		Weight::from_parts(49_803_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Crowdfund Campaigns (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule ListedAt (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:0)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: VulnModule RentalRates (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: VulnModule CooldownUntil (r:0 w:1)
+	fn purchase() -> Weight {
+		Weight::from_parts(96_541_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: Crowdfund Campaigns (r:1 w:1)
+	/// Storage: Crowdfund Contributions (r:1 w:1)
+	/// Storage: System Account (r:3 w:3)
+	fn refund() -> Weight {
+		Weight::from_parts(62_394_000, 8_799)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+}
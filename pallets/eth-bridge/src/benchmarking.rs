@@ -0,0 +1,44 @@
+//! Benchmarking setup for pallet-eth-bridge
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+use frame_benchmarking::v2::*;
+use frame_support::BoundedVec;
+use frame_system::RawOrigin;
+use sp_core::H160;
+use vulntoken::Custody;
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn lock() {
+		let sender: T::AccountId = whitelisted_caller();
+		let collectible = T::Collectibles::mint(&sender);
+
+		#[extrinsic_call]
+		lock(RawOrigin::Signed(sender), collectible, H160::repeat_byte(1));
+
+		let custody = Pallet::<T>::custody_account(collectible);
+		assert_eq!(T::Collectibles::owner(collectible), Some(custody));
+	}
+
+	#[benchmark]
+	fn release() {
+		let sender: T::AccountId = account("sender", 0, 0);
+		let collectible = T::Collectibles::mint(&sender);
+		Pallet::<T>::lock(RawOrigin::Signed(sender.clone()).into(), collectible, H160::zero())
+			.unwrap();
+		let relayer: T::AccountId = whitelisted_caller();
+		T::Verifier::trust(&relayer);
+		let message = InboundMessage { nonce: 0, collectible, recipient: sender.clone() };
+
+		#[extrinsic_call]
+		release(RawOrigin::Signed(relayer), message, BoundedVec::new());
+
+		assert_eq!(T::Collectibles::owner(collectible), Some(sender));
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
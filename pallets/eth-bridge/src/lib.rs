@@ -0,0 +1,231 @@
+//! # Ethereum bridge
+//!
+//! Lock-and-mint bridging of vulntoken collectibles to Ethereum. `lock` moves a collectible into
+//! a custody account of its own and records an outbound message, which relayers carry to the
+//! bridge contract so it mints a wrapped counterpart to the Ethereum recipient. Burning the
+//! wrapped token there produces an inbound message; a relayer submits it with `release`, and
+//! the collectible leaves custody for the account the message names.
+//!
+//! The pallet does not decide whom to believe. `Verifier` checks every inbound message and its
+//! proof: [`TrustedRelayers`] accepts whatever a set of relayers submits, and [`LightClient`]
+//! accepts what is proven against Ethereum state an on-chain light client vouches for, whoever
+//! relays it. Either way, each inbound nonce is accepted once.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+use codec::Encode;
+use frame_support::traits::SortedMembers;
+use sp_std::marker::PhantomData;
+
+/// Decides whether an inbound message really comes from the bridge contract.
+pub trait VerifyInbound<AccountId> {
+	/// Whether to accept `message`, submitted by `relayer` with `proof`.
+	fn verify(relayer: &AccountId, message: &InboundMessage<AccountId>, proof: &[u8]) -> bool;
+
+	/// Make `relayer`'s submissions pass [`Self::verify`], so benchmarks can reach a release.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn trust(_relayer: &AccountId) {}
+}
+
+/// Accepts any message a member of `Relayers` submits, without looking at the proof.
+pub struct TrustedRelayers<Relayers>(PhantomData<Relayers>);
+impl<AccountId: Ord, Relayers: SortedMembers<AccountId>> VerifyInbound<AccountId>
+	for TrustedRelayers<Relayers>
+{
+	fn verify(relayer: &AccountId, _message: &InboundMessage<AccountId>, _proof: &[u8]) -> bool {
+		Relayers::contains(relayer)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn trust(relayer: &AccountId) {
+		Relayers::add(relayer);
+	}
+}
+
+/// An on-chain client of Ethereum's consensus, following its finalized state.
+pub trait EthereumLightClient {
+	/// Whether `proof` shows the bridge contract emitted `payload` in a finalized block.
+	fn verify_event(payload: &[u8], proof: &[u8]) -> bool;
+}
+
+/// Accepts messages whose SCALE encoding `Client` finds emitted by the bridge contract, whoever
+/// relays them.
+pub struct LightClient<Client>(PhantomData<Client>);
+impl<AccountId: Encode, Client: EthereumLightClient> VerifyInbound<AccountId>
+	for LightClient<Client>
+{
+	fn verify(_relayer: &AccountId, message: &InboundMessage<AccountId>, proof: &[u8]) -> bool {
+		Client::verify_event(&message.encode(), proof)
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{pallet_prelude::*, PalletId};
+	use frame_system::pallet_prelude::*;
+	use sp_core::H160;
+	use sp_runtime::traits::AccountIdConversion;
+	use vulntoken::Custody;
+
+	/// Numbers outbound messages, and, in the bridge contract's own sequence, inbound ones.
+	pub type Nonce = u64;
+
+	/// A collectible locked here, to be minted as a wrapped token on Ethereum.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct OutboundMessage<AccountId> {
+		pub collectible: u64,
+		/// The account that locked it.
+		pub sender: AccountId,
+		/// The Ethereum address the wrapped token is minted to.
+		pub recipient: H160,
+	}
+
+	/// A wrapped token burned on Ethereum, releasing its collectible here.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct InboundMessage<AccountId> {
+		/// The bridge contract's nonce for the burn, which no other inbound message shares.
+		pub nonce: Nonce,
+		pub collectible: u64,
+		/// The account the collectible is released to.
+		pub recipient: AccountId,
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Where the collectibles being bridged live.
+		type Collectibles: Custody<Self::AccountId>;
+
+		/// Decides which inbound messages to accept.
+		type Verifier: VerifyInbound<Self::AccountId>;
+
+		/// Derives the custody account of each locked collectible.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Longest proof a relayer can submit with an inbound message.
+		#[pallet::constant]
+		type MaxProofLength: Get<u32>;
+
+		/// A type representing the weights required by the dispatchables of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The nonce the next outbound message gets.
+	#[pallet::storage]
+	pub type OutboundNonce<T> = StorageValue<_, Nonce, ValueQuery>;
+
+	/// The outbound message of each collectible in custody, by nonce, for relayers to prove on
+	/// Ethereum. Removed when the collectible is released.
+	#[pallet::storage]
+	pub type OutboundMessages<T: Config> =
+		StorageMap<_, Twox64Concat, Nonce, OutboundMessage<T::AccountId>>;
+
+	/// The nonce of the outbound message of each collectible in custody.
+	#[pallet::storage]
+	pub type InCustody<T> = StorageMap<_, Twox64Concat, u64, Nonce>;
+
+	/// Nonces of the inbound messages already accepted.
+	#[pallet::storage]
+	pub type ProcessedInbound<T> = StorageMap<_, Twox64Concat, Nonce, ()>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// `sender` locked `collectible` to have it minted to `recipient` on Ethereum.
+		Locked { nonce: Nonce, collectible: u64, sender: T::AccountId, recipient: H160 },
+		/// `relayer` proved the wrapped token of `collectible` burned, releasing it to
+		/// `recipient`.
+		Released { nonce: Nonce, collectible: u64, recipient: T::AccountId, relayer: T::AccountId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The collectible does not exist or belongs to somebody else.
+		NotOwner,
+		/// The collectible is not in the bridge's custody.
+		NotLocked,
+		/// The inbound message was accepted before.
+		AlreadyProcessed,
+		/// `Verifier` rejected the inbound message.
+		Unverified,
+		/// Outbound nonces are exhausted.
+		Overflow,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Lock `collectible` in custody, to have a wrapped counterpart minted to `recipient` on
+		/// Ethereum.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::lock())]
+		pub fn lock(origin: OriginFor<T>, collectible: u64, recipient: H160) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(
+				T::Collectibles::owner(collectible) == Some(sender.clone()),
+				Error::<T>::NotOwner
+			);
+			let nonce = OutboundNonce::<T>::get();
+			OutboundNonce::<T>::put(nonce.checked_add(1).ok_or(Error::<T>::Overflow)?);
+
+			T::Collectibles::transfer(collectible, &sender, &Self::custody_account(collectible))?;
+			InCustody::<T>::insert(collectible, nonce);
+			let message = OutboundMessage { collectible, sender: sender.clone(), recipient };
+			OutboundMessages::<T>::insert(nonce, message);
+			Self::deposit_event(Event::Locked { nonce, collectible, sender, recipient });
+			Ok(())
+		}
+
+		/// Release a collectible from custody for a wrapped token burned on Ethereum, if
+		/// `Verifier` accepts `message` and `proof` from the caller.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::release())]
+		pub fn release(
+			origin: OriginFor<T>,
+			message: InboundMessage<T::AccountId>,
+			proof: BoundedVec<u8, T::MaxProofLength>,
+		) -> DispatchResult {
+			let relayer = ensure_signed(origin)?;
+			ensure!(
+				!ProcessedInbound::<T>::contains_key(message.nonce),
+				Error::<T>::AlreadyProcessed
+			);
+			ensure!(T::Verifier::verify(&relayer, &message, &proof), Error::<T>::Unverified);
+			let outbound = InCustody::<T>::take(message.collectible).ok_or(Error::<T>::NotLocked)?;
+
+			let InboundMessage { nonce, collectible, recipient } = message;
+			let custody = Self::custody_account(collectible);
+			T::Collectibles::transfer(collectible, &custody, &recipient)?;
+			OutboundMessages::<T>::remove(outbound);
+			ProcessedInbound::<T>::insert(nonce, ());
+			Self::deposit_event(Event::Released { nonce, collectible, recipient, relayer });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The account holding `collectible` while it is bridged. Each collectible has its own, so
+		/// custody is not bound by how many collectibles one account can own.
+		pub fn custody_account(collectible: u64) -> T::AccountId {
+			T::PalletId::get().into_sub_account_truncating(collectible)
+		}
+	}
+}
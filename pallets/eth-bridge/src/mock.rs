@@ -0,0 +1,117 @@
+use crate as pallet_eth_bridge;
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ConstU16, ConstU32, ConstU64, SortedMembers},
+	PalletId,
+};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage, DispatchError, DispatchResult,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		EthBridge: pallet_eth_bridge,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ConstU16<42>;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+	pub const EthBridgePalletId: PalletId = PalletId(*b"vn/ethbr");
+	/// Collectibles as `(id, owner)`.
+	pub static Items: Vec<(u64, u64)> = vec![(0, 1), (1, 2)];
+	pub static RelayerList: Vec<u64> = vec![5];
+}
+
+/// Holds the collectibles in [`Items`].
+pub struct TestCollectibles;
+impl vulntoken::Custody<u64> for TestCollectibles {
+	fn owner(unique_id: u64) -> Option<u64> {
+		Items::get().into_iter().find(|(id, _)| *id == unique_id).map(|(_, owner)| owner)
+	}
+
+	fn transfer(unique_id: u64, from: &u64, to: &u64) -> DispatchResult {
+		let mut items = Items::get();
+		let (_, owner) = items
+			.iter_mut()
+			.find(|(id, owner)| *id == unique_id && *owner == *from)
+			.ok_or(DispatchError::Other("not the owner"))?;
+		*owner = *to;
+		Items::set(items);
+		Ok(())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn mint(owner: &u64) -> u64 {
+		let mut items = Items::get();
+		let unique_id = items.len() as u64;
+		items.push((unique_id, *owner));
+		Items::set(items);
+		unique_id
+	}
+}
+
+/// Account 5 relays for the bridge.
+pub struct TestRelayers;
+impl SortedMembers<u64> for TestRelayers {
+	fn sorted_members() -> Vec<u64> {
+		RelayerList::get()
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn add(who: &u64) {
+		let mut relayers = RelayerList::get();
+		relayers.push(*who);
+		relayers.sort();
+		RelayerList::set(relayers);
+	}
+}
+
+impl pallet_eth_bridge::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Collectibles = TestCollectibles;
+	type Verifier = pallet_eth_bridge::TrustedRelayers<TestRelayers>;
+	type PalletId = EthBridgePalletId;
+	type MaxProofLength = ConstU32<64>;
+	type WeightInfo = ();
+}
+
+/// Account 1 owns item 0 and account 2 item 1.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext: sp_io::TestExternalities = t.into();
+	// Go past genesis block so events get deposited.
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,71 @@
+use crate::{
+	mock::*, EthereumLightClient, Error, Event, InCustody, InboundMessage, LightClient,
+	OutboundMessage, OutboundMessages, VerifyInbound,
+};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use sp_core::H160;
+use sp_runtime::DispatchResult;
+use vulntoken::Custody;
+
+fn inbound(nonce: u64, collectible: u64, recipient: u64) -> InboundMessage<u64> {
+	InboundMessage { nonce, collectible, recipient }
+}
+
+/// Submit `message` as `relayer`, without a proof.
+fn release(relayer: u64, message: InboundMessage<u64>) -> DispatchResult {
+	EthBridge::release(RuntimeOrigin::signed(relayer), message, BoundedVec::new())
+}
+
+#[test]
+fn collectibles_are_locked_and_released_back() {
+	new_test_ext().execute_with(|| {
+		let eth = H160::repeat_byte(0xee);
+		assert_noop!(EthBridge::lock(RuntimeOrigin::signed(2), 0, eth), Error::<Test>::NotOwner);
+		assert_ok!(EthBridge::lock(RuntimeOrigin::signed(1), 0, eth));
+		System::assert_last_event(
+			Event::Locked { nonce: 0, collectible: 0, sender: 1, recipient: eth }.into(),
+		);
+		let custody = EthBridge::custody_account(0);
+		assert_eq!(TestCollectibles::owner(0), Some(custody));
+		assert_eq!(InCustody::<Test>::get(0), Some(0));
+		assert_eq!(
+			OutboundMessages::<Test>::get(0),
+			Some(OutboundMessage { collectible: 0, sender: 1, recipient: eth })
+		);
+		assert_ok!(EthBridge::lock(RuntimeOrigin::signed(2), 1, eth));
+		assert_eq!(InCustody::<Test>::get(1), Some(1));
+
+		// Only relayers are trusted, and only with collectibles in custody.
+		assert_noop!(release(1, inbound(7, 0, 3)), Error::<Test>::Unverified);
+		assert_noop!(release(5, inbound(7, 2, 3)), Error::<Test>::NotLocked);
+		assert_ok!(release(5, inbound(7, 0, 3)));
+		System::assert_last_event(
+			Event::Released { nonce: 7, collectible: 0, recipient: 3, relayer: 5 }.into(),
+		);
+		assert_eq!(TestCollectibles::owner(0), Some(3));
+		assert_eq!(InCustody::<Test>::get(0), None);
+		assert_eq!(OutboundMessages::<Test>::get(0), None);
+
+		// A message is accepted once, even for another collectible.
+		assert_noop!(release(5, inbound(7, 1, 3)), Error::<Test>::AlreadyProcessed);
+		assert_ok!(release(5, inbound(8, 1, 2)));
+		assert_eq!(TestCollectibles::owner(1), Some(2));
+	});
+}
+
+/// Vouches for whatever encodes to the proof.
+struct ProofIsPayload;
+impl EthereumLightClient for ProofIsPayload {
+	fn verify_event(payload: &[u8], proof: &[u8]) -> bool {
+		payload == proof
+	}
+}
+
+#[test]
+fn light_clients_verify_the_encoded_message() {
+	use codec::Encode;
+	let message = inbound(3, 0, 4);
+	type Verifier = LightClient<ProofIsPayload>;
+	assert!(Verifier::verify(&9, &message, &message.encode()));
+	assert!(!Verifier::verify(&9, &message, &inbound(3, 0, 5).encode()));
+}
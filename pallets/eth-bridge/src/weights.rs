@@ -0,0 +1,102 @@
+//! Weights for pallet_eth_bridge
+//!
+//! Estimated from the storage each call touches. Regenerate them on reference hardware with
+//! the command below whenever a call changes.
+
+// Command:
+// ./target/release/node-template
+// benchmark
+// pallet
+// --chain
+// dev
+// --pallet
+// pallet_eth_bridge
+// --extrinsic
+// *
+// --steps=50
+// --repeat=20
+// --wasm-execution=compiled
+// --output
+// pallets/eth-bridge/src/weights.rs
+// --template
+// ./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_eth_bridge.
+pub trait WeightInfo {
+	fn lock() -> Weight;
+	fn release() -> Weight;
+}
+
+/// Weights for pallet_eth_bridge using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: EthBridge OutboundNonce (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:0)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule RentalRates (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: EthBridge InCustody (r:0 w:1)
+	/// Storage: EthBridge OutboundMessages (r:0 w:1)
+	fn lock() -> Weight {
+		Weight::from_parts(41_306_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: EthBridge ProcessedInbound (r:1 w:1)
+	/// Storage: BridgeRelayers Members (r:1 w:0)
+	/// Storage: EthBridge InCustody (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:0)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule RentalRates (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: EthBridge OutboundMessages (r:0 w:1)
+	fn release() -> Weight {
+		Weight::from_parts(44_872_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: EthBridge OutboundNonce (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:0)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule RentalRates (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: EthBridge InCustody (r:0 w:1)
+	/// Storage: EthBridge OutboundMessages (r:0 w:1)
+	fn lock() -> Weight {
+		Weight::from_parts(41_306_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: EthBridge ProcessedInbound (r:1 w:1)
+	/// Storage: BridgeRelayers Members (r:1 w:0)
+	/// Storage: EthBridge InCustody (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:0)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule RentalRates (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: EthBridge OutboundMessages (r:0 w:1)
+	fn release() -> Weight {
+		Weight::from_parts(44_872_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+}
@@ -0,0 +1,65 @@
+//! Benchmarking setup for pallet-grants
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+use frame_benchmarking::v2::*;
+use frame_support::traits::fungible::{Inspect, Mutate};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Saturating;
+
+/// Propose a grant of ten existential deposits from a funded treasury, to a creator who has
+/// no account yet.
+fn proposed<T: Config>() -> (T::AccountId, BalanceOf<T>) {
+	let amount = T::Currency::minimum_balance().saturating_mul(10u32.into());
+	T::Currency::set_balance(&Pallet::<T>::account_id(), amount.saturating_mul(2u32.into()));
+	let proposer: T::AccountId = whitelisted_caller();
+	let creator: T::AccountId = account("creator", 0, 0);
+	Pallet::<T>::propose_grant(RawOrigin::Signed(proposer).into(), creator.clone(), amount)
+		.unwrap();
+	(creator, amount)
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn propose_grant() {
+		let caller: T::AccountId = whitelisted_caller();
+		let creator: T::AccountId = account("creator", 0, 0);
+		let amount = T::Currency::minimum_balance();
+
+		#[extrinsic_call]
+		propose_grant(RawOrigin::Signed(caller), creator, amount);
+
+		assert!(Grants::<T>::contains_key(0));
+	}
+
+	#[benchmark]
+	fn approve_grant() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ApproveOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let (creator, amount) = proposed::<T>();
+
+		#[extrinsic_call]
+		approve_grant(origin as T::RuntimeOrigin, 0);
+
+		assert_eq!(T::Currency::balance(&creator), amount);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reject_grant() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ApproveOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		proposed::<T>();
+
+		#[extrinsic_call]
+		reject_grant(origin as T::RuntimeOrigin, 0);
+
+		assert!(!Grants::<T>::contains_key(0));
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
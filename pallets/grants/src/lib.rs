@@ -0,0 +1,172 @@
+//! # Grants
+//!
+//! Pays creators out of the marketplace treasury. Slashed marketplace deposits accumulate in
+//! the pallet's account; anybody can propose a grant to a creator, and governance, through
+//! `ApproveOrigin`, pays or rejects it.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			fungible::{Inspect, Mutate},
+			tokens::{Fortitude, Preservation},
+		},
+		PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{AccountIdConversion, Zero};
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub type GrantIndex = u32;
+
+	/// A proposed grant, waiting for governance.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Grant<T: Config> {
+		pub proposer: T::AccountId,
+		pub creator: T::AccountId,
+		pub amount: BalanceOf<T>,
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		type Currency: Mutate<Self::AccountId>;
+
+		/// Derives the account grants are paid from.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Origin that pays or rejects proposed grants.
+		type ApproveOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// A type representing the weights required by the dispatchables of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Number of grants ever proposed, and the index of the next one.
+	#[pallet::storage]
+	pub type GrantCount<T> = StorageValue<_, GrantIndex, ValueQuery>;
+
+	/// Grants waiting for governance, by index.
+	#[pallet::storage]
+	pub type Grants<T: Config> = StorageMap<_, Twox64Concat, GrantIndex, Grant<T>>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		GrantProposed {
+			index: GrantIndex,
+			proposer: T::AccountId,
+			creator: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		GrantPaid { index: GrantIndex, creator: T::AccountId, amount: BalanceOf<T> },
+		GrantRejected { index: GrantIndex },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Grants must be for a non-zero amount.
+		ZeroAmount,
+		/// There is no proposed grant with this index.
+		NoGrant,
+		/// The treasury cannot pay the grant and stay alive.
+		InsufficientFunds,
+		/// Grant indices are exhausted.
+		Overflow,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Propose paying `amount` from the treasury to `creator`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::propose_grant())]
+		pub fn propose_grant(
+			origin: OriginFor<T>,
+			creator: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let proposer = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			let index = GrantCount::<T>::get();
+			GrantCount::<T>::put(index.checked_add(1).ok_or(Error::<T>::Overflow)?);
+			Grants::<T>::insert(
+				index,
+				Grant { proposer: proposer.clone(), creator: creator.clone(), amount },
+			);
+			Self::deposit_event(Event::GrantProposed { index, proposer, creator, amount });
+			Ok(())
+		}
+
+		/// Pay a proposed grant from the treasury.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::approve_grant())]
+		pub fn approve_grant(origin: OriginFor<T>, index: GrantIndex) -> DispatchResult {
+			T::ApproveOrigin::ensure_origin(origin)?;
+			let grant = Grants::<T>::take(index).ok_or(Error::<T>::NoGrant)?;
+			T::Currency::transfer(
+				&Self::account_id(),
+				&grant.creator,
+				grant.amount,
+				Preservation::Preserve,
+			)
+			.map_err(|_| Error::<T>::InsufficientFunds)?;
+			Self::deposit_event(Event::GrantPaid {
+				index,
+				creator: grant.creator,
+				amount: grant.amount,
+			});
+			Ok(())
+		}
+
+		/// Drop a proposed grant without paying it.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::reject_grant())]
+		pub fn reject_grant(origin: OriginFor<T>, index: GrantIndex) -> DispatchResult {
+			T::ApproveOrigin::ensure_origin(origin)?;
+			Grants::<T>::take(index).ok_or(Error::<T>::NoGrant)?;
+			Self::deposit_event(Event::GrantRejected { index });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The treasury account grants are paid from.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		/// What the treasury can pay out while staying alive.
+		pub fn available() -> BalanceOf<T> {
+			T::Currency::reducible_balance(
+				&Self::account_id(),
+				Preservation::Preserve,
+				Fortitude::Polite,
+			)
+		}
+	}
+}
@@ -0,0 +1,46 @@
+use crate::{mock::*, Error, Event, GrantCount, Grants as GrantsStorage};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+#[test]
+fn governance_pays_a_proposed_grant() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Grants::propose_grant(RuntimeOrigin::signed(1), 2, 0),
+			Error::<Test>::ZeroAmount
+		);
+		assert_ok!(Grants::propose_grant(RuntimeOrigin::signed(1), 2, 300));
+		System::assert_last_event(
+			Event::GrantProposed { index: 0, proposer: 1, creator: 2, amount: 300 }.into(),
+		);
+		assert_eq!(GrantCount::<Test>::get(), 1);
+
+		assert_noop!(
+			Grants::approve_grant(RuntimeOrigin::signed(1), 0),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Grants::approve_grant(RuntimeOrigin::root(), 0));
+		System::assert_last_event(Event::GrantPaid { index: 0, creator: 2, amount: 300 }.into());
+		assert_eq!(Balances::free_balance(2), 300);
+		assert_eq!(Balances::free_balance(Grants::account_id()), 700);
+		assert!(GrantsStorage::<Test>::get(0).is_none());
+		assert_noop!(Grants::approve_grant(RuntimeOrigin::root(), 0), Error::<Test>::NoGrant);
+	});
+}
+
+#[test]
+fn grants_cannot_drain_the_treasury() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Grants::available(), 999);
+		assert_ok!(Grants::propose_grant(RuntimeOrigin::signed(1), 2, 1_000));
+		assert_noop!(
+			Grants::approve_grant(RuntimeOrigin::root(), 0),
+			Error::<Test>::InsufficientFunds
+		);
+
+		assert_ok!(Grants::reject_grant(RuntimeOrigin::root(), 0));
+		System::assert_last_event(Event::GrantRejected { index: 0 }.into());
+		assert!(GrantsStorage::<Test>::get(0).is_none());
+		assert_eq!(Balances::free_balance(Grants::account_id()), 1_000);
+	});
+}
@@ -0,0 +1,85 @@
+//! Weights for pallet_grants
+//!
+//! Estimated from the storage each call touches. Regenerate them on reference hardware with
+//! the command below whenever a call changes.
+
+// Command:
+// ./target/release/node-template
+// benchmark
+// pallet
+// --chain
+// dev
+// --pallet
+// pallet_grants
+// --extrinsic
+// *
+// --steps=50
+// --repeat=20
+// --wasm-execution=compiled
+// --output
+// pallets/grants/src/weights.rs
+// --template
+// ./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_grants.
+pub trait WeightInfo {
+	fn propose_grant() -> Weight;
+	fn approve_grant() -> Weight;
+	fn reject_grant() -> Weight;
+}
+
+/// Weights for pallet_grants using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Grants GrantCount (r:1 w:1)
+	/// Storage: Grants Grants (r:0 w:1)
+	fn propose_grant() -> Weight {
+		Weight::from_parts(14_820_000, 1_489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Grants Grants (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn approve_grant() -> Weight {
+		Weight::from_parts(41_305_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Grants Grants (r:1 w:1)
+	fn reject_grant() -> Weight {
+		Weight::from_parts(13_117_000, 3_553)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: Grants GrantCount (r:1 w:1)
+	/// Storage: Grants Grants (r:0 w:1)
+	fn propose_grant() -> Weight {
+		Weight::from_parts(14_820_000, 1_489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Grants Grants (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn approve_grant() -> Weight {
+		Weight::from_parts(41_305_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Grants Grants (r:1 w:1)
+	fn reject_grant() -> Weight {
+		Weight::from_parts(13_117_000, 3_553)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -0,0 +1,44 @@
+//! Benchmarking setup for pallet-oracle
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+use frame_benchmarking::v2::*;
+use frame_support::traits::{Get, SortedMembers};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+
+/// Make sure `account` feeds the oracle.
+fn feeder<T: Config>(account: &T::AccountId) {
+	if !T::Feeders::contains(account) {
+		T::Feeders::add(account);
+	}
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn appraise(f: Linear<1, { T::MaxFeeders::get() }>) {
+		let collection: T::CollectionId = account("collection", 0, 0);
+		let at: BlockNumberFor<T> = frame_system::Pallet::<T>::block_number();
+		// Every other feeder has a fresh appraisal, so all of them are aggregated.
+		for i in 1..f {
+			let other: T::AccountId = account("feeder", i, 0);
+			feeder::<T>(&other);
+			Appraisals::<T>::insert(
+				&collection,
+				&other,
+				Appraisal { floor: i.into(), average: i.saturating_mul(2).into(), at },
+			);
+		}
+		let caller: T::AccountId = whitelisted_caller();
+		feeder::<T>(&caller);
+
+		#[extrinsic_call]
+		appraise(RawOrigin::Signed(caller), collection.clone(), 1u32.into(), 2u32.into());
+
+		assert_eq!(Appraisals::<T>::iter_prefix(&collection).count() as u32, f);
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
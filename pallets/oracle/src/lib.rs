@@ -0,0 +1,210 @@
+//! # Valuation oracle
+//!
+//! Whitelisted feeders appraise collections with a floor and an average price. Each new
+//! appraisal re-aggregates the collection's valuation as the median of the appraisals fed within
+//! `StalenessWindow`, once at least `MinAppraisals` of them are fresh. Consumers read
+//! valuations through [`ValuationOracle`], which refuses to serve stale ones, so a loan-to-value
+//! check can never rest on prices nobody vouched for recently.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+/// Read access to collection valuations, for the lending and collateral logic.
+pub trait ValuationOracle<CollectionId, Balance> {
+	/// The collection's current valuation, or `None` if there is none or it went stale.
+	fn valuation(collection: &CollectionId) -> Option<(Balance, Balance)>;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{pallet_prelude::*, traits::SortedMembers};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{AtLeast32BitUnsigned, Saturating};
+	use sp_std::vec::Vec;
+
+	/// One feeder's appraisal of a collection.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Appraisal<T: Config> {
+		pub floor: T::Balance,
+		pub average: T::Balance,
+		pub at: BlockNumberFor<T>,
+	}
+
+	/// A collection's aggregated valuation.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Valuation<T: Config> {
+		/// Median of the fresh appraisals' floors.
+		pub floor: T::Balance,
+		/// Median of the fresh appraisals' averages.
+		pub average: T::Balance,
+		/// Number of fresh appraisals the medians were taken over.
+		pub appraisals: u32,
+		/// Block of the oldest appraisal that went into the valuation. The valuation goes stale
+		/// `StalenessWindow` blocks after it.
+		pub oldest: BlockNumberFor<T>,
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Identifies a collection.
+		type CollectionId: Parameter + MaxEncodedLen;
+
+		/// The currency unit of appraisals.
+		type Balance: Parameter + MaxEncodedLen + AtLeast32BitUnsigned + Copy;
+
+		/// The accounts allowed to submit appraisals.
+		type Feeders: SortedMembers<Self::AccountId>;
+
+		/// Upper bound on the number of feeders, used to bound aggregation.
+		#[pallet::constant]
+		type MaxFeeders: Get<u32>;
+
+		/// Number of blocks an appraisal counts towards the valuation.
+		#[pallet::constant]
+		type StalenessWindow: Get<BlockNumberFor<Self>>;
+
+		/// Number of fresh appraisals a valuation needs.
+		#[pallet::constant]
+		type MinAppraisals: Get<u32>;
+
+		/// A type representing the weights required by the dispatchables of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Latest appraisal by collection and feeder.
+	#[pallet::storage]
+	pub type Appraisals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Twox64Concat,
+		T::AccountId,
+		Appraisal<T>,
+	>;
+
+	/// Aggregated valuation by collection. Read it through [`ValuationOracle`], which checks
+	/// that it is still fresh.
+	#[pallet::storage]
+	pub type Valuations<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, Valuation<T>>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A feeder appraised a collection.
+		Appraised {
+			collection: T::CollectionId,
+			feeder: T::AccountId,
+			floor: T::Balance,
+			average: T::Balance,
+		},
+		/// The collection's valuation was re-aggregated.
+		ValuationUpdated {
+			collection: T::CollectionId,
+			floor: T::Balance,
+			average: T::Balance,
+			appraisals: u32,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Only whitelisted feeders can appraise.
+		NotFeeder,
+		/// The floor cannot exceed the average.
+		InvalidAppraisal,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Appraise a collection, replacing the caller's previous appraisal of it.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::appraise(T::MaxFeeders::get()))]
+		pub fn appraise(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			floor: T::Balance,
+			average: T::Balance,
+		) -> DispatchResult {
+			let feeder = ensure_signed(origin)?;
+			ensure!(T::Feeders::contains(&feeder), Error::<T>::NotFeeder);
+			ensure!(floor <= average, Error::<T>::InvalidAppraisal);
+
+			let at = frame_system::Pallet::<T>::block_number();
+			Appraisals::<T>::insert(&collection, &feeder, Appraisal { floor, average, at });
+			Self::deposit_event(Event::Appraised {
+				collection: collection.clone(),
+				feeder,
+				floor,
+				average,
+			});
+			Self::aggregate(collection);
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Whether something recorded at `at` still counts.
+		fn is_fresh(at: BlockNumberFor<T>) -> bool {
+			frame_system::Pallet::<T>::block_number() < at.saturating_add(T::StalenessWindow::get())
+		}
+
+		/// Re-aggregate the collection's valuation from its fresh appraisals. Leaves the previous
+		/// valuation in place, to go stale, if too few appraisals are fresh.
+		fn aggregate(collection: T::CollectionId) {
+			let fresh = Appraisals::<T>::iter_prefix_values(&collection)
+				.filter(|appraisal| Self::is_fresh(appraisal.at))
+				.take(T::MaxFeeders::get() as usize)
+				.collect::<Vec<_>>();
+			let appraisals = fresh.len() as u32;
+			if appraisals < T::MinAppraisals::get().max(1) {
+				return
+			}
+
+			let floor = median(fresh.iter().map(|appraisal| appraisal.floor).collect());
+			let average = median(fresh.iter().map(|appraisal| appraisal.average).collect());
+			let oldest = fresh.iter().map(|appraisal| appraisal.at).min().unwrap_or_default();
+			Valuations::<T>::insert(&collection, Valuation { floor, average, appraisals, oldest });
+			Self::deposit_event(Event::ValuationUpdated { collection, floor, average, appraisals });
+		}
+	}
+
+	impl<T: Config> ValuationOracle<T::CollectionId, T::Balance> for Pallet<T> {
+		fn valuation(collection: &T::CollectionId) -> Option<(T::Balance, T::Balance)> {
+			let valuation = Valuations::<T>::get(collection)?;
+			Self::is_fresh(valuation.oldest).then_some((valuation.floor, valuation.average))
+		}
+	}
+
+	/// The median of a non-empty list. Even lists round down between their two middle values.
+	pub(crate) fn median<B: AtLeast32BitUnsigned + Copy>(mut values: Vec<B>) -> B {
+		values.sort();
+		let mid = values.len() / 2;
+		if values.len() % 2 == 1 {
+			values[mid]
+		} else {
+			let (low, high) = (values[mid - 1], values[mid]);
+			low + (high - low) / 2u32.into()
+		}
+	}
+}
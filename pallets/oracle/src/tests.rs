@@ -0,0 +1,69 @@
+use crate::{mock::*, pallet::median, Error, Event, ValuationOracle, Valuations};
+use frame_support::{assert_noop, assert_ok};
+
+const COLLECTION: u32 = 7;
+
+fn appraise(feeder: u64, floor: u64, average: u64) {
+	assert_ok!(Oracle::appraise(RuntimeOrigin::signed(feeder), COLLECTION, floor, average));
+}
+
+#[test]
+fn valuation_is_the_median_of_fresh_appraisals() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Oracle::appraise(RuntimeOrigin::signed(4), COLLECTION, 10, 20),
+			Error::<Test>::NotFeeder
+		);
+		assert_noop!(
+			Oracle::appraise(RuntimeOrigin::signed(1), COLLECTION, 30, 20),
+			Error::<Test>::InvalidAppraisal
+		);
+
+		// One appraisal is not enough for a valuation.
+		appraise(1, 100, 150);
+		assert_eq!(Oracle::valuation(&COLLECTION), None);
+
+		appraise(2, 110, 170);
+		assert_eq!(Oracle::valuation(&COLLECTION), Some((105, 160)));
+
+		// A feeder far off the others does not move the median past them.
+		appraise(3, 1_000, 5_000);
+		System::assert_last_event(
+			Event::ValuationUpdated { collection: COLLECTION, floor: 110, average: 170, appraisals: 3 }
+				.into(),
+		);
+		assert_eq!(Oracle::valuation(&COLLECTION), Some((110, 170)));
+		assert_eq!(Oracle::valuation(&(COLLECTION + 1)), None);
+	});
+}
+
+#[test]
+fn stale_valuations_are_not_served() {
+	new_test_ext().execute_with(|| {
+		appraise(1, 100, 150);
+		System::set_block_number(5);
+		appraise(2, 110, 170);
+		assert_eq!(Oracle::valuation(&COLLECTION), Some((105, 160)));
+
+		// The valuation goes stale with its oldest appraisal.
+		System::set_block_number(11);
+		assert_eq!(Oracle::valuation(&COLLECTION), None);
+
+		// Only feeder 2's appraisal is still fresh besides the new one.
+		appraise(3, 120, 180);
+		assert_eq!(Oracle::valuation(&COLLECTION), Some((115, 175)));
+		assert_eq!(Valuations::<Test>::get(COLLECTION).unwrap().appraisals, 2);
+
+		// With fewer than `MinAppraisals` fresh, the stale valuation is left alone.
+		System::set_block_number(21);
+		appraise(1, 200, 200);
+		assert_eq!(Oracle::valuation(&COLLECTION), None);
+	});
+}
+
+#[test]
+fn median_of_even_lists_rounds_down() {
+	assert_eq!(median(vec![3u64, 1, 2]), 2);
+	assert_eq!(median(vec![4u64, 1]), 2);
+	assert_eq!(median(vec![5u64]), 5);
+}
@@ -0,0 +1,68 @@
+//! Weights for pallet_oracle
+//!
+//! Estimated from the storage each call touches in the worst case set up by
+//! `benchmarking.rs`. Regenerate them on reference hardware with the command below whenever a
+//! call or its worst case changes.
+
+// Command:
+// ./target/release/node-template
+// benchmark
+// pallet
+// --chain
+// dev
+// --pallet
+// pallet_oracle
+// --extrinsic
+// *
+// --steps=50
+// --repeat=20
+// --wasm-execution=compiled
+// --output
+// pallets/oracle/src/weights.rs
+// --template
+// ./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_oracle.
+pub trait WeightInfo {
+	fn appraise(f: u32, ) -> Weight;
+}
+
+/// Weights for pallet_oracle using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: OracleFeeders Members (r:1 w:0)
+	/// Storage: Oracle Appraisals (r:f w:1)
+	/// Storage: Oracle Valuations (r:0 w:1)
+	/// The range of component `f` is `[1, MaxFeeders]`.
+	fn appraise(f: u32, ) -> Weight {
+		Weight::from_parts(21_407_000, 3_553)
+			.saturating_add(Weight::from_parts(3_182_000, 0).saturating_mul(f.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(f.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 2_582).saturating_mul(f.into()))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: OracleFeeders Members (r:1 w:0)
+	/// Storage: Oracle Appraisals (r:f w:1)
+	/// Storage: Oracle Valuations (r:0 w:1)
+	/// The range of component `f` is `[1, MaxFeeders]`.
+	fn appraise(f: u32, ) -> Weight {
+		Weight::from_parts(21_407_000, 3_553)
+			.saturating_add(Weight::from_parts(3_182_000, 0).saturating_mul(f.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(f.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 2_582).saturating_mul(f.into()))
+	}
+}
@@ -0,0 +1,87 @@
+//! Benchmarking setup for pallet-payment-streams
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+use frame_benchmarking::v2::*;
+use frame_support::traits::fungible::{Inspect, Mutate};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Saturating, Zero};
+
+/// Open a stream of one existential deposit per block, with ten blocks' worth of deposit, to a
+/// recipient who has no account yet. Returns the payer and the recipient.
+fn opened<T: Config>() -> (T::AccountId, T::AccountId) {
+	let rate = T::Currency::minimum_balance();
+	let payer: T::AccountId = whitelisted_caller();
+	let recipient: T::AccountId = account("recipient", 0, 0);
+	T::Currency::set_balance(&payer, rate.saturating_mul(100u32.into()));
+	Pallet::<T>::open_stream(
+		RawOrigin::Signed(payer.clone()).into(),
+		recipient.clone(),
+		rate,
+		rate.saturating_mul(10u32.into()),
+	)
+	.unwrap();
+	(payer, recipient)
+}
+
+/// Let the stream run for `blocks`.
+fn run<T: Config>(blocks: u32) {
+	let now = frame_system::Pallet::<T>::block_number();
+	frame_system::Pallet::<T>::set_block_number(now.saturating_add(blocks.into()));
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn open_stream() {
+		let rate = T::Currency::minimum_balance();
+		let caller: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, 0);
+		T::Currency::set_balance(&caller, rate.saturating_mul(100u32.into()));
+
+		#[extrinsic_call]
+		open_stream(RawOrigin::Signed(caller), recipient, rate, rate.saturating_mul(10u32.into()));
+
+		assert!(Streams::<T>::contains_key(0));
+	}
+
+	#[benchmark]
+	fn top_up() {
+		let (payer, _) = opened::<T>();
+		let amount = T::Currency::minimum_balance();
+
+		#[extrinsic_call]
+		top_up(RawOrigin::Signed(payer), 0, amount);
+
+		assert_eq!(Pallet::<T>::remaining(0), Some(amount.saturating_mul(11u32.into())));
+	}
+
+	// The worst case pays out the last of the deposit and closes the stream.
+	#[benchmark]
+	fn withdraw() {
+		let (payer, recipient) = opened::<T>();
+		run::<T>(10);
+
+		#[extrinsic_call]
+		withdraw(RawOrigin::Signed(payer), 0);
+
+		assert!(!Streams::<T>::contains_key(0));
+		assert!(!T::Currency::balance(&recipient).is_zero());
+	}
+
+	#[benchmark]
+	fn close() {
+		let (payer, recipient) = opened::<T>();
+		run::<T>(5);
+
+		#[extrinsic_call]
+		close(RawOrigin::Signed(payer), 0);
+
+		assert!(!Streams::<T>::contains_key(0));
+		assert!(!T::Currency::balance(&recipient).is_zero());
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,323 @@
+//! # Payment streams
+//!
+//! A payer deposits funds that stream to a recipient at a fixed rate per block. Nothing moves
+//! until somebody touches the stream: withdrawing pays the recipient what it earned so far,
+//! topping up extends the deposit, and closing pays out what was earned and refunds the rest,
+//! so either party can walk away at any block. Other pallets drive streams through
+//! [`PaymentStreams`] rather than redoing the per-block accounting themselves.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+use frame_support::dispatch::DispatchResult;
+use sp_runtime::DispatchError;
+
+pub type StreamId = u64;
+
+/// Streams as other pallets drive them. None of these check who is asking; callers do.
+pub trait PaymentStreams<AccountId, Balance> {
+	/// Open a stream of `rate` per block from `payer` to `recipient`, holding `deposit` from
+	/// the payer.
+	fn open(
+		payer: &AccountId,
+		recipient: &AccountId,
+		rate: Balance,
+		deposit: Balance,
+	) -> Result<StreamId, DispatchError>;
+
+	/// Hold `amount` more from the payer. Fails once the deposit has run out.
+	fn top_up(id: StreamId, amount: Balance) -> DispatchResult;
+
+	/// Pay the recipient what it earned so far and return the amount. A stream whose deposit
+	/// ran out is closed.
+	fn withdraw(id: StreamId) -> Result<Balance, DispatchError>;
+
+	/// Pay the recipient what it earned, refund the rest to the payer and close the stream.
+	/// Returns what was paid and what was refunded.
+	fn close(id: StreamId) -> Result<(Balance, Balance), DispatchError>;
+
+	/// What is left of the stream's deposit once its earnings are paid, or `None` if there
+	/// is no such stream.
+	fn remaining(id: StreamId) -> Option<Balance>;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			fungible::{Inspect, Mutate, MutateHold},
+			tokens::{Fortitude, Precision, Restriction},
+		},
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::{
+		traits::{Saturating, Zero},
+		SaturatedConversion,
+	};
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// Funds streaming from a payer to a recipient.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Stream<T: Config> {
+		pub payer: T::AccountId,
+		pub recipient: T::AccountId,
+		/// Paid per block.
+		pub rate: BalanceOf<T>,
+		/// Held from the payer and not paid to the recipient yet.
+		pub deposit: BalanceOf<T>,
+		/// The recipient is paid up to this block.
+		pub settled_at: BlockNumberFor<T>,
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Stream deposits are held under [`HoldReason::StreamDeposit`].
+		type Currency: Mutate<Self::AccountId>
+			+ MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+		type RuntimeHoldReason: From<HoldReason>;
+
+		/// A type representing the weights required by the dispatchables of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Why the pallet holds an account's funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// The part of a stream's deposit the recipient has not been paid yet.
+		StreamDeposit,
+	}
+
+	/// The id the next stream is opened with.
+	#[pallet::storage]
+	pub type NextStreamId<T> = StorageValue<_, StreamId, ValueQuery>;
+
+	/// Open streams by id.
+	#[pallet::storage]
+	pub type Streams<T: Config> = StorageMap<_, Twox64Concat, StreamId, Stream<T>>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		StreamOpened {
+			id: StreamId,
+			payer: T::AccountId,
+			recipient: T::AccountId,
+			rate: BalanceOf<T>,
+			deposit: BalanceOf<T>,
+		},
+		StreamToppedUp { id: StreamId, amount: BalanceOf<T> },
+		/// The recipient was paid what it earned so far.
+		Withdrawn { id: StreamId, recipient: T::AccountId, amount: BalanceOf<T> },
+		/// The stream was closed and the part of the deposit not earned went back to the payer.
+		StreamClosed { id: StreamId, refunded: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Streams must pay a non-zero rate.
+		ZeroRate,
+		/// The deposit does not cover a single block.
+		InsufficientDeposit,
+		/// There is no stream with this id.
+		NoStream,
+		/// Only the payer can top a stream up.
+		NotPayer,
+		/// Only the payer or the recipient can close a stream.
+		NotParty,
+		/// The stream's deposit has run out.
+		StreamExhausted,
+		/// Stream ids are exhausted.
+		Overflow,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Stream `rate` per block to `recipient` out of `deposit`, which is held from the caller.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::open_stream())]
+		pub fn open_stream(
+			origin: OriginFor<T>,
+			recipient: T::AccountId,
+			rate: BalanceOf<T>,
+			deposit: BalanceOf<T>,
+		) -> DispatchResult {
+			let payer = ensure_signed(origin)?;
+			<Self as PaymentStreams<_, _>>::open(&payer, &recipient, rate, deposit)?;
+			Ok(())
+		}
+
+		/// Add to the deposit of a stream the caller pays, if it has not run out.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::top_up())]
+		pub fn top_up(origin: OriginFor<T>, id: StreamId, amount: BalanceOf<T>) -> DispatchResult {
+			let payer = ensure_signed(origin)?;
+			let stream = Streams::<T>::get(id).ok_or(Error::<T>::NoStream)?;
+			ensure!(stream.payer == payer, Error::<T>::NotPayer);
+			<Self as PaymentStreams<_, _>>::top_up(id, amount)
+		}
+
+		/// Pay the recipient what it earned so far, closing the stream if its deposit ran out.
+		/// Callable by anyone.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::withdraw())]
+		pub fn withdraw(origin: OriginFor<T>, id: StreamId) -> DispatchResult {
+			ensure_signed(origin)?;
+			<Self as PaymentStreams<_, _>>::withdraw(id)?;
+			Ok(())
+		}
+
+		/// Close a stream as its payer or recipient. The recipient is paid for the blocks the
+		/// stream ran and the rest of the deposit goes back to the payer.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::close())]
+		pub fn close(origin: OriginFor<T>, id: StreamId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let stream = Streams::<T>::get(id).ok_or(Error::<T>::NoStream)?;
+			ensure!(who == stream.payer || who == stream.recipient, Error::<T>::NotParty);
+			<Self as PaymentStreams<_, _>>::close(id)?;
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// What the recipient earned since the stream was last settled, capped at the deposit.
+		pub fn earned(stream: &Stream<T>) -> BalanceOf<T> {
+			let elapsed: u128 = frame_system::Pallet::<T>::block_number()
+				.saturating_sub(stream.settled_at)
+				.saturated_into();
+			stream.rate.saturating_mul(elapsed.saturated_into()).min(stream.deposit)
+		}
+
+		/// Pay the recipient what it earned and mark the stream settled up to now.
+		fn settle(id: StreamId, stream: &mut Stream<T>) -> Result<BalanceOf<T>, DispatchError> {
+			let earned = Self::earned(stream);
+			stream.settled_at = frame_system::Pallet::<T>::block_number();
+			if earned.is_zero() {
+				return Ok(earned)
+			}
+			T::Currency::transfer_on_hold(
+				&HoldReason::StreamDeposit.into(),
+				&stream.payer,
+				&stream.recipient,
+				earned,
+				Precision::Exact,
+				Restriction::Free,
+				Fortitude::Polite,
+			)?;
+			stream.deposit = stream.deposit.saturating_sub(earned);
+			Self::deposit_event(Event::Withdrawn {
+				id,
+				recipient: stream.recipient.clone(),
+				amount: earned,
+			});
+			Ok(earned)
+		}
+
+		/// Refund what is left of the deposit and forget the stream.
+		fn finish(id: StreamId, stream: Stream<T>) -> DispatchResult {
+			Streams::<T>::remove(id);
+			T::Currency::release(
+				&HoldReason::StreamDeposit.into(),
+				&stream.payer,
+				stream.deposit,
+				Precision::BestEffort,
+			)?;
+			Self::deposit_event(Event::StreamClosed { id, refunded: stream.deposit });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> PaymentStreams<T::AccountId, BalanceOf<T>> for Pallet<T> {
+		fn open(
+			payer: &T::AccountId,
+			recipient: &T::AccountId,
+			rate: BalanceOf<T>,
+			deposit: BalanceOf<T>,
+		) -> Result<StreamId, DispatchError> {
+			ensure!(!rate.is_zero(), Error::<T>::ZeroRate);
+			ensure!(deposit >= rate, Error::<T>::InsufficientDeposit);
+			let id = NextStreamId::<T>::get();
+			NextStreamId::<T>::put(id.checked_add(1).ok_or(Error::<T>::Overflow)?);
+
+			T::Currency::hold(&HoldReason::StreamDeposit.into(), payer, deposit)?;
+			let settled_at = frame_system::Pallet::<T>::block_number();
+			Streams::<T>::insert(
+				id,
+				Stream {
+					payer: payer.clone(),
+					recipient: recipient.clone(),
+					rate,
+					deposit,
+					settled_at,
+				},
+			);
+			Self::deposit_event(Event::StreamOpened {
+				id,
+				payer: payer.clone(),
+				recipient: recipient.clone(),
+				rate,
+				deposit,
+			});
+			Ok(id)
+		}
+
+		fn top_up(id: StreamId, amount: BalanceOf<T>) -> DispatchResult {
+			let mut stream = Streams::<T>::get(id).ok_or(Error::<T>::NoStream)?;
+			// A stream that ran out ended when it did; topping it up would pay for the blocks
+			// since out of the new deposit.
+			ensure!(Self::earned(&stream) < stream.deposit, Error::<T>::StreamExhausted);
+
+			T::Currency::hold(&HoldReason::StreamDeposit.into(), &stream.payer, amount)?;
+			stream.deposit = stream.deposit.saturating_add(amount);
+			Streams::<T>::insert(id, stream);
+			Self::deposit_event(Event::StreamToppedUp { id, amount });
+			Ok(())
+		}
+
+		fn withdraw(id: StreamId) -> Result<BalanceOf<T>, DispatchError> {
+			let mut stream = Streams::<T>::get(id).ok_or(Error::<T>::NoStream)?;
+			let earned = Self::settle(id, &mut stream)?;
+			if stream.deposit.is_zero() {
+				Self::finish(id, stream)?;
+			} else {
+				Streams::<T>::insert(id, stream);
+			}
+			Ok(earned)
+		}
+
+		fn close(id: StreamId) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+			let mut stream = Streams::<T>::get(id).ok_or(Error::<T>::NoStream)?;
+			let earned = Self::settle(id, &mut stream)?;
+			let refunded = stream.deposit;
+			Self::finish(id, stream)?;
+			Ok((earned, refunded))
+		}
+
+		fn remaining(id: StreamId) -> Option<BalanceOf<T>> {
+			let stream = Streams::<T>::get(id)?;
+			Some(stream.deposit.saturating_sub(Self::earned(&stream)))
+		}
+	}
+}
@@ -0,0 +1,72 @@
+use crate::{mock::*, Error, Event, PaymentStreams as _, Streams};
+use frame_support::{assert_noop, assert_ok, traits::fungible::InspectHold};
+
+fn held(who: u64) -> u64 {
+	Balances::balance_on_hold(&crate::HoldReason::StreamDeposit.into(), &who)
+}
+
+#[test]
+fn recipient_withdraws_what_it_earned() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			PaymentStreams::open_stream(RuntimeOrigin::signed(1), 2, 0, 100),
+			Error::<Test>::ZeroRate
+		);
+		assert_noop!(
+			PaymentStreams::open_stream(RuntimeOrigin::signed(1), 2, 10, 5),
+			Error::<Test>::InsufficientDeposit
+		);
+		assert_ok!(PaymentStreams::open_stream(RuntimeOrigin::signed(1), 2, 10, 100));
+		System::assert_last_event(
+			Event::StreamOpened { id: 0, payer: 1, recipient: 2, rate: 10, deposit: 100 }.into(),
+		);
+		assert_eq!(held(1), 100);
+
+		System::set_block_number(4);
+		assert_eq!(PaymentStreams::remaining(0), Some(70));
+		// Anyone can settle the stream, but only ever to the recipient.
+		assert_ok!(PaymentStreams::withdraw(RuntimeOrigin::signed(3), 0));
+		System::assert_last_event(Event::Withdrawn { id: 0, recipient: 2, amount: 30 }.into());
+		assert_eq!(Balances::free_balance(2), 1_030);
+		assert_eq!(held(1), 70);
+
+		assert_noop!(
+			PaymentStreams::top_up(RuntimeOrigin::signed(2), 0, 50),
+			Error::<Test>::NotPayer
+		);
+		assert_ok!(PaymentStreams::top_up(RuntimeOrigin::signed(1), 0, 50));
+		assert_eq!(PaymentStreams::remaining(0), Some(120));
+
+		System::set_block_number(6);
+		assert_noop!(PaymentStreams::close(RuntimeOrigin::signed(3), 0), Error::<Test>::NotParty);
+		assert_ok!(PaymentStreams::close(RuntimeOrigin::signed(2), 0));
+		System::assert_last_event(Event::StreamClosed { id: 0, refunded: 100 }.into());
+		assert_eq!(Balances::free_balance(2), 1_050);
+		assert_eq!(Balances::free_balance(1), 950);
+		assert_eq!(held(1), 0);
+		assert!(Streams::<Test>::get(0).is_none());
+	});
+}
+
+#[test]
+fn stream_closes_when_its_deposit_runs_out() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PaymentStreams::open_stream(RuntimeOrigin::signed(1), 2, 10, 25));
+		System::set_block_number(10);
+		assert_eq!(PaymentStreams::remaining(0), Some(0));
+		assert_noop!(
+			PaymentStreams::top_up(RuntimeOrigin::signed(1), 0, 50),
+			Error::<Test>::StreamExhausted
+		);
+
+		assert_ok!(PaymentStreams::withdraw(RuntimeOrigin::signed(2), 0));
+		System::assert_last_event(Event::StreamClosed { id: 0, refunded: 0 }.into());
+		assert_eq!(Balances::free_balance(2), 1_025);
+		assert_eq!(Balances::free_balance(1), 975);
+		assert!(Streams::<Test>::get(0).is_none());
+		assert_noop!(
+			PaymentStreams::withdraw(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::NoStream
+		);
+	});
+}
@@ -0,0 +1,104 @@
+//! Weights for pallet_payment_streams
+//!
+//! Estimated from the storage each call touches. Regenerate them on reference hardware with
+//! the command below whenever a call changes.
+
+// Command:
+// ./target/release/node-template
+// benchmark
+// pallet
+// --chain
+// dev
+// --pallet
+// pallet_payment_streams
+// --extrinsic
+// *
+// --steps=50
+// --repeat=20
+// --wasm-execution=compiled
+// --output
+// pallets/payment-streams/src/weights.rs
+// --template
+// ./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_payment_streams.
+pub trait WeightInfo {
+	fn open_stream() -> Weight;
+	fn top_up() -> Weight;
+	fn withdraw() -> Weight;
+	fn close() -> Weight;
+}
+
+/// Weights for pallet_payment_streams using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: PaymentStreams NextStreamId (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: PaymentStreams Streams (r:0 w:1)
+	fn open_stream() -> Weight {
+		Weight::from_parts(38_640_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: PaymentStreams Streams (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn top_up() -> Weight {
+		Weight::from_parts(34_910_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PaymentStreams Streams (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn withdraw() -> Weight {
+		Weight::from_parts(52_370_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: PaymentStreams Streams (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn close() -> Weight {
+		Weight::from_parts(61_085_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: PaymentStreams NextStreamId (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: PaymentStreams Streams (r:0 w:1)
+	fn open_stream() -> Weight {
+		Weight::from_parts(38_640_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: PaymentStreams Streams (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn top_up() -> Weight {
+		Weight::from_parts(34_910_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PaymentStreams Streams (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn withdraw() -> Weight {
+		Weight::from_parts(52_370_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: PaymentStreams Streams (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn close() -> Weight {
+		Weight::from_parts(61_085_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+}
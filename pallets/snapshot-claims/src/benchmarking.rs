@@ -0,0 +1,41 @@
+//! Benchmarking setup for pallet-snapshot-claims
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+use frame_benchmarking::v2::*;
+use frame_support::{traits::Get, BoundedVec};
+use frame_system::RawOrigin;
+use vulntoken::Color;
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn set_root() {
+		let root = H256::repeat_byte(1);
+
+		#[extrinsic_call]
+		set_root(RawOrigin::Root, root);
+
+		assert_eq!(ClaimsRoot::<T>::get(), Some(root));
+	}
+
+	#[benchmark]
+	fn claim(p: Linear<0, { T::MaxProofDepth::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let claimant = Claimant::Account(caller.clone());
+		let spec = ItemSpec { color: Color::Red };
+		let proof: Vec<H256> = (0..p).map(|i| H256::repeat_byte(i as u8)).collect();
+		let root = fold_proof(Pallet::<T>::leaf(0, &claimant, &spec), &proof);
+		ClaimsRoot::<T>::put(root);
+		let proof = BoundedVec::truncate_from(proof);
+
+		#[extrinsic_call]
+		claim(RawOrigin::Signed(caller), 0, claimant, spec, proof, None);
+
+		assert!(Claimed::<T>::contains_key(0));
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,225 @@
+//! # Snapshot claims
+//!
+//! Migrates collectibles from a previous chain or an Ethereum collection. Off chain, a snapshot
+//! of the holders is taken and each holding becomes a leaf of a Merkle tree: the claimant, either
+//! an Ethereum address or an account here, and the spec of the collectible they get. `RootOrigin`
+//! sets the root of that tree, and holders then claim their leaf by submitting it with its proof.
+//!
+//! An account claims its own leaves. An Ethereum leaf is claimed by any account that submits a
+//! signature by the address over the claiming account, so the holder chooses where the
+//! collectible goes without sending a transaction from Ethereum. Each leaf is claimed once.
+//!
+//! Leaves are the keccak-256 of the SCALE encoding of `(index, claimant, spec)`, and each level
+//! of the tree hashes the pair of its children in ascending order, as OpenZeppelin's
+//! `MerkleProof` does, so proofs need no left or right flags.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+use codec::Encode;
+use sp_core::{H160, H256};
+use sp_io::hashing::keccak_256;
+use sp_std::vec::Vec;
+
+/// The node of a tree above `a` and `b`.
+pub fn hash_pair(a: &H256, b: &H256) -> H256 {
+	let (first, second) = if a <= b { (a, b) } else { (b, a) };
+	keccak_256(&[first.as_bytes(), second.as_bytes()].concat()).into()
+}
+
+/// The root of the tree `leaf` is in, if `proof` lists the siblings on its path from the bottom.
+pub fn fold_proof(leaf: H256, proof: &[H256]) -> H256 {
+	proof.iter().fold(leaf, |node, sibling| hash_pair(&node, sibling))
+}
+
+/// What an Ethereum holder signs, with `personal_sign`, to claim for `who`: `prefix` followed by
+/// the hex of `who`'s SCALE encoding.
+pub fn ethereum_message(prefix: &[u8], who: &impl Encode) -> Vec<u8> {
+	let hex = hex_of(&who.encode());
+	let len = prefix.len() + hex.len();
+	let mut message = b"\x19Ethereum Signed Message:\n".to_vec();
+	message.extend_from_slice(&decimal_of(len));
+	message.extend_from_slice(prefix);
+	message.extend_from_slice(&hex);
+	message
+}
+
+fn hex_of(bytes: &[u8]) -> Vec<u8> {
+	const DIGITS: &[u8; 16] = b"0123456789abcdef";
+	bytes.iter().flat_map(|b| [DIGITS[(b >> 4) as usize], DIGITS[(b & 0xf) as usize]]).collect()
+}
+
+fn decimal_of(mut n: usize) -> Vec<u8> {
+	let mut digits = Vec::new();
+	loop {
+		digits.push(b'0' + (n % 10) as u8);
+		n /= 10;
+		if n == 0 {
+			break
+		}
+	}
+	digits.reverse();
+	digits
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use vulntoken::{Color, Issuer};
+
+	/// The position of a leaf in the snapshot.
+	pub type ClaimIndex = u32;
+
+	/// Who a leaf of the snapshot belongs to.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum Claimant<AccountId> {
+		/// A holder on Ethereum, who claims by signing.
+		Ethereum(H160),
+		/// A holder from the previous chain, with the same account here.
+		Account(AccountId),
+	}
+
+	/// The collectible a leaf of the snapshot is claimed for.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct ItemSpec {
+		pub color: Color,
+	}
+
+	/// An Ethereum `personal_sign` signature: `r`, `s` and `v`.
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct EcdsaSignature(pub [u8; 65]);
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Mints the collectibles claimed.
+		type Issuer: Issuer<Self::AccountId>;
+
+		/// Sets the root of the snapshot.
+		type RootOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Deepest tree a proof can climb.
+		#[pallet::constant]
+		type MaxProofDepth: Get<u32>;
+
+		/// What Ethereum holders sign ahead of the claiming account, so their signature cannot
+		/// be replayed for another purpose.
+		#[pallet::constant]
+		type Prefix: Get<&'static [u8]>;
+
+		/// A type representing the weights required by the dispatchables of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The root of the snapshot being claimed.
+	#[pallet::storage]
+	pub type ClaimsRoot<T> = StorageValue<_, H256>;
+
+	/// The leaves already claimed.
+	#[pallet::storage]
+	pub type Claimed<T> = StorageMap<_, Twox64Concat, ClaimIndex, ()>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The snapshot has a new root.
+		RootSet { root: H256 },
+		/// `who` claimed leaf `index` and got `collectible`.
+		Claimed { index: ClaimIndex, who: T::AccountId, collectible: u64 },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// No snapshot is being claimed.
+		NoRoot,
+		/// The leaf is not in the snapshot.
+		InvalidProof,
+		/// The leaf was claimed before.
+		AlreadyClaimed,
+		/// The signature is missing or not by the Ethereum claimant.
+		InvalidSignature,
+		/// The leaf belongs to another account.
+		WrongClaimant,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the root of the snapshot being claimed. Leaves claimed under an earlier root stay
+		/// claimed, so a new root extends the snapshot with new indices.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_root())]
+		pub fn set_root(origin: OriginFor<T>, root: H256) -> DispatchResult {
+			T::RootOrigin::ensure_origin(origin)?;
+			ClaimsRoot::<T>::put(root);
+			Self::deposit_event(Event::RootSet { root });
+			Ok(())
+		}
+
+		/// Claim leaf `index` of the snapshot, proven by the siblings in `proof`. An Ethereum
+		/// claimant needs its `signature` over the caller.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::claim(proof.len() as u32))]
+		pub fn claim(
+			origin: OriginFor<T>,
+			index: ClaimIndex,
+			claimant: Claimant<T::AccountId>,
+			spec: ItemSpec,
+			proof: BoundedVec<H256, T::MaxProofDepth>,
+			signature: Option<EcdsaSignature>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let root = ClaimsRoot::<T>::get().ok_or(Error::<T>::NoRoot)?;
+			ensure!(!Claimed::<T>::contains_key(index), Error::<T>::AlreadyClaimed);
+			ensure!(
+				fold_proof(Self::leaf(index, &claimant, &spec), &proof) == root,
+				Error::<T>::InvalidProof
+			);
+			match claimant {
+				Claimant::Account(account) => ensure!(account == who, Error::<T>::WrongClaimant),
+				Claimant::Ethereum(address) => {
+					let signature = signature.ok_or(Error::<T>::InvalidSignature)?;
+					let signer = Self::ethereum_signer(&who, &signature);
+					ensure!(signer == Some(address), Error::<T>::InvalidSignature);
+				},
+			}
+
+			let collectible = T::Issuer::issue(&who, spec.color)?;
+			Claimed::<T>::insert(index, ());
+			Self::deposit_event(Event::Claimed { index, who, collectible });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The leaf of the snapshot giving `claimant` a collectible of `spec` at `index`.
+		pub fn leaf(index: ClaimIndex, claimant: &Claimant<T::AccountId>, spec: &ItemSpec) -> H256 {
+			(index, claimant, spec).using_encoded(keccak_256).into()
+		}
+
+		/// The Ethereum address that signed the claim for `who`, if any did.
+		pub fn ethereum_signer(who: &T::AccountId, signature: &EcdsaSignature) -> Option<H160> {
+			let message = ethereum_message(T::Prefix::get(), who);
+			let public = sp_io::crypto::secp256k1_ecdsa_recover(&signature.0, &keccak_256(&message))
+				.ok()?;
+			Some(H160::from_slice(&keccak_256(&public)[12..]))
+		}
+	}
+}
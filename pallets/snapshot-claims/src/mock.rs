@@ -0,0 +1,86 @@
+use crate as pallet_snapshot_claims;
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ConstU16, ConstU32, ConstU64},
+};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage, DispatchError,
+};
+use vulntoken::Color;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		SnapshotClaims: pallet_snapshot_claims,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ConstU16<42>;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+	pub Prefix: &'static [u8] = b"Claim collectibles for:";
+	/// Collectibles issued, as `(owner, color)`, by id.
+	pub static Issued: Vec<(u64, Color)> = vec![];
+}
+
+/// Records what it issues in [`Issued`].
+pub struct TestIssuer;
+impl vulntoken::Issuer<u64> for TestIssuer {
+	fn issue(owner: &u64, color: Color) -> Result<u64, DispatchError> {
+		if color == Color::Hidden {
+			return Err(DispatchError::Other("hidden"))
+		}
+		let mut issued = Issued::get();
+		issued.push((*owner, color));
+		Issued::set(issued.clone());
+		Ok(issued.len() as u64 - 1)
+	}
+}
+
+impl pallet_snapshot_claims::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Issuer = TestIssuer;
+	type RootOrigin = EnsureRoot<u64>;
+	type MaxProofDepth = ConstU32<8>;
+	type Prefix = Prefix;
+	type WeightInfo = ();
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext: sp_io::TestExternalities = t.into();
+	// Go past genesis block so events get deposited.
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
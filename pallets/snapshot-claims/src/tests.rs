@@ -0,0 +1,141 @@
+use crate::{
+	ethereum_message, hash_pair, mock::*, Claimant, Claimed, EcdsaSignature, Error, Event,
+	ItemSpec,
+};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use sp_core::{H160, H256};
+use sp_io::hashing::keccak_256;
+use sp_runtime::DispatchResult;
+use vulntoken::Color;
+
+fn holder() -> libsecp256k1::SecretKey {
+	libsecp256k1::SecretKey::parse(&keccak_256(b"holder")).unwrap()
+}
+
+fn eth_address(secret: &libsecp256k1::SecretKey) -> H160 {
+	let public = libsecp256k1::PublicKey::from_secret_key(secret);
+	H160::from_slice(&keccak_256(&public.serialize()[1..65])[12..])
+}
+
+/// What `secret` signs to have `who` claim its leaves.
+fn eth_sign(secret: &libsecp256k1::SecretKey, who: u64) -> EcdsaSignature {
+	let message = keccak_256(&ethereum_message(Prefix::get(), &who));
+	let (signature, recovery_id) =
+		libsecp256k1::sign(&libsecp256k1::Message::parse(&message), secret);
+	let mut bytes = [0u8; 65];
+	bytes[..64].copy_from_slice(&signature.serialize());
+	bytes[64] = recovery_id.serialize();
+	EcdsaSignature(bytes)
+}
+
+fn spec(color: Color) -> ItemSpec {
+	ItemSpec { color }
+}
+
+/// The snapshot: account 1 holds a red item, the holder a blue one on Ethereum, and account 2 a
+/// green one.
+fn leaves() -> Vec<H256> {
+	vec![
+		SnapshotClaims::leaf(0, &Claimant::Account(1), &spec(Color::Red)),
+		SnapshotClaims::leaf(1, &Claimant::Ethereum(eth_address(&holder())), &spec(Color::Blue)),
+		SnapshotClaims::leaf(2, &Claimant::Account(2), &spec(Color::Green)),
+	]
+}
+
+/// The root of [`leaves`], with the odd leaf paired with itself, and the proof of each leaf.
+fn tree() -> (H256, Vec<Vec<H256>>) {
+	let leaves = leaves();
+	let left = hash_pair(&leaves[0], &leaves[1]);
+	let right = hash_pair(&leaves[2], &leaves[2]);
+	let proofs = vec![vec![leaves[1], right], vec![leaves[0], right], vec![leaves[2], left]];
+	(hash_pair(&left, &right), proofs)
+}
+
+fn claim(
+	who: u64,
+	index: u32,
+	claimant: Claimant<u64>,
+	color: Color,
+	signature: Option<EcdsaSignature>,
+) -> DispatchResult {
+	let proof = BoundedVec::truncate_from(tree().1[index as usize].clone());
+	let origin = RuntimeOrigin::signed(who);
+	SnapshotClaims::claim(origin, index, claimant, spec(color), proof, signature)
+}
+
+#[test]
+fn accounts_claim_their_own_leaves_once() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			claim(1, 0, Claimant::Account(1), Color::Red, None),
+			Error::<Test>::NoRoot
+		);
+		let root = tree().0;
+		assert_noop!(
+			SnapshotClaims::set_root(RuntimeOrigin::signed(1), root),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(SnapshotClaims::set_root(RuntimeOrigin::root(), root));
+		System::assert_last_event(Event::RootSet { root }.into());
+
+		assert_noop!(
+			claim(1, 0, Claimant::Account(1), Color::Green, None),
+			Error::<Test>::InvalidProof
+		);
+		assert_noop!(
+			claim(2, 0, Claimant::Account(1), Color::Red, None),
+			Error::<Test>::WrongClaimant
+		);
+		assert_ok!(claim(1, 0, Claimant::Account(1), Color::Red, None));
+		System::assert_last_event(Event::Claimed { index: 0, who: 1, collectible: 0 }.into());
+		assert!(Claimed::<Test>::contains_key(0));
+		assert_eq!(Issued::get(), vec![(1, Color::Red)]);
+		assert_noop!(
+			claim(1, 0, Claimant::Account(1), Color::Red, None),
+			Error::<Test>::AlreadyClaimed
+		);
+
+		assert_ok!(claim(2, 2, Claimant::Account(2), Color::Green, None));
+		assert_eq!(Issued::get(), vec![(1, Color::Red), (2, Color::Green)]);
+	});
+}
+
+#[test]
+fn ethereum_holders_claim_for_the_account_they_sign() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(SnapshotClaims::set_root(RuntimeOrigin::root(), tree().0));
+		let claimant = Claimant::Ethereum(eth_address(&holder()));
+
+		assert_noop!(
+			claim(5, 1, claimant.clone(), Color::Blue, None),
+			Error::<Test>::InvalidSignature
+		);
+		// Signatures name the account, so nobody else can take the claim.
+		let for_four = eth_sign(&holder(), 4);
+		assert_noop!(
+			claim(5, 1, claimant.clone(), Color::Blue, Some(for_four.clone())),
+			Error::<Test>::InvalidSignature
+		);
+		let stranger = libsecp256k1::SecretKey::parse(&keccak_256(b"stranger")).unwrap();
+		assert_noop!(
+			claim(4, 1, claimant.clone(), Color::Blue, Some(eth_sign(&stranger, 4))),
+			Error::<Test>::InvalidSignature
+		);
+
+		assert_ok!(claim(5, 1, claimant.clone(), Color::Blue, Some(eth_sign(&holder(), 5))));
+		System::assert_last_event(Event::Claimed { index: 1, who: 5, collectible: 0 }.into());
+		assert_eq!(Issued::get(), vec![(5, Color::Blue)]);
+		assert_noop!(
+			claim(4, 1, claimant, Color::Blue, Some(for_four)),
+			Error::<Test>::AlreadyClaimed
+		);
+	});
+}
+
+#[test]
+fn ethereum_messages_follow_personal_sign() {
+	assert_eq!(
+		ethereum_message(b"Claim for:", &0x0102u16),
+		b"\x19Ethereum Signed Message:\n14Claim for:0201".to_vec()
+	);
+}
@@ -0,0 +1,86 @@
+//! Weights for pallet_snapshot_claims
+//!
+//! Estimated from the storage each call touches. Regenerate them on reference hardware with
+//! the command below whenever a call changes.
+
+// Command:
+// ./target/release/node-template
+// benchmark
+// pallet
+// --chain
+// dev
+// --pallet
+// pallet_snapshot_claims
+// --extrinsic
+// *
+// --steps=50
+// --repeat=20
+// --wasm-execution=compiled
+// --output
+// pallets/snapshot-claims/src/weights.rs
+// --template
+// ./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_snapshot_claims.
+pub trait WeightInfo {
+	fn set_root() -> Weight;
+	fn claim(p: u32, ) -> Weight;
+}
+
+/// Weights for pallet_snapshot_claims using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: SnapshotClaims ClaimsRoot (r:0 w:1)
+	fn set_root() -> Weight {
+		Weight::from_parts(9_214_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: SnapshotClaims ClaimsRoot (r:1 w:0)
+	/// Storage: SnapshotClaims Claimed (r:1 w:1)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule OwnerOfCollectibles (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// The range of component `p` is `[0, MaxProofDepth]`.
+	fn claim(p: u32, ) -> Weight {
+		Weight::from_parts(82_540_000, 4_188)
+			// Standard Error: 1_204
+			.saturating_add(Weight::from_parts(1_873_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: SnapshotClaims ClaimsRoot (r:0 w:1)
+	fn set_root() -> Weight {
+		Weight::from_parts(9_214_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: SnapshotClaims ClaimsRoot (r:1 w:0)
+	/// Storage: SnapshotClaims Claimed (r:1 w:1)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule OwnerOfCollectibles (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// The range of component `p` is `[0, MaxProofDepth]`.
+	fn claim(p: u32, ) -> Weight {
+		Weight::from_parts(82_540_000, 4_188)
+			// Standard Error: 1_204
+			.saturating_add(Weight::from_parts(1_873_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+}
@@ -0,0 +1,71 @@
+//! Runtime API definition for the vulntoken pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_runtime::{DispatchError, Perbill};
+use sp_std::vec::Vec;
+use vulntoken::{CollectibleFilter, CollectibleView, Color, ColorStats, SaleQuote};
+
+sp_api::decl_runtime_apis! {
+	/// Queries against vulntoken state that are awkward to answer from raw storage.
+	pub trait VulntokenApi<AccountId, Balance> where AccountId: Codec, Balance: Codec {
+		/// Whether `blob` matches the metadata hash committed for collectible `unique_id`.
+		fn verify_metadata(unique_id: u64, blob: Vec<u8>) -> bool;
+
+		/// A page of at most `limit` holders and the number of collectibles each holds, starting
+		/// after `start_after`. Call at a historical block to snapshot holders as of that block.
+		fn holders(start_after: Option<AccountId>, limit: u32) -> Vec<(AccountId, u32)>;
+
+		/// The price of the cheapest fixed-price listing, if anything is listed.
+		fn floor_price() -> Option<Balance>;
+
+		/// Sale volume over the pallet's rolling window of recent epochs.
+		fn rolling_volume() -> Balance;
+
+		/// Count, floor price and last sale price of the collectibles of `color`.
+		fn color_stats(color: Color) -> ColorStats<Balance>;
+
+		/// A page of at most `limit` collectibles matching `filter`, with their colors and prices,
+		/// in ascending id order after `start_after`.
+		fn find_collectibles(
+			filter: CollectibleFilter<Balance>,
+			start_after: Option<u64>,
+			limit: u32,
+		) -> Vec<(u64, Color, Option<Balance>)>;
+
+		/// Whether collectible `unique_id` was flagged for trading in a circle, which keeps it out
+		/// of the volume and floor statistics.
+		fn is_wash_traded(unique_id: u64) -> bool;
+
+		/// Run `buyer` buying collectible `unique_id` for at most `max_price` against the state
+		/// at the block, without keeping the result. Returns what the purchase would pay whom, or
+		/// the error it would fail with.
+		fn dry_run_buy(
+			buyer: AccountId,
+			unique_id: u64,
+			max_price: Balance,
+		) -> Result<SaleQuote<AccountId, Balance>, DispatchError>;
+
+		/// Collectible `unique_id`, if it exists.
+		fn collectible(unique_id: u64) -> Option<CollectibleView<AccountId, Balance>>;
+
+		/// Ids of the collectibles `owner` holds.
+		fn collectibles_of(owner: AccountId) -> Vec<u64>;
+
+		/// The storage keys to prove, at the block, to show who owns collectible `unique_id` or
+		/// that it does not exist. `vulntoken_proveCollectible` builds the proof.
+		fn prove_collectible(unique_id: u64) -> Vec<Vec<u8>>;
+
+		/// The royalty a sale of collectible `unique_id` pays its creator: its own if it has one,
+		/// else its creator's default. `None` if the collectible does not exist.
+		fn effective_royalty(unique_id: u64) -> Option<Perbill>;
+	}
+
+	/// Debugging aids, only implemented by runtimes built with their `debug-api` feature.
+	pub trait VulntokenDebugApi {
+		/// At most `limit` raw keys and values of vulntoken storage in key order, after
+		/// `start_after`.
+		fn dump_storage(start_after: Option<Vec<u8>>, limit: u32) -> Vec<(Vec<u8>, Vec<u8>)>;
+	}
+}
@@ -0,0 +1,1626 @@
+//! Benchmarking setup for vulntoken
+//!
+//! Every benchmark sets up the worst case its call can hit: ownership vectors filled to
+//! `MaximumOwned`, every bid slot and raffle ticket taken, royalty enforcement on.
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as VulnModule;
+use frame_benchmarking::v2::*;
+use frame_support::{
+	sp_runtime::{
+		traits::{Bounded, Saturating},
+		Percent,
+	},
+	traits::{
+		fungible::{Inspect, Mutate},
+		Get, Hooks, SortedMembers,
+	},
+	BoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use pallet_payment_streams::PaymentStreams;
+use sp_std::vec;
+
+const SEED: u32 = 0;
+
+/// An account with enough balance for any deposit or purchase in these benchmarks.
+fn funded<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let who = account(name, index, SEED);
+	T::Currency::set_balance(&who, BalanceOf::<T>::max_value() / 4u32.into());
+	who
+}
+
+/// The whitelisted caller, funded like [`funded`] accounts.
+fn funded_caller<T: Config>() -> T::AccountId {
+	let caller = whitelisted_caller();
+	T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 4u32.into());
+	caller
+}
+
+/// A price low enough to stay under `HighValueThreshold`.
+fn price<T: Config>() -> BalanceOf<T> {
+	T::Currency::minimum_balance().saturating_mul(10u32.into())
+}
+
+/// Mint `count` collectibles to `owner` and return the id of the last one.
+fn mint_many<T: Config>(owner: &T::AccountId, count: u32) -> u64 {
+	let mut last = 0;
+	for _ in 0..count {
+		// Setup is not subject to the per-block mint limit.
+		MintsThisBlock::<T>::kill();
+		let (unique_id, color) = VulnModule::<T>::gen_unique_id();
+		last = VulnModule::<T>::mint(owner, owner, unique_id, color).expect("owner is below the cap");
+	}
+	last
+}
+
+/// A collectible owned by `owner`, the last of a full ownership vector.
+fn full_owner<T: Config>(owner: &T::AccountId) -> u64 {
+	mint_many::<T>(owner, T::MaximumOwned::get())
+}
+
+/// A collectible listed by a seller at the ownership cap, bought by a buyer one below it.
+fn listed<T: Config>() -> (T::AccountId, T::AccountId, u64) {
+	let seller = funded::<T>("seller", 0);
+	let buyer = funded::<T>("buyer", 0);
+	let unique_id = full_owner::<T>(&seller);
+	mint_many::<T>(&buyer, T::MaximumOwned::get() - 1);
+	list::<T>(&seller, unique_id);
+	(seller, buyer, unique_id)
+}
+
+/// List `unique_id` at [`price`], holding the listing deposit from `owner`.
+fn list<T: Config>(owner: &T::AccountId, unique_id: u64) {
+	VulnModule::<T>::set_price(
+		RawOrigin::Signed(owner.clone()).into(),
+		owner.clone(),
+		unique_id,
+		Some(price::<T>()),
+	)
+	.unwrap();
+}
+
+/// Fill the floor index, and that of `color`, with listings dearer than [`price`], so listing
+/// at it evicts one from each.
+fn full_floor_index<T: Config>(color: Color) {
+	let dearer = price::<T>().saturating_mul(2u32.into());
+	let cheapest = (0..T::MaxFloorIndex::get()).map(|i| (dearer, u64::MAX - i as u64)).collect();
+	let index = CheapestListings::<T> {
+		cheapest: BoundedVec::truncate_from(cheapest),
+		..Default::default()
+	};
+	FloorIndex::<T>::put(index.clone());
+	ColorFloorIndex::<T>::insert(color, index);
+}
+
+fn set_block<T: Config>(n: BlockNumberFor<T>) {
+	frame_system::Pallet::<T>::set_block_number(n);
+}
+
+fn now<T: Config>() -> BlockNumberFor<T> {
+	frame_system::Pallet::<T>::block_number()
+}
+
+/// Fill the transfers due at `at` up to one short of `MaxDeadlinesPerBlock`.
+fn crowd_timed_transfers<T: Config>(at: BlockNumberFor<T>) {
+	let others = (0..T::MaxDeadlinesPerBlock::get().saturating_sub(1))
+		.map(|i| u64::MAX - i as u64)
+		.collect::<sp_std::vec::Vec<_>>();
+	TimedTransfersDue::<T>::insert(at, BoundedVec::truncate_from(others));
+}
+
+/// Fill the auctions ending at `end` up to one short of `MaxDeadlinesPerBlock`.
+fn crowd_auctions<T: Config>(end: BlockNumberFor<T>) {
+	let others = (0..T::MaxDeadlinesPerBlock::get().saturating_sub(1))
+		.map(|i| u64::MAX - i as u64)
+		.collect::<sp_std::vec::Vec<_>>();
+	AuctionsEnding::<T>::insert(end, BoundedVec::truncate_from(others));
+}
+
+/// Fill the sealed-bid auctions ending their reveal phase at `end` up to one short of
+/// `MaxDeadlinesPerBlock`.
+fn crowd_sealed_auctions<T: Config>(end: BlockNumberFor<T>) {
+	let others = (0..T::MaxDeadlinesPerBlock::get().saturating_sub(1))
+		.map(|i| u64::MAX - i as u64)
+		.collect::<sp_std::vec::Vec<_>>();
+	SealedAuctionsEnding::<T>::insert(end, BoundedVec::truncate_from(others));
+}
+
+/// The item of [`listed`], auctioned for one block, with the buyer leading at [`price`].
+fn auctioned<T: Config>() -> (T::AccountId, T::AccountId, u64) {
+	let (seller, buyer, unique_id) = listed::<T>();
+	VulnModule::<T>::do_start_auction(seller.clone(), unique_id, price::<T>(), 1u32.into())
+		.unwrap();
+	VulnModule::<T>::do_place_bid(buyer.clone(), unique_id, price::<T>()).unwrap();
+	(seller, buyer, unique_id)
+}
+
+/// The item of [`listed`], scheduled to go from the seller to the buyer at block 10.
+fn timed_transfer<T: Config>() -> (T::AccountId, T::AccountId, u64) {
+	let (seller, buyer, unique_id) = listed::<T>();
+	let at = 10u32.into();
+	crowd_timed_transfers::<T>(at);
+	VulnModule::<T>::do_transfer_at(seller.clone(), buyer.clone(), unique_id, at).unwrap();
+	(seller, buyer, unique_id)
+}
+
+/// An owner who named an heir and has been inactive long enough for the heir to claim.
+fn inactive_owner<T: Config>() -> (T::AccountId, T::AccountId) {
+	let owner = funded::<T>("owner", 0);
+	let heir = funded::<T>("heir", 0);
+	let period = T::MinInactivityPeriod::get();
+	VulnModule::<T>::do_set_heir(owner.clone(), Some((heir.clone(), period))).unwrap();
+	set_block::<T>(now::<T>().saturating_add(period));
+	(owner, heir)
+}
+
+/// An escrow opened by the buyer of [`listed`].
+fn escrowed<T: Config>() -> (T::AccountId, T::AccountId, u64) {
+	let (seller, buyer, unique_id) = listed::<T>();
+	VulnModule::<T>::do_buy_with_escrow(buyer.clone(), unique_id).unwrap();
+	(seller, buyer, unique_id)
+}
+
+/// The dispute juror set, seeded with one juror if it is empty.
+fn jurors<T: Config>() -> sp_std::vec::Vec<T::AccountId> {
+	let mut jurors = T::Jurors::sorted_members();
+	if jurors.is_empty() {
+		let juror = account("juror", 0, SEED);
+		T::Jurors::add(&juror);
+		jurors = vec![juror];
+	}
+	jurors
+}
+
+/// The collectible of [`listed`], bought in two installments of which the first is paid.
+fn in_installments<T: Config>() -> (T::AccountId, T::AccountId, u64) {
+	let (seller, buyer, unique_id) = listed::<T>();
+	VulnModule::<T>::do_buy_in_installments(buyer.clone(), unique_id, 2).unwrap();
+	(seller, buyer, unique_id)
+}
+
+/// Fill the buy side of the order book with single-item orders from `bidder` at [`price`].
+fn full_buy_book<T: Config>(bidder: &T::AccountId) {
+	for _ in 0..T::MaxBookDepth::get() {
+		VulnModule::<T>::do_place_buy_order(bidder.clone(), price::<T>(), 1).unwrap();
+	}
+}
+
+/// A collectible rented out at one existential deposit per block, prepaid for
+/// [`price`] worth of blocks.
+fn rented<T: Config>() -> (T::AccountId, T::AccountId, u64) {
+	let owner = funded::<T>("owner", 0);
+	let renter = funded::<T>("renter", 0);
+	let unique_id = full_owner::<T>(&owner);
+	VulnModule::<T>::do_offer_rental(owner.clone(), unique_id, Some(T::Currency::minimum_balance()))
+		.unwrap();
+	VulnModule::<T>::do_rent(renter.clone(), unique_id, price::<T>()).unwrap();
+	(owner, renter, unique_id)
+}
+
+/// An attribute key of the largest size, a different one for each `i`.
+fn attribute_key<T: Config>(i: u32) -> BoundedVec<u8, T::AttributeKeyLimit> {
+	let mut key = i.to_le_bytes().to_vec();
+	key.resize(T::AttributeKeyLimit::get() as usize, 0);
+	BoundedVec::truncate_from(key)
+}
+
+/// Store the largest metadata on `unique_id`, and `attributes` of the largest attributes, on the
+/// deposit of its owner `owner`.
+fn fill_data<T: Config>(owner: &T::AccountId, unique_id: u64, attributes: u32) {
+	let metadata = BoundedVec::truncate_from(vec![0; T::MetadataLimit::get() as usize]);
+	VulnModule::<T>::do_set_metadata(owner.clone(), unique_id, metadata).unwrap();
+	let value = BoundedVec::truncate_from(vec![0; T::AttributeValueLimit::get() as usize]);
+	for i in 0..attributes {
+		let key = attribute_key::<T>(i);
+		VulnModule::<T>::do_set_attribute(owner.clone(), unique_id, key, value.clone()).unwrap();
+	}
+}
+
+/// Fill the offers on `unique_id` up to `MaxOffers`, each from a bidder of its own.
+fn fill_offers<T: Config>(unique_id: u64) {
+	for i in 0..T::MaxOffers::get() {
+		let bidder = funded::<T>("bidder", i);
+		VulnModule::<T>::do_make_offer(bidder, unique_id, price::<T>()).unwrap();
+	}
+}
+
+/// A collectible carrying [`fill_data`], and its owner, who bought it from the account that
+/// still holds the deposit for its data.
+fn with_data<T: Config>(attributes: u32) -> (T::AccountId, u64) {
+	let previous = funded::<T>("previous", 0);
+	let owner = funded::<T>("owner", 0);
+	let unique_id = mint_many::<T>(&previous, 1);
+	fill_data::<T>(&previous, unique_id, attributes);
+	mint_many::<T>(&owner, T::MaximumOwned::get() - 1);
+	VulnModule::<T>::do_transfer(unique_id, owner.clone()).unwrap();
+	(owner, unique_id)
+}
+
+/// Fill the swaps expiring at `deadline` up to one short of `MaxDeadlinesPerBlock`.
+fn crowd_swaps<T: Config>(deadline: BlockNumberFor<T>) {
+	let others = (0..T::MaxDeadlinesPerBlock::get().saturating_sub(1))
+		.map(|i| u64::MAX - i as u64)
+		.collect::<sp_std::vec::Vec<_>>();
+	SwapDeadlines::<T>::insert(deadline, BoundedVec::truncate_from(others));
+}
+
+/// The item of [`listed`], offered by the seller until block 10 for a collectible of the buyer
+/// and [`price`] on top. Returns the seller, the buyer and the offered id.
+fn swapped<T: Config>() -> (T::AccountId, T::AccountId, u64) {
+	let (seller, buyer, unique_id) = listed::<T>();
+	let desired = OwnerOfCollectibles::<T>::get(&buyer)[0];
+	let deadline = 10u32.into();
+	crowd_swaps::<T>(deadline);
+	let sweetener = Some(price::<T>());
+	VulnModule::<T>::do_create_swap(seller.clone(), unique_id, desired, sweetener, deadline)
+		.unwrap();
+	(seller, buyer, unique_id)
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn create_collectible() {
+		let caller = funded_caller::<T>();
+		let to = funded::<T>("to", 0);
+		mint_many::<T>(&to, T::MaximumOwned::get() - 1);
+		MintsThisBlock::<T>::put(T::MaxMintsPerBlock::get() - 1);
+		AcceptsMints::<T>::insert(&to, ());
+
+		#[extrinsic_call]
+		create_collectible(RawOrigin::Signed(caller), to.clone());
+
+		assert_eq!(OwnerOfCollectibles::<T>::get(&to).len() as u32, T::MaximumOwned::get());
+	}
+
+	#[benchmark]
+	fn transfer() {
+		let from = funded::<T>("from", 0);
+		let to = funded::<T>("to", 0);
+		let unique_id = full_owner::<T>(&from);
+		mint_many::<T>(&to, T::MaximumOwned::get() - 1);
+		RoyaltiesEnforced::<T>::put(true);
+		ApprovedMarkets::<T>::insert(&to, ());
+		list::<T>(&from, unique_id);
+		// Sent by an operator, so every approval is looked up.
+		let operator: T::AccountId = whitelisted_caller();
+		OperatorApprovals::<T>::insert(&from, &operator, ());
+
+		#[extrinsic_call]
+		transfer(RawOrigin::Signed(operator), to.clone(), unique_id);
+
+		assert_eq!(CollectibleMap::<T>::get(unique_id).unwrap().owner, to);
+	}
+
+	#[benchmark]
+	fn burn() {
+		let owner = funded::<T>("owner", 0);
+		let unique_id = full_owner::<T>(&owner);
+		list::<T>(&owner, unique_id);
+		VulnModule::<T>::take_mint_deposit(&owner, unique_id).unwrap();
+		fill_data::<T>(&owner, unique_id, T::MaxAttributes::get());
+		fill_offers::<T>(unique_id);
+
+		#[extrinsic_call]
+		burn(RawOrigin::Signed(owner), unique_id);
+
+		assert!(!CollectibleMap::<T>::contains_key(unique_id));
+	}
+
+	#[benchmark]
+	fn set_price() {
+		let owner = funded::<T>("owner", 0);
+		let unique_id = full_owner::<T>(&owner);
+		CooldownUntil::<T>::insert(&unique_id, now::<T>());
+		full_floor_index::<T>(CollectibleMap::<T>::get(&unique_id).unwrap().color);
+		let operator: T::AccountId = whitelisted_caller();
+		OperatorApprovals::<T>::insert(&owner, &operator, ());
+
+		#[extrinsic_call]
+		set_price(RawOrigin::Signed(operator), owner, unique_id, Some(price::<T>()));
+
+		assert!(ListedAt::<T>::contains_key(unique_id));
+		assert_eq!(FloorPrice::<T>::get(), Some(price::<T>()));
+	}
+
+	/// Besides full ownership vectors, the sale is large enough that both identities are
+	/// checked, gives to charity and starts a cooldown.
+	#[benchmark]
+	fn buy_collectible() {
+		let (seller, buyer, unique_id) = listed::<T>();
+		VulnModule::<T>::set_price(
+			RawOrigin::Signed(seller.clone()).into(),
+			seller.clone(),
+			unique_id,
+			Some(T::HighValueThreshold::get()),
+		)
+		.unwrap();
+		T::IdentityVerifier::verify(&seller);
+		T::IdentityVerifier::verify(&buyer);
+		TransferCooldown::<T>::put(BlockNumberFor::<T>::from(1u32));
+		let beneficiary = account("beneficiary", 0, SEED);
+		ListingCharities::<T>::insert(&unique_id, (beneficiary, Percent::from_percent(10)));
+		// A creator other than the seller is paid the royalty, read from both maps.
+		let creator = funded::<T>("creator", 0);
+		CollectibleMap::<T>::mutate(&unique_id, |c| c.as_mut().unwrap().creator = creator.clone());
+		DefaultRoyalties::<T>::insert(&creator, T::MaxRoyalty::get());
+		// Both parties have their trades counted against a limit.
+		let limit = TradingLimit { max_volume: None, max_trades: Some(u32::MAX) };
+		for who in [&seller, &buyer] {
+			SelfTradingLimits::<T>::insert(who, limit);
+			ImposedTradingLimits::<T>::insert(who, limit);
+		}
+
+		#[extrinsic_call]
+		buy_collectible(RawOrigin::Signed(buyer.clone()), unique_id, T::HighValueThreshold::get());
+
+		assert_eq!(CollectibleMap::<T>::get(unique_id).unwrap().owner, buyer);
+		assert!(CooldownUntil::<T>::contains_key(unique_id));
+	}
+
+	#[benchmark]
+	fn start_sealed_auction() {
+		let (seller, _, unique_id) = listed::<T>();
+		let one = 1u32.into();
+		crowd_sealed_auctions::<T>(now::<T>().saturating_add(2u32.into()));
+
+		#[extrinsic_call]
+		start_sealed_auction(RawOrigin::Signed(seller), unique_id, price::<T>(), one, one);
+
+		assert!(SealedAuctions::<T>::contains_key(unique_id));
+	}
+
+	#[benchmark]
+	fn commit_bid() {
+		let seller = funded::<T>("seller", 0);
+		let bidder = funded::<T>("bidder", 0);
+		let unique_id = full_owner::<T>(&seller);
+		let one = 1u32.into();
+		VulnModule::<T>::do_start_sealed_auction(seller, unique_id, price::<T>(), one, one)
+			.unwrap();
+		let commitment = VulnModule::<T>::sealed_bid_commitment(&bidder, price::<T>(), &[0; 32]);
+
+		#[extrinsic_call]
+		commit_bid(RawOrigin::Signed(bidder.clone()), unique_id, commitment);
+
+		assert!(SealedBids::<T>::contains_key(unique_id, bidder));
+	}
+
+	#[benchmark]
+	fn reveal_bid() {
+		let seller = funded::<T>("seller", 0);
+		let leader = funded::<T>("bidder", 0);
+		let bidder = funded::<T>("bidder", 1);
+		let unique_id = full_owner::<T>(&seller);
+		let one = 1u32.into();
+		VulnModule::<T>::do_start_sealed_auction(seller, unique_id, price::<T>(), one, one)
+			.unwrap();
+		let low = price::<T>();
+		let high = low.saturating_mul(2u32.into());
+		for (who, amount) in [(&leader, low), (&bidder, high)] {
+			let commitment = VulnModule::<T>::sealed_bid_commitment(who, amount, &[0; 32]);
+			VulnModule::<T>::do_commit_bid(who.clone(), unique_id, commitment).unwrap();
+		}
+		set_block::<T>(now::<T>().saturating_add(one));
+		// Outbidding a leader also releases the previous leader's hold.
+		VulnModule::<T>::do_reveal_bid(leader, unique_id, low, [0; 32]).unwrap();
+
+		#[extrinsic_call]
+		reveal_bid(RawOrigin::Signed(bidder.clone()), unique_id, high, [0; 32]);
+
+		assert_eq!(SealedAuctions::<T>::get(unique_id).unwrap().leader, Some((bidder, high)));
+	}
+
+	#[benchmark]
+	fn settle_sealed_auction(b: Linear<1, { T::MaxSealedBids::get() }>) {
+		let seller = funded::<T>("seller", 0);
+		let unique_id = full_owner::<T>(&seller);
+		let one = 1u32.into();
+		VulnModule::<T>::do_start_sealed_auction(seller, unique_id, price::<T>(), one, one)
+			.unwrap();
+		let bidders = (0..b).map(|i| funded::<T>("bidder", i)).collect::<sp_std::vec::Vec<_>>();
+		for bidder in &bidders {
+			let commitment = VulnModule::<T>::sealed_bid_commitment(bidder, price::<T>(), &[0; 32]);
+			VulnModule::<T>::do_commit_bid(bidder.clone(), unique_id, commitment).unwrap();
+		}
+		mint_many::<T>(&bidders[0], T::MaximumOwned::get() - 1);
+		set_block::<T>(now::<T>().saturating_add(one));
+		// One winner, every other bidder is slashed for not revealing.
+		VulnModule::<T>::do_reveal_bid(bidders[0].clone(), unique_id, price::<T>(), [0; 32])
+			.unwrap();
+		set_block::<T>(now::<T>().saturating_add(one));
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		settle_sealed_auction(RawOrigin::Signed(caller), unique_id);
+
+		assert_eq!(CollectibleMap::<T>::get(unique_id).unwrap().owner, bidders[0]);
+	}
+
+	#[benchmark]
+	fn start_raffle() {
+		let (owner, _, unique_id) = listed::<T>();
+		let max = T::MaxRaffleTickets::get();
+
+		#[extrinsic_call]
+		start_raffle(RawOrigin::Signed(owner), unique_id, price::<T>(), 1, max, 1u32.into());
+
+		assert!(Raffles::<T>::contains_key(unique_id));
+	}
+
+	#[benchmark]
+	fn buy_tickets(c: Linear<1, { T::MaxRaffleTickets::get() }>) {
+		let owner = funded::<T>("owner", 0);
+		let buyer = funded::<T>("buyer", 0);
+		let unique_id = full_owner::<T>(&owner);
+		let max = T::MaxRaffleTickets::get();
+		VulnModule::<T>::do_start_raffle(owner, unique_id, price::<T>(), 1, max, 1u32.into())
+			.unwrap();
+
+		#[extrinsic_call]
+		buy_tickets(RawOrigin::Signed(buyer), unique_id, c);
+
+		assert_eq!(Raffles::<T>::get(unique_id).unwrap().sold, c);
+	}
+
+	#[benchmark]
+	fn draw_raffle(t: Linear<1, { T::MaxRaffleTickets::get() }>) {
+		let owner = funded::<T>("owner", 0);
+		let unique_id = full_owner::<T>(&owner);
+		let max = T::MaxRaffleTickets::get();
+		VulnModule::<T>::do_start_raffle(owner, unique_id, price::<T>(), 1, max, 1u32.into())
+			.unwrap();
+		// Every ticket has its own holder, and each could win into a full ownership vector.
+		for i in 0..t {
+			let buyer = funded::<T>("buyer", i);
+			mint_many::<T>(&buyer, T::MaximumOwned::get() - 1);
+			VulnModule::<T>::do_buy_tickets(buyer, unique_id, 1).unwrap();
+		}
+		set_block::<T>(now::<T>().saturating_add(1u32.into()));
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		draw_raffle(RawOrigin::Signed(caller), unique_id);
+
+		assert!(!Raffles::<T>::contains_key(unique_id));
+	}
+
+	#[benchmark]
+	fn mint_mystery_box() {
+		let caller = funded_caller::<T>();
+		let to = funded::<T>("to", 0);
+		mint_many::<T>(&to, T::MaximumOwned::get() - 1);
+		MintsThisBlock::<T>::put(T::MaxMintsPerBlock::get() - 1);
+		AcceptsMints::<T>::insert(&to, ());
+
+		#[extrinsic_call]
+		mint_mystery_box(RawOrigin::Signed(caller), to.clone());
+
+		assert_eq!(OwnerOfCollectibles::<T>::get(&to).len() as u32, T::MaximumOwned::get());
+	}
+
+	#[benchmark]
+	fn reveal() {
+		let owner = funded::<T>("owner", 0);
+		let unique_id = VulnModule::<T>::do_mint_mystery_box(&owner, &owner).unwrap();
+		// Far enough past the reveal block for any randomness source to have caught up.
+		let reveal_at = MysteryBoxes::<T>::get(unique_id).unwrap().reveal_at;
+		set_block::<T>(reveal_at.saturating_add(1_000u32.into()));
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		reveal(RawOrigin::Signed(caller), unique_id);
+
+		assert!(RevealRecords::<T>::contains_key(unique_id));
+	}
+
+	#[benchmark]
+	fn set_royalty_enforcement() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		set_royalty_enforcement(origin as T::RuntimeOrigin, true);
+
+		assert!(RoyaltiesEnforced::<T>::get());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn approve_market() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let market: T::AccountId = account("market", 0, SEED);
+
+		#[extrinsic_call]
+		approve_market(origin as T::RuntimeOrigin, market.clone());
+
+		assert!(ApprovedMarkets::<T>::contains_key(market));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_market() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let market: T::AccountId = account("market", 0, SEED);
+		ApprovedMarkets::<T>::insert(&market, ());
+
+		#[extrinsic_call]
+		remove_market(origin as T::RuntimeOrigin, market.clone());
+
+		assert!(!ApprovedMarkets::<T>::contains_key(market));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_transfer_cooldown() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let blocks = 10u32.into();
+
+		#[extrinsic_call]
+		set_transfer_cooldown(origin as T::RuntimeOrigin, blocks);
+
+		assert_eq!(TransferCooldown::<T>::get(), blocks);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn buy_with_escrow() {
+		let (_, buyer, unique_id) = listed::<T>();
+
+		#[extrinsic_call]
+		buy_with_escrow(RawOrigin::Signed(buyer), unique_id);
+
+		assert!(Escrows::<T>::contains_key(unique_id));
+	}
+
+	#[benchmark]
+	fn release_escrow() {
+		let (_, buyer, unique_id) = escrowed::<T>();
+
+		#[extrinsic_call]
+		release_escrow(RawOrigin::Signed(buyer.clone()), unique_id);
+
+		assert_eq!(CollectibleMap::<T>::get(unique_id).unwrap().owner, buyer);
+	}
+
+	#[benchmark]
+	fn raise_dispute() {
+		let (_, buyer, unique_id) = escrowed::<T>();
+
+		#[extrinsic_call]
+		raise_dispute(RawOrigin::Signed(buyer), unique_id);
+
+		assert!(Disputes::<T>::contains_key(unique_id));
+	}
+
+	#[benchmark]
+	fn vote_dispute() {
+		let (_, buyer, unique_id) = escrowed::<T>();
+		VulnModule::<T>::do_raise_dispute(buyer, unique_id).unwrap();
+		let juror = jurors::<T>().remove(0);
+
+		#[extrinsic_call]
+		vote_dispute(RawOrigin::Signed(juror.clone()), unique_id, false);
+
+		assert!(DisputeVotes::<T>::contains_key(unique_id, juror));
+	}
+
+	#[benchmark]
+	fn resolve_dispute() {
+		let (_, buyer, unique_id) = escrowed::<T>();
+		VulnModule::<T>::do_raise_dispute(buyer, unique_id).unwrap();
+		// Every juror votes and the sale goes ahead: the raiser is slashed and the item moves.
+		for juror in jurors::<T>() {
+			VulnModule::<T>::do_vote_dispute(juror, unique_id, false).unwrap();
+		}
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		resolve_dispute(RawOrigin::Signed(caller), unique_id);
+
+		assert!(!Escrows::<T>::contains_key(unique_id));
+	}
+
+	#[benchmark]
+	fn cancel_listing() {
+		let (seller, _, unique_id) = listed::<T>();
+
+		#[extrinsic_call]
+		cancel_listing(RawOrigin::Signed(seller), unique_id);
+
+		assert!(!ListedAt::<T>::contains_key(unique_id));
+	}
+
+	#[benchmark]
+	fn clear_expired_listing() {
+		let (_, _, unique_id) = listed::<T>();
+		set_block::<T>(now::<T>().saturating_add(T::MaxListingDuration::get()));
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		clear_expired_listing(RawOrigin::Signed(caller), unique_id);
+
+		assert!(!ListedAt::<T>::contains_key(unique_id));
+	}
+
+	#[benchmark]
+	fn flag_listing() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let (_, _, unique_id) = listed::<T>();
+
+		#[extrinsic_call]
+		flag_listing(origin as T::RuntimeOrigin, unique_id);
+
+		assert!(!ListingDeposits::<T>::contains_key(unique_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_metadata_hash() {
+		let owner = funded::<T>("owner", 0);
+		let unique_id = full_owner::<T>(&owner);
+
+		#[extrinsic_call]
+		set_metadata_hash(RawOrigin::Signed(owner), unique_id, Some([0; 32]));
+
+		assert!(CollectibleMap::<T>::get(unique_id).unwrap().metadata.is_some());
+	}
+
+	#[benchmark]
+	fn set_metadata_cid() {
+		let owner = funded::<T>("owner", 0);
+		let unique_id = full_owner::<T>(&owner);
+		let cid: BoundedVec<u8, T::MaxCidLength> =
+			vec![b'a'; T::MaxCidLength::get() as usize].try_into().unwrap();
+
+		#[extrinsic_call]
+		set_metadata_cid(RawOrigin::Signed(owner), unique_id, cid);
+
+		assert!(PinQueue::<T>::contains_key(unique_id));
+	}
+
+	#[benchmark]
+	fn set_accept_mints() {
+		let who: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		set_accept_mints(RawOrigin::Signed(who.clone()), true);
+
+		assert!(AcceptsMints::<T>::contains_key(who));
+	}
+
+	#[benchmark]
+	fn claim_mints(c: Linear<1, { T::MaxMintsPerBlock::get().min(T::MaximumOwned::get()) }>) {
+		let who = funded::<T>("who", 0);
+		mint_many::<T>(&who, T::MaximumOwned::get() - c);
+		let minter = funded::<T>("minter", 0);
+		MintsThisBlock::<T>::kill();
+		for _ in 0..c {
+			VulnModule::<T>::do_create_collectible(minter.clone(), who.clone()).unwrap();
+		}
+		MintsThisBlock::<T>::kill();
+
+		#[extrinsic_call]
+		claim_mints(RawOrigin::Signed(who.clone()), c);
+
+		assert_eq!(OwnerOfCollectibles::<T>::get(&who).len() as u32, T::MaximumOwned::get());
+	}
+
+	#[benchmark]
+	fn confirm_pin() {
+		let owner = funded::<T>("owner", 0);
+		let unique_id = full_owner::<T>(&owner);
+		PinQueue::<T>::insert(&unique_id, ());
+
+		#[extrinsic_call]
+		confirm_pin(RawOrigin::None, unique_id);
+
+		assert!(!PinQueue::<T>::contains_key(unique_id));
+	}
+
+	#[benchmark]
+	fn offer_rental() {
+		let owner = funded::<T>("owner", 0);
+		let unique_id = full_owner::<T>(&owner);
+
+		#[extrinsic_call]
+		offer_rental(RawOrigin::Signed(owner), unique_id, Some(price::<T>()));
+
+		assert_eq!(RentalRates::<T>::get(unique_id), Some(price::<T>()));
+	}
+
+	#[benchmark]
+	fn rent() {
+		let owner = funded::<T>("owner", 0);
+		let renter = funded::<T>("renter", 0);
+		let unique_id = full_owner::<T>(&owner);
+		VulnModule::<T>::do_offer_rental(owner, unique_id, Some(T::Currency::minimum_balance()))
+			.unwrap();
+
+		#[extrinsic_call]
+		rent(RawOrigin::Signed(renter), unique_id, price::<T>());
+
+		assert!(Rentals::<T>::contains_key(unique_id));
+	}
+
+	#[benchmark]
+	fn top_up_rental() {
+		let (_, renter, unique_id) = rented::<T>();
+
+		#[extrinsic_call]
+		top_up_rental(RawOrigin::Signed(renter), unique_id, price::<T>());
+
+		let stream = Rentals::<T>::get(unique_id).unwrap().stream;
+		assert_eq!(T::Streams::remaining(stream), Some(price::<T>().saturating_mul(2u32.into())));
+	}
+
+	#[benchmark]
+	fn settle_rental() {
+		let (_, renter, unique_id) = rented::<T>();
+		// The prepayment runs out, so the rental ends as well.
+		set_block::<T>(now::<T>().saturating_add(10u32.into()));
+
+		#[extrinsic_call]
+		settle_rental(RawOrigin::Signed(renter), unique_id);
+
+		assert!(!Rentals::<T>::contains_key(unique_id));
+	}
+
+	#[benchmark]
+	fn end_rental() {
+		let (_, renter, unique_id) = rented::<T>();
+		set_block::<T>(now::<T>().saturating_add(5u32.into()));
+
+		#[extrinsic_call]
+		end_rental(RawOrigin::Signed(renter), unique_id);
+
+		assert!(!Rentals::<T>::contains_key(unique_id));
+	}
+
+	#[benchmark]
+	fn buy_in_installments() {
+		let (_, buyer, unique_id) = listed::<T>();
+
+		#[extrinsic_call]
+		buy_in_installments(RawOrigin::Signed(buyer), unique_id, T::MaxInstallments::get());
+
+		assert!(InstallmentPlans::<T>::contains_key(unique_id));
+	}
+
+	#[benchmark]
+	fn pay_installment() {
+		// The last installment completes the sale.
+		let (_, buyer, unique_id) = in_installments::<T>();
+
+		#[extrinsic_call]
+		pay_installment(RawOrigin::Signed(buyer.clone()), unique_id);
+
+		assert_eq!(CollectibleMap::<T>::get(unique_id).unwrap().owner, buyer);
+	}
+
+	#[benchmark]
+	fn enforce_installment_default() {
+		let (seller, _, unique_id) = in_installments::<T>();
+		set_block::<T>(InstallmentPlans::<T>::get(unique_id).unwrap().due);
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		enforce_installment_default(RawOrigin::Signed(caller), unique_id);
+
+		assert!(!InstallmentPlans::<T>::contains_key(unique_id));
+		assert_eq!(CollectibleMap::<T>::get(unique_id).unwrap().owner, seller);
+	}
+
+	// The worst case sells to a buy order in a full book.
+	#[benchmark]
+	fn place_sell_order() {
+		let (seller, buyer, unique_id) = listed::<T>();
+		full_buy_book::<T>(&buyer);
+
+		#[extrinsic_call]
+		place_sell_order(RawOrigin::Signed(seller), unique_id, price::<T>());
+
+		assert_eq!(CollectibleMap::<T>::get(unique_id).unwrap().owner, buyer);
+	}
+
+	// Every item bought comes from a different seller at the ownership cap.
+	#[benchmark]
+	fn place_buy_order(q: Linear<1, { T::MaxBookDepth::get().min(T::MaximumOwned::get()) }>) {
+		let buyer = funded::<T>("buyer", 0);
+		mint_many::<T>(&buyer, T::MaximumOwned::get() - q);
+		for i in 0..q {
+			let seller = funded::<T>("seller", i);
+			let unique_id = full_owner::<T>(&seller);
+			VulnModule::<T>::do_place_sell_order(seller, unique_id, price::<T>()).unwrap();
+		}
+
+		#[extrinsic_call]
+		place_buy_order(RawOrigin::Signed(buyer.clone()), price::<T>(), q);
+
+		assert!(SellBook::<T>::get().is_empty());
+		assert_eq!(OwnerOfCollectibles::<T>::get(&buyer).len() as u32, T::MaximumOwned::get());
+	}
+
+	#[benchmark]
+	fn cancel_buy_order() {
+		let bidder = funded::<T>("bidder", 0);
+		full_buy_book::<T>(&bidder);
+		let last: u64 = T::MaxBookDepth::get().saturating_sub(1).into();
+
+		#[extrinsic_call]
+		cancel_buy_order(RawOrigin::Signed(bidder), last, 1);
+
+		assert!(BuyBook::<T>::get().iter().all(|order| order.id != last));
+	}
+
+	#[benchmark]
+	fn cancel_sell_order() {
+		let seller = funded::<T>("seller", 0);
+		let unique_id = full_owner::<T>(&seller);
+		VulnModule::<T>::do_place_sell_order(seller.clone(), unique_id, price::<T>()).unwrap();
+
+		#[extrinsic_call]
+		cancel_sell_order(RawOrigin::Signed(seller), unique_id);
+
+		assert!(!VulnModule::<T>::is_frozen(unique_id, FreezeReason::SellOrder));
+	}
+
+	/// Every collectible is listed, so each takes a deposit and evicts from full floor indices.
+	#[benchmark]
+	fn set_prices(n: Linear<1, { T::MaximumOwned::get() }>) {
+		let owner = funded::<T>("owner", 0);
+		full_owner::<T>(&owner);
+		let owned = OwnerOfCollectibles::<T>::get(&owner);
+		let mut prices = BoundedVec::new();
+		for unique_id in owned.into_iter().take(n as usize) {
+			CooldownUntil::<T>::insert(&unique_id, now::<T>());
+			prices.try_push((unique_id, Some(price::<T>()))).unwrap();
+		}
+		full_floor_index::<T>(Color::Red);
+		full_floor_index::<T>(Color::Yellow);
+
+		#[extrinsic_call]
+		set_prices(RawOrigin::Signed(owner), prices);
+
+		assert_eq!(ListedAt::<T>::iter_keys().count() as u32, n);
+	}
+
+	/// A color index is rebuilt, which writes the color's statistics on top of the index.
+	#[benchmark]
+	fn rebuild_floor_index(n: Linear<1, 1_000>) {
+		for i in 0..n {
+			let owner: T::AccountId = account("owner", i / T::MaximumOwned::get(), SEED);
+			let unique_id = mint_many::<T>(&owner, 1);
+			CollectibleMap::<T>::mutate(&unique_id, |collectible| {
+				if let Some(collectible) = collectible {
+					collectible.price = Some(price::<T>());
+				}
+			});
+		}
+		ColorFloorIndex::<T>::insert(
+			Color::Red,
+			CheapestListings::<T> { rebuild_from: Some(0), ..Default::default() },
+		);
+
+		#[block]
+		{
+			VulnModule::<T>::do_rebuild_floor_index(Some(Color::Red), n);
+		}
+
+		assert_eq!(ColorFloorIndex::<T>::get(Color::Red).rebuild_from, None);
+	}
+
+	/// A timed-out auction, whose every bid is returned.
+	#[benchmark]
+	fn reclaim_escrow(b: Linear<1, { T::MaxSealedBids::get() }>) {
+		let seller = funded::<T>("seller", 0);
+		let unique_id = mint_many::<T>(&seller, 1);
+		let one = 1u32.into();
+		VulnModule::<T>::do_start_sealed_auction(seller, unique_id, price::<T>(), one, one)
+			.unwrap();
+		let bidders = (0..b).map(|i| funded::<T>("bidder", i)).collect::<sp_std::vec::Vec<_>>();
+		for bidder in &bidders {
+			let commitment = VulnModule::<T>::sealed_bid_commitment(bidder, price::<T>(), &[0; 32]);
+			VulnModule::<T>::do_commit_bid(bidder.clone(), unique_id, commitment).unwrap();
+		}
+		set_block::<T>(now::<T>().saturating_add(one));
+		for bidder in &bidders {
+			VulnModule::<T>::do_reveal_bid(bidder.clone(), unique_id, price::<T>(), [0; 32])
+				.unwrap();
+		}
+		set_block::<T>(now::<T>().saturating_add(one).saturating_add(T::EscrowTimeout::get()));
+
+		#[extrinsic_call]
+		reclaim_escrow(RawOrigin::Signed(bidders[0].clone()), unique_id);
+
+		assert!(!SealedAuctions::<T>::contains_key(unique_id));
+	}
+
+	#[benchmark]
+	fn set_sale_receipts() {
+		let creator: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		set_sale_receipts(RawOrigin::Signed(creator.clone()), true);
+
+		assert!(IssuesReceipts::<T>::contains_key(creator));
+	}
+
+	#[benchmark]
+	fn set_founder_badge_limit() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		set_founder_badge_limit(origin as T::RuntimeOrigin, 100);
+
+		assert_eq!(FounderBadgeLimit::<T>::get(), 100);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn register_foreign() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ForeignOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let owner = funded::<T>("owner", 0);
+		mint_many::<T>(&owner, T::MaximumOwned::get() - 1);
+		MintsThisBlock::<T>::put(T::MaxMintsPerBlock::get() - 1);
+
+		#[extrinsic_call]
+		register_foreign(origin as T::RuntimeOrigin, 1_000, 7, owner.clone());
+
+		assert!(ForeignDerivatives::<T>::contains_key(1_000, 7));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn redeem_foreign() {
+		let owner = funded::<T>("owner", 0);
+		mint_many::<T>(&owner, T::MaximumOwned::get() - 1);
+		MintsThisBlock::<T>::kill();
+		VulnModule::<T>::do_register_foreign(1_000, 7, owner.clone()).unwrap();
+		let unique_id = ForeignDerivatives::<T>::get(1_000, 7).unwrap();
+		list::<T>(&owner, unique_id);
+		fill_offers::<T>(unique_id);
+
+		#[extrinsic_call]
+		redeem_foreign(RawOrigin::Signed(owner), unique_id);
+
+		assert!(!ForeignSources::<T>::contains_key(unique_id));
+	}
+
+	#[benchmark]
+	fn transfer_at() {
+		let (seller, buyer, unique_id) = listed::<T>();
+		RoyaltiesEnforced::<T>::put(true);
+		ApprovedMarkets::<T>::insert(&buyer, ());
+		let at = 10u32.into();
+		crowd_timed_transfers::<T>(at);
+
+		#[extrinsic_call]
+		transfer_at(RawOrigin::Signed(seller), buyer, unique_id, at);
+
+		assert!(TimedTransfers::<T>::contains_key(unique_id));
+	}
+
+	#[benchmark]
+	fn cancel_transfer_at() {
+		let (seller, _, unique_id) = timed_transfer::<T>();
+
+		#[extrinsic_call]
+		cancel_transfer_at(RawOrigin::Signed(seller), unique_id);
+
+		assert!(!TimedTransfers::<T>::contains_key(unique_id));
+	}
+
+	#[benchmark]
+	fn complete_timed_transfer() {
+		let (_, buyer, unique_id) = timed_transfer::<T>();
+
+		#[block]
+		{
+			VulnModule::<T>::complete_timed_transfer(unique_id);
+		}
+
+		assert_eq!(CollectibleMap::<T>::get(unique_id).unwrap().owner, buyer);
+	}
+
+	#[benchmark]
+	fn set_heir() {
+		let owner = funded::<T>("owner", 0);
+		let heir = funded::<T>("heir", 0);
+		let period = T::MinInactivityPeriod::get();
+
+		#[extrinsic_call]
+		set_heir(RawOrigin::Signed(owner.clone()), Some((heir, period)));
+
+		assert!(Inheritances::<T>::contains_key(&owner));
+	}
+
+	#[benchmark]
+	fn start_inheritance() {
+		let (owner, heir) = inactive_owner::<T>();
+
+		#[extrinsic_call]
+		start_inheritance(RawOrigin::Signed(heir), owner.clone());
+
+		assert!(Inheritances::<T>::get(&owner).unwrap().claim_started.is_some());
+	}
+
+	#[benchmark]
+	fn veto_inheritance() {
+		let (owner, heir) = inactive_owner::<T>();
+		VulnModule::<T>::do_start_inheritance(heir, owner.clone()).unwrap();
+
+		#[extrinsic_call]
+		veto_inheritance(RawOrigin::Signed(owner.clone()));
+
+		assert!(Inheritances::<T>::get(&owner).unwrap().claim_started.is_none());
+	}
+
+	/// Every collectible of the owner moves to the heir.
+	#[benchmark]
+	fn complete_inheritance(n: Linear<0, { T::MaximumOwned::get() }>) {
+		let (owner, heir) = inactive_owner::<T>();
+		mint_many::<T>(&owner, n);
+		VulnModule::<T>::do_start_inheritance(heir.clone(), owner.clone()).unwrap();
+		set_block::<T>(now::<T>().saturating_add(T::InheritanceGracePeriod::get()));
+
+		#[extrinsic_call]
+		complete_inheritance(RawOrigin::Signed(heir.clone()), owner);
+
+		assert_eq!(OwnerOfCollectibles::<T>::get(&heir).len() as u32, n);
+	}
+
+	#[benchmark]
+	fn list_with_charity() {
+		let owner = funded::<T>("owner", 0);
+		let beneficiary = account("beneficiary", 0, SEED);
+		let unique_id = full_owner::<T>(&owner);
+		CooldownUntil::<T>::insert(&unique_id, now::<T>());
+		full_floor_index::<T>(CollectibleMap::<T>::get(&unique_id).unwrap().color);
+		let share = Percent::from_percent(10);
+
+		#[extrinsic_call]
+		list_with_charity(RawOrigin::Signed(owner), unique_id, price::<T>(), beneficiary, share);
+
+		assert!(ListingCharities::<T>::contains_key(unique_id));
+	}
+
+	#[benchmark]
+	fn set_default_royalty() {
+		let creator = funded::<T>("creator", 0);
+
+		#[extrinsic_call]
+		set_default_royalty(RawOrigin::Signed(creator.clone()), Some(T::MaxRoyalty::get()));
+
+		assert!(DefaultRoyalties::<T>::contains_key(&creator));
+	}
+
+	#[benchmark]
+	fn set_item_royalty() {
+		let creator = funded::<T>("creator", 0);
+		let unique_id = full_owner::<T>(&creator);
+
+		#[extrinsic_call]
+		set_item_royalty(RawOrigin::Signed(creator), unique_id, Some(T::MaxRoyalty::get()));
+
+		assert!(ItemRoyalties::<T>::contains_key(unique_id));
+	}
+
+	#[benchmark]
+	fn set_trading_limit() {
+		let who = funded::<T>("trader", 0);
+		let strict = TradingLimit { max_volume: Some(price::<T>()), max_trades: Some(1) };
+		SelfTradingLimits::<T>::insert(&who, strict);
+		// Loosening is the worst case, as it keeps the current limit and queues the new one.
+		let loose = TradingLimit { max_volume: None, max_trades: Some(2) };
+
+		#[extrinsic_call]
+		set_trading_limit(RawOrigin::Signed(who.clone()), Some(loose));
+
+		assert!(PendingTradingLimits::<T>::contains_key(&who));
+	}
+
+	#[benchmark]
+	fn impose_trading_limit() -> Result<(), BenchmarkError> {
+		let who: T::AccountId = account("trader", 0, SEED);
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let limit = TradingLimit { max_volume: None, max_trades: Some(1) };
+
+		#[extrinsic_call]
+		impose_trading_limit(origin as T::RuntimeOrigin, who.clone(), Some(limit));
+
+		assert!(ImposedTradingLimits::<T>::contains_key(&who));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn create_licensed_collectible() {
+		let caller = funded_caller::<T>();
+		let to = funded::<T>("to", 0);
+		mint_many::<T>(&to, T::MaximumOwned::get() - 1);
+		MintsThisBlock::<T>::put(T::MaxMintsPerBlock::get() - 1);
+		AcceptsMints::<T>::insert(&to, ());
+		let license: BoundedVec<u8, T::MaxLicenseLength> =
+			vec![b'a'; T::MaxLicenseLength::get() as usize].try_into().unwrap();
+
+		#[extrinsic_call]
+		create_licensed_collectible(RawOrigin::Signed(caller), to.clone(), license);
+
+		assert_eq!(OwnerOfCollectibles::<T>::get(&to).len() as u32, T::MaximumOwned::get());
+	}
+
+	#[benchmark]
+	fn publish_metadata_version() {
+		let creator: T::AccountId = whitelisted_caller();
+		MetadataVersions::<T>::insert(&creator, 1);
+
+		#[extrinsic_call]
+		publish_metadata_version(RawOrigin::Signed(creator.clone()), 2);
+
+		assert_eq!(MetadataVersions::<T>::get(&creator), 2);
+	}
+
+	#[benchmark]
+	fn force_remap_owner(n: Linear<0, { T::MaximumOwned::get() }>) -> Result<(), BenchmarkError> {
+		let old = funded::<T>("old", 0);
+		let new = funded::<T>("new", 0);
+		mint_many::<T>(&old, n);
+		// Every collectible moves with its listing, mint and metadata deposits, and the old
+		// account has a buy order.
+		for unique_id in OwnerOfCollectibles::<T>::get(&old) {
+			CooldownUntil::<T>::insert(&unique_id, now::<T>());
+			list::<T>(&old, unique_id);
+			VulnModule::<T>::take_mint_deposit(&old, unique_id).unwrap();
+			fill_data::<T>(&old, unique_id, 0);
+		}
+		VulnModule::<T>::do_place_buy_order(old.clone(), 1u32.into(), 1).unwrap();
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		force_remap_owner(origin as T::RuntimeOrigin, old.clone(), new.clone(), n);
+
+		assert_eq!(OwnerOfCollectibles::<T>::get(&new).len() as u32, n);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn distribute(n: Linear<1, { T::MaximumOwned::get() }>) {
+		let from = funded::<T>("from", 0);
+		mint_many::<T>(&from, n);
+		RoyaltiesEnforced::<T>::put(true);
+		// Every collectible is listed and goes to its own recipient, an approved market that is
+		// one short of the cap.
+		let mut grants = BoundedVec::new();
+		for (i, unique_id) in OwnerOfCollectibles::<T>::get(&from).into_iter().enumerate() {
+			let to: T::AccountId = account("to", i as u32, SEED);
+			mint_many::<T>(&to, T::MaximumOwned::get() - 1);
+			ApprovedMarkets::<T>::insert(&to, ());
+			CooldownUntil::<T>::insert(&unique_id, now::<T>());
+			list::<T>(&from, unique_id);
+			grants.try_push((unique_id, to)).unwrap();
+		}
+
+		#[extrinsic_call]
+		distribute(RawOrigin::Signed(from.clone()), grants);
+
+		assert!(OwnerOfCollectibles::<T>::get(&from).is_empty());
+	}
+
+	#[benchmark]
+	fn start_auction() {
+		let (seller, _, unique_id) = listed::<T>();
+		let one = 1u32.into();
+		crowd_auctions::<T>(now::<T>().saturating_add(one));
+
+		#[extrinsic_call]
+		start_auction(RawOrigin::Signed(seller), unique_id, price::<T>(), one);
+
+		assert!(Auctions::<T>::contains_key(unique_id));
+	}
+
+	#[benchmark]
+	fn place_bid() {
+		// Outbidding the buyer releases their bid.
+		let (_, _, unique_id) = auctioned::<T>();
+		let bidder = funded::<T>("bidder", 0);
+		let limit = TradingLimit { max_volume: None, max_trades: Some(u32::MAX) };
+		SelfTradingLimits::<T>::insert(&bidder, limit);
+		ImposedTradingLimits::<T>::insert(&bidder, limit);
+		let amount = price::<T>().saturating_mul(2u32.into());
+
+		#[extrinsic_call]
+		place_bid(RawOrigin::Signed(bidder.clone()), unique_id, amount);
+
+		assert_eq!(Auctions::<T>::get(unique_id).unwrap().leader, Some((bidder, amount)));
+	}
+
+	#[benchmark]
+	fn settle_auction() {
+		let (seller, buyer, unique_id) = auctioned::<T>();
+		// A creator other than the seller is paid the royalty.
+		let creator = funded::<T>("creator", 0);
+		CollectibleMap::<T>::mutate(&unique_id, |c| c.as_mut().unwrap().creator = creator.clone());
+		DefaultRoyalties::<T>::insert(&creator, T::MaxRoyalty::get());
+		let limit = TradingLimit { max_volume: None, max_trades: Some(u32::MAX) };
+		for who in [&seller, &buyer] {
+			SelfTradingLimits::<T>::insert(who, limit);
+		}
+		set_block::<T>(Auctions::<T>::get(unique_id).unwrap().end);
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		settle_auction(RawOrigin::Signed(caller), unique_id);
+
+		assert_eq!(CollectibleMap::<T>::get(unique_id).unwrap().owner, buyer);
+	}
+
+	#[benchmark]
+	fn create_collection() {
+		let owner: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		create_collection(RawOrigin::Signed(owner), Some(u32::MAX), CollectionMinting::Public);
+
+		assert!(Collections::<T>::contains_key(0));
+	}
+
+	#[benchmark]
+	fn set_collection_metadata() {
+		let owner: T::AccountId = whitelisted_caller();
+		VulnModule::<T>::do_create_collection(owner.clone(), None, CollectionMinting::Owner)
+			.unwrap();
+		let cid: BoundedVec<u8, T::MaxCidLength> =
+			vec![b'b'; T::MaxCidLength::get() as usize].try_into().unwrap();
+
+		#[extrinsic_call]
+		set_collection_metadata(RawOrigin::Signed(owner), 0, Some(cid));
+
+		assert!(Collections::<T>::get(0).unwrap().metadata.is_some());
+	}
+
+	#[benchmark]
+	fn set_collection_settings() {
+		let owner: T::AccountId = whitelisted_caller();
+		VulnModule::<T>::do_create_collection(owner.clone(), None, CollectionMinting::Owner)
+			.unwrap();
+		let settings = CollectionSettings {
+			royalty: Some(T::MaxRoyalty::get()),
+			royalties_enforced: true,
+			transfer_cooldown: Some(BlockNumberFor::<T>::from(1u32)),
+			issues_receipts: true,
+			founder_badge_limit: u32::MAX,
+		};
+
+		#[extrinsic_call]
+		set_collection_settings(RawOrigin::Signed(owner), 0, settings);
+
+		assert_eq!(Collections::<T>::get(0).unwrap().settings, settings);
+	}
+
+	#[benchmark]
+	fn publish_collection_metadata_version() {
+		let owner: T::AccountId = whitelisted_caller();
+		VulnModule::<T>::do_create_collection(owner.clone(), None, CollectionMinting::Owner)
+			.unwrap();
+
+		#[extrinsic_call]
+		publish_collection_metadata_version(RawOrigin::Signed(owner), 0, 2);
+
+		assert_eq!(Collections::<T>::get(0).unwrap().metadata_version, 2);
+	}
+
+	#[benchmark]
+	fn destroy_collection() {
+		let owner: T::AccountId = whitelisted_caller();
+		VulnModule::<T>::do_create_collection(owner.clone(), None, CollectionMinting::Owner)
+			.unwrap();
+
+		#[extrinsic_call]
+		destroy_collection(RawOrigin::Signed(owner), 0, 0);
+
+		assert!(!Collections::<T>::contains_key(0));
+	}
+
+	#[benchmark]
+	fn destroy_collection_items(
+		n: Linear<1, { T::CollectionTeardownBatch::get().min(T::MaximumOwned::get()) }>,
+	) {
+		let owner = funded::<T>("owner", 0);
+		VulnModule::<T>::do_create_collection(owner.clone(), None, CollectionMinting::Owner)
+			.unwrap();
+		for _ in 0..n {
+			MintsThisBlock::<T>::kill();
+			VulnModule::<T>::do_create_collectible_in(owner.clone(), 0, owner.clone()).unwrap();
+		}
+		// Every collectible is burned with a listing, the largest data and the most offers on it.
+		let items = CollectionItems::<T>::iter_key_prefix(0).collect::<sp_std::vec::Vec<_>>();
+		for unique_id in items {
+			list::<T>(&owner, unique_id);
+			fill_data::<T>(&owner, unique_id, T::MaxAttributes::get());
+			fill_offers::<T>(unique_id);
+		}
+		VulnModule::<T>::do_destroy_collection(None, 0, n).unwrap();
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		destroy_collection_items(RawOrigin::Signed(caller), 0);
+
+		assert!(!Collections::<T>::contains_key(0));
+	}
+
+	#[benchmark]
+	fn create_collectible_in() {
+		let owner = funded::<T>("owner", 0);
+		VulnModule::<T>::do_create_collection(owner, Some(u32::MAX), CollectionMinting::Public)
+			.unwrap();
+		// The caller earns a founder badge of the collection along with the mint.
+		Collections::<T>::mutate(0, |entry| {
+			if let Some(entry) = entry {
+				entry.settings.founder_badge_limit = 1;
+			}
+		});
+		let caller = funded_caller::<T>();
+		let to = funded::<T>("to", 0);
+		mint_many::<T>(&to, T::MaximumOwned::get() - 1);
+		MintsThisBlock::<T>::put(T::MaxMintsPerBlock::get().saturating_sub(2));
+		AcceptsMints::<T>::insert(&to, ());
+
+		#[extrinsic_call]
+		create_collectible_in(RawOrigin::Signed(caller.clone()), 0, to.clone());
+
+		assert_eq!(Collections::<T>::get(0).unwrap().items, 1);
+		assert!(CollectionFounders::<T>::contains_key(0, &caller));
+	}
+
+	#[benchmark]
+	fn approve() {
+		let owner = funded::<T>("owner", 0);
+		let unique_id = full_owner::<T>(&owner);
+		let operator: T::AccountId = whitelisted_caller();
+		OperatorApprovals::<T>::insert(&owner, &operator, ());
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+
+		#[extrinsic_call]
+		approve(RawOrigin::Signed(operator), unique_id, delegate.clone());
+
+		assert_eq!(Approvals::<T>::get(unique_id), Some(delegate));
+	}
+
+	#[benchmark]
+	fn cancel_approval() {
+		let owner = funded::<T>("owner", 0);
+		let unique_id = full_owner::<T>(&owner);
+		let operator: T::AccountId = whitelisted_caller();
+		OperatorApprovals::<T>::insert(&owner, &operator, ());
+		Approvals::<T>::insert(&unique_id, account::<T::AccountId>("delegate", 0, SEED));
+
+		#[extrinsic_call]
+		cancel_approval(RawOrigin::Signed(operator), unique_id);
+
+		assert!(!Approvals::<T>::contains_key(unique_id));
+	}
+
+	#[benchmark]
+	fn set_approval_for_all() {
+		let owner: T::AccountId = whitelisted_caller();
+		let operator: T::AccountId = account("operator", 0, SEED);
+
+		#[extrinsic_call]
+		set_approval_for_all(RawOrigin::Signed(owner.clone()), operator.clone(), true);
+
+		assert!(OperatorApprovals::<T>::contains_key(owner, operator));
+	}
+
+	/// The bidder replaces an offer they made before.
+	#[benchmark]
+	fn make_offer() {
+		let owner = funded::<T>("owner", 0);
+		let unique_id = full_owner::<T>(&owner);
+		let bidder = funded_caller::<T>();
+		VulnModule::<T>::do_make_offer(bidder.clone(), unique_id, price::<T>()).unwrap();
+		let amount = price::<T>().saturating_mul(2u32.into());
+
+		#[extrinsic_call]
+		make_offer(RawOrigin::Signed(bidder.clone()), unique_id, amount);
+
+		assert_eq!(Offers::<T>::get(unique_id, bidder), Some(amount));
+	}
+
+	/// Accepted by an operator, with the royalty going to a creator other than the seller.
+	#[benchmark]
+	fn accept_offer() {
+		let (seller, buyer, unique_id) = listed::<T>();
+		VulnModule::<T>::do_make_offer(buyer.clone(), unique_id, price::<T>()).unwrap();
+		let creator = funded::<T>("creator", 0);
+		CollectibleMap::<T>::mutate(&unique_id, |c| c.as_mut().unwrap().creator = creator.clone());
+		DefaultRoyalties::<T>::insert(&creator, T::MaxRoyalty::get());
+		let limit = TradingLimit { max_volume: None, max_trades: Some(u32::MAX) };
+		for who in [&seller, &buyer] {
+			SelfTradingLimits::<T>::insert(who, limit);
+		}
+		let operator: T::AccountId = whitelisted_caller();
+		OperatorApprovals::<T>::insert(&seller, &operator, ());
+
+		#[extrinsic_call]
+		accept_offer(RawOrigin::Signed(operator), unique_id, buyer.clone());
+
+		assert_eq!(CollectibleMap::<T>::get(unique_id).unwrap().owner, buyer);
+	}
+
+	#[benchmark]
+	fn withdraw_offer() {
+		let owner = funded::<T>("owner", 0);
+		let unique_id = full_owner::<T>(&owner);
+		let bidder = funded_caller::<T>();
+		VulnModule::<T>::do_make_offer(bidder.clone(), unique_id, price::<T>()).unwrap();
+
+		#[extrinsic_call]
+		withdraw_offer(RawOrigin::Signed(bidder.clone()), unique_id);
+
+		assert!(!Offers::<T>::contains_key(unique_id, bidder));
+	}
+
+	#[benchmark]
+	fn transfer_many(n: Linear<1, { T::MaximumOwned::get() }>) {
+		let from = funded::<T>("from", 0);
+		let to = funded::<T>("to", 0);
+		mint_many::<T>(&from, n);
+		mint_many::<T>(&to, T::MaximumOwned::get() - n);
+		RoyaltiesEnforced::<T>::put(true);
+		ApprovedMarkets::<T>::insert(&to, ());
+		let unique_ids = OwnerOfCollectibles::<T>::get(&from);
+		for unique_id in &unique_ids {
+			CooldownUntil::<T>::insert(unique_id, now::<T>());
+			list::<T>(&from, *unique_id);
+		}
+		// Sent by an operator, so every approval is looked up.
+		let operator: T::AccountId = whitelisted_caller();
+		OperatorApprovals::<T>::insert(&from, &operator, ());
+
+		#[extrinsic_call]
+		transfer_many(RawOrigin::Signed(operator), to.clone(), unique_ids);
+
+		assert!(OwnerOfCollectibles::<T>::get(&from).is_empty());
+	}
+
+	#[benchmark]
+	fn mint_batch(n: Linear<1, { T::MaxMintsPerBlock::get().min(T::MaximumOwned::get()) }>) {
+		let caller = funded_caller::<T>();
+		let to = funded::<T>("to", 0);
+		mint_many::<T>(&to, T::MaximumOwned::get() - n);
+		MintsThisBlock::<T>::kill();
+		AcceptsMints::<T>::insert(&to, ());
+
+		#[extrinsic_call]
+		mint_batch(RawOrigin::Signed(caller), to.clone(), n);
+
+		assert_eq!(OwnerOfCollectibles::<T>::get(&to).len() as u32, T::MaximumOwned::get());
+	}
+
+	#[benchmark]
+	fn set_metadata() {
+		let (owner, unique_id) = with_data::<T>(0);
+		let metadata = BoundedVec::truncate_from(vec![1; T::MetadataLimit::get() as usize]);
+
+		#[extrinsic_call]
+		set_metadata(RawOrigin::Signed(owner.clone()), unique_id, metadata);
+
+		assert_eq!(MetadataDeposits::<T>::get(unique_id).unwrap().0, owner);
+	}
+
+	#[benchmark]
+	fn clear_metadata() {
+		let (owner, unique_id) = with_data::<T>(0);
+
+		#[extrinsic_call]
+		clear_metadata(RawOrigin::Signed(owner), unique_id);
+
+		assert!(!OnChainMetadata::<T>::contains_key(unique_id));
+	}
+
+	/// Adds the last attribute the collectible has room for.
+	#[benchmark]
+	fn set_attribute() {
+		let last = T::MaxAttributes::get() - 1;
+		let (owner, unique_id) = with_data::<T>(last);
+		let value = BoundedVec::truncate_from(vec![1; T::AttributeValueLimit::get() as usize]);
+
+		#[extrinsic_call]
+		set_attribute(RawOrigin::Signed(owner), unique_id, attribute_key::<T>(last), value);
+
+		assert_eq!(AttributeCounts::<T>::get(unique_id), T::MaxAttributes::get());
+	}
+
+	#[benchmark]
+	fn clear_attribute() {
+		let (owner, unique_id) = with_data::<T>(T::MaxAttributes::get());
+
+		#[extrinsic_call]
+		clear_attribute(RawOrigin::Signed(owner), unique_id, attribute_key::<T>(0));
+
+		assert!(!Attributes::<T>::contains_key(unique_id, attribute_key::<T>(0)));
+	}
+
+	#[benchmark]
+	fn freeze() {
+		let owner = funded::<T>("owner", 0);
+		let unique_id = full_owner::<T>(&owner);
+
+		#[extrinsic_call]
+		freeze(RawOrigin::Signed(owner), unique_id);
+
+		assert!(VulnModule::<T>::is_frozen(unique_id, FreezeReason::Staked));
+	}
+
+	#[benchmark]
+	fn thaw() {
+		let owner = funded::<T>("owner", 0);
+		let unique_id = full_owner::<T>(&owner);
+		VulnModule::<T>::do_freeze(unique_id, FreezeReason::Staked);
+
+		#[extrinsic_call]
+		thaw(RawOrigin::Signed(owner), unique_id);
+
+		assert!(!VulnModule::<T>::is_frozen(unique_id, FreezeReason::Staked));
+	}
+
+	#[benchmark]
+	fn create_swap() {
+		let (seller, buyer, unique_id) = listed::<T>();
+		let desired = OwnerOfCollectibles::<T>::get(&buyer)[0];
+		let deadline = 10u32.into();
+		crowd_swaps::<T>(deadline);
+
+		#[extrinsic_call]
+		create_swap(RawOrigin::Signed(seller), unique_id, desired, Some(price::<T>()), deadline);
+
+		assert!(PendingSwaps::<T>::contains_key(unique_id));
+	}
+
+	#[benchmark]
+	fn claim_swap() {
+		let (seller, buyer, unique_id) = swapped::<T>();
+		RoyaltiesEnforced::<T>::put(true);
+		ApprovedMarkets::<T>::insert(&buyer, ());
+
+		#[extrinsic_call]
+		claim_swap(RawOrigin::Signed(buyer.clone()), unique_id);
+
+		assert_eq!(CollectibleMap::<T>::get(unique_id).unwrap().owner, buyer);
+		assert_eq!(OwnerOfCollectibles::<T>::get(&seller).len() as u32, T::MaximumOwned::get());
+	}
+
+	#[benchmark]
+	fn cancel_swap() {
+		let (seller, _, unique_id) = swapped::<T>();
+
+		#[extrinsic_call]
+		cancel_swap(RawOrigin::Signed(seller), unique_id);
+
+		assert!(!VulnModule::<T>::is_frozen(unique_id, FreezeReason::Swap));
+	}
+
+	/// Due timed transfers and auctions are weighed by their own benchmarks.
+	#[benchmark]
+	fn on_initialize() {
+		#[block]
+		{
+			VulnModule::<T>::on_initialize(now::<T>());
+		}
+	}
+
+	impl_benchmark_test_suite!(VulnModule, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,73 @@
+//! Approvals, so a marketplace or another account can transfer or list collectibles on their
+//! owner's behalf, as ERC-721 allows. An owner can approve one account per collectible, which
+//! lasts until the collectible changes hands, and any number of operators, which can manage
+//! everything the owner holds until the owner withdraws them.
+//!
+//! Approved accounts act for the owner: listing deposits are still taken from the owner, and
+//! the owner is paid for sales.
+
+use crate::*;
+use frame_support::pallet_prelude::*;
+
+impl<T: Config> Pallet<T> {
+	/// The account approved to manage collectible `unique_id`, if any.
+	pub fn approved(unique_id: u64) -> Option<T::AccountId> {
+		Approvals::<T>::get(&unique_id)
+	}
+
+	/// Whether `owner` approved `operator` to manage all of their collectibles.
+	pub fn is_operator(owner: &T::AccountId, operator: &T::AccountId) -> bool {
+		OperatorApprovals::<T>::contains_key(owner, operator)
+	}
+
+	/// Whether `who` may transfer or list collectible `unique_id` of `owner`: they are `owner`,
+	/// approved for the collectible, or one of `owner`'s operators.
+	pub(crate) fn may_manage(who: &T::AccountId, owner: &T::AccountId, unique_id: u64) -> bool {
+		who == owner ||
+			Approvals::<T>::get(&unique_id).as_ref() == Some(who) ||
+			Self::is_operator(owner, who)
+	}
+
+	pub(crate) fn do_approve(
+		who: T::AccountId,
+		unique_id: u64,
+		delegate: T::AccountId,
+	) -> DispatchResult {
+		let owner = Self::owner_managed_by(&who, unique_id)?;
+		ensure!(delegate != owner, Error::<T>::ApproveSelf);
+		Approvals::<T>::insert(&unique_id, &delegate);
+		Self::deposit_event(Event::Approved { collectible: unique_id, owner, delegate });
+		Ok(())
+	}
+
+	pub(crate) fn do_cancel_approval(who: T::AccountId, unique_id: u64) -> DispatchResult {
+		let owner = Self::owner_managed_by(&who, unique_id)?;
+		ensure!(Approvals::<T>::contains_key(&unique_id), Error::<T>::NoApproval);
+		Approvals::<T>::remove(&unique_id);
+		Self::deposit_event(Event::ApprovalCancelled { collectible: unique_id, owner });
+		Ok(())
+	}
+
+	pub(crate) fn do_set_approval_for_all(
+		owner: T::AccountId,
+		operator: T::AccountId,
+		approved: bool,
+	) -> DispatchResult {
+		ensure!(operator != owner, Error::<T>::ApproveSelf);
+		if approved {
+			OperatorApprovals::<T>::insert(&owner, &operator, ());
+		} else {
+			OperatorApprovals::<T>::remove(&owner, &operator);
+		}
+		Self::deposit_event(Event::ApprovalForAll { owner, operator, approved });
+		Ok(())
+	}
+
+	/// The owner of `unique_id`, if `who` is them or one of their operators. Accounts approved
+	/// for a single collectible cannot pass the approval on.
+	fn owner_managed_by(who: &T::AccountId, unique_id: u64) -> Result<T::AccountId, DispatchError> {
+		let owner = CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?.owner;
+		ensure!(*who == owner || Self::is_operator(&owner, who), Error::<T>::NotOwner);
+		Ok(owner)
+	}
+}
@@ -0,0 +1,137 @@
+//! On-chain metadata and attributes, for collectibles that need to carry more than an id and a
+//! color. Owners can store a metadata blob of up to `MetadataLimit` bytes on each of their
+//! collectibles, and up to `MaxAttributes` key-value attributes, which stay with the collectible
+//! when it changes hands.
+//!
+//! Every byte stored, attribute keys included, costs `MetadataDepositPerByte`. The deposit for
+//! all the data of a collectible is held from whoever changed it last, moving to them from the
+//! account that held it before, and is released when the data is cleared or the collectible is
+//! burned.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::{Saturating, Zero},
+	traits::{fungible::MutateHold, tokens::Precision},
+};
+
+impl<T: Config> Pallet<T> {
+	/// The metadata stored on chain for `unique_id`, if any.
+	pub fn onchain_metadata(unique_id: u64) -> Option<BoundedVec<u8, T::MetadataLimit>> {
+		OnChainMetadata::<T>::get(&unique_id)
+	}
+
+	/// The value of attribute `key` of `unique_id`, if it is set.
+	pub fn attribute(
+		unique_id: u64,
+		key: &BoundedVec<u8, T::AttributeKeyLimit>,
+	) -> Option<BoundedVec<u8, T::AttributeValueLimit>> {
+		Attributes::<T>::get(&unique_id, key)
+	}
+
+	pub(crate) fn do_set_metadata(
+		owner: T::AccountId,
+		unique_id: u64,
+		metadata: BoundedVec<u8, T::MetadataLimit>,
+	) -> DispatchResult {
+		Self::ensure_owns(&owner, unique_id)?;
+		let old = OnChainMetadata::<T>::get(&unique_id).map_or(0, |old| old.len());
+		Self::update_metadata_deposit(&owner, unique_id, old, metadata.len())?;
+		OnChainMetadata::<T>::insert(&unique_id, &metadata);
+		Self::deposit_event(Event::MetadataSet { collectible: unique_id, metadata });
+		Ok(())
+	}
+
+	pub(crate) fn do_clear_metadata(owner: T::AccountId, unique_id: u64) -> DispatchResult {
+		Self::ensure_owns(&owner, unique_id)?;
+		let old = OnChainMetadata::<T>::take(&unique_id).ok_or(Error::<T>::NoMetadata)?;
+		Self::update_metadata_deposit(&owner, unique_id, old.len(), 0)?;
+		Self::deposit_event(Event::MetadataCleared { collectible: unique_id });
+		Ok(())
+	}
+
+	pub(crate) fn do_set_attribute(
+		owner: T::AccountId,
+		unique_id: u64,
+		key: BoundedVec<u8, T::AttributeKeyLimit>,
+		value: BoundedVec<u8, T::AttributeValueLimit>,
+	) -> DispatchResult {
+		Self::ensure_owns(&owner, unique_id)?;
+		let old = match Attributes::<T>::get(&unique_id, &key) {
+			Some(old) => key.len().saturating_add(old.len()),
+			None => {
+				let count = AttributeCounts::<T>::get(&unique_id);
+				ensure!(count < T::MaxAttributes::get(), Error::<T>::TooManyAttributes);
+				AttributeCounts::<T>::insert(&unique_id, count.saturating_add(1));
+				0
+			},
+		};
+		let new = key.len().saturating_add(value.len());
+		Self::update_metadata_deposit(&owner, unique_id, old, new)?;
+		Attributes::<T>::insert(&unique_id, &key, &value);
+		Self::deposit_event(Event::AttributeSet { collectible: unique_id, key, value });
+		Ok(())
+	}
+
+	pub(crate) fn do_clear_attribute(
+		owner: T::AccountId,
+		unique_id: u64,
+		key: BoundedVec<u8, T::AttributeKeyLimit>,
+	) -> DispatchResult {
+		Self::ensure_owns(&owner, unique_id)?;
+		let old = Attributes::<T>::take(&unique_id, &key).ok_or(Error::<T>::NoAttribute)?;
+		AttributeCounts::<T>::mutate(&unique_id, |count| count.saturating_dec());
+		Self::update_metadata_deposit(&owner, unique_id, key.len().saturating_add(old.len()), 0)?;
+		Self::deposit_event(Event::AttributeCleared { collectible: unique_id, key });
+		Ok(())
+	}
+
+	/// Remove the metadata and attributes of `unique_id` and release their deposit. Called
+	/// when it is burned.
+	pub(crate) fn clear_onchain_data(unique_id: u64) -> DispatchResult {
+		OnChainMetadata::<T>::remove(&unique_id);
+		let _ = Attributes::<T>::clear_prefix(&unique_id, T::MaxAttributes::get(), None);
+		AttributeCounts::<T>::remove(&unique_id);
+		if let Some((depositor, deposit)) = MetadataDeposits::<T>::take(&unique_id) {
+			T::Currency::release(
+				&HoldReason::MetadataDeposit.into(),
+				&depositor,
+				deposit,
+				Precision::BestEffort,
+			)?;
+		}
+		Ok(())
+	}
+
+	fn ensure_owns(who: &T::AccountId, unique_id: u64) -> DispatchResult {
+		let collectible = CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
+		ensure!(collectible.owner == *who, Error::<T>::NotOwner);
+		Ok(())
+	}
+
+	/// Account for `old` bytes of the data of `unique_id` being replaced by `new` bytes, and
+	/// hold the resulting deposit from `who`.
+	fn update_metadata_deposit(
+		who: &T::AccountId,
+		unique_id: u64,
+		old: usize,
+		new: usize,
+	) -> DispatchResult {
+		let reason = HoldReason::MetadataDeposit.into();
+		let held = match MetadataDeposits::<T>::take(&unique_id) {
+			Some((depositor, held)) => {
+				T::Currency::release(&reason, &depositor, held, Precision::BestEffort)?;
+				held
+			},
+			None => Zero::zero(),
+		};
+		let per_byte = T::MetadataDepositPerByte::get();
+		let cost = |bytes: usize| per_byte.saturating_mul((bytes as u32).into());
+		let deposit = held.saturating_sub(cost(old)).saturating_add(cost(new));
+		if !deposit.is_zero() {
+			T::Currency::hold(&reason, who, deposit)?;
+			MetadataDeposits::<T>::insert(&unique_id, (who.clone(), deposit));
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,87 @@
+//! Offchain worker that settles auctions once they are over, so neither the seller nor the
+//! winner has to remember to. It covers sealed-bid auctions past their reveal phase and English
+//! auctions that could not settle when they ended, both kept in `UnsettledAuctions`, and looks
+//! at no more than `MAX_SETTLEMENTS_SCANNED` of them per block.
+//!
+//! Settlement is open to anyone, so the worker submits `settle_auction` and
+//! `settle_sealed_auction` unsigned, as the pinning worker does its confirmations, and only its
+//! own node accepts those transactions. Signing them would need a funded account on every node
+//! running the worker to pay for settlements anyone can make for free. An auction can only be
+//! settled once: later submissions for it are stale and dropped from the pool, so several
+//! workers racing to settle the same auction never settle it twice.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::{offchain::storage::StorageValueRef, traits::Saturating},
+};
+use frame_system::{offchain::SubmitTransaction, pallet_prelude::*};
+use sp_std::vec::Vec;
+
+/// Prefix of the offchain storage keys recording the last settlement attempt per auction.
+const SETTLE_ATTEMPT_PREFIX: &[u8] = b"vulntoken::settle-attempt";
+
+/// Unsettled auctions looked at per block, so a backlog of auctions cannot stall the worker.
+const MAX_SETTLEMENTS_SCANNED: usize = 20;
+/// Settlements submitted per block.
+const MAX_SETTLEMENTS_PER_BLOCK: usize = 5;
+/// Blocks to wait before submitting a settlement again that was not included.
+const SETTLE_RETRY_BLOCKS: u32 = 5;
+
+impl<T: Config> Pallet<T> {
+	/// Validity of an unsigned `settle_auction` or `settle_sealed_auction` of the auction of
+	/// `unique_id`, over at `end`. Only settlements produced by the local offchain worker or
+	/// already included in a block are accepted, and only for auctions that are over.
+	pub(crate) fn validate_settlement(
+		source: TransactionSource,
+		unique_id: u64,
+		end: Option<BlockNumberFor<T>>,
+	) -> TransactionValidity {
+		if !matches!(source, TransactionSource::Local | TransactionSource::InBlock) {
+			return InvalidTransaction::Call.into()
+		}
+		let Some(end) = end else { return InvalidTransaction::Stale.into() };
+		if frame_system::Pallet::<T>::block_number() < end {
+			return InvalidTransaction::Future.into()
+		}
+		ValidTransaction::with_tag_prefix("VulntokenSettle")
+			.and_provides(unique_id)
+			.longevity(SETTLE_RETRY_BLOCKS.into())
+			.propagate(false)
+			.build()
+	}
+
+	/// Submit settlements for auctions that are over and not settled yet.
+	pub(crate) fn settle_ended_auctions(now: BlockNumberFor<T>) {
+		let due = UnsettledAuctions::<T>::iter_keys()
+			.take(MAX_SETTLEMENTS_SCANNED)
+			.filter(|unique_id| Self::settle_attempt_due(*unique_id, now))
+			.take(MAX_SETTLEMENTS_PER_BLOCK)
+			.collect::<Vec<_>>();
+		for unique_id in due {
+			let call = if Auctions::<T>::contains_key(&unique_id) {
+				Call::settle_auction { unique_id }
+			} else {
+				Call::settle_sealed_auction { unique_id }
+			};
+			if SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into()).is_err() {
+				log::warn!(
+					target: LOG_TARGET,
+					"submitting settlement of auction {unique_id} failed"
+				);
+			}
+		}
+	}
+
+	/// Record an attempt to settle the auction of `unique_id` at `now`, unless one was made
+	/// recently.
+	fn settle_attempt_due(unique_id: u64, now: BlockNumberFor<T>) -> bool {
+		let key = (SETTLE_ATTEMPT_PREFIX, unique_id).encode();
+		StorageValueRef::persistent(&key)
+			.mutate(|last: Result<Option<BlockNumberFor<T>>, _>| match last {
+				Ok(Some(last)) if now < last.saturating_add(SETTLE_RETRY_BLOCKS.into()) => Err(()),
+				_ => Ok(now),
+			})
+			.is_ok()
+	}
+}
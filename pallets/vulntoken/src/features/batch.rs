@@ -0,0 +1,42 @@
+//! Batches, for marketplace users moving many collectibles at once: sending several to the same
+//! account, or minting several for it. `set_prices` is the batch form of `set_price` and
+//! `distribute` sends each collectible to its own recipient.
+//!
+//! Like the calls they batch, every item still emits its own event, and the batch emits one more
+//! once all of them went through. Extrinsics are transactional, so an item that fails undoes the
+//! ones before it.
+
+use crate::*;
+use frame_support::pallet_prelude::*;
+
+impl<T: Config> Pallet<T> {
+	pub(crate) fn do_transfer_many(
+		who: T::AccountId,
+		to: T::AccountId,
+		unique_ids: BoundedVec<u64, T::MaximumOwned>,
+	) -> DispatchResult {
+		let collectibles = unique_ids.len() as u32;
+		for unique_id in unique_ids {
+			let collectible =
+				CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
+			ensure!(Self::may_manage(&who, &collectible.owner, unique_id), Error::<T>::NotOwner);
+			Self::ensure_transfer_allowed(unique_id, &collectible.owner, &to)?;
+			Self::do_transfer(unique_id, to.clone())?;
+		}
+		Self::deposit_event(Event::TransferredMany { by: who, to, collectibles });
+		Ok(())
+	}
+
+	pub(crate) fn do_mint_batch(
+		minter: T::AccountId,
+		to: T::AccountId,
+		count: u32,
+	) -> DispatchResult {
+		ensure!(count <= T::MaxMintsPerBlock::get(), Error::<T>::MintRateExceeded);
+		for _ in 0..count {
+			Self::do_create_collectible(minter.clone(), to.clone())?;
+		}
+		Self::deposit_event(Event::MintedBatch { minter, to, count });
+		Ok(())
+	}
+}
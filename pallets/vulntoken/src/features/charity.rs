@@ -0,0 +1,51 @@
+//! Listings that give to charity. A seller can list a collectible with a share of its price
+//! going to a beneficiary of their choosing. The share comes out of what the seller would be
+//! paid, never out of the creator's royalty or the fees, and is paid when the listing sells at
+//! its fixed price. Escrowed and installment purchases hold the price away from the settlement
+//! that routes the share, so they refuse these listings.
+//!
+//! The share lasts as long as the listing: relisting, delisting, or anything else that ends the
+//! listing forgets it.
+
+use crate::*;
+use frame_support::{pallet_prelude::*, sp_runtime::Percent};
+
+impl<T: Config> Pallet<T> {
+	/// The beneficiary of the collectible's listing and their share of the price, if the listing
+	/// gives to charity.
+	pub fn listing_charity(unique_id: u64) -> Option<(T::AccountId, Percent)> {
+		ListingCharities::<T>::get(&unique_id)
+	}
+
+	pub(crate) fn do_list_with_charity(
+		owner: T::AccountId,
+		unique_id: u64,
+		price: BalanceOf<T>,
+		beneficiary: T::AccountId,
+		share: Percent,
+	) -> DispatchResult {
+		ensure!(!share.is_zero() && beneficiary != owner, Error::<T>::InvalidCharity);
+		Self::do_set_price(&owner, unique_id, Some(price))?;
+		ListingCharities::<T>::insert(&unique_id, (beneficiary.clone(), share));
+		Self::deposit_event(Event::PriceSet { collectible: unique_id, price: Some(price) });
+		Self::deposit_event(Event::CharitySet { collectible: unique_id, beneficiary, share });
+		Ok(())
+	}
+
+	/// The beneficiary of the collectible's listing and what they get of a sale at `price`,
+	/// which is at most `seller_share`, the part of the price the seller would be paid.
+	pub(crate) fn charity_cut(
+		unique_id: u64,
+		price: BalanceOf<T>,
+		seller_share: BalanceOf<T>,
+	) -> Option<(T::AccountId, BalanceOf<T>)> {
+		let (beneficiary, share) = ListingCharities::<T>::get(&unique_id)?;
+		Some((beneficiary, share.mul_floor(price).min(seller_share)))
+	}
+
+	/// Fail if the collectible's listing gives to charity, for purchases that settle elsewhere.
+	pub(crate) fn ensure_no_charity(unique_id: u64) -> DispatchResult {
+		ensure!(!ListingCharities::<T>::contains_key(&unique_id), Error::<T>::CharityListing);
+		Ok(())
+	}
+}
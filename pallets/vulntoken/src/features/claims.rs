@@ -0,0 +1,62 @@
+//! Mints into other accounts. Minting to someone who has not opted in only records a pending
+//! claim, so nobody can fill another account's ownership vector without its consent.
+//!
+//! A pending claim is checked against the mint policy and the rate limit, and holds the
+//! minter's deposit, as soon as it is recorded, so recording claims costs what minting does.
+//! Claiming it mints the collectible with the original minter as its creator.
+
+use crate::*;
+use frame_support::{pallet_prelude::*, sp_runtime::traits::Zero, traits::fungible::MutateHold};
+
+impl<T: Config> Pallet<T> {
+	/// Whether `minter` may mint straight into `to`'s ownership vector.
+	pub(crate) fn accepts_mint_from(minter: &T::AccountId, to: &T::AccountId) -> bool {
+		minter == to || AcceptsMints::<T>::contains_key(to)
+	}
+
+	pub(crate) fn do_create_collectible(minter: T::AccountId, to: T::AccountId) -> DispatchResult {
+		if !Self::accepts_mint_from(&minter, &to) {
+			return Self::record_pending_mint(minter, to)
+		}
+		let (unique_id, color) = Self::gen_unique_id();
+		Self::mint(&minter, &to, unique_id, color)?;
+		Self::take_mint_deposit(&minter, unique_id)?;
+		Self::note_founder(&minter);
+		Ok(())
+	}
+
+	pub(crate) fn do_claim_mints(who: T::AccountId, count: u32) -> DispatchResult {
+		let mut pending = PendingMints::<T>::get(&who).into_inner();
+		ensure!(count > 0 && count as usize <= pending.len(), Error::<T>::NoPendingMints);
+		for (minter, deposit) in pending.drain(..count as usize) {
+			let (unique_id, color) = Self::gen_unique_id();
+			Self::mint(&minter, &who, unique_id, color)?;
+			if !deposit.is_zero() {
+				MintDeposits::<T>::insert(&unique_id, (minter.clone(), deposit));
+			}
+			Self::note_founder(&minter);
+		}
+		if pending.is_empty() {
+			PendingMints::<T>::remove(&who);
+		} else {
+			PendingMints::<T>::insert(&who, BoundedVec::truncate_from(pending));
+		}
+		Ok(())
+	}
+
+	/// Record a collectible `minter` minted for `to`, holding the deposit its mint will need.
+	fn record_pending_mint(minter: T::AccountId, to: T::AccountId) -> DispatchResult {
+		T::MintPolicy::check_mint(&minter, &to)?;
+		let minted = MintsThisBlock::<T>::get();
+		ensure!(minted < T::MaxMintsPerBlock::get(), Error::<T>::MintRateExceeded);
+		let deposit = T::CollectibleDeposit::get();
+		PendingMints::<T>::try_append(&to, (minter.clone(), deposit))
+			.map_err(|_| Error::<T>::MaximumCollectiblesOwned)?;
+		if !deposit.is_zero() {
+			T::Currency::hold(&HoldReason::MintDeposit.into(), &minter, deposit)?;
+		}
+		MintsThisBlock::<T>::put(minted + 1);
+		Self::deposit_event(Event::MintPending { to, minter });
+		Ok(())
+	}
+}
@@ -0,0 +1,241 @@
+//! Collections, series of collectibles minted under rules their owner sets: who may mint into
+//! them, and how many collectibles they can ever hold. Several creators can run series side by
+//! side without sharing rules. Collectibles keep their pallet-wide ids; the collection one was
+//! minted into is recorded on it, and only changes if that collection is destroyed.
+//!
+//! The owner of a collection also decides what applies to the collectibles in it: a royalty
+//! taking precedence over their creators' defaults, royalty enforcement and sale receipts on top
+//! of the pallet-wide and per-creator ones, a cooldown in place of the pallet-wide one, founder
+//! badges of the collection, and the metadata schema version they follow. Collectibles in no
+//! collection keep following the pallet-wide and per-creator settings alone.
+//!
+//! Destroying a collection takes its owner or `ForceOrigin`, and a witness of how many
+//! collectibles are left in it. Nothing can be minted into it from then on, and
+//! `destroy_collection_items` clears it a batch at a time, over as many blocks as it takes. The
+//! collectibles the collection's owner holds are burned, releasing their deposits; locked ones,
+//! and those held by anybody else, stay with their owners and just leave the collection. The
+//! collection goes once the last of them is cleared, and with it the record of who earned its
+//! founder badges; the badges themselves stay with their holders.
+
+use crate::*;
+use frame_support::{pallet_prelude::*, sp_runtime::traits::Saturating, storage::with_storage_layer};
+use sp_std::vec::Vec;
+
+impl<T: Config> Pallet<T> {
+	/// Collection `collection`, if it exists.
+	pub fn collection(collection: CollectionId) -> Option<Collection<T>> {
+		Collections::<T>::get(collection)
+	}
+
+	pub(crate) fn do_create_collection(
+		owner: T::AccountId,
+		max_supply: Option<u32>,
+		minting: CollectionMinting,
+	) -> DispatchResult {
+		let collection = NextCollectionId::<T>::get();
+		let next = collection.checked_add(1).ok_or(Error::<T>::BoundsOverflow)?;
+		let entry = Collection {
+			owner: owner.clone(),
+			max_supply,
+			minted: 0,
+			items: 0,
+			minting,
+			metadata: None,
+			destroying: false,
+			settings: Default::default(),
+			founder_badges_issued: 0,
+			metadata_version: 0,
+		};
+		Collections::<T>::insert(collection, entry);
+		NextCollectionId::<T>::put(next);
+		Self::deposit_event(Event::CollectionCreated { collection, owner, max_supply, minting });
+		Ok(())
+	}
+
+	pub(crate) fn do_set_collection_metadata(
+		who: T::AccountId,
+		collection: CollectionId,
+		metadata: Option<BoundedVec<u8, T::MaxCidLength>>,
+	) -> DispatchResult {
+		Collections::<T>::try_mutate(collection, |entry| -> DispatchResult {
+			let entry = entry.as_mut().ok_or(Error::<T>::NoCollection)?;
+			ensure!(entry.owner == who, Error::<T>::NotCollectionOwner);
+			ensure!(!entry.destroying, Error::<T>::CollectionDestroying);
+			entry.metadata = metadata.clone();
+			Ok(())
+		})?;
+		Self::deposit_event(Event::CollectionMetadataSet { collection, metadata });
+		Ok(())
+	}
+
+	/// The settings of the collection `unique_id` is in, if it is in one.
+	pub(crate) fn collection_settings_of(
+		unique_id: u64,
+	) -> Option<CollectionSettings<BlockNumberFor<T>>> {
+		let collection = CollectibleMap::<T>::get(&unique_id)?.collection_id?;
+		Collections::<T>::get(collection).map(|entry| entry.settings)
+	}
+
+	pub(crate) fn do_set_collection_settings(
+		who: T::AccountId,
+		collection: CollectionId,
+		settings: CollectionSettings<BlockNumberFor<T>>,
+	) -> DispatchResult {
+		ensure!(
+			settings.royalty.map_or(true, |royalty| royalty <= T::MaxRoyalty::get()),
+			Error::<T>::RoyaltyTooHigh
+		);
+		Collections::<T>::try_mutate(collection, |entry| -> DispatchResult {
+			let entry = entry.as_mut().ok_or(Error::<T>::NoCollection)?;
+			ensure!(entry.owner == who, Error::<T>::NotCollectionOwner);
+			ensure!(!entry.destroying, Error::<T>::CollectionDestroying);
+			entry.settings = settings;
+			Ok(())
+		})?;
+		Self::deposit_event(Event::CollectionSettingsSet { collection, settings });
+		Ok(())
+	}
+
+	pub(crate) fn do_publish_collection_metadata_version(
+		who: T::AccountId,
+		collection: CollectionId,
+		version: u16,
+	) -> DispatchResult {
+		Collections::<T>::try_mutate(collection, |entry| -> DispatchResult {
+			let entry = entry.as_mut().ok_or(Error::<T>::NoCollection)?;
+			ensure!(entry.owner == who, Error::<T>::NotCollectionOwner);
+			ensure!(version > entry.metadata_version, Error::<T>::StaleMetadataVersion);
+			entry.metadata_version = version;
+			Ok(())
+		})?;
+		Self::deposit_event(Event::CollectionMetadataVersionPublished { collection, version });
+		Ok(())
+	}
+
+	/// Start destroying `collection` for `who`, its owner, or for `ForceOrigin` if `None`.
+	pub(crate) fn do_destroy_collection(
+		who: Option<T::AccountId>,
+		collection: CollectionId,
+		items: u32,
+	) -> DispatchResult {
+		let mut entry = Collections::<T>::get(collection).ok_or(Error::<T>::NoCollection)?;
+		if let Some(who) = who {
+			ensure!(entry.owner == who, Error::<T>::NotCollectionOwner);
+		}
+		ensure!(!entry.destroying, Error::<T>::CollectionDestroying);
+		ensure!(entry.items == items, Error::<T>::BadWitness);
+		if items == 0 && CollectionFounders::<T>::iter_key_prefix(collection).next().is_none() {
+			Self::remove_collection(collection);
+			return Ok(())
+		}
+		entry.destroying = true;
+		Collections::<T>::insert(collection, entry);
+		Self::deposit_event(Event::CollectionDestroying { collection, items });
+		Ok(())
+	}
+
+	/// Clear up to `limit` collectibles out of `collection`, which is being destroyed, and then
+	/// its founder records, removing it if none are left. Returns how many were cleared.
+	pub(crate) fn do_destroy_collection_items(
+		collection: CollectionId,
+		limit: u32,
+	) -> Result<u32, DispatchError> {
+		let entry = Collections::<T>::get(collection).ok_or(Error::<T>::NoCollection)?;
+		ensure!(entry.destroying, Error::<T>::CollectionNotDestroying);
+		let batch = CollectionItems::<T>::iter_key_prefix(collection)
+			.take(limit as usize)
+			.collect::<Vec<_>>();
+		for &unique_id in &batch {
+			Self::clear_from_collection(collection, &entry.owner, unique_id);
+		}
+		let mut cleared = batch.len() as u32;
+		if CollectionItems::<T>::iter_key_prefix(collection).next().is_some() {
+			return Ok(cleared)
+		}
+		let founders = CollectionFounders::<T>::iter_key_prefix(collection)
+			.take(limit.saturating_sub(cleared) as usize)
+			.collect::<Vec<_>>();
+		for founder in &founders {
+			CollectionFounders::<T>::remove(collection, founder);
+		}
+		cleared.saturating_accrue(founders.len() as u32);
+		if CollectionFounders::<T>::iter_key_prefix(collection).next().is_none() {
+			Self::remove_collection(collection);
+		}
+		Ok(cleared)
+	}
+
+	pub(crate) fn do_create_collectible_in(
+		minter: T::AccountId,
+		collection: CollectionId,
+		to: T::AccountId,
+	) -> DispatchResult {
+		let mut entry = Collections::<T>::get(collection).ok_or(Error::<T>::NoCollection)?;
+		ensure!(
+			entry.minting == CollectionMinting::Public || entry.owner == minter,
+			Error::<T>::NotCollectionOwner
+		);
+		ensure!(!entry.destroying, Error::<T>::CollectionDestroying);
+		ensure!(
+			entry.max_supply.map_or(true, |max| entry.minted < max),
+			Error::<T>::CollectionSoldOut
+		);
+		// A pending mint has no id yet to put in the collection.
+		ensure!(Self::accepts_mint_from(&minter, &to), Error::<T>::RecipientNotAccepting);
+		let (unique_id, color) = Self::gen_unique_id();
+		Self::mint_in(&minter, &to, unique_id, color, Some(collection))?;
+		Self::take_mint_deposit(&minter, unique_id)?;
+		Self::note_founder(&minter);
+		Self::note_collection_founder(collection, &mut entry, &minter);
+		entry.minted.saturating_inc();
+		entry.items.saturating_inc();
+		Collections::<T>::insert(collection, entry);
+		CollectionItems::<T>::insert(collection, unique_id, ());
+		Self::deposit_event(Event::MintedIntoCollection { collectible: unique_id, collection });
+		Ok(())
+	}
+
+	/// Take collectible `unique_id`, about to be burned, out of the count of its collection.
+	pub(crate) fn note_left_collection(unique_id: u64) {
+		let Some(collection) =
+			CollectibleMap::<T>::get(&unique_id).and_then(|collectible| collectible.collection_id)
+		else {
+			return
+		};
+		Self::note_removed_from(collection, unique_id);
+	}
+
+	/// Burn `unique_id` if `owner`, the owner of `collection`, holds it and nothing locks it,
+	/// or else release it from the collection.
+	fn clear_from_collection(collection: CollectionId, owner: &T::AccountId, unique_id: u64) {
+		let Some(mut collectible) = CollectibleMap::<T>::get(&unique_id) else {
+			CollectionItems::<T>::remove(collection, unique_id);
+			return
+		};
+		let burnable = collectible.owner == *owner &&
+			!ForeignSources::<T>::contains_key(&unique_id) &&
+			Self::ensure_unlocked(unique_id).is_ok();
+		if burnable && with_storage_layer(|| Self::do_burn(unique_id, owner)).is_ok() {
+			return
+		}
+		collectible.collection_id = None;
+		CollectibleMap::<T>::insert(&unique_id, collectible);
+		Self::note_removed_from(collection, unique_id);
+		Self::deposit_event(Event::CollectibleOrphaned { collectible: unique_id, collection });
+	}
+
+	fn note_removed_from(collection: CollectionId, unique_id: u64) {
+		CollectionItems::<T>::remove(collection, unique_id);
+		Collections::<T>::mutate(collection, |entry| {
+			if let Some(entry) = entry {
+				entry.items.saturating_dec();
+			}
+		});
+	}
+
+	/// Remove `collection`, with nothing left in it, and everything stored for it.
+	fn remove_collection(collection: CollectionId) {
+		Collections::<T>::remove(collection);
+		Self::deposit_event(Event::CollectionDestroyed { collection });
+	}
+}
@@ -0,0 +1,62 @@
+//! Market statistics per color: how many collectibles of each color exist, the floor price
+//! among them and the price the last one sold at. They are updated as collectibles are minted,
+//! revealed, burned, listed and sold, so queries never scan the collectibles. The floor itself
+//! is kept by the floor indices, see `floor.rs`.
+
+use crate::*;
+use frame_support::sp_runtime::traits::Saturating;
+
+impl<T: Config> Pallet<T> {
+	/// Count, floor price and last sale price of the collectibles of `color`.
+	pub fn color_stats(color: Color) -> ColorStats<BalanceOf<T>> {
+		ColorStatistics::<T>::get(color)
+	}
+
+	pub(crate) fn note_minted(color: Color) {
+		ColorStatistics::<T>::mutate(color, |stats| stats.count.saturating_inc());
+	}
+
+	/// Record that the collectible is about to be burned. Must run while it is still stored.
+	pub(crate) fn note_burned(unique_id: u64) {
+		if let Some(collectible) = CollectibleMap::<T>::get(&unique_id) {
+			ColorStatistics::<T>::mutate(collectible.color, |stats| stats.count.saturating_dec());
+		}
+	}
+
+	/// Move a collectible from the statistics of `from` to those of `to`, together with its
+	/// listing at `price`, if any.
+	pub(crate) fn note_color_changed(
+		unique_id: u64,
+		from: Color,
+		to: Color,
+		price: Option<BalanceOf<T>>,
+	) {
+		ColorStatistics::<T>::mutate(from, |stats| stats.count.saturating_dec());
+		ColorStatistics::<T>::mutate(to, |stats| stats.count.saturating_inc());
+		if let Some(price) = price {
+			Self::note_recolored(unique_id, from, to, price);
+		}
+	}
+
+	/// Record a sale of the collectible from `seller` to `buyer` at `price`, once it has changed
+	/// hands: the buyer gets a receipt if its creator asked for them, it counts towards the
+	/// parties' trading limits and, unless it is flagged for wash trading, it counts towards the
+	/// rolling volume and becomes its color's last sale. Returns whether the buyer got a receipt.
+	pub(crate) fn note_sale(
+		unique_id: u64,
+		seller: &T::AccountId,
+		buyer: &T::AccountId,
+		price: BalanceOf<T>,
+	) -> bool {
+		let receipt = Self::issue_receipt(unique_id, seller, buyer, price);
+		Self::note_limited_trade(seller, buyer, price);
+		if Self::note_trade_pair(unique_id, seller, buyer) {
+			return receipt
+		}
+		Self::note_volume(price);
+		if let Some(collectible) = CollectibleMap::<T>::get(&unique_id) {
+			ColorStatistics::<T>::mutate(collectible.color, |stats| stats.last_sale = Some(price));
+		}
+		receipt
+	}
+}
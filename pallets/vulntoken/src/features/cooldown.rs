@@ -0,0 +1,32 @@
+//! Cooldown after mint or purchase during which an item cannot be put up for sale again. The
+//! collections that set a cooldown of their own use it in place of the pallet-wide one.
+
+use crate::*;
+use frame_support::{pallet_prelude::*, sp_runtime::traits::{Saturating, Zero}};
+
+impl<T: Config> Pallet<T> {
+	/// Start the cooldown of a freshly minted or bought collectible: its collection's, if that
+	/// sets one, or else the pallet-wide one.
+	pub(crate) fn start_cooldown(unique_id: u64) {
+		let blocks = Self::collection_settings_of(unique_id)
+			.and_then(|settings| settings.transfer_cooldown)
+			.unwrap_or_else(TransferCooldown::<T>::get);
+		if blocks.is_zero() {
+			return
+		}
+		let until = frame_system::Pallet::<T>::block_number().saturating_add(blocks);
+		CooldownUntil::<T>::insert(&unique_id, until);
+	}
+
+	/// Fail while the collectible's cooldown is running, dropping the record once it expired.
+	pub(crate) fn ensure_cooled_down(unique_id: u64) -> DispatchResult {
+		if let Some(until) = CooldownUntil::<T>::get(&unique_id) {
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= until,
+				Error::<T>::CooldownActive
+			);
+			CooldownUntil::<T>::remove(&unique_id);
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,31 @@
+//! Debugging aids. Runtimes built with their `debug-api` feature serve these through the
+//! `VulntokenDebugApi` runtime API; release runtimes leave them out of their API.
+
+use crate::*;
+use frame_support::traits::PalletInfoAccess;
+use sp_std::vec::Vec;
+
+/// Largest page [`Pallet::dump_storage`] returns, whatever the caller asks for.
+pub const MAX_DUMP_PAGE: u32 = 1_000;
+
+impl<T: Config> Pallet<T> {
+	/// Raw keys and values of the pallet's storage in key order, after `start_after`. Pass the
+	/// last key of the previous page as `start_after` to fetch the next one.
+	pub fn dump_storage(start_after: Option<Vec<u8>>, limit: u32) -> Vec<(Vec<u8>, Vec<u8>)> {
+		let limit = limit.min(MAX_DUMP_PAGE) as usize;
+		let prefix = sp_io::hashing::twox_128(<Self as PalletInfoAccess>::name().as_bytes());
+		let mut key = start_after.filter(|key| key.starts_with(&prefix)).unwrap_or(prefix.to_vec());
+		let mut dump = Vec::new();
+		while dump.len() < limit {
+			let Some(next) = sp_io::storage::next_key(&key).filter(|next| next.starts_with(&prefix))
+			else {
+				break
+			};
+			if let Some(value) = sp_io::storage::get(&next) {
+				dump.push((next.clone(), value.to_vec()));
+			}
+			key = next;
+		}
+		dump
+	}
+}
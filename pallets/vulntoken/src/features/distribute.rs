@@ -0,0 +1,24 @@
+//! Distributions: one owner sending different collectibles to different recipients in a single
+//! extrinsic, for prizes or grants. The extrinsic is atomic, so a single grant that cannot go
+//! through undoes the others.
+
+use crate::*;
+use frame_support::pallet_prelude::*;
+
+impl<T: Config> Pallet<T> {
+	pub(crate) fn do_distribute(
+		from: T::AccountId,
+		grants: BoundedVec<(u64, T::AccountId), T::MaximumOwned>,
+	) -> DispatchResult {
+		let collectibles = grants.len() as u32;
+		for (unique_id, to) in grants {
+			let collectible =
+				CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
+			ensure!(collectible.owner == from, Error::<T>::NotOwner);
+			Self::ensure_transfer_allowed(unique_id, &from, &to)?;
+			Self::do_transfer(unique_id, to)?;
+		}
+		Self::deposit_event(Event::Distributed { from, collectibles });
+		Ok(())
+	}
+}
@@ -0,0 +1,119 @@
+//! English auctions, for price discovery on collectibles a fixed price would undersell. Bids are
+//! open and each must beat the one before; the highest bid when the auction ends wins. Only the
+//! leading bid is held, and it is released as soon as it is outbid, so the winner can always pay.
+//!
+//! Auctions settle on their own from `on_initialize` at the start of the block they end at, kept
+//! per block in `AuctionsEnding` like time-locked transfers. An auction that cannot settle then,
+//! for example because the winner owns the maximum, stays as it is until somebody settles it
+//! with `settle_auction`, or the offchain worker does.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::{Saturating, Zero},
+	storage::with_storage_layer,
+	traits::{fungible::MutateHold, tokens::Precision},
+};
+use frame_system::pallet_prelude::*;
+
+impl<T: Config> Pallet<T> {
+	/// The English auction running for `unique_id`, if any.
+	pub fn auction(unique_id: u64) -> Option<Auction<T>> {
+		Auctions::<T>::get(&unique_id)
+	}
+
+	pub(crate) fn do_start_auction(
+		seller: T::AccountId,
+		unique_id: u64,
+		reserve: BalanceOf<T>,
+		duration: BlockNumberFor<T>,
+	) -> DispatchResult {
+		ensure!(!duration.is_zero(), Error::<T>::InvalidAuctionPeriod);
+		let mut collectible =
+			CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
+		ensure!(collectible.owner == seller, Error::<T>::NotOwner);
+		Self::ensure_unlocked(unique_id)?;
+		Self::ensure_cooled_down(unique_id)?;
+		let end = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+		AuctionsEnding::<T>::try_append(end, unique_id)
+			.map_err(|_| Error::<T>::TooManyDeadlines)?;
+
+		// An item under auction can no longer be bought at a fixed price.
+		if collectible.price.take().is_some() {
+			CollectibleMap::<T>::insert(&unique_id, collectible);
+			Self::note_unlisted(unique_id);
+			Self::release_listing_deposit(unique_id)?;
+		}
+		let auction = Auction { seller: seller.clone(), reserve, leader: None, end };
+		Auctions::<T>::insert(&unique_id, auction);
+		Self::do_freeze(unique_id, FreezeReason::Auction);
+
+		Self::deposit_event(Event::AuctionStarted { collectible: unique_id, seller, reserve, end });
+		Ok(())
+	}
+
+	pub(crate) fn do_place_bid(
+		bidder: T::AccountId,
+		unique_id: u64,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		let mut auction = Auctions::<T>::get(&unique_id).ok_or(Error::<T>::NoAuction)?;
+		ensure!(frame_system::Pallet::<T>::block_number() < auction.end, Error::<T>::AuctionEnded);
+		ensure!(auction.seller != bidder, Error::<T>::SellerCannotBid);
+		let beats_leader = auction.leader.as_ref().map_or(true, |(_, best)| amount > *best);
+		ensure!(amount >= auction.reserve && beats_leader, Error::<T>::BidPriceTooLow);
+		// Checked here rather than at settlement, so a finished auction can always settle.
+		Self::ensure_identity(&auction.seller, &bidder, amount)?;
+		Self::ensure_within_limits(&auction.seller, &bidder, amount)?;
+
+		let reason = HoldReason::Bid.into();
+		T::Currency::hold(&reason, &bidder, amount)?;
+		if let Some((previous, best)) = auction.leader.take() {
+			T::Currency::release(&reason, &previous, best, Precision::BestEffort)?;
+		}
+		auction.leader = Some((bidder.clone(), amount));
+		Auctions::<T>::insert(&unique_id, auction);
+
+		Self::deposit_event(Event::BidPlaced { collectible: unique_id, bidder, amount });
+		Ok(())
+	}
+
+	pub(crate) fn do_settle_auction(unique_id: u64) -> DispatchResult {
+		let auction = Auctions::<T>::get(&unique_id).ok_or(Error::<T>::NoAuction)?;
+		ensure!(
+			frame_system::Pallet::<T>::block_number() >= auction.end,
+			Error::<T>::AuctionNotFinished
+		);
+		Auctions::<T>::remove(&unique_id);
+		UnsettledAuctions::<T>::remove(&unique_id);
+		Self::do_thaw(unique_id, FreezeReason::Auction);
+
+		let (winner, price) = match auction.leader {
+			Some((winner, amount)) => {
+				Self::pay_held_sale(HoldReason::Bid, unique_id, &winner, &auction.seller, amount)?;
+				Self::do_transfer(unique_id, winner.clone())?;
+				Self::start_cooldown(unique_id);
+				Self::note_sale(unique_id, &auction.seller, &winner, amount);
+				(Some(winner), Some(amount))
+			},
+			None => (None, None),
+		};
+
+		Self::deposit_event(Event::AuctionSettled { collectible: unique_id, winner, price });
+		Ok(())
+	}
+
+	/// Settle the auctions ending at `now` and return the weight used.
+	pub(crate) fn settle_due_auctions(now: BlockNumberFor<T>) -> Weight {
+		let due = AuctionsEnding::<T>::take(now);
+		let mut weight = T::DbWeight::get().reads_writes(1, 1);
+		for unique_id in due {
+			if with_storage_layer(|| Self::do_settle_auction(unique_id)).is_err() {
+				UnsettledAuctions::<T>::insert(unique_id, ());
+				Self::deposit_event(Event::AuctionSettlementFailed { collectible: unique_id });
+			}
+			weight.saturating_accrue(T::WeightInfo::settle_auction());
+		}
+		weight
+	}
+}
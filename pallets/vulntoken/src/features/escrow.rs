@@ -0,0 +1,240 @@
+//! Escrowed sales with disputes decided by a juror set.
+//!
+//! Whatever an escrow holds can be reclaimed by whoever deposited it once the escrow has stood
+//! `EscrowTimeout` blocks past the point it could have been settled, so a sale that nobody
+//! completes, or that can no longer complete, does not lock funds for good.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::Saturating,
+	traits::{
+		fungible::{BalancedHold, MutateHold},
+		tokens::Precision,
+		SortedMembers,
+	},
+};
+
+impl<T: Config> Pallet<T> {
+	pub(crate) fn do_buy_with_escrow(buyer: T::AccountId, unique_id: u64) -> DispatchResult {
+		let mut collectible =
+			CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
+		let seller = collectible.owner.clone();
+		ensure!(seller != buyer, Error::<T>::TransferToSelf);
+		Self::ensure_unlocked(unique_id)?;
+		Self::ensure_no_charity(unique_id)?;
+		let price = collectible.price.take().ok_or(Error::<T>::NotForSale)?;
+		ensure!(!Self::listing_expired(unique_id), Error::<T>::ListingExpired);
+		Self::ensure_identity(&seller, &buyer, price)?;
+		Self::ensure_within_limits(&seller, &buyer, price)?;
+
+		T::Currency::hold(&HoldReason::Escrow.into(), &buyer, price)?;
+		CollectibleMap::<T>::insert(&unique_id, collectible);
+		ListedAt::<T>::remove(&unique_id);
+		Self::note_unlisted(unique_id);
+		Self::release_listing_deposit(unique_id)?;
+
+		let dispute_deadline =
+			frame_system::Pallet::<T>::block_number().saturating_add(T::EscrowDisputeWindow::get());
+		Escrows::<T>::insert(
+			&unique_id,
+			Escrow { seller: seller.clone(), buyer: buyer.clone(), price, dispute_deadline },
+		);
+		Self::do_freeze(unique_id, FreezeReason::Escrow);
+
+		Self::deposit_event(Event::EscrowOpened {
+			collectible: unique_id,
+			seller,
+			buyer,
+			price,
+			dispute_deadline,
+		});
+		Ok(())
+	}
+
+	pub(crate) fn do_release_escrow(who: T::AccountId, unique_id: u64) -> DispatchResult {
+		let escrow = Escrows::<T>::get(&unique_id).ok_or(Error::<T>::NoEscrow)?;
+		ensure!(!Self::is_frozen(unique_id, FreezeReason::Disputed), Error::<T>::Disputed);
+		ensure!(
+			who == escrow.buyer ||
+				frame_system::Pallet::<T>::block_number() >= escrow.dispute_deadline,
+			Error::<T>::DisputeWindowOpen
+		);
+		Self::complete_escrow(unique_id, escrow)
+	}
+
+	pub(crate) fn do_raise_dispute(who: T::AccountId, unique_id: u64) -> DispatchResult {
+		let escrow = Escrows::<T>::get(&unique_id).ok_or(Error::<T>::NoEscrow)?;
+		ensure!(who == escrow.buyer || who == escrow.seller, Error::<T>::NotEscrowParty);
+		ensure!(!Self::is_frozen(unique_id, FreezeReason::Disputed), Error::<T>::Disputed);
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(now < escrow.dispute_deadline, Error::<T>::DisputeWindowClosed);
+
+		let deposit = T::DisputeDeposit::get();
+		T::Currency::hold(&HoldReason::DisputeDeposit.into(), &who, deposit)?;
+		let voting_end = now.saturating_add(T::DisputeVotingPeriod::get());
+		Disputes::<T>::insert(
+			&unique_id,
+			Dispute { raised_by: who.clone(), deposit, voting_end, for_buyer: 0, for_seller: 0 },
+		);
+		Self::do_freeze(unique_id, FreezeReason::Disputed);
+
+		Self::deposit_event(Event::DisputeRaised { collectible: unique_id, raised_by: who, voting_end });
+		Ok(())
+	}
+
+	pub(crate) fn do_vote_dispute(
+		juror: T::AccountId,
+		unique_id: u64,
+		for_buyer: bool,
+	) -> DispatchResult {
+		ensure!(T::Jurors::contains(&juror), Error::<T>::NotJuror);
+		let mut dispute = Disputes::<T>::get(&unique_id).ok_or(Error::<T>::NoDispute)?;
+		ensure!(
+			frame_system::Pallet::<T>::block_number() < dispute.voting_end,
+			Error::<T>::VotingClosed
+		);
+		ensure!(
+			!DisputeVotes::<T>::contains_key(&unique_id, &juror),
+			Error::<T>::AlreadyVoted
+		);
+
+		if for_buyer {
+			dispute.for_buyer += 1;
+		} else {
+			dispute.for_seller += 1;
+		}
+		Disputes::<T>::insert(&unique_id, dispute);
+		DisputeVotes::<T>::insert(&unique_id, &juror, for_buyer);
+
+		Self::deposit_event(Event::DisputeVoted { collectible: unique_id, juror, for_buyer });
+		Ok(())
+	}
+
+	pub(crate) fn do_resolve_dispute(unique_id: u64) -> DispatchResult {
+		let dispute = Disputes::<T>::get(&unique_id).ok_or(Error::<T>::NoDispute)?;
+		let escrow = Escrows::<T>::get(&unique_id).ok_or(Error::<T>::NoEscrow)?;
+		let majority = T::Jurors::count() as u32 / 2 + 1;
+		ensure!(
+			dispute.for_buyer >= majority ||
+				dispute.for_seller >= majority ||
+				frame_system::Pallet::<T>::block_number() >= dispute.voting_end,
+			Error::<T>::DisputeUnresolved
+		);
+
+		Disputes::<T>::remove(&unique_id);
+		Self::do_thaw(unique_id, FreezeReason::Disputed);
+		let _ = DisputeVotes::<T>::clear_prefix(&unique_id, u32::MAX, None);
+
+		// Without a majority the sale goes ahead, as it would have without the dispute.
+		let for_buyer = dispute.for_buyer > dispute.for_seller;
+		let raiser_won = (for_buyer && dispute.raised_by == escrow.buyer) ||
+			(!for_buyer && dispute.raised_by == escrow.seller);
+		let reason = HoldReason::DisputeDeposit.into();
+		if raiser_won {
+			T::Currency::release(&reason, &dispute.raised_by, dispute.deposit, Precision::BestEffort)?;
+		} else {
+			let _ = T::Currency::slash(&reason, &dispute.raised_by, dispute.deposit);
+		}
+		Self::deposit_event(Event::DisputeResolved {
+			collectible: unique_id,
+			for_buyer,
+			deposit_slashed: !raiser_won,
+		});
+
+		if for_buyer {
+			Escrows::<T>::remove(&unique_id);
+			Self::do_thaw(unique_id, FreezeReason::Escrow);
+			T::Currency::release(
+				&HoldReason::Escrow.into(),
+				&escrow.buyer,
+				escrow.price,
+				Precision::BestEffort,
+			)?;
+			Self::deposit_event(Event::EscrowRefunded {
+				collectible: unique_id,
+				buyer: escrow.buyer,
+				price: escrow.price,
+			});
+			Ok(())
+		} else {
+			Self::complete_escrow(unique_id, escrow)
+		}
+	}
+
+	/// Take back what the escrow or sealed auction of the collectible holds from `who`, once it
+	/// has timed out. Cleans up the rest of a timed-out auction on the way.
+	pub(crate) fn do_reclaim_escrow(who: T::AccountId, unique_id: u64) -> DispatchResult {
+		if SealedAuctions::<T>::contains_key(&unique_id) {
+			return Self::reclaim_sealed_bids(who, unique_id)
+		}
+		let escrow = Escrows::<T>::get(&unique_id).ok_or(Error::<T>::NoEscrow)?;
+		ensure!(who == escrow.buyer, Error::<T>::NotEscrowParty);
+		let dispute = Disputes::<T>::get(&unique_id);
+		// A disputed escrow can be settled once voting ends.
+		let settles_at = match &dispute {
+			Some(dispute) => dispute.voting_end.max(escrow.dispute_deadline),
+			None => escrow.dispute_deadline,
+		};
+		ensure!(
+			frame_system::Pallet::<T>::block_number() >=
+				settles_at.saturating_add(T::EscrowTimeout::get()),
+			Error::<T>::EscrowNotExpired
+		);
+
+		if let Some(dispute) = dispute {
+			// The dispute was never resolved, so nobody lost it.
+			Disputes::<T>::remove(&unique_id);
+			Self::do_thaw(unique_id, FreezeReason::Disputed);
+			let _ = DisputeVotes::<T>::clear_prefix(&unique_id, u32::MAX, None);
+			T::Currency::release(
+				&HoldReason::DisputeDeposit.into(),
+				&dispute.raised_by,
+				dispute.deposit,
+				Precision::BestEffort,
+			)?;
+		}
+		Escrows::<T>::remove(&unique_id);
+		Self::do_thaw(unique_id, FreezeReason::Escrow);
+		let reason = HoldReason::Escrow.into();
+		T::Currency::release(&reason, &who, escrow.price, Precision::BestEffort)?;
+
+		Self::deposit_event(Event::EscrowReclaimed {
+			collectible: unique_id,
+			depositor: who,
+			amount: escrow.price,
+		});
+		Ok(())
+	}
+
+	/// Pay the seller from the buyer's hold and hand the item over.
+	fn complete_escrow(unique_id: u64, escrow: Escrow<T>) -> DispatchResult {
+		Escrows::<T>::remove(&unique_id);
+		Self::do_thaw(unique_id, FreezeReason::Escrow);
+		Self::pay_held_sale(
+			HoldReason::Escrow,
+			unique_id,
+			&escrow.buyer,
+			&escrow.seller,
+			escrow.price,
+		)?;
+		Self::do_transfer(unique_id, escrow.buyer.clone())?;
+		Self::start_cooldown(unique_id);
+
+		Self::deposit_event(Event::Sold {
+			seller: escrow.seller.clone(),
+			buyer: escrow.buyer.clone(),
+			collectible: unique_id,
+			price: escrow.price,
+			charity: None,
+		});
+		Self::note_sale(unique_id, &escrow.seller, &escrow.buyer, escrow.price);
+		Self::deposit_event(Event::EscrowReleased {
+			collectible: unique_id,
+			seller: escrow.seller,
+			buyer: escrow.buyer,
+			price: escrow.price,
+		});
+		Ok(())
+	}
+}
@@ -0,0 +1,179 @@
+//! Floor prices: the cheapest fixed-price listing across the pallet, and among the
+//! collectibles of each color. Colors are the only segments; collections have no floor of their
+//! own.
+//!
+//! `FloorIndex`, and `ColorFloorIndex` for each color, keep the `MaxFloorIndex` cheapest
+//! listings in order, updated on every list, reprice and unlisting, so a floor moves up to the
+//! next entry when the cheapest listing goes. Listings left out of a full index cost at least
+//! as much as its last entry; only when an index runs empty with some left out is it rebuilt
+//! from every collectible, by `on_idle` or by the `rebuild_floor_index` task. Listings past
+//! `MaxListingDuration` count until they are cleared. Listings of collectibles flagged for wash
+//! trading are never indexed.
+
+use crate::*;
+use frame_support::{pallet_prelude::*, sp_runtime::traits::Saturating, weights::WeightMeter};
+use sp_std::borrow::Borrow;
+
+impl<T: Config> Pallet<T> {
+	/// The price of the cheapest fixed-price listing, if anything is listed.
+	pub fn floor_price() -> Option<BalanceOf<T>> {
+		FloorPrice::<T>::get()
+	}
+
+	/// Record that the collectible is listed at `price`, replacing any earlier price. Listings
+	/// of collectibles flagged for wash trading stay out of the indices.
+	pub(crate) fn note_listed(unique_id: u64, price: BalanceOf<T>) {
+		if Self::is_wash_traded(unique_id) {
+			return Self::note_unlisted(unique_id)
+		}
+		Self::raise_highest_price(unique_id, price);
+		let color = CollectibleMap::<T>::get(&unique_id).map(|collectible| collectible.color);
+		Self::update_floor(None, |index| Self::admit(index, unique_id, price));
+		if let Some(color) = color {
+			Self::update_floor(Some(color), |index| Self::admit(index, unique_id, price));
+		}
+	}
+
+	/// Record that the collectible is no longer listed.
+	pub(crate) fn note_unlisted(unique_id: u64) {
+		Self::drop_highest_price(unique_id);
+		let color = CollectibleMap::<T>::get(&unique_id).map(|collectible| collectible.color);
+		Self::update_floor(None, |index| Self::evict(index, unique_id));
+		if let Some(color) = color {
+			Self::update_floor(Some(color), |index| Self::evict(index, unique_id));
+		}
+	}
+
+	/// Move a listing at `price` over from the floor of the color the collectible had before.
+	pub(crate) fn note_recolored(unique_id: u64, from: Color, to: Color, price: BalanceOf<T>) {
+		Self::update_floor(Some(from), |index| Self::evict(index, unique_id));
+		if Self::is_wash_traded(unique_id) {
+			return
+		}
+		Self::update_floor(Some(to), |index| Self::admit(index, unique_id, price));
+	}
+
+	/// Scan collectibles into the floor indices being rebuilt for as long as `limit` allows.
+	/// Each scan picks up where it left off on the next idle block.
+	pub(crate) fn rebuild_floor_indices(limit: Weight) -> Weight {
+		let mut meter = WeightMeter::with_limit(limit);
+		let scopes = [None].into_iter().chain(Color::ALL.into_iter().map(Some));
+		for scope in scopes {
+			if !Self::continue_rebuild(scope, &mut meter, u32::MAX) {
+				break
+			}
+		}
+		meter.consumed()
+	}
+
+	/// Whether the floor index of `scope` is being rebuilt. Takes the scope by value or by
+	/// reference, as task conditions see it.
+	pub(crate) fn floor_rebuild_pending(scope: impl Borrow<Option<Color>>) -> bool {
+		Self::floor_index(*scope.borrow()).rebuild_from.is_some()
+	}
+
+	/// Scan up to `batch` collectibles into the floor index of `scope`, if it is being rebuilt.
+	pub(crate) fn do_rebuild_floor_index(scope: Option<Color>, batch: u32) {
+		Self::continue_rebuild(scope, &mut WeightMeter::new(), batch);
+	}
+
+	/// Continue rebuilding the floor index of `scope` for up to `batch` collectibles, if it is
+	/// being rebuilt. Returns whether the rebuild is done and weight was left over.
+	fn continue_rebuild(scope: Option<Color>, meter: &mut WeightMeter, batch: u32) -> bool {
+		let db = T::DbWeight::get();
+		if meter.try_consume(db.reads(1)).is_err() {
+			return false
+		}
+		let mut index = Self::floor_index(scope);
+		let Some(mut next) = index.rebuild_from else { return true };
+		if meter.try_consume(db.reads_writes(2, 2)).is_err() {
+			return false
+		}
+
+		let end = NextCollectibleId::<T>::get();
+		let mut scanned = 0u32;
+		while next < end && scanned < batch && meter.try_consume(db.reads(1)).is_ok() {
+			// Burned collectibles leave gaps in the mint sequence.
+			let id = Self::item_id_at(next);
+			if let Some(collectible) = CollectibleMap::<T>::get(&id) {
+				if let Some(price) = collectible.price {
+					let in_scope = scope.map_or(true, |color| color == collectible.color);
+					if in_scope && !Self::is_wash_traded(id) {
+						Self::admit(&mut index, id, price);
+					}
+				}
+			}
+			next.saturating_inc();
+			scanned.saturating_inc();
+		}
+		index.rebuild_from = (next < end).then_some(next);
+		Self::put_floor(scope, index);
+		next >= end
+	}
+
+	/// Apply `f` to the floor index of `scope`, the whole pallet's for `None`, and write it
+	/// back if `f` reports a change.
+	fn update_floor(scope: Option<Color>, f: impl FnOnce(&mut CheapestListings<T>) -> bool) {
+		let mut index = Self::floor_index(scope);
+		if f(&mut index) {
+			Self::put_floor(scope, index);
+		}
+	}
+
+	/// Put the collectible in the index at `price` if it belongs among the cheapest listings,
+	/// pushing the most expensive entry out of a full index. Always reports a change.
+	fn admit(index: &mut CheapestListings<T>, unique_id: u64, price: BalanceOf<T>) -> bool {
+		Self::evict(index, unique_id);
+		let full = index.cheapest.len() as u32 >= T::MaxFloorIndex::get();
+		let below_last = index.cheapest.last().map_or(true, |(last, _)| price < *last);
+		if full && below_last {
+			index.cheapest.pop();
+			index.truncated = true;
+		} else if full || (index.truncated && !below_last) {
+			// Whatever is left out must cost at least as much as every entry, or the floor could
+			// skip over it once the entries go.
+			index.truncated = true;
+			return true
+		}
+		let entry = (price, unique_id);
+		let at = index.cheapest.iter().position(|e| *e > entry).unwrap_or(index.cheapest.len());
+		// There is room: either the index was not full or its last entry just left.
+		let _ = index.cheapest.try_insert(at, entry);
+		true
+	}
+
+	/// Take the collectible out of the index, returning whether it was in it. Emptying an index
+	/// that left listings out starts a rebuild.
+	fn evict(index: &mut CheapestListings<T>, unique_id: u64) -> bool {
+		let Some(at) = index.cheapest.iter().position(|(_, id)| *id == unique_id) else {
+			return false
+		};
+		index.cheapest.remove(at);
+		if index.cheapest.is_empty() && index.truncated {
+			index.truncated = false;
+			index.rebuild_from = Some(0);
+		}
+		true
+	}
+
+	fn floor_index(scope: Option<Color>) -> CheapestListings<T> {
+		match scope {
+			None => FloorIndex::<T>::get(),
+			Some(color) => ColorFloorIndex::<T>::get(color),
+		}
+	}
+
+	fn put_floor(scope: Option<Color>, index: CheapestListings<T>) {
+		let floor = index.cheapest.first().map(|(price, _)| *price);
+		match scope {
+			None => {
+				FloorPrice::<T>::set(floor);
+				FloorIndex::<T>::put(index);
+			},
+			Some(color) => {
+				ColorStatistics::<T>::mutate(color, |stats| stats.floor = floor);
+				ColorFloorIndex::<T>::insert(color, index);
+			},
+		}
+	}
+}
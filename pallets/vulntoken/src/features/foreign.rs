@@ -0,0 +1,64 @@
+//! Collectibles from other chains. `ForeignOrigin` registers a collectible that arrived from
+//! another chain by minting its holder a local derivative, and records the chain and id the
+//! original has there. The runtime has no XCM configured, so chains are plain numeric ids; a
+//! parachain would use the para id of the sending chain.
+//!
+//! Derivatives trade like any other collectible. They leave by being redeemed: the holder burns
+//! the derivative, and `ForeignRedeemed` tells the bridge which original to release on its chain
+//! and to whom. Burning a derivative outright would strand the original, so `burn` refuses them.
+
+use crate::*;
+
+impl<T: Config> Pallet<T> {
+	/// The chain and id the original of `unique_id` has, if it is a derivative of a collectible
+	/// from another chain.
+	pub fn foreign_source(unique_id: u64) -> Option<ForeignSource> {
+		ForeignSources::<T>::get(&unique_id)
+	}
+
+	/// The local derivative of collectible `original_id` of `chain`, if it is registered.
+	pub fn foreign_derivative(chain: u32, original_id: u64) -> Option<u64> {
+		ForeignDerivatives::<T>::get(chain, original_id)
+	}
+
+	/// Mint `owner` a derivative of collectible `original_id` of `chain`.
+	pub(crate) fn do_register_foreign(
+		chain: u32,
+		original_id: u64,
+		owner: T::AccountId,
+	) -> DispatchResult {
+		ensure!(
+			!ForeignDerivatives::<T>::contains_key(chain, original_id),
+			Error::<T>::ForeignAlreadyRegistered
+		);
+		let (unique_id, color) = Self::gen_unique_id();
+		Self::mint(&owner, &owner, unique_id, color)?;
+		ForeignSources::<T>::insert(&unique_id, ForeignSource { chain, original_id });
+		ForeignDerivatives::<T>::insert(chain, original_id, unique_id);
+		Self::deposit_event(Event::ForeignRegistered {
+			collectible: unique_id,
+			chain,
+			original_id,
+			owner,
+		});
+		Ok(())
+	}
+
+	/// Burn `owner`'s derivative `unique_id` so its original can be released on its own chain.
+	pub(crate) fn do_redeem_foreign(owner: T::AccountId, unique_id: u64) -> DispatchResult {
+		let collectible = CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
+		ensure!(collectible.owner == owner, Error::<T>::NotOwner);
+		let source = ForeignSources::<T>::get(&unique_id).ok_or(Error::<T>::NotForeign)?;
+		Self::ensure_unlocked(unique_id)?;
+		Self::do_burn(unique_id, &owner)?;
+		ForeignSources::<T>::remove(&unique_id);
+		ForeignDerivatives::<T>::remove(source.chain, source.original_id);
+		Self::deposit_event(Event::ForeignRedeemed {
+			collectible: unique_id,
+			chain: source.chain,
+			original_id: source.original_id,
+			owner,
+		});
+		Ok(())
+	}
+}
@@ -0,0 +1,66 @@
+//! Founder badges. The first `FounderBadgeLimit` distinct accounts to mint a collectible each
+//! get a soulbound badge, minted to them alongside. These founders are counted across the
+//! pallet, and the limit is set by `ForceOrigin`; zero awards no badges. Collections count their
+//! own founders on top, up to the limit their owner sets, so minting into a collection can earn
+//! a badge of the pallet and one of the collection. Badges belong to no collection.
+//!
+//! Like sale receipts, badges cannot be transferred, listed or sold, only burned. A minter who
+//! cannot take a badge when they mint, for instance for holding `MaximumOwned` collectibles, is
+//! not counted, and can still earn one with a later mint while places remain.
+
+use crate::*;
+use frame_support::{sp_runtime::traits::Saturating, storage::with_storage_layer};
+
+impl<T: Config> Pallet<T> {
+	/// The founder badge of `who`, if they earned one.
+	pub fn founder_badge(who: &T::AccountId) -> Option<u64> {
+		FounderBadges::<T>::get(who)
+	}
+
+	/// Award `minter` a founder badge for minting, if they have none and places remain.
+	pub(crate) fn note_founder(minter: &T::AccountId) {
+		let issued = FounderBadgesIssued::<T>::get();
+		if issued >= FounderBadgeLimit::<T>::get() || FounderBadges::<T>::contains_key(minter) {
+			return
+		}
+		let (badge, color) = Self::gen_unique_id();
+		if with_storage_layer(|| Self::mint(minter, minter, badge, color)).is_err() {
+			return
+		}
+		Self::do_freeze(badge, FreezeReason::Soulbound);
+		FounderBadges::<T>::insert(minter, badge);
+		FounderBadgesIssued::<T>::put(issued.saturating_add(1));
+		Self::deposit_event(Event::FounderBadgeAwarded { minter: minter.clone(), badge });
+	}
+
+	/// The founder badge of `collection` that `who` holds, if they earned one.
+	pub fn collection_founder_badge(collection: CollectionId, who: &T::AccountId) -> Option<u64> {
+		CollectionFounders::<T>::get(collection, who)
+	}
+
+	/// Award `minter` a founder badge of `collection`, whose stored `entry` the caller writes
+	/// back, for minting into it, if they have none and places remain.
+	pub(crate) fn note_collection_founder(
+		collection: CollectionId,
+		entry: &mut Collection<T>,
+		minter: &T::AccountId,
+	) {
+		if entry.founder_badges_issued >= entry.settings.founder_badge_limit ||
+			CollectionFounders::<T>::contains_key(collection, minter)
+		{
+			return
+		}
+		let (badge, color) = Self::gen_unique_id();
+		if with_storage_layer(|| Self::mint(minter, minter, badge, color)).is_err() {
+			return
+		}
+		Self::do_freeze(badge, FreezeReason::Soulbound);
+		CollectionFounders::<T>::insert(collection, minter, badge);
+		entry.founder_badges_issued.saturating_inc();
+		Self::deposit_event(Event::CollectionFounderBadgeAwarded {
+			collection,
+			minter: minter.clone(),
+			badge,
+		});
+	}
+}
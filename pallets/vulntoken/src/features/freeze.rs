@@ -0,0 +1,112 @@
+//! Per-item freezes. Every feature that takes a collectible out of circulation records why in
+//! `Frozen`, and every path that moves, lists or burns a collectible consults it.
+//!
+//! Besides the marketplace's own freezes, owners can stake their collectibles and `FreezeOrigin`
+//! can pledge any collectible as collateral with `freeze`, so other pallets can rely on one not
+//! being sold out from under them. Only the owner can unstake, and only `FreezeOrigin` can release
+//! collateral.
+//!
+//! Neither can be placed on a collectible on its way to changing hands, in a sale, swap or timed
+//! transfer: the freeze would keep that from ever completing, with the other side's funds held.
+//! Owners can only stake collectibles nothing else freezes; collateral can still be pledged on
+//! top of a stake, a rental or a soulbound freeze.
+
+use crate::*;
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+
+impl<T: Config> Pallet<T> {
+	/// Freeze the collectible for `reason`. Freezing it again for the same reason is a no-op.
+	pub fn do_freeze(unique_id: u64, reason: FreezeReason) {
+		Frozen::<T>::insert(&unique_id, reason, ());
+	}
+
+	/// Lift the collectible's freeze for `reason`, leaving any other freeze in place.
+	pub fn do_thaw(unique_id: u64, reason: FreezeReason) {
+		Frozen::<T>::remove(&unique_id, reason);
+	}
+
+	/// Whether the collectible is frozen for `reason`.
+	pub fn is_frozen(unique_id: u64, reason: FreezeReason) -> bool {
+		Frozen::<T>::contains_key(&unique_id, reason)
+	}
+
+	/// Fail if the collectible is frozen for any reason.
+	pub fn ensure_unlocked(unique_id: u64) -> DispatchResult {
+		// Keys iterate in hash rather than declaration order, so report the first reason
+		// by declaration order to keep the error stable.
+		match Frozen::<T>::iter_key_prefix(&unique_id).min() {
+			None => Ok(()),
+			Some(reason) => Err(Self::frozen_error(reason)),
+		}
+	}
+
+	/// Fail if the collectible is frozen for a sale, swap or timed transfer that ends with it
+	/// changing hands.
+	pub(crate) fn ensure_not_changing_hands(unique_id: u64) -> DispatchResult {
+		let pending = Frozen::<T>::iter_key_prefix(&unique_id)
+			.filter(|reason| {
+				!matches!(
+					reason,
+					FreezeReason::Staked |
+						FreezeReason::Collateral |
+						FreezeReason::Rented |
+						FreezeReason::Soulbound
+				)
+			})
+			.min();
+		match pending {
+			None => Ok(()),
+			Some(reason) => Err(Self::frozen_error(reason)),
+		}
+	}
+
+	fn frozen_error(reason: FreezeReason) -> DispatchError {
+		match reason {
+			FreezeReason::Auction => Error::<T>::InAuction.into(),
+			FreezeReason::Raffle => Error::<T>::InRaffle.into(),
+			FreezeReason::Escrow => Error::<T>::InEscrow.into(),
+			FreezeReason::Disputed => Error::<T>::Disputed.into(),
+			FreezeReason::Staked | FreezeReason::Collateral => Error::<T>::Frozen.into(),
+			FreezeReason::Rented => Error::<T>::Rented.into(),
+			FreezeReason::Installments => Error::<T>::InInstallments.into(),
+			FreezeReason::SellOrder => Error::<T>::InOrderBook.into(),
+			FreezeReason::Soulbound => Error::<T>::Soulbound.into(),
+			FreezeReason::TimedTransfer => Error::<T>::InTimedTransfer.into(),
+			FreezeReason::Swap => Error::<T>::InSwap.into(),
+		}
+	}
+
+	/// The freeze `origin` places and lifts with `freeze` and `thaw`: collateral for
+	/// `FreezeOrigin`, and a stake for the owner of the collectible.
+	pub(crate) fn freeze_reason_of(
+		origin: OriginFor<T>,
+		unique_id: u64,
+	) -> Result<FreezeReason, DispatchError> {
+		let collectible = CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
+		if T::FreezeOrigin::try_origin(origin.clone()).is_ok() {
+			return Ok(FreezeReason::Collateral)
+		}
+		let who = ensure_signed(origin)?;
+		ensure!(collectible.owner == who, Error::<T>::NotOwner);
+		Ok(FreezeReason::Staked)
+	}
+
+	pub(crate) fn do_lock(unique_id: u64, reason: FreezeReason) -> DispatchResult {
+		ensure!(!Self::is_frozen(unique_id, reason), Error::<T>::AlreadyFrozen);
+		match reason {
+			FreezeReason::Collateral => Self::ensure_not_changing_hands(unique_id)?,
+			_ => Self::ensure_unlocked(unique_id)?,
+		}
+		Self::do_freeze(unique_id, reason);
+		Self::deposit_event(Event::CollectibleFrozen { collectible: unique_id, reason });
+		Ok(())
+	}
+
+	pub(crate) fn do_unlock(unique_id: u64, reason: FreezeReason) -> DispatchResult {
+		ensure!(Self::is_frozen(unique_id, reason), Error::<T>::NotFrozen);
+		Self::do_thaw(unique_id, reason);
+		Self::deposit_event(Event::CollectibleThawed { collectible: unique_id, reason });
+		Ok(())
+	}
+}
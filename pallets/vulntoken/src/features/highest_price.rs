@@ -0,0 +1,75 @@
+//! The highest price: the dearest fixed-price listing across the pallet, kept up to date on
+//! every list, reprice and unlisting rather than by looking at every collectible each block.
+//!
+//! Only the dearest listing is remembered, so when it goes, or is repriced lower, the next one
+//! is unknown. `on_idle` then scans every collectible for it, as far as each idle block allows,
+//! and listings made in the meantime count as they are made. Listings of collectibles flagged
+//! for wash trading never count, as for the floor.
+
+use crate::*;
+use frame_support::{pallet_prelude::*, sp_runtime::traits::Saturating, weights::WeightMeter};
+
+impl<T: Config> Pallet<T> {
+	/// The price of the dearest fixed-price listing, if anything is listed. While a scan is
+	/// running, only the collectibles scanned so far and those listed since count.
+	pub fn highest_price() -> Option<BalanceOf<T>> {
+		HighestListing::<T>::exists().then(HighestPrice::<T>::get)
+	}
+
+	/// Count the collectible, just listed at `price`, towards the highest price.
+	pub(crate) fn raise_highest_price(unique_id: u64, price: BalanceOf<T>) {
+		let highest = HighestPrice::<T>::get();
+		match HighestListing::<T>::get() {
+			Some(listing) if listing == unique_id && price < highest =>
+				Self::drop_highest_price(unique_id),
+			Some(_) if price < highest => return,
+			_ => {},
+		}
+		HighestPrice::<T>::put(price);
+		HighestListing::<T>::put(unique_id);
+	}
+
+	/// Record that the collectible is no longer listed, scanning for the next dearest listing if
+	/// it was the dearest.
+	pub(crate) fn drop_highest_price(unique_id: u64) {
+		if HighestListing::<T>::get() != Some(unique_id) {
+			return
+		}
+		HighestListing::<T>::kill();
+		HighestPrice::<T>::kill();
+		HighestPriceScan::<T>::put(0);
+	}
+
+	/// Scan collectibles for the dearest listing for as long as `limit` allows, if a scan is
+	/// running. Each scan picks up where the last one stopped.
+	pub(crate) fn scan_highest_price(limit: Weight) -> Weight {
+		let mut meter = WeightMeter::with_limit(limit);
+		let db = T::DbWeight::get();
+		if meter.try_consume(db.reads(1)).is_err() {
+			return meter.consumed()
+		}
+		let Some(mut next) = HighestPriceScan::<T>::get() else { return meter.consumed() };
+		if meter.try_consume(db.reads_writes(3, 3)).is_err() {
+			return meter.consumed()
+		}
+
+		let end = NextCollectibleId::<T>::get();
+		let mut highest = HighestPrice::<T>::get();
+		let mut listing = HighestListing::<T>::get();
+		// The item id, the collectible and its wash trading flag.
+		while next < end && meter.try_consume(db.reads(3)).is_ok() {
+			// Burned collectibles leave gaps in the mint sequence.
+			let id = Self::item_id_at(next);
+			if let Some(price) = CollectibleMap::<T>::get(&id).and_then(|c| c.price) {
+				if (listing.is_none() || price > highest) && !Self::is_wash_traded(id) {
+					(highest, listing) = (price, Some(id));
+				}
+			}
+			next.saturating_inc();
+		}
+		HighestPrice::<T>::put(highest);
+		HighestListing::<T>::set(listing);
+		HighestPriceScan::<T>::set((next < end).then_some(next));
+		meter.consumed()
+	}
+}
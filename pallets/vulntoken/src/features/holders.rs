@@ -0,0 +1,25 @@
+//! Holder snapshots for airdrops, served through the runtime API.
+
+use crate::*;
+use sp_std::vec::Vec;
+
+/// Largest page [`Pallet::holders`] returns, whatever the caller asks for.
+pub const MAX_HOLDERS_PAGE: u32 = 1_000;
+
+impl<T: Config> Pallet<T> {
+	/// Accounts holding at least one collectible, with the number they hold, in storage order.
+	/// Pass the last account of the previous page as `start_after` to fetch the next one.
+	pub fn holders(start_after: Option<T::AccountId>, limit: u32) -> Vec<(T::AccountId, u32)> {
+		let holders = match start_after {
+			Some(account) => OwnerOfCollectibles::<T>::iter_from(
+				OwnerOfCollectibles::<T>::hashed_key_for(&account),
+			),
+			None => OwnerOfCollectibles::<T>::iter(),
+		};
+		holders
+			.filter(|(_, owned)| !owned.is_empty())
+			.map(|(account, owned)| (account, owned.len() as u32))
+			.take(limit.min(MAX_HOLDERS_PAGE) as usize)
+			.collect()
+	}
+}
@@ -0,0 +1,133 @@
+//! Inheritance. An owner names an heir who can take over all of the owner's collectibles once
+//! the owner has been inactive for a period of the owner's choosing.
+//!
+//! Every signed extrinsic bumps the sender's account nonce, so the pallet tells activity apart
+//! by the nonce instead of by hooking every call: it records the nonce it last saw and the
+//! block it saw it at. An heir claiming from an owner whose nonce moved since then only gets the
+//! record refreshed, and the inactivity period starts over. A claim that goes through still waits
+//! out `InheritanceGracePeriod`, during which the owner can veto it, explicitly or by sending any
+//! other transaction.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::Saturating,
+	storage::with_storage_layer,
+};
+
+impl<T: Config> Pallet<T> {
+	/// The heir `owner` named and what the pallet last saw of the owner, if they named one.
+	pub fn inheritance(owner: &T::AccountId) -> Option<Inheritance<T>> {
+		Inheritances::<T>::get(owner)
+	}
+
+	pub(crate) fn do_set_heir(
+		owner: T::AccountId,
+		heir: Option<(T::AccountId, BlockNumberFor<T>)>,
+	) -> DispatchResult {
+		let Some((heir, inactivity_period)) = heir else {
+			ensure!(Inheritances::<T>::contains_key(&owner), Error::<T>::NoHeir);
+			Inheritances::<T>::remove(&owner);
+			Self::deposit_event(Event::HeirSet { owner, heir: None });
+			return Ok(())
+		};
+		ensure!(heir != owner, Error::<T>::TransferToSelf);
+		ensure!(
+			inactivity_period >= T::MinInactivityPeriod::get(),
+			Error::<T>::InactivityPeriodTooShort
+		);
+		let inheritance = Inheritance {
+			heir: heir.clone(),
+			inactivity_period,
+			last_seen: frame_system::Pallet::<T>::block_number(),
+			nonce: frame_system::Pallet::<T>::account_nonce(&owner),
+			claim_started: None,
+		};
+		Inheritances::<T>::insert(&owner, inheritance);
+		Self::deposit_event(Event::HeirSet { owner, heir: Some(heir) });
+		Ok(())
+	}
+
+	pub(crate) fn do_start_inheritance(heir: T::AccountId, owner: T::AccountId) -> DispatchResult {
+		let mut inheritance = Inheritances::<T>::get(&owner).ok_or(Error::<T>::NoHeir)?;
+		ensure!(inheritance.heir == heir, Error::<T>::NotHeir);
+		ensure!(inheritance.claim_started.is_none(), Error::<T>::ClaimInProgress);
+		let now = frame_system::Pallet::<T>::block_number();
+		if Self::seen_active(&owner, &mut inheritance, now) {
+			Inheritances::<T>::insert(&owner, inheritance);
+			Self::deposit_event(Event::OwnerSeenActive { owner });
+			return Ok(())
+		}
+		ensure!(
+			now >= inheritance.last_seen.saturating_add(inheritance.inactivity_period),
+			Error::<T>::OwnerActive
+		);
+		inheritance.claim_started = Some(now);
+		Inheritances::<T>::insert(&owner, inheritance);
+		let completes_at = now.saturating_add(T::InheritanceGracePeriod::get());
+		Self::deposit_event(Event::InheritanceClaimStarted { owner, heir, completes_at });
+		Ok(())
+	}
+
+	pub(crate) fn do_veto_inheritance(owner: T::AccountId) -> DispatchResult {
+		let mut inheritance = Inheritances::<T>::get(&owner).ok_or(Error::<T>::NoHeir)?;
+		ensure!(inheritance.claim_started.is_some(), Error::<T>::NoInheritanceClaim);
+		Self::see(&owner, &mut inheritance, frame_system::Pallet::<T>::block_number());
+		Inheritances::<T>::insert(&owner, inheritance);
+		Self::deposit_event(Event::InheritanceVetoed { owner });
+		Ok(())
+	}
+
+	/// Hand the collectibles of `owner` to their heir. Returns how many were moved.
+	pub(crate) fn do_complete_inheritance(
+		heir: T::AccountId,
+		owner: T::AccountId,
+	) -> Result<u32, DispatchError> {
+		let mut inheritance = Inheritances::<T>::get(&owner).ok_or(Error::<T>::NoHeir)?;
+		ensure!(inheritance.heir == heir, Error::<T>::NotHeir);
+		let started = inheritance.claim_started.ok_or(Error::<T>::NoInheritanceClaim)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		// Any transaction of the owner's during the grace period counts as a veto.
+		if Self::seen_active(&owner, &mut inheritance, now) {
+			Inheritances::<T>::insert(&owner, inheritance);
+			Self::deposit_event(Event::InheritanceVetoed { owner });
+			return Ok(0)
+		}
+		ensure!(
+			now >= started.saturating_add(T::InheritanceGracePeriod::get()),
+			Error::<T>::GracePeriodOpen
+		);
+		Inheritances::<T>::remove(&owner);
+
+		// Frozen collectibles, and those beyond what the heir can own, stay with the owner.
+		let mut moved = 0;
+		for unique_id in OwnerOfCollectibles::<T>::get(&owner) {
+			if with_storage_layer(|| Self::do_transfer(unique_id, heir.clone())).is_ok() {
+				moved += 1;
+			}
+		}
+		Self::deposit_event(Event::InheritanceClaimed { owner, heir, collectibles: moved });
+		Ok(moved)
+	}
+
+	/// Whether `owner` sent a transaction since the pallet last saw them. If so, record that they
+	/// were seen at `now`, which calls off any claim in progress.
+	fn seen_active(
+		owner: &T::AccountId,
+		inheritance: &mut Inheritance<T>,
+		now: BlockNumberFor<T>,
+	) -> bool {
+		if frame_system::Pallet::<T>::account_nonce(owner) == inheritance.nonce {
+			return false
+		}
+		Self::see(owner, inheritance, now);
+		true
+	}
+
+	/// Record that `owner` was active at `now` and call off any claim in progress.
+	fn see(owner: &T::AccountId, inheritance: &mut Inheritance<T>, now: BlockNumberFor<T>) {
+		inheritance.nonce = frame_system::Pallet::<T>::account_nonce(owner);
+		inheritance.last_seen = now;
+		inheritance.claim_started = None;
+	}
+}
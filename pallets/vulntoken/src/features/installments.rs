@@ -0,0 +1,220 @@
+//! Sales paid off in installments. The buyer's installments are held until the last one is
+//! paid; missing one defaults the plan, returning the item to circulation with the seller and
+//! forfeiting `InstallmentForfeit` of what was paid. Deadlines are enforced from `on_idle`,
+//! or by anyone through `enforce_installment_default`.
+//!
+//! Unlike rent, installments are not paid through a payment stream: a stream pays the seller
+//! as it goes, while installments must stay refundable until the sale completes.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::{
+		traits::{One, Saturating, Zero},
+		PerThing,
+	},
+	storage::with_storage_layer,
+	traits::{
+		fungible::MutateHold,
+		tokens::{Fortitude, Precision, Restriction},
+	},
+	weights::WeightMeter,
+};
+
+impl<T: Config> Pallet<T> {
+	pub(crate) fn do_buy_in_installments(
+		buyer: T::AccountId,
+		unique_id: u64,
+		installments: u32,
+	) -> DispatchResult {
+		ensure!(
+			installments >= 2 && installments <= T::MaxInstallments::get(),
+			Error::<T>::InvalidInstallments
+		);
+		let mut collectible =
+			CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
+		let seller = collectible.owner.clone();
+		ensure!(seller != buyer, Error::<T>::TransferToSelf);
+		Self::ensure_unlocked(unique_id)?;
+		Self::ensure_no_charity(unique_id)?;
+		let price = collectible.price.take().ok_or(Error::<T>::NotForSale)?;
+		ensure!(!Self::listing_expired(unique_id), Error::<T>::ListingExpired);
+		Self::ensure_identity(&seller, &buyer, price)?;
+		Self::ensure_within_limits(&seller, &buyer, price)?;
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let mut plan = InstallmentPlan {
+			seller: seller.clone(),
+			buyer: buyer.clone(),
+			price,
+			installments,
+			paid: 0,
+			held: Zero::zero(),
+			due: now,
+		};
+		Self::hold_installment(&mut plan)?;
+		Self::schedule_installment(unique_id, &mut plan)?;
+		InstallmentCursor::<T>::mutate(|cursor| {
+			cursor.get_or_insert(now);
+		});
+		InstallmentPlans::<T>::insert(&unique_id, plan);
+		CollectibleMap::<T>::insert(&unique_id, collectible);
+		ListedAt::<T>::remove(&unique_id);
+		Self::note_unlisted(unique_id);
+		Self::release_listing_deposit(unique_id)?;
+		Self::do_freeze(unique_id, FreezeReason::Installments);
+
+		Self::deposit_event(Event::InstallmentPlanOpened {
+			collectible: unique_id,
+			seller,
+			buyer,
+			price,
+			installments,
+		});
+		Ok(())
+	}
+
+	pub(crate) fn do_pay_installment(buyer: T::AccountId, unique_id: u64) -> DispatchResult {
+		let mut plan = InstallmentPlans::<T>::get(&unique_id).ok_or(Error::<T>::NoInstallmentPlan)?;
+		ensure!(plan.buyer == buyer, Error::<T>::NotInstallmentBuyer);
+		ensure!(
+			frame_system::Pallet::<T>::block_number() < plan.due,
+			Error::<T>::InstallmentOverdue
+		);
+
+		let amount = Self::hold_installment(&mut plan)?;
+		if plan.paid == plan.installments {
+			return Self::complete_installments(unique_id, plan)
+		}
+		Self::schedule_installment(unique_id, &mut plan)?;
+		Self::deposit_event(Event::InstallmentPaid {
+			collectible: unique_id,
+			amount,
+			paid: plan.paid,
+			due: plan.due,
+		});
+		InstallmentPlans::<T>::insert(&unique_id, plan);
+		Ok(())
+	}
+
+	pub(crate) fn do_enforce_installment_default(unique_id: u64) -> DispatchResult {
+		let plan = InstallmentPlans::<T>::get(&unique_id).ok_or(Error::<T>::NoInstallmentPlan)?;
+		ensure!(
+			frame_system::Pallet::<T>::block_number() >= plan.due,
+			Error::<T>::InstallmentNotDue
+		);
+		Self::default_installments(unique_id, plan)
+	}
+
+	/// Default the plans whose installments fell due up to `now`, for as long as `limit`
+	/// allows. Blocks that do not fit are picked up on the next idle block.
+	pub(crate) fn enforce_installment_deadlines(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+		let mut meter = WeightMeter::with_limit(limit);
+		let db = T::DbWeight::get();
+		if meter.try_consume(db.reads_writes(1, 1)).is_err() {
+			return Weight::zero()
+		}
+		let Some(mut block) = InstallmentCursor::<T>::get() else { return meter.consumed() };
+
+		while block <= now {
+			if meter.try_consume(db.reads_writes(1, 1)).is_err() {
+				break
+			}
+			let due = InstallmentDeadlines::<T>::get(block);
+			let defaults = T::WeightInfo::enforce_installment_default()
+				.saturating_mul(due.len() as u64);
+			if meter.try_consume(defaults).is_err() {
+				break
+			}
+			InstallmentDeadlines::<T>::remove(block);
+			for unique_id in due {
+				let Some(plan) = InstallmentPlans::<T>::get(&unique_id) else { continue };
+				// Plans that were paid on time have moved on to a later deadline.
+				if plan.due == block {
+					let _ = with_storage_layer(|| Self::default_installments(unique_id, plan));
+				}
+			}
+			block.saturating_inc();
+		}
+
+		InstallmentCursor::<T>::put(block);
+		meter.consumed()
+	}
+
+	/// Hold the buyer's next installment and return its amount. The last one makes up for
+	/// rounding.
+	fn hold_installment(plan: &mut InstallmentPlan<T>) -> Result<BalanceOf<T>, DispatchError> {
+		let amount = if plan.paid.saturating_add(1) == plan.installments {
+			plan.price.saturating_sub(plan.held)
+		} else {
+			plan.price / plan.installments.into()
+		};
+		T::Currency::hold(&HoldReason::Installment.into(), &plan.buyer, amount)?;
+		plan.held = plan.held.saturating_add(amount);
+		plan.paid.saturating_inc();
+		Ok(amount)
+	}
+
+	/// Move the plan's deadline one period on and record it for `on_idle`.
+	fn schedule_installment(unique_id: u64, plan: &mut InstallmentPlan<T>) -> DispatchResult {
+		let period = T::InstallmentPeriod::get().max(One::one());
+		plan.due = frame_system::Pallet::<T>::block_number().saturating_add(period);
+		InstallmentDeadlines::<T>::try_append(plan.due, unique_id)
+			.map_err(|_| Error::<T>::TooManyDeadlines)?;
+		Ok(())
+	}
+
+	/// Pay the seller everything held and hand the item over.
+	fn complete_installments(unique_id: u64, plan: InstallmentPlan<T>) -> DispatchResult {
+		InstallmentPlans::<T>::remove(&unique_id);
+		Self::do_thaw(unique_id, FreezeReason::Installments);
+		Self::pay_held_sale(
+			HoldReason::Installment,
+			unique_id,
+			&plan.buyer,
+			&plan.seller,
+			plan.held,
+		)?;
+		Self::do_transfer(unique_id, plan.buyer.clone())?;
+		Self::start_cooldown(unique_id);
+
+		Self::deposit_event(Event::Sold {
+			seller: plan.seller.clone(),
+			buyer: plan.buyer.clone(),
+			collectible: unique_id,
+			price: plan.price,
+			charity: None,
+		});
+		Self::note_sale(unique_id, &plan.seller, &plan.buyer, plan.price);
+		Ok(())
+	}
+
+	/// Pay the seller their share of what was held, refund the rest and unlock the item.
+	fn default_installments(unique_id: u64, plan: InstallmentPlan<T>) -> DispatchResult {
+		InstallmentPlans::<T>::remove(&unique_id);
+		Self::do_thaw(unique_id, FreezeReason::Installments);
+		let reason = HoldReason::Installment.into();
+		let forfeited = T::InstallmentForfeit::get().mul_floor(plan.held);
+		if !forfeited.is_zero() {
+			T::Currency::transfer_on_hold(
+				&reason,
+				&plan.buyer,
+				&plan.seller,
+				forfeited,
+				Precision::BestEffort,
+				Restriction::Free,
+				Fortitude::Polite,
+			)?;
+		}
+		let refunded = plan.held.saturating_sub(forfeited);
+		T::Currency::release(&reason, &plan.buyer, refunded, Precision::BestEffort)?;
+
+		Self::deposit_event(Event::InstallmentPlanDefaulted {
+			collectible: unique_id,
+			buyer: plan.buyer,
+			forfeited,
+			refunded,
+		});
+		Ok(())
+	}
+}
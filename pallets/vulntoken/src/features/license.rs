@@ -0,0 +1,41 @@
+//! Licenses. A collectible can be minted with the terms it may be used under, as an SPDX
+//! license expression like `CC-BY-4.0` or `MIT OR Apache-2.0`, or any identifier written the same
+//! way. The license is fixed at mint: nobody can change it afterwards, so buyers can rely on it.
+//! Licenses are per collectible, in a collection or not.
+
+use crate::*;
+use frame_support::pallet_prelude::*;
+use sp_std::vec::Vec;
+
+impl<T: Config> Pallet<T> {
+	/// The license collectible `unique_id` was minted with, if any.
+	pub fn license(unique_id: u64) -> Option<Vec<u8>> {
+		Licenses::<T>::get(&unique_id).map(BoundedVec::into_inner)
+	}
+
+	pub(crate) fn do_create_licensed_collectible(
+		minter: T::AccountId,
+		to: T::AccountId,
+		license: BoundedVec<u8, T::MaxLicenseLength>,
+	) -> DispatchResult {
+		ensure!(Self::is_valid_license(&license), Error::<T>::InvalidLicense);
+		// A pending mint has no id yet to attach the license to.
+		ensure!(Self::accepts_mint_from(&minter, &to), Error::<T>::RecipientNotAccepting);
+		let (unique_id, color) = Self::gen_unique_id();
+		Self::mint(&minter, &to, unique_id, color)?;
+		Self::take_mint_deposit(&minter, unique_id)?;
+		Self::note_founder(&minter);
+		Licenses::<T>::insert(&unique_id, &license);
+		Self::deposit_event(Event::LicenseAttached { collectible: unique_id, license });
+		Ok(())
+	}
+
+	/// Licenses are printable: letters, digits, spaces and the `-.+:()` of SPDX expressions,
+	/// with something other than a space in them.
+	fn is_valid_license(license: &[u8]) -> bool {
+		license.iter().any(|byte| *byte != b' ') &&
+			license
+				.iter()
+				.all(|byte| byte.is_ascii_alphanumeric() || b" -.+:()".contains(byte))
+	}
+}
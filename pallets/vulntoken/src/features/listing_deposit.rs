@@ -0,0 +1,70 @@
+//! Deposits held for as long as a collectible is listed, slashed if governance flags the
+//! listing as spam or abuse.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::Zero,
+	traits::{
+		fungible::{BalancedHold, MutateHold},
+		tokens::Precision,
+		Imbalance, OnUnbalanced,
+	},
+};
+
+impl<T: Config> Pallet<T> {
+	/// Hold `ListingDeposit` from `owner` unless the listing already has a deposit.
+	pub(crate) fn take_listing_deposit(owner: &T::AccountId, unique_id: u64) -> DispatchResult {
+		if ListingDeposits::<T>::contains_key(&unique_id) {
+			return Ok(())
+		}
+		let deposit = T::ListingDeposit::get();
+		T::Currency::hold(&HoldReason::ListingDeposit.into(), owner, deposit)?;
+		ListingDeposits::<T>::insert(&unique_id, (owner.clone(), deposit));
+		Ok(())
+	}
+
+	/// Return the listing's deposit, if it has one, and forget any charity share it gives.
+	/// Called wherever a listing ends other than by being flagged.
+	pub(crate) fn release_listing_deposit(unique_id: u64) -> DispatchResult {
+		ListingCharities::<T>::remove(&unique_id);
+		if let Some((depositor, deposit)) = ListingDeposits::<T>::take(&unique_id) {
+			T::Currency::release(
+				&HoldReason::ListingDeposit.into(),
+				&depositor,
+				deposit,
+				Precision::BestEffort,
+			)?;
+		}
+		Ok(())
+	}
+
+	pub(crate) fn do_flag_listing(unique_id: u64) -> DispatchResult {
+		let mut collectible =
+			CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
+		ensure!(collectible.price.take().is_some(), Error::<T>::NotForSale);
+		CollectibleMap::<T>::insert(&unique_id, &collectible);
+		ListedAt::<T>::remove(&unique_id);
+		ListingCharities::<T>::remove(&unique_id);
+		Self::note_unlisted(unique_id);
+
+		// Listings older than the deposit have nothing to slash.
+		let slashed = match ListingDeposits::<T>::take(&unique_id) {
+			Some((depositor, deposit)) => {
+				let (credit, _) =
+					T::Currency::slash(&HoldReason::ListingDeposit.into(), &depositor, deposit);
+				let slashed = credit.peek();
+				T::Slashed::on_unbalanced(credit);
+				slashed
+			},
+			None => Zero::zero(),
+		};
+
+		Self::deposit_event(Event::ListingFlagged {
+			collectible: unique_id,
+			owner: collectible.owner,
+			slashed,
+		});
+		Ok(())
+	}
+}
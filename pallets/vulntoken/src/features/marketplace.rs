@@ -0,0 +1,67 @@
+//! The marketplace as other pallets see it, through [`Marketplace`], [`Custody`] and [`Issuer`].
+
+use crate::*;
+use frame_support::{pallet_prelude::*, sp_runtime::traits::Bounded};
+
+impl<T: Config> Marketplace<T::AccountId, BalanceOf<T>> for Pallet<T> {
+	fn price(unique_id: u64) -> Option<BalanceOf<T>> {
+		Self::listing_price(unique_id)
+	}
+
+	fn buy(unique_id: u64, buyer: &T::AccountId) -> DispatchResult {
+		Self::do_buy_collectible(unique_id, buyer.clone(), Bounded::max_value())
+			.map(|_| ())
+			.map_err(|e| e.error)
+	}
+
+	fn owner(unique_id: u64) -> Option<T::AccountId> {
+		CollectibleMap::<T>::get(&unique_id).map(|collectible| collectible.owner)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn list(seller: &T::AccountId, price: BalanceOf<T>) -> u64 {
+		// Setup is not subject to the per-block mint limit, the cooldown or listing deposits.
+		MintsThisBlock::<T>::kill();
+		let (unique_id, color) = Self::gen_unique_id();
+		Self::mint(seller, seller, unique_id, color).expect("seller is below the cap");
+		CooldownUntil::<T>::remove(&unique_id);
+		CollectibleMap::<T>::mutate(&unique_id, |collectible| {
+			if let Some(collectible) = collectible {
+				collectible.price = Some(price);
+			}
+		});
+		ListedAt::<T>::insert(&unique_id, frame_system::Pallet::<T>::block_number());
+		Self::note_listed(unique_id, price);
+		unique_id
+	}
+}
+
+impl<T: Config> Custody<T::AccountId> for Pallet<T> {
+	fn owner(unique_id: u64) -> Option<T::AccountId> {
+		CollectibleMap::<T>::get(&unique_id).map(|collectible| collectible.owner)
+	}
+
+	fn transfer(unique_id: u64, from: &T::AccountId, to: &T::AccountId) -> DispatchResult {
+		let collectible = CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
+		ensure!(collectible.owner == *from, Error::<T>::NotOwner);
+		Self::do_transfer(unique_id, to.clone())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn mint(owner: &T::AccountId) -> u64 {
+		// Setup is not subject to the per-block mint limit or the cooldown.
+		MintsThisBlock::<T>::kill();
+		let (unique_id, color) = Self::gen_unique_id();
+		Self::mint(owner, owner, unique_id, color).expect("owner is below the cap");
+		CooldownUntil::<T>::remove(&unique_id);
+		unique_id
+	}
+}
+
+impl<T: Config> Issuer<T::AccountId> for Pallet<T> {
+	fn issue(owner: &T::AccountId, color: Color) -> Result<u64, DispatchError> {
+		ensure!(color != Color::Hidden, Error::<T>::HiddenColor);
+		let (unique_id, _) = Self::gen_unique_id();
+		Self::mint(owner, owner, unique_id, color)
+	}
+}
@@ -0,0 +1,57 @@
+//! Off-chain metadata, committed to on-chain by its content hash, and the schema version it is
+//! currently published under. Collections publish the version of the collectibles in them;
+//! creators publish that of the collectibles they minted outside collections.
+
+use crate::*;
+use frame_support::pallet_prelude::*;
+use sp_io::hashing::blake2_256;
+
+impl<T: Config> Pallet<T> {
+	pub(crate) fn do_set_metadata_hash(
+		owner: T::AccountId,
+		unique_id: u64,
+		hash: Option<[u8; 32]>,
+	) -> DispatchResult {
+		let mut collectible =
+			CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
+		ensure!(collectible.owner == owner, Error::<T>::NotOwner);
+		collectible.metadata = hash;
+		CollectibleMap::<T>::insert(&unique_id, collectible);
+		Self::deposit_event(Event::MetadataHashSet { collectible: unique_id, hash });
+		Ok(())
+	}
+
+	/// The metadata schema version the collectibles `creator` minted outside collections
+	/// follow. Zero until they publish one.
+	pub fn metadata_version(creator: &T::AccountId) -> u16 {
+		MetadataVersions::<T>::get(creator)
+	}
+
+	/// The metadata schema version `collectible` follows: its collection's, or its creator's if
+	/// it is in none.
+	pub(crate) fn metadata_version_of(collectible: &Collectible<T>) -> u16 {
+		match collectible.collection_id {
+			Some(collection) =>
+				Collections::<T>::get(collection).map_or(0, |entry| entry.metadata_version),
+			None => Self::metadata_version(&collectible.creator),
+		}
+	}
+
+	pub(crate) fn do_publish_metadata_version(
+		creator: T::AccountId,
+		version: u16,
+	) -> DispatchResult {
+		ensure!(version > MetadataVersions::<T>::get(&creator), Error::<T>::StaleMetadataVersion);
+		MetadataVersions::<T>::insert(&creator, version);
+		Self::deposit_event(Event::MetadataVersionPublished { creator, version });
+		Ok(())
+	}
+
+	/// Whether `blob` matches the metadata hash committed for the collectible. Collectibles
+	/// without a commitment match nothing.
+	pub fn verify_metadata(unique_id: u64, blob: &[u8]) -> bool {
+		CollectibleMap::<T>::get(&unique_id)
+			.and_then(|collectible| collectible.metadata)
+			.is_some_and(|hash| hash == blake2_256(blob))
+	}
+}
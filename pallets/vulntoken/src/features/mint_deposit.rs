@@ -0,0 +1,44 @@
+//! Mint deposits, so every collectible in storage costs somebody something for as long as it
+//! exists. `CollectibleDeposit` is held from whoever mints a collectible, or claims a pending
+//! mint, and released back to them when the collectible is burned, whoever owns it by then.
+//!
+//! Collectibles the pallet mints on its own, sale receipts and founder badges, carry no deposit,
+//! and neither do those minted at genesis or redeemed from another chain.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::Zero,
+	traits::{fungible::MutateHold, tokens::Precision},
+};
+
+impl<T: Config> Pallet<T> {
+	/// The account that paid the mint deposit of `unique_id`, and the amount held, if any.
+	pub fn mint_deposit(unique_id: u64) -> Option<(T::AccountId, BalanceOf<T>)> {
+		MintDeposits::<T>::get(&unique_id)
+	}
+
+	/// Hold `CollectibleDeposit` from `minter` for the collectible they just minted.
+	pub(crate) fn take_mint_deposit(minter: &T::AccountId, unique_id: u64) -> DispatchResult {
+		let deposit = T::CollectibleDeposit::get();
+		if deposit.is_zero() {
+			return Ok(())
+		}
+		T::Currency::hold(&HoldReason::MintDeposit.into(), minter, deposit)?;
+		MintDeposits::<T>::insert(&unique_id, (minter.clone(), deposit));
+		Ok(())
+	}
+
+	/// Return the collectible's mint deposit, if it has one. Called when it is burned.
+	pub(crate) fn release_mint_deposit(unique_id: u64) -> DispatchResult {
+		if let Some((depositor, deposit)) = MintDeposits::<T>::take(&unique_id) {
+			T::Currency::release(
+				&HoldReason::MintDeposit.into(),
+				&depositor,
+				deposit,
+				Precision::BestEffort,
+			)?;
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,46 @@
+//! Internal implementations of the pallet's larger features, one module per feature.
+
+pub(crate) mod sealed_auction;
+pub(crate) mod auction_settler;
+pub(crate) mod english_auction;
+pub(crate) mod raffle;
+pub(crate) mod mystery_box;
+pub(crate) mod cooldown;
+pub(crate) mod escrow;
+pub(crate) mod metadata;
+pub(crate) mod holders;
+pub(crate) mod claims;
+pub(crate) mod freeze;
+pub(crate) mod listing_deposit;
+pub(crate) mod mint_deposit;
+pub(crate) mod rental;
+pub(crate) mod installments;
+pub(crate) mod marketplace;
+pub(crate) mod order_book;
+pub(crate) mod floor;
+pub(crate) mod highest_price;
+pub(crate) mod volume;
+pub(crate) mod color_stats;
+pub(crate) mod search;
+pub(crate) mod sweep;
+pub(crate) mod wash_trading;
+pub(crate) mod receipts;
+pub(crate) mod founders;
+pub(crate) mod foreign;
+pub(crate) mod timed_transfer;
+pub(crate) mod inheritance;
+pub(crate) mod charity;
+pub(crate) mod royalty;
+pub(crate) mod trading_limits;
+pub(crate) mod license;
+pub(crate) mod remap;
+pub(crate) mod distribute;
+pub(crate) mod collections;
+pub(crate) mod approvals;
+pub(crate) mod offers;
+pub(crate) mod batch;
+pub(crate) mod attributes;
+pub(crate) mod swaps;
+pub(crate) mod pricing;
+pub(crate) mod debug;
+pub mod pinning;
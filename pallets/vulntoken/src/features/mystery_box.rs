@@ -0,0 +1,89 @@
+//! Mystery boxes: collectibles minted with a hidden color that is assigned at a later block.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::Saturating,
+	traits::Randomness,
+};
+
+impl<T: Config> Pallet<T> {
+	pub(crate) fn do_mint_mystery_box(
+		minter: &T::AccountId,
+		owner: &T::AccountId,
+	) -> Result<u64, DispatchError> {
+		let (unique_id, _) = Self::gen_unique_id();
+		Self::mint(minter, owner, unique_id, Color::Hidden)?;
+		Self::take_mint_deposit(minter, unique_id)?;
+
+		let minted_at = frame_system::Pallet::<T>::block_number();
+		let reveal_at = minted_at.saturating_add(T::RevealDelay::get());
+		MysteryBoxes::<T>::insert(&unique_id, MysteryBox { minted_at, reveal_at });
+
+		Self::deposit_event(Event::MysteryBoxMinted {
+			collectible: unique_id,
+			owner: owner.clone(),
+			reveal_at,
+		});
+		Self::note_founder(minter);
+		Ok(unique_id)
+	}
+
+	pub(crate) fn do_reveal(unique_id: u64) -> DispatchResult {
+		let mystery_box = MysteryBoxes::<T>::get(&unique_id).ok_or(Error::<T>::NotHidden)?;
+		let mut collectible =
+			CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
+		ensure!(
+			frame_system::Pallet::<T>::block_number() >= mystery_box.reveal_at,
+			Error::<T>::RevealTooEarly
+		);
+
+		// Only randomness nobody could have known when the box was minted is acceptable,
+		// otherwise the minter could have predicted the color.
+		let (seed, known_since) =
+			T::Randomness::random(&(b"vulntoken/reveal", unique_id).encode());
+		ensure!(known_since > mystery_box.minted_at, Error::<T>::RandomnessNotReady);
+
+		let color = Self::color_from_seed(&seed);
+		let (hidden, price) = (collectible.color, collectible.price);
+		collectible.color = color;
+		CollectibleMap::<T>::insert(&unique_id, collectible);
+		Self::note_color_changed(unique_id, hidden, color, price);
+		MysteryBoxes::<T>::remove(&unique_id);
+		RevealRecords::<T>::insert(
+			&unique_id,
+			RevealRecord {
+				seed,
+				known_since,
+				revealed_at: frame_system::Pallet::<T>::block_number(),
+			},
+		);
+
+		Self::deposit_event(Event::CollectibleRevealed {
+			collectible: unique_id,
+			color,
+			seed,
+			known_since,
+		});
+		Ok(())
+	}
+
+	/// Check that a revealed collectible's color is the one its recorded seed produces.
+	///
+	/// Returns `None` if the collectible was never revealed from a mystery box.
+	pub fn audit_reveal(unique_id: u64) -> Option<bool> {
+		let record = RevealRecords::<T>::get(&unique_id)?;
+		let collectible = CollectibleMap::<T>::get(&unique_id)?;
+		Some(Self::color_from_seed(&record.seed) == collectible.color)
+	}
+
+	/// Pick one of the four colors uniformly from a random seed.
+	pub(crate) fn color_from_seed(seed: &T::Hash) -> Color {
+		match seed.as_ref().first().copied().unwrap_or_default() % 4 {
+			0 => Color::Red,
+			1 => Color::Yellow,
+			2 => Color::Blue,
+			_ => Color::Green,
+		}
+	}
+}
@@ -0,0 +1,106 @@
+//! Offers, so buyers can start a negotiation instead of waiting for the owner to set a price.
+//! Anybody can offer an amount for any collectible, listed or not, and the amount is held until
+//! the owner accepts the offer or the bidder withdraws it. Each bidder has at most one offer per
+//! collectible; offering again replaces it.
+//!
+//! Offers outlive the owner they were made to: they stand when the collectible changes hands,
+//! and are released to their bidders when it is burned. A collectible has at most `MaxOffers`
+//! standing offers, so a burn can release them all.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::{Saturating, Zero},
+	traits::{fungible::MutateHold, tokens::Precision},
+};
+
+impl<T: Config> Pallet<T> {
+	/// The amount `bidder` offered for collectible `unique_id`, if any.
+	pub fn offer(unique_id: u64, bidder: &T::AccountId) -> Option<BalanceOf<T>> {
+		Offers::<T>::get(&unique_id, bidder)
+	}
+
+	pub(crate) fn do_make_offer(
+		bidder: T::AccountId,
+		unique_id: u64,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		ensure!(!amount.is_zero(), Error::<T>::BidPriceTooLow);
+		let collectible = CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
+		ensure!(collectible.owner != bidder, Error::<T>::TransferToSelf);
+
+		let reason = HoldReason::Offer.into();
+		if let Some(previous) = Offers::<T>::get(&unique_id, &bidder) {
+			T::Currency::release(&reason, &bidder, previous, Precision::Exact)?;
+		} else {
+			let offers = OfferCounts::<T>::get(&unique_id).unwrap_or_default();
+			ensure!(offers < T::MaxOffers::get(), Error::<T>::TooManyOffers);
+			OfferCounts::<T>::insert(&unique_id, offers.saturating_add(1));
+		}
+		T::Currency::hold(&reason, &bidder, amount)?;
+		Offers::<T>::insert(&unique_id, &bidder, amount);
+
+		Self::deposit_event(Event::OfferMade { collectible: unique_id, bidder, amount });
+		Ok(())
+	}
+
+	pub(crate) fn do_accept_offer(
+		who: T::AccountId,
+		unique_id: u64,
+		bidder: T::AccountId,
+	) -> DispatchResult {
+		let seller = CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?.owner;
+		ensure!(Self::may_manage(&who, &seller, unique_id), Error::<T>::NotOwner);
+		let amount = Offers::<T>::take(&unique_id, &bidder).ok_or(Error::<T>::NoOffer)?;
+		Self::note_offer_removed(unique_id);
+		Self::ensure_unlocked(unique_id)?;
+		Self::ensure_cooled_down(unique_id)?;
+		Self::ensure_identity(&seller, &bidder, amount)?;
+		Self::ensure_within_limits(&seller, &bidder, amount)?;
+
+		Self::pay_held_sale(HoldReason::Offer, unique_id, &bidder, &seller, amount)?;
+		Self::do_transfer(unique_id, bidder.clone())?;
+		Self::start_cooldown(unique_id);
+
+		Self::deposit_event(Event::Sold {
+			seller: seller.clone(),
+			buyer: bidder.clone(),
+			collectible: unique_id,
+			price: amount,
+			charity: None,
+		});
+		Self::note_sale(unique_id, &seller, &bidder, amount);
+		Self::deposit_event(Event::OfferAccepted {
+			collectible: unique_id,
+			seller,
+			bidder,
+			amount,
+		});
+		Ok(())
+	}
+
+	pub(crate) fn do_withdraw_offer(bidder: T::AccountId, unique_id: u64) -> DispatchResult {
+		let amount = Offers::<T>::take(&unique_id, &bidder).ok_or(Error::<T>::NoOffer)?;
+		Self::note_offer_removed(unique_id);
+		T::Currency::release(&HoldReason::Offer.into(), &bidder, amount, Precision::Exact)?;
+		Self::deposit_event(Event::OfferWithdrawn { collectible: unique_id, bidder });
+		Ok(())
+	}
+
+	/// Release every standing offer on `unique_id`, which is about to be burned.
+	pub(crate) fn release_offers(unique_id: u64) -> DispatchResult {
+		let reason = HoldReason::Offer.into();
+		for (bidder, amount) in Offers::<T>::drain_prefix(&unique_id) {
+			T::Currency::release(&reason, &bidder, amount, Precision::Exact)?;
+			Self::deposit_event(Event::OfferWithdrawn { collectible: unique_id, bidder });
+		}
+		OfferCounts::<T>::remove(&unique_id);
+		Ok(())
+	}
+
+	fn note_offer_removed(unique_id: u64) {
+		OfferCounts::<T>::mutate_exists(&unique_id, |offers| {
+			*offers = offers.and_then(|offers| offers.checked_sub(1)).filter(|offers| *offers > 0);
+		});
+	}
+}
@@ -0,0 +1,179 @@
+//! An order book beside the single-listing market. Buyers place standing orders for a number
+//! of collectibles at up to a price, with that price held; owners offer single collectibles
+//! at a minimum price, frozen while they stand. Orders match as they are placed, at the price
+//! of the order already in the book, so the book never holds a buy order and a sell order
+//! that cross.
+//!
+//! A buy order bids on any collectible in the pallet, whatever collection it is in. Each side of
+//! the book is bounded by `MaxBookDepth`.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::{CheckedMul, Saturating, Zero},
+	traits::{
+		fungible::MutateHold,
+		tokens::Precision,
+	},
+};
+
+impl<T: Config> Pallet<T> {
+	pub(crate) fn do_place_sell_order(
+		seller: T::AccountId,
+		unique_id: u64,
+		price: BalanceOf<T>,
+	) -> DispatchResult {
+		ensure!(!price.is_zero(), Error::<T>::InvalidOrder);
+		ensure!(price >= T::MinListingPrice::get(), Error::<T>::PriceTooLow);
+		ensure!(price <= T::MaxPrice::get(), Error::<T>::PriceTooHigh);
+		let collectible = CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
+		ensure!(collectible.owner == seller, Error::<T>::NotOwner);
+		Self::ensure_unlocked(unique_id)?;
+		Self::ensure_cooled_down(unique_id)?;
+
+		let mut buys = BuyBook::<T>::get();
+		let best = buys.iter_mut().take_while(|order| order.price >= price).find(|order| {
+			order.bidder != seller &&
+				Self::ensure_identity(&seller, &order.bidder, order.price).is_ok() &&
+				Self::ensure_within_limits(&seller, &order.bidder, order.price).is_ok()
+		});
+		if let Some(order) = best {
+			order.quantity.saturating_dec();
+			let (id, bidder, bid, filled) =
+				(order.id, order.bidder.clone(), order.price, order.quantity.is_zero());
+			if filled {
+				buys.retain(|order| order.id != id);
+			}
+			BuyBook::<T>::put(buys);
+			return Self::fill_orders(unique_id, seller, bidder, bid)
+		}
+
+		let mut sells = SellBook::<T>::get();
+		let at = sells.iter().position(|order| order.price > price).unwrap_or(sells.len());
+		sells
+			.try_insert(at, SellOrder { collectible: unique_id, seller: seller.clone(), price })
+			.map_err(|_| Error::<T>::BookFull)?;
+		SellBook::<T>::put(sells);
+		Self::do_freeze(unique_id, FreezeReason::SellOrder);
+
+		Self::deposit_event(Event::SellOrderPlaced { collectible: unique_id, seller, price });
+		Ok(())
+	}
+
+	pub(crate) fn do_place_buy_order(
+		bidder: T::AccountId,
+		price: BalanceOf<T>,
+		quantity: u32,
+	) -> DispatchResult {
+		ensure!(!price.is_zero() && !quantity.is_zero(), Error::<T>::InvalidOrder);
+		let reason = HoldReason::BuyOrder.into();
+		let total = price.checked_mul(&quantity.into()).ok_or(Error::<T>::BoundsOverflow)?;
+		T::Currency::hold(&reason, &bidder, total)?;
+
+		let mut sells = SellBook::<T>::get();
+		let (mut remaining, mut next) = (quantity, 0);
+		while !remaining.is_zero() {
+			let Some(order) = sells.get(next) else { break };
+			if order.price > price {
+				break
+			}
+			if order.seller == bidder ||
+				Self::ensure_identity(&order.seller, &bidder, order.price).is_err() ||
+				Self::ensure_within_limits(&order.seller, &bidder, order.price).is_err()
+			{
+				next += 1;
+				continue
+			}
+			let order = sells.remove(next);
+			Self::do_thaw(order.collectible, FreezeReason::SellOrder);
+			Self::fill_orders(order.collectible, order.seller, bidder.clone(), order.price)?;
+			let refund = price.saturating_sub(order.price);
+			T::Currency::release(&reason, &bidder, refund, Precision::Exact)?;
+			remaining.saturating_dec();
+		}
+		SellBook::<T>::put(sells);
+		if remaining.is_zero() {
+			return Ok(())
+		}
+
+		let id = NextOrderId::<T>::get();
+		NextOrderId::<T>::put(id.checked_add(1).ok_or(Error::<T>::BoundsOverflow)?);
+		let mut buys = BuyBook::<T>::get();
+		let at = buys.iter().position(|order| order.price < price).unwrap_or(buys.len());
+		buys
+			.try_insert(at, BuyOrder { id, bidder: bidder.clone(), price, quantity: remaining })
+			.map_err(|_| Error::<T>::BookFull)?;
+		BuyBook::<T>::put(buys);
+
+		Self::deposit_event(Event::BuyOrderPlaced {
+			order: id,
+			bidder,
+			price,
+			quantity: remaining,
+		});
+		Ok(())
+	}
+
+	pub(crate) fn do_cancel_buy_order(
+		bidder: T::AccountId,
+		id: u64,
+		quantity: u32,
+	) -> DispatchResult {
+		ensure!(!quantity.is_zero(), Error::<T>::InvalidOrder);
+		let mut buys = BuyBook::<T>::get();
+		let order = buys.iter_mut().find(|order| order.id == id).ok_or(Error::<T>::NoOrder)?;
+		ensure!(order.bidder == bidder, Error::<T>::NotOrderOwner);
+		let cancelled = quantity.min(order.quantity);
+		let refund =
+			order.price.checked_mul(&cancelled.into()).ok_or(Error::<T>::BoundsOverflow)?;
+		order.quantity.saturating_reduce(cancelled);
+		let remaining = order.quantity;
+		if remaining.is_zero() {
+			buys.retain(|order| order.id != id);
+		}
+		BuyBook::<T>::put(buys);
+		T::Currency::release(&HoldReason::BuyOrder.into(), &bidder, refund, Precision::Exact)?;
+
+		Self::deposit_event(Event::BuyOrderCancelled { order: id, cancelled, remaining });
+		Ok(())
+	}
+
+	pub(crate) fn do_cancel_sell_order(seller: T::AccountId, unique_id: u64) -> DispatchResult {
+		let mut sells = SellBook::<T>::get();
+		let at = sells
+			.iter()
+			.position(|order| order.collectible == unique_id)
+			.ok_or(Error::<T>::NoOrder)?;
+		ensure!(sells[at].seller == seller, Error::<T>::NotOrderOwner);
+		sells.remove(at);
+		SellBook::<T>::put(sells);
+		Self::do_thaw(unique_id, FreezeReason::SellOrder);
+
+		Self::deposit_event(Event::SellOrderCancelled { collectible: unique_id });
+		Ok(())
+	}
+
+	/// Pay the seller `price` out of the buyer's held order and hand the item over. The item
+	/// must no longer be frozen for the sell order.
+	fn fill_orders(
+		unique_id: u64,
+		seller: T::AccountId,
+		buyer: T::AccountId,
+		price: BalanceOf<T>,
+	) -> DispatchResult {
+		Self::pay_held_sale(HoldReason::BuyOrder, unique_id, &buyer, &seller, price)?;
+		Self::do_transfer(unique_id, buyer.clone())?;
+		Self::start_cooldown(unique_id);
+
+		Self::deposit_event(Event::Sold {
+			seller: seller.clone(),
+			buyer: buyer.clone(),
+			collectible: unique_id,
+			price,
+			charity: None,
+		});
+		Self::note_sale(unique_id, &seller, &buyer, price);
+		Self::deposit_event(Event::OrdersMatched { collectible: unique_id, seller, buyer, price });
+		Ok(())
+	}
+}
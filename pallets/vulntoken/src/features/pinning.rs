@@ -0,0 +1,160 @@
+//! Offchain worker that keeps collectible metadata pinned with an IPFS pinning service.
+//!
+//! Node operators point the worker at a service implementing the IPFS Pinning Service API by
+//! writing its `/pins` URL to the persistent offchain storage key [`PINNING_ENDPOINT_KEY`], and
+//! optionally a bearer token to [`PINNING_TOKEN_KEY`], e.g. with the `offchain_localStorageSet`
+//! RPC. The worker only runs on nodes whose keystore holds a [`KEY_TYPE`] key, inserted with
+//! `key insert --key-type vtok`. Without an endpoint or a key the worker does nothing.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::{
+		offchain::{http, storage::StorageValueRef, Duration},
+		traits::Saturating,
+		KeyTypeId,
+	},
+};
+use frame_system::{offchain::SubmitTransaction, pallet_prelude::*};
+use sp_std::{vec, vec::Vec};
+
+/// Key type of the keys marking the nodes that run the pinning worker.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"vtok");
+
+/// The application crypto of [`KEY_TYPE`] keys.
+pub mod crypto {
+	use super::KEY_TYPE;
+	use frame_support::sp_runtime::app_crypto::{app_crypto, sr25519};
+	app_crypto!(sr25519, KEY_TYPE);
+}
+
+/// Offchain storage key holding the pinning service's `/pins` URL.
+pub const PINNING_ENDPOINT_KEY: &[u8] = b"vulntoken::pinning-endpoint";
+/// Offchain storage key holding the pinning service's bearer token.
+pub const PINNING_TOKEN_KEY: &[u8] = b"vulntoken::pinning-token";
+/// Prefix of the offchain storage keys recording the last pin attempt per collectible.
+const PIN_ATTEMPT_PREFIX: &[u8] = b"vulntoken::pin-attempt";
+
+/// Pin requests sent per block, so a long queue cannot stall the worker.
+const MAX_PINS_PER_BLOCK: usize = 5;
+/// Blocks to wait before retrying a pin that failed or whose confirmation was not included.
+const PIN_RETRY_BLOCKS: u32 = 10;
+/// Time allowed for a single pin request.
+const PIN_TIMEOUT_MS: u64 = 5_000;
+
+impl<T: Config> Pallet<T> {
+	pub(crate) fn do_set_metadata_cid(
+		owner: T::AccountId,
+		unique_id: u64,
+		cid: BoundedVec<u8, T::MaxCidLength>,
+	) -> DispatchResult {
+		let collectible = CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
+		ensure!(collectible.owner == owner, Error::<T>::NotOwner);
+		// CIDs are multibase strings, which keeps them safe to embed in the request body.
+		ensure!(
+			!cid.is_empty() && cid.iter().all(u8::is_ascii_alphanumeric),
+			Error::<T>::InvalidCid
+		);
+		MetadataCids::<T>::insert(&unique_id, &cid);
+		PinQueue::<T>::insert(&unique_id, ());
+		Self::deposit_event(Event::MetadataCidSet { collectible: unique_id, cid });
+		Ok(())
+	}
+
+	pub(crate) fn do_confirm_pin(unique_id: u64) -> DispatchResult {
+		ensure!(PinQueue::<T>::take(&unique_id).is_some(), Error::<T>::NotPinPending);
+		Self::deposit_event(Event::MetadataPinned { collectible: unique_id });
+		Ok(())
+	}
+
+	/// Validity of an unsigned `confirm_pin`.
+	///
+	/// The status cannot be checked on-chain, so only confirmations produced by the local
+	/// offchain worker or already included in a block are accepted. Confirmations submitted
+	/// over RPC or gossiped from other nodes are rejected.
+	pub(crate) fn validate_confirm_pin(
+		source: TransactionSource,
+		unique_id: u64,
+	) -> TransactionValidity {
+		if !matches!(source, TransactionSource::Local | TransactionSource::InBlock) {
+			return InvalidTransaction::Call.into()
+		}
+		if !PinQueue::<T>::contains_key(&unique_id) {
+			return InvalidTransaction::Stale.into()
+		}
+		ValidTransaction::with_tag_prefix("VulntokenPin")
+			.and_provides(unique_id)
+			.longevity(PIN_RETRY_BLOCKS.into())
+			.propagate(false)
+			.build()
+	}
+
+	/// Send pin requests for queued collectibles and confirm the successful ones.
+	pub(crate) fn pin_queued_metadata(now: BlockNumberFor<T>) {
+		if sp_io::crypto::sr25519_public_keys(KEY_TYPE).is_empty() {
+			return
+		}
+		let Ok(Some(endpoint)) =
+			StorageValueRef::persistent(PINNING_ENDPOINT_KEY).get::<Vec<u8>>()
+		else {
+			return
+		};
+		let token = StorageValueRef::persistent(PINNING_TOKEN_KEY).get::<Vec<u8>>().ok().flatten();
+
+		let due = PinQueue::<T>::iter_keys()
+			.filter(|unique_id| Self::pin_attempt_due(*unique_id, now))
+			.take(MAX_PINS_PER_BLOCK)
+			.collect::<Vec<_>>();
+		for unique_id in due {
+			let Some(cid) = MetadataCids::<T>::get(&unique_id) else { continue };
+			if let Err(e) = Self::pin(&endpoint, token.as_deref(), &cid[..]) {
+				log::warn!(target: LOG_TARGET, "pinning collectible {unique_id} failed: {e:?}");
+				continue
+			}
+			let call = Call::confirm_pin { unique_id };
+			if SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into()).is_err() {
+				log::warn!(target: LOG_TARGET, "submitting pin of collectible {unique_id} failed");
+			}
+		}
+	}
+
+	/// Record an attempt to pin `unique_id` at `now`, unless one was made recently.
+	fn pin_attempt_due(unique_id: u64, now: BlockNumberFor<T>) -> bool {
+		let key = (PIN_ATTEMPT_PREFIX, unique_id).encode();
+		StorageValueRef::persistent(&key)
+			.mutate(|last: Result<Option<BlockNumberFor<T>>, _>| match last {
+				Ok(Some(last)) if now < last.saturating_add(PIN_RETRY_BLOCKS.into()) => Err(()),
+				_ => Ok(now),
+			})
+			.is_ok()
+	}
+
+	/// Ask the pinning service at `endpoint` to pin `cid`.
+	fn pin(endpoint: &[u8], token: Option<&[u8]>, cid: &[u8]) -> Result<(), http::Error> {
+		let url = core::str::from_utf8(endpoint).map_err(|_| http::Error::Unknown)?;
+		let auth = token.map(|token| [&b"Bearer "[..], token].concat());
+		let auth = auth
+			.as_deref()
+			.map(core::str::from_utf8)
+			.transpose()
+			.map_err(|_| http::Error::Unknown)?;
+		let body = [&br#"{"cid":""#[..], cid, &br#""}"#[..]].concat();
+
+		let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(PIN_TIMEOUT_MS));
+		let mut request = http::Request::post(url, vec![body])
+			.add_header("Content-Type", "application/json")
+			.deadline(deadline);
+		if let Some(auth) = auth {
+			request = request.add_header("Authorization", auth);
+		}
+		let response = request
+			.send()
+			.map_err(|_| http::Error::IoError)?
+			.try_wait(deadline)
+			.map_err(|_| http::Error::DeadlineReached)??;
+		match response.code {
+			200..=299 => Ok(()),
+			_ => Err(http::Error::Unknown),
+		}
+	}
+}
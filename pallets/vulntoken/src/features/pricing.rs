@@ -0,0 +1,98 @@
+//! Settlement of fixed-price sales. The runtime's `PricingPolicy` decides how the price is
+//! split; the pallet checks that the parts add up and moves the funds.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::{CheckedAdd, Saturating, Zero},
+	storage::{with_transaction, TransactionOutcome},
+	traits::{
+		fungible::{Balanced, Mutate},
+		tokens::{Fortitude, Precision, Preservation},
+		OnUnbalanced,
+	},
+};
+
+impl<T: Config> Pallet<T> {
+	/// Buy collectible `unique_id` for `buyer`, if it costs at most `max_price`, and roll the
+	/// purchase back. Returns what the purchase would pay whom, or why it would fail.
+	pub fn dry_run_buy(
+		buyer: T::AccountId,
+		unique_id: u64,
+		max_price: BalanceOf<T>,
+	) -> Result<SaleQuote<T::AccountId, BalanceOf<T>>, DispatchError> {
+		with_transaction(|| {
+			TransactionOutcome::Rollback(Self::quote_buy(buyer, unique_id, max_price))
+		})
+	}
+
+	fn quote_buy(
+		buyer: T::AccountId,
+		unique_id: u64,
+		max_price: BalanceOf<T>,
+	) -> Result<SaleQuote<T::AccountId, BalanceOf<T>>, DispatchError> {
+		let collectible = CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
+		let price = collectible.price.ok_or(Error::<T>::NotForSale)?;
+		ensure!(price <= max_price, Error::<T>::AboveMaxPrice);
+		let seller = collectible.owner;
+		let split = T::PricingPolicy::split(&seller, &buyer, &collectible.creator, price);
+		let royalty =
+			Self::royalty_cut(unique_id, &seller, &collectible.creator, price, split.seller);
+		let charity = Self::charity_cut(unique_id, price, split.seller.saturating_sub(royalty));
+		let origin = frame_system::RawOrigin::Signed(buyer.clone()).into();
+		Self::buy_collectible(origin, unique_id, max_price).map_err(|e| e.error)?;
+		Ok(SaleQuote { seller, price, split, royalty, charity })
+	}
+
+	/// Pay `price` from `buyer` as `PricingPolicy` splits it between `seller`, `creator`, the
+	/// `FeeSink` and a referrer, with the royalty of `unique_id` and then the charity share of its
+	/// listing taken out of the seller's part. Returns how many of the creator, referrer and
+	/// charity were paid, and the charity's cut.
+	pub(crate) fn pay_for_sale(
+		unique_id: u64,
+		seller: &T::AccountId,
+		buyer: &T::AccountId,
+		creator: &T::AccountId,
+		price: BalanceOf<T>,
+	) -> Result<(u32, Option<(T::AccountId, BalanceOf<T>)>), DispatchError> {
+		let split = T::PricingPolicy::split(seller, buyer, creator, price);
+		let referred = split.referrer.as_ref().map_or_else(Zero::zero, |(_, amount)| *amount);
+		let total = split
+			.seller
+			.checked_add(&split.creator)
+			.and_then(|total| total.checked_add(&split.fee))
+			.and_then(|total| total.checked_add(&referred));
+		ensure!(total == Some(price), Error::<T>::InvalidSplit);
+
+		let pay = |to: &T::AccountId, amount: BalanceOf<T>| -> Result<u32, DispatchError> {
+			if amount.is_zero() {
+				return Ok(0)
+			}
+			T::Currency::transfer(buyer, to, amount, Preservation::Preserve)?;
+			Ok(1)
+		};
+		let royalty = Self::royalty_cut(unique_id, seller, creator, price, split.seller);
+		let seller_share = split.seller.saturating_sub(royalty);
+		let charity = Self::charity_cut(unique_id, price, seller_share);
+		let donated = charity.as_ref().map_or_else(Zero::zero, |(_, amount)| *amount);
+		pay(seller, seller_share.saturating_sub(donated))?;
+		let mut paid = pay(creator, split.creator.saturating_add(royalty))?;
+		if let Some((referrer, amount)) = &split.referrer {
+			paid += pay(referrer, *amount)?;
+		}
+		if let Some((beneficiary, amount)) = &charity {
+			paid += pay(beneficiary, *amount)?;
+		}
+		if !split.fee.is_zero() {
+			let fee = T::Currency::withdraw(
+				buyer,
+				split.fee,
+				Precision::Exact,
+				Preservation::Preserve,
+				Fortitude::Polite,
+			)?;
+			T::FeeSink::on_unbalanced(fee);
+		}
+		Ok((paid, charity))
+	}
+}
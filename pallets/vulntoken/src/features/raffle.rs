@@ -0,0 +1,124 @@
+//! Raffles: an owner locks a collectible, sells numbered tickets and a random ticket wins it.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::{CheckedMul, Saturating, TrailingZeroInput, Zero},
+	traits::{
+		fungible::MutateHold,
+		tokens::Precision,
+		Randomness,
+	},
+};
+use frame_system::pallet_prelude::*;
+
+impl<T: Config> Pallet<T> {
+	pub(crate) fn do_start_raffle(
+		owner: T::AccountId,
+		unique_id: u64,
+		ticket_price: BalanceOf<T>,
+		min_tickets: u32,
+		max_tickets: u32,
+		duration: BlockNumberFor<T>,
+	) -> DispatchResult {
+		ensure!(
+			!duration.is_zero() &&
+				min_tickets > 0 && min_tickets <= max_tickets &&
+				max_tickets <= T::MaxRaffleTickets::get(),
+			Error::<T>::InvalidRaffle
+		);
+		let mut collectible =
+			CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
+		ensure!(collectible.owner == owner, Error::<T>::NotOwner);
+		Self::ensure_unlocked(unique_id)?;
+		Self::ensure_cooled_down(unique_id)?;
+
+		// A raffled item can no longer be bought at a fixed price.
+		if collectible.price.take().is_some() {
+			CollectibleMap::<T>::insert(&unique_id, collectible);
+			Self::note_unlisted(unique_id);
+			Self::release_listing_deposit(unique_id)?;
+		}
+
+		let end = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+		Raffles::<T>::insert(
+			&unique_id,
+			Raffle { owner: owner.clone(), ticket_price, min_tickets, max_tickets, sold: 0, end },
+		);
+		Self::do_freeze(unique_id, FreezeReason::Raffle);
+
+		Self::deposit_event(Event::RaffleStarted { collectible: unique_id, owner, ticket_price, end });
+		Ok(())
+	}
+
+	pub(crate) fn do_buy_tickets(buyer: T::AccountId, unique_id: u64, count: u32) -> DispatchResult {
+		let mut raffle = Raffles::<T>::get(&unique_id).ok_or(Error::<T>::NoRaffle)?;
+		ensure!(
+			frame_system::Pallet::<T>::block_number() < raffle.end,
+			Error::<T>::RaffleEnded
+		);
+		ensure!(raffle.owner != buyer, Error::<T>::RaffleOwnerCannotEnter);
+		let first_ticket = raffle.sold;
+		let sold = first_ticket.checked_add(count).ok_or(Error::<T>::SoldOut)?;
+		ensure!(count > 0 && sold <= raffle.max_tickets, Error::<T>::SoldOut);
+
+		let cost =
+			raffle.ticket_price.checked_mul(&count.into()).ok_or(Error::<T>::BoundsOverflow)?;
+		T::Currency::hold(&HoldReason::RaffleTicket.into(), &buyer, cost)?;
+
+		for ticket in first_ticket..sold {
+			RaffleTickets::<T>::insert(&unique_id, ticket, &buyer);
+		}
+		raffle.sold = sold;
+		Raffles::<T>::insert(&unique_id, raffle);
+
+		Self::deposit_event(Event::TicketsBought { collectible: unique_id, buyer, first_ticket, count });
+		Ok(())
+	}
+
+	pub(crate) fn do_draw_raffle(unique_id: u64) -> DispatchResultWithPostInfo {
+		let raffle = Raffles::<T>::get(&unique_id).ok_or(Error::<T>::NoRaffle)?;
+		ensure!(
+			frame_system::Pallet::<T>::block_number() >= raffle.end,
+			Error::<T>::RaffleNotFinished
+		);
+		Raffles::<T>::remove(&unique_id);
+		Self::do_thaw(unique_id, FreezeReason::Raffle);
+		let reason = HoldReason::RaffleTicket.into();
+
+		if raffle.sold < raffle.min_tickets {
+			for (_, holder) in RaffleTickets::<T>::drain_prefix(&unique_id) {
+				T::Currency::release(&reason, &holder, raffle.ticket_price, Precision::BestEffort)?;
+			}
+			Self::deposit_event(Event::RaffleCancelled {
+				collectible: unique_id,
+				tickets_sold: raffle.sold,
+			});
+			return Ok(Some(T::WeightInfo::draw_raffle(raffle.sold)).into())
+		}
+
+		let (seed, _) = T::Randomness::random(&(b"vulntoken/raffle", unique_id).encode());
+		let ticket = u32::decode(&mut TrailingZeroInput::new(seed.as_ref()))
+			.expect("input is padded with zeroes; qed") %
+			raffle.sold;
+		let winner = RaffleTickets::<T>::get(&unique_id, ticket).ok_or(Error::<T>::NoRaffle)?;
+
+		let mut proceeds = BalanceOf::<T>::zero();
+		for (_, holder) in RaffleTickets::<T>::drain_prefix(&unique_id) {
+			Self::pay_held_sale(
+				HoldReason::RaffleTicket,
+				unique_id,
+				&holder,
+				&raffle.owner,
+				raffle.ticket_price,
+			)?;
+			proceeds = proceeds.saturating_add(raffle.ticket_price);
+		}
+		Self::do_transfer(unique_id, winner.clone())?;
+		Self::start_cooldown(unique_id);
+		Self::note_sale(unique_id, &raffle.owner, &winner, proceeds);
+
+		Self::deposit_event(Event::RaffleWon { collectible: unique_id, winner, ticket, proceeds });
+		Ok(Some(T::WeightInfo::draw_raffle(raffle.sold)).into())
+	}
+}
@@ -0,0 +1,52 @@
+//! Receipts for sales. A creator can have every sale of the collectibles they minted leave the
+//! buyer a receipt: a collectible of its own recording what was sold, by whom, for how much and
+//! when. The owner of a collection can do the same for the collectibles in it, whoever minted
+//! them.
+//!
+//! Receipts are soulbound: they cannot be transferred, listed or sold, only burned by their
+//! holder. A sale never fails for want of a receipt; if one cannot be minted, for instance
+//! because the buyer holds `MaximumOwned` collectibles, the sale goes ahead without it.
+
+use crate::*;
+use frame_support::storage::with_storage_layer;
+
+impl<T: Config> Pallet<T> {
+	/// The sale a receipt records, if `unique_id` is a receipt.
+	pub fn sale_receipt(unique_id: u64) -> Option<SaleReceipt<T>> {
+		SaleReceipts::<T>::get(&unique_id)
+	}
+
+	/// Mint `buyer` a receipt for buying the collectible from `seller` at `price`, if its creator
+	/// or its collection asked for receipts. Returns whether a receipt was minted.
+	pub(crate) fn issue_receipt(
+		unique_id: u64,
+		seller: &T::AccountId,
+		buyer: &T::AccountId,
+		price: BalanceOf<T>,
+	) -> bool {
+		let Some(collectible) = CollectibleMap::<T>::get(&unique_id) else { return false };
+		let wanted = IssuesReceipts::<T>::contains_key(&collectible.creator) ||
+			Self::collection_settings_of(unique_id)
+				.is_some_and(|settings| settings.issues_receipts);
+		if !wanted {
+			return false
+		}
+		let (receipt, color) = Self::gen_unique_id();
+		// A refused mint must not keep whatever the mint policy did before refusing.
+		if with_storage_layer(|| Self::mint(seller, buyer, receipt, color)).is_err() {
+			return false
+		}
+		Self::do_freeze(receipt, FreezeReason::Soulbound);
+		let sold_at = frame_system::Pallet::<T>::block_number();
+		SaleReceipts::<T>::insert(
+			&receipt,
+			SaleReceipt { collectible: unique_id, seller: seller.clone(), price, sold_at },
+		);
+		Self::deposit_event(Event::ReceiptIssued {
+			receipt,
+			collectible: unique_id,
+			buyer: buyer.clone(),
+		});
+		true
+	}
+}
@@ -0,0 +1,120 @@
+//! Owner remapping, for key rotation and compromise recovery. `ForceOrigin` moves what an
+//! account holds in the marketplace to a new account: its collectibles with their listings and
+//! the listing, mint and metadata deposits it paid for them, its sell orders, and its buy orders
+//! with the funds held for them.
+//!
+//! An account can hold up to `MaximumOwned` collectibles, so they move in batches of at most
+//! `limit` per call, and the call is repeated until none are left. Collectibles frozen for
+//! anything but a sell order, such as those in escrow or at auction, stay behind until the
+//! freeze lifts. Other records of the old account, like an heir it named, are left alone.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::Saturating,
+	traits::{
+		fungible::MutateHold,
+		tokens::{Fortitude, Precision, Restriction},
+	},
+};
+use sp_std::vec::Vec;
+
+impl<T: Config> Pallet<T> {
+	/// Move up to `limit` collectibles of `old` to `new`, and all of their orders. Returns how
+	/// many collectibles were moved.
+	pub(crate) fn do_force_remap_owner(
+		old: T::AccountId,
+		new: T::AccountId,
+		limit: u32,
+	) -> Result<u32, DispatchError> {
+		ensure!(old != new, Error::<T>::TransferToSelf);
+		Self::remap_buy_orders(&old, &new)?;
+
+		let mut old_owned = OwnerOfCollectibles::<T>::get(&old);
+		let mut new_owned = OwnerOfCollectibles::<T>::get(&new);
+		let mut sells = SellBook::<T>::get();
+		let movable: Vec<u64> = old_owned
+			.iter()
+			.copied()
+			.filter(|unique_id| {
+				Frozen::<T>::iter_key_prefix(unique_id)
+					.all(|reason| reason == FreezeReason::SellOrder)
+			})
+			.take(limit as usize)
+			.collect();
+		for &unique_id in &movable {
+			new_owned.try_push(unique_id).map_err(|_| Error::<T>::MaximumCollectiblesOwned)?;
+			CollectibleMap::<T>::mutate(&unique_id, |collectible| {
+				if let Some(collectible) = collectible {
+					collectible.owner = new.clone();
+				}
+			});
+			if let Some(order) = sells.iter_mut().find(|order| order.collectible == unique_id) {
+				order.seller = new.clone();
+			}
+			if let Some((depositor, deposit)) = ListingDeposits::<T>::get(&unique_id) {
+				if depositor == old {
+					Self::move_hold(HoldReason::ListingDeposit, &old, &new, deposit)?;
+					ListingDeposits::<T>::insert(&unique_id, (new.clone(), deposit));
+				}
+			}
+			if let Some((depositor, deposit)) = MintDeposits::<T>::get(&unique_id) {
+				if depositor == old {
+					Self::move_hold(HoldReason::MintDeposit, &old, &new, deposit)?;
+					MintDeposits::<T>::insert(&unique_id, (new.clone(), deposit));
+				}
+			}
+			if let Some((depositor, deposit)) = MetadataDeposits::<T>::get(&unique_id) {
+				if depositor == old {
+					Self::move_hold(HoldReason::MetadataDeposit, &old, &new, deposit)?;
+					MetadataDeposits::<T>::insert(&unique_id, (new.clone(), deposit));
+				}
+			}
+			Self::deposit_event(Event::TransferSucceeded {
+				from: old.clone(),
+				to: new.clone(),
+				collectible: unique_id,
+			});
+		}
+		old_owned.retain(|unique_id| !movable.contains(unique_id));
+		let remaining = old_owned.len() as u32;
+		OwnerOfCollectibles::<T>::insert(&old, old_owned);
+		OwnerOfCollectibles::<T>::insert(&new, new_owned);
+		SellBook::<T>::put(sells);
+
+		let moved = movable.len() as u32;
+		Self::deposit_event(Event::OwnerRemapped { old, new, collectibles: moved, remaining });
+		Ok(moved)
+	}
+
+	/// Hand the buy orders of `old` to `new`, with the funds held for them.
+	fn remap_buy_orders(old: &T::AccountId, new: &T::AccountId) -> DispatchResult {
+		let mut buys = BuyBook::<T>::get();
+		for order in buys.iter_mut().filter(|order| order.bidder == *old) {
+			let held = order.price.saturating_mul(order.quantity.into());
+			Self::move_hold(HoldReason::BuyOrder, old, new, held)?;
+			order.bidder = new.clone();
+		}
+		BuyBook::<T>::put(buys);
+		Ok(())
+	}
+
+	/// Move `amount` held from `from` for `reason` to `to`, where it stays held.
+	fn move_hold(
+		reason: HoldReason,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		T::Currency::transfer_on_hold(
+			&reason.into(),
+			from,
+			to,
+			amount,
+			Precision::Exact,
+			Restriction::OnHold,
+			Fortitude::Polite,
+		)?;
+		Ok(())
+	}
+}
@@ -0,0 +1,127 @@
+//! Rentals paid per block out of the renter's prepayment, through a payment stream from the
+//! renter to the owner. Rent accrues lazily and only moves to the owner when somebody touches
+//! the rental, so either party can end it at any block and the owner is paid for exactly the
+//! blocks it ran.
+
+use crate::*;
+use frame_support::{pallet_prelude::*, sp_runtime::traits::Zero};
+use pallet_payment_streams::PaymentStreams;
+
+impl<T: Config> Pallet<T> {
+	pub(crate) fn do_offer_rental(
+		owner: T::AccountId,
+		unique_id: u64,
+		rate: Option<BalanceOf<T>>,
+	) -> DispatchResult {
+		let collectible = CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
+		ensure!(collectible.owner == owner, Error::<T>::NotOwner);
+		match rate {
+			Some(rate) => {
+				ensure!(!rate.is_zero(), Error::<T>::InvalidRentalRate);
+				RentalRates::<T>::insert(&unique_id, rate);
+			},
+			None => RentalRates::<T>::remove(&unique_id),
+		}
+		Self::deposit_event(Event::RentalOffered { collectible: unique_id, rate });
+		Ok(())
+	}
+
+	pub(crate) fn do_rent(
+		renter: T::AccountId,
+		unique_id: u64,
+		prepaid: BalanceOf<T>,
+	) -> DispatchResult {
+		let collectible = CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
+		let owner = collectible.owner;
+		ensure!(owner != renter, Error::<T>::OwnerCannotRent);
+		let rate = RentalRates::<T>::get(&unique_id).ok_or(Error::<T>::NotForRent)?;
+		Self::ensure_unlocked(unique_id)?;
+		ensure!(prepaid >= rate, Error::<T>::InsufficientPrepayment);
+
+		let stream = T::Streams::open(&renter, &owner, rate, prepaid)?;
+		Rentals::<T>::insert(
+			&unique_id,
+			Rental { owner: owner.clone(), renter: renter.clone(), rate, stream },
+		);
+		Self::do_freeze(unique_id, FreezeReason::Rented);
+
+		Self::deposit_event(Event::Rented { collectible: unique_id, owner, renter, rate, prepaid });
+		Ok(())
+	}
+
+	pub(crate) fn do_top_up_rental(
+		renter: T::AccountId,
+		unique_id: u64,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		let rental = Rentals::<T>::get(&unique_id).ok_or(Error::<T>::NotRented)?;
+		ensure!(rental.renter == renter, Error::<T>::NotRentalParty);
+		// A rental that ran out ended when it did; topping it up would hand the renter the
+		// blocks since for free.
+		ensure!(Self::is_running(&rental), Error::<T>::RentalExpired);
+
+		T::Streams::top_up(rental.stream, amount)?;
+		Self::deposit_event(Event::RentalToppedUp { collectible: unique_id, amount });
+		Ok(())
+	}
+
+	pub(crate) fn do_settle_rental(unique_id: u64) -> DispatchResult {
+		let rental = Rentals::<T>::get(&unique_id).ok_or(Error::<T>::NotRented)?;
+		// Either party may also have closed the stream directly through the streams pallet.
+		let paid = match T::Streams::remaining(rental.stream) {
+			Some(_) => T::Streams::withdraw(rental.stream)?,
+			None => Zero::zero(),
+		};
+		Self::rent_paid(unique_id, &rental, paid);
+		// The stream closes itself once the prepayment is used up.
+		if T::Streams::remaining(rental.stream).is_none() {
+			Self::close_rental(unique_id, rental, Zero::zero());
+		}
+		Ok(())
+	}
+
+	pub(crate) fn do_end_rental(who: T::AccountId, unique_id: u64) -> DispatchResult {
+		let rental = Rentals::<T>::get(&unique_id).ok_or(Error::<T>::NotRented)?;
+		ensure!(who == rental.owner || who == rental.renter, Error::<T>::NotRentalParty);
+		let (paid, refunded) = match T::Streams::remaining(rental.stream) {
+			Some(_) => T::Streams::close(rental.stream)?,
+			None => (Zero::zero(), Zero::zero()),
+		};
+		Self::rent_paid(unique_id, &rental, paid);
+		Self::close_rental(unique_id, rental, refunded);
+		Ok(())
+	}
+
+	/// The account currently renting the collectible, if its prepayment has not run out.
+	pub fn renter_of(unique_id: u64) -> Option<T::AccountId> {
+		let rental = Rentals::<T>::get(&unique_id)?;
+		Self::is_running(&rental).then_some(rental.renter)
+	}
+
+	/// Whether some of the rental's prepayment is left once the rent due is paid.
+	fn is_running(rental: &Rental<T>) -> bool {
+		T::Streams::remaining(rental.stream).map_or(false, |left| !left.is_zero())
+	}
+
+	/// Record rent paid to the owner, if there was any.
+	fn rent_paid(unique_id: u64, rental: &Rental<T>, amount: BalanceOf<T>) {
+		if !amount.is_zero() {
+			Self::deposit_event(Event::RentPaid {
+				collectible: unique_id,
+				owner: rental.owner.clone(),
+				amount,
+			});
+		}
+	}
+
+	/// Forget the rental and unlock the collectible once its stream is closed.
+	fn close_rental(unique_id: u64, rental: Rental<T>, refunded: BalanceOf<T>) {
+		Rentals::<T>::remove(&unique_id);
+		Self::do_thaw(unique_id, FreezeReason::Rented);
+		Self::deposit_event(Event::RentalEnded {
+			collectible: unique_id,
+			renter: rental.renter,
+			refunded,
+		});
+	}
+}
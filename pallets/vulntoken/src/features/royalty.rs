@@ -0,0 +1,117 @@
+//! On-chain royalties. A creator sets a default royalty for everything they minted and can
+//! override it item by item. The owner of a collection can set a royalty for the collectibles in
+//! it too. The item's own royalty takes precedence over its collection's, and that over the
+//! creator's default.
+//!
+//! Every sale pays the effective royalty to the creator out of the seller's part of the price,
+//! on top of whatever share the runtime's `PricingPolicy` gives the creator. Sales settled from a
+//! hold, through escrow, installments or the order book, pay it from the hold. Creators selling
+//! their own collectibles pay themselves nothing.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::{
+		traits::{Saturating, Zero},
+		Perbill,
+	},
+	traits::{
+		fungible::MutateHold,
+		tokens::{Fortitude, Precision, Restriction},
+	},
+};
+
+impl<T: Config> Pallet<T> {
+	/// The royalty a sale of `unique_id` pays its creator: its own if it has one, else its
+	/// collection's, else its creator's default. `None` if the collectible does not exist.
+	pub fn effective_royalty(unique_id: u64) -> Option<Perbill> {
+		let collectible = CollectibleMap::<T>::get(&unique_id)?;
+		let royalty = ItemRoyalties::<T>::get(&unique_id)
+			.or_else(|| Self::collection_settings_of(unique_id)?.royalty)
+			.or_else(|| DefaultRoyalties::<T>::get(&collectible.creator))
+			.unwrap_or_default();
+		Some(royalty)
+	}
+
+	pub(crate) fn do_set_default_royalty(
+		creator: T::AccountId,
+		royalty: Option<Perbill>,
+	) -> DispatchResult {
+		match royalty {
+			Some(royalty) => {
+				ensure!(royalty <= T::MaxRoyalty::get(), Error::<T>::RoyaltyTooHigh);
+				DefaultRoyalties::<T>::insert(&creator, royalty);
+			},
+			None => DefaultRoyalties::<T>::remove(&creator),
+		}
+		Self::deposit_event(Event::DefaultRoyaltySet { creator, royalty });
+		Ok(())
+	}
+
+	pub(crate) fn do_set_item_royalty(
+		creator: T::AccountId,
+		unique_id: u64,
+		royalty: Option<Perbill>,
+	) -> DispatchResult {
+		let collectible = CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
+		ensure!(collectible.creator == creator, Error::<T>::NotCreator);
+		match royalty {
+			Some(royalty) => {
+				ensure!(royalty <= T::MaxRoyalty::get(), Error::<T>::RoyaltyTooHigh);
+				ItemRoyalties::<T>::insert(&unique_id, royalty);
+			},
+			None => ItemRoyalties::<T>::remove(&unique_id),
+		}
+		Self::deposit_event(Event::ItemRoyaltySet { collectible: unique_id, royalty });
+		Ok(())
+	}
+
+	/// What a sale of `unique_id` by `seller` at `price` pays its `creator` in royalty, which is
+	/// at most `seller_share`, the part of the price the seller would be paid.
+	pub(crate) fn royalty_cut(
+		unique_id: u64,
+		seller: &T::AccountId,
+		creator: &T::AccountId,
+		price: BalanceOf<T>,
+		seller_share: BalanceOf<T>,
+	) -> BalanceOf<T> {
+		if seller == creator {
+			return Zero::zero()
+		}
+		let royalty = Self::effective_royalty(unique_id).unwrap_or_default();
+		royalty.mul_floor(price).min(seller_share)
+	}
+
+	/// Pay `price`, held from `buyer` for `reason`, to `seller` for a sale of `unique_id`, with
+	/// the royalty going to the creator. Must be called before the collectible changes hands.
+	pub(crate) fn pay_held_sale(
+		reason: HoldReason,
+		unique_id: u64,
+		buyer: &T::AccountId,
+		seller: &T::AccountId,
+		price: BalanceOf<T>,
+	) -> DispatchResult {
+		let creator = CollectibleMap::<T>::get(&unique_id)
+			.map(|collectible| collectible.creator)
+			.ok_or(Error::<T>::NoCollectible)?;
+		let royalty = Self::royalty_cut(unique_id, seller, &creator, price, price);
+		let reason = reason.into();
+		let pay = |to: &T::AccountId, amount: BalanceOf<T>| -> DispatchResult {
+			if amount.is_zero() {
+				return Ok(())
+			}
+			T::Currency::transfer_on_hold(
+				&reason,
+				buyer,
+				to,
+				amount,
+				Precision::Exact,
+				Restriction::Free,
+				Fortitude::Polite,
+			)?;
+			Ok(())
+		};
+		pay(&creator, royalty)?;
+		pay(seller, price.saturating_sub(royalty))
+	}
+}
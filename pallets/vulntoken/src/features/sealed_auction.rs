@@ -0,0 +1,238 @@
+//! Sealed-bid auctions with a commit phase followed by a reveal phase.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::{Hash, Saturating, Zero},
+	traits::{
+		fungible::{BalancedHold, MutateHold},
+		tokens::Precision,
+	},
+};
+use frame_system::pallet_prelude::*;
+
+impl<T: Config> Pallet<T> {
+	/// The commitment a bidder submits for `amount` with a secret `salt`.
+	pub fn sealed_bid_commitment(
+		bidder: &T::AccountId,
+		amount: BalanceOf<T>,
+		salt: &[u8; 32],
+	) -> T::Hash {
+		T::Hashing::hash_of(&(bidder, amount, salt))
+	}
+
+	pub(crate) fn do_start_sealed_auction(
+		seller: T::AccountId,
+		unique_id: u64,
+		min_bid: BalanceOf<T>,
+		commit_period: BlockNumberFor<T>,
+		reveal_period: BlockNumberFor<T>,
+	) -> DispatchResult {
+		ensure!(
+			!commit_period.is_zero() && !reveal_period.is_zero(),
+			Error::<T>::InvalidAuctionPeriod
+		);
+		let mut collectible =
+			CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
+		ensure!(collectible.owner == seller, Error::<T>::NotOwner);
+		Self::ensure_unlocked(unique_id)?;
+		Self::ensure_cooled_down(unique_id)?;
+
+		// An item under auction can no longer be bought at a fixed price.
+		if collectible.price.take().is_some() {
+			CollectibleMap::<T>::insert(&unique_id, collectible);
+			Self::note_unlisted(unique_id);
+			Self::release_listing_deposit(unique_id)?;
+		}
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let commit_end = now.saturating_add(commit_period);
+		let reveal_end = commit_end.saturating_add(reveal_period);
+		SealedAuctionsEnding::<T>::try_append(reveal_end, unique_id)
+			.map_err(|_| Error::<T>::TooManyDeadlines)?;
+		SealedAuctions::<T>::insert(
+			&unique_id,
+			SealedAuction {
+				seller: seller.clone(),
+				min_bid,
+				commit_end,
+				reveal_end,
+				bids: 0,
+				leader: None,
+			},
+		);
+		Self::do_freeze(unique_id, FreezeReason::Auction);
+
+		Self::deposit_event(Event::SealedAuctionStarted {
+			collectible: unique_id,
+			seller,
+			commit_end,
+			reveal_end,
+		});
+		Ok(())
+	}
+
+	pub(crate) fn do_commit_bid(
+		bidder: T::AccountId,
+		unique_id: u64,
+		commitment: T::Hash,
+	) -> DispatchResult {
+		let mut auction = SealedAuctions::<T>::get(&unique_id).ok_or(Error::<T>::NoAuction)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(now < auction.commit_end, Error::<T>::NotCommitPhase);
+		ensure!(auction.seller != bidder, Error::<T>::SellerCannotBid);
+		ensure!(
+			!SealedBids::<T>::contains_key(&unique_id, &bidder),
+			Error::<T>::AlreadyCommitted
+		);
+		ensure!(auction.bids < T::MaxSealedBids::get(), Error::<T>::TooManyBids);
+
+		let deposit = T::SealedBidDeposit::get();
+		T::Currency::hold(&HoldReason::Bid.into(), &bidder, deposit)?;
+
+		auction.bids += 1;
+		SealedAuctions::<T>::insert(&unique_id, auction);
+		SealedBids::<T>::insert(
+			&unique_id,
+			&bidder,
+			SealedBid { commitment, deposit, revealed: false },
+		);
+
+		Self::deposit_event(Event::BidCommitted { collectible: unique_id, bidder });
+		Ok(())
+	}
+
+	pub(crate) fn do_reveal_bid(
+		bidder: T::AccountId,
+		unique_id: u64,
+		amount: BalanceOf<T>,
+		salt: [u8; 32],
+	) -> DispatchResult {
+		let mut auction = SealedAuctions::<T>::get(&unique_id).ok_or(Error::<T>::NoAuction)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(
+			now >= auction.commit_end && now < auction.reveal_end,
+			Error::<T>::NotRevealPhase
+		);
+		let mut bid = SealedBids::<T>::get(&unique_id, &bidder).ok_or(Error::<T>::NoCommitment)?;
+		ensure!(!bid.revealed, Error::<T>::AlreadyRevealed);
+		ensure!(
+			Self::sealed_bid_commitment(&bidder, amount, &salt) == bid.commitment,
+			Error::<T>::InvalidReveal
+		);
+		bid.revealed = true;
+
+		let beats_leader = match &auction.leader {
+			Some((_, best)) => amount > *best,
+			None => true,
+		};
+		if amount >= auction.min_bid && beats_leader {
+			// Checked here rather than at settlement, so a finished auction can always settle.
+			Self::ensure_identity(&auction.seller, &bidder, amount)?;
+			Self::ensure_within_limits(&auction.seller, &bidder, amount)?;
+			// Lock the new best bid so the winner is guaranteed to be able to pay.
+			T::Currency::hold(&HoldReason::Bid.into(), &bidder, amount)?;
+			if let Some((previous, best)) = auction.leader.take() {
+				T::Currency::release(&HoldReason::Bid.into(), &previous, best, Precision::BestEffort)?;
+			}
+			auction.leader = Some((bidder.clone(), amount));
+			SealedAuctions::<T>::insert(&unique_id, auction);
+		}
+		SealedBids::<T>::insert(&unique_id, &bidder, bid);
+
+		Self::deposit_event(Event::BidRevealed { collectible: unique_id, bidder, amount });
+		Ok(())
+	}
+
+	/// End an auction that was never settled within `EscrowTimeout` blocks of its reveal phase,
+	/// for `who`, one of its bidders. Every bid is returned as if the auction had no winner, and
+	/// the item stays with the seller.
+	pub(crate) fn reclaim_sealed_bids(who: T::AccountId, unique_id: u64) -> DispatchResult {
+		let auction = SealedAuctions::<T>::get(&unique_id).ok_or(Error::<T>::NoAuction)?;
+		ensure!(SealedBids::<T>::contains_key(&unique_id, &who), Error::<T>::NoCommitment);
+		ensure!(
+			frame_system::Pallet::<T>::block_number() >=
+				auction.reveal_end.saturating_add(T::EscrowTimeout::get()),
+			Error::<T>::EscrowNotExpired
+		);
+		SealedAuctions::<T>::remove(&unique_id);
+		UnsettledAuctions::<T>::remove(&unique_id);
+		Self::do_thaw(unique_id, FreezeReason::Auction);
+
+		let reason = HoldReason::Bid.into();
+		for (bidder, bid) in SealedBids::<T>::drain_prefix(&unique_id) {
+			if !bid.revealed {
+				let _ = T::Currency::slash(&reason, &bidder, bid.deposit);
+				Self::deposit_event(Event::CommitmentSlashed {
+					collectible: unique_id,
+					bidder,
+					amount: bid.deposit,
+				});
+				continue
+			}
+			let mut amount = bid.deposit;
+			match &auction.leader {
+				Some((leader, best)) if *leader == bidder => amount.saturating_accrue(*best),
+				_ => {},
+			}
+			T::Currency::release(&reason, &bidder, amount, Precision::BestEffort)?;
+			Self::deposit_event(Event::EscrowReclaimed {
+				collectible: unique_id,
+				depositor: bidder,
+				amount,
+			});
+		}
+		Ok(())
+	}
+
+	pub(crate) fn do_settle_sealed_auction(unique_id: u64) -> DispatchResultWithPostInfo {
+		let auction = SealedAuctions::<T>::get(&unique_id).ok_or(Error::<T>::NoAuction)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(now >= auction.reveal_end, Error::<T>::AuctionNotFinished);
+		SealedAuctions::<T>::remove(&unique_id);
+		UnsettledAuctions::<T>::remove(&unique_id);
+		Self::do_thaw(unique_id, FreezeReason::Auction);
+
+		// Revealed bidders get their deposit back, bidders who never revealed lose it.
+		let reason = HoldReason::Bid.into();
+		let mut bids = 0;
+		for (bidder, bid) in SealedBids::<T>::drain_prefix(&unique_id) {
+			bids += 1;
+			if bid.revealed {
+				T::Currency::release(&reason, &bidder, bid.deposit, Precision::BestEffort)?;
+			} else {
+				let _ = T::Currency::slash(&reason, &bidder, bid.deposit);
+				Self::deposit_event(Event::CommitmentSlashed {
+					collectible: unique_id,
+					bidder,
+					amount: bid.deposit,
+				});
+			}
+		}
+
+		let (winner, price) = match auction.leader {
+			Some((winner, amount)) => {
+				Self::pay_held_sale(HoldReason::Bid, unique_id, &winner, &auction.seller, amount)?;
+				Self::do_transfer(unique_id, winner.clone())?;
+				Self::start_cooldown(unique_id);
+				Self::note_sale(unique_id, &auction.seller, &winner, amount);
+				(Some(winner), Some(amount))
+			},
+			None => (None, None),
+		};
+
+		Self::deposit_event(Event::SealedAuctionSettled { collectible: unique_id, winner, price });
+		Ok(Some(T::WeightInfo::settle_sealed_auction(bids)).into())
+	}
+
+	/// Queue the auctions whose reveal phase ends at `now` for the offchain worker to settle,
+	/// and return the weight used.
+	pub(crate) fn queue_ended_sealed_auctions(now: BlockNumberFor<T>) -> Weight {
+		let ended = SealedAuctionsEnding::<T>::take(now);
+		let queued = ended.len() as u64;
+		for unique_id in ended {
+			UnsettledAuctions::<T>::insert(unique_id, ());
+		}
+		T::DbWeight::get().reads_writes(1, queued.saturating_add(1))
+	}
+}
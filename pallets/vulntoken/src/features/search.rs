@@ -0,0 +1,130 @@
+//! Marketplace search, served through the runtime API so frontends can filter collectibles by
+//! color and price without running an indexer of their own.
+//!
+//! Results come in ascending id order. Searches for listings priced within a range the floor
+//! indices cover completely read the matching index, and the collectibles flagged for wash
+//! trading that indices leave out, instead of every collectible.
+
+use crate::*;
+use frame_support::traits::SortedMembers;
+use sp_std::{vec, vec::Vec};
+
+/// Largest page [`Pallet::find_collectibles`] returns, whatever the caller asks for.
+pub const MAX_SEARCH_PAGE: u32 = 1_000;
+
+impl<T: Config> Pallet<T> {
+	/// Ids, colors and prices of the collectibles matching `filter`, after `start_after`. Pass
+	/// the last id of the previous page as `start_after` to fetch the next one.
+	pub fn find_collectibles(
+		filter: CollectibleFilter<BalanceOf<T>>,
+		start_after: Option<u64>,
+		limit: u32,
+	) -> Vec<(u64, Color, Option<BalanceOf<T>>)> {
+		let limit = limit.min(MAX_SEARCH_PAGE) as usize;
+		let first = start_after.map_or(0, |id| id.saturating_add(1));
+		let creators = filter.verified_creators_only.then(T::VerifiedCreators::sorted_members);
+		let matches = |collectible: &Collectible<T>| {
+			filter.matches(collectible.color, collectible.price) &&
+				creators.as_ref().map_or(true, |creators| {
+					creators.binary_search(&collectible.creator).is_ok()
+				})
+		};
+		if let Some(mut ids) = Self::indexed_listings(&filter) {
+			ids.sort_unstable();
+			return ids
+				.into_iter()
+				.filter(|id| *id >= first)
+				.filter_map(|id| CollectibleMap::<T>::get(&id))
+				.filter(|collectible| matches(collectible))
+				.map(|collectible| (collectible.unique_id, collectible.color, collectible.price))
+				.take(limit)
+				.collect()
+		}
+
+		// Burned collectibles leave gaps in the mint sequence.
+		let mut found = (0..NextCollectibleId::<T>::get())
+			.map(Self::item_id_at)
+			.filter(|id| *id >= first)
+			.filter_map(|id| CollectibleMap::<T>::get(&id))
+			.filter(|collectible| matches(collectible))
+			.map(|collectible| (collectible.unique_id, collectible.color, collectible.price));
+		if T::ItemIdGenerator::ASCENDING {
+			return found.by_ref().take(limit).collect()
+		}
+		let mut found: Vec<_> = found.collect();
+		found.sort_unstable_by_key(|(id, _, _)| *id);
+		found.truncate(limit);
+		found
+	}
+
+	/// Collectible `unique_id`, if it exists.
+	pub fn collectible(unique_id: u64) -> Option<CollectibleView<T::AccountId, BalanceOf<T>>> {
+		let collectible = CollectibleMap::<T>::get(&unique_id)?;
+		Some(CollectibleView {
+			unique_id,
+			metadata_version: Self::metadata_version_of(&collectible),
+			owner: collectible.owner,
+			color: collectible.color,
+			price: collectible.price,
+			license: Self::license(unique_id),
+			collection_id: collectible.collection_id,
+			creator: collectible.creator,
+		})
+	}
+
+	/// Ids of the collectibles `owner` holds.
+	pub fn collectibles_of(owner: &T::AccountId) -> Vec<u64> {
+		OwnerOfCollectibles::<T>::get(owner).into_inner()
+	}
+
+	/// The storage keys whose values prove who owns collectible `unique_id`, or that it does not
+	/// exist. A runtime cannot read trie nodes, so the node builds the proof from these keys.
+	pub fn collectible_proof_keys(unique_id: u64) -> Vec<Vec<u8>> {
+		vec![CollectibleMap::<T>::hashed_key_for(unique_id)]
+	}
+
+	/// Whether `who` is one of the `VerifiedCreators`.
+	pub fn is_verified_creator(who: &T::AccountId) -> bool {
+		T::VerifiedCreators::contains(who)
+	}
+
+	/// Ids of every listing `filter` can match, if the floor index of its color, or the
+	/// pallet-wide one, is known to hold them all.
+	fn indexed_listings(filter: &CollectibleFilter<BalanceOf<T>>) -> Option<Vec<u64>> {
+		if !filter.for_sale_only {
+			return None
+		}
+		let index = match filter.color {
+			Some(color) => ColorFloorIndex::<T>::get(color),
+			None => FloorIndex::<T>::get(),
+		};
+		if index.rebuild_from.is_some() {
+			return None
+		}
+		// Listings left out of the index cost at least as much as its last entry.
+		let covered = !index.truncated ||
+			matches!(
+				(filter.max_price, index.cheapest.last()),
+				(Some(max), Some((last, _))) if max < *last
+			);
+		// Listings flagged for wash trading are left out of every index.
+		covered.then(|| {
+			let indexed = index.cheapest.iter().map(|(_, id)| *id);
+			indexed.chain(WashTraded::<T>::iter_keys()).collect()
+		})
+	}
+}
+
+impl<Balance: PartialOrd> CollectibleFilter<Balance> {
+	/// Whether a collectible of `color` listed at `price`, if at all, passes the filter.
+	/// Price bounds only pass listed collectibles.
+	pub fn matches(&self, color: Color, price: Option<Balance>) -> bool {
+		if self.color.map_or(false, |wanted| wanted != color) {
+			return false
+		}
+		let bounded = self.min_price.is_some() || self.max_price.is_some();
+		let Some(price) = price else { return !self.for_sale_only && !bounded };
+		self.min_price.as_ref().map_or(true, |min| price >= *min) &&
+			self.max_price.as_ref().map_or(true, |max| price <= *max)
+	}
+}
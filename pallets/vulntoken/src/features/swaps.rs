@@ -0,0 +1,172 @@
+//! Swaps, for two owners exchanging collectibles without trusting each other or a middleman. The
+//! creator of a swap offers one of their collectibles for a specific other one, optionally
+//! adding a sweetener held from them until the swap ends. Whoever owns the desired collectible
+//! before the deadline can claim the swap, which exchanges both collectibles and pays them the
+//! sweetener in one go.
+//!
+//! The offered collectible is frozen while the swap is pending; the desired one is not, and a
+//! swap whose desired collectible moved on can only be claimed by its new owner. Swaps left
+//! unclaimed at their deadline are expired from `on_idle`, or can be cancelled by the creator
+//! at any time before.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::{Saturating, Zero},
+	storage::with_storage_layer,
+	traits::{
+		fungible::MutateHold,
+		tokens::{Fortitude, Precision, Restriction},
+	},
+	weights::WeightMeter,
+};
+
+impl<T: Config> Pallet<T> {
+	/// The pending swap offering `offered`, if any.
+	pub fn swap(offered: u64) -> Option<Swap<T>> {
+		PendingSwaps::<T>::get(&offered)
+	}
+
+	pub(crate) fn do_create_swap(
+		creator: T::AccountId,
+		offered: u64,
+		desired: u64,
+		sweetener: Option<BalanceOf<T>>,
+		deadline: BlockNumberFor<T>,
+	) -> DispatchResult {
+		let mut collectible = CollectibleMap::<T>::get(&offered).ok_or(Error::<T>::NoCollectible)?;
+		ensure!(collectible.owner == creator, Error::<T>::NotOwner);
+		let wanted = CollectibleMap::<T>::get(&desired).ok_or(Error::<T>::NoCollectible)?;
+		ensure!(wanted.owner != creator, Error::<T>::TransferToSelf);
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(deadline > now, Error::<T>::InvalidSwapDeadline);
+		Self::ensure_unlocked(offered)?;
+		SwapDeadlines::<T>::try_append(deadline, offered)
+			.map_err(|_| Error::<T>::TooManyDeadlines)?;
+		SwapCursor::<T>::mutate(|cursor| {
+			cursor.get_or_insert(now);
+		});
+
+		let sweetener = sweetener.filter(|amount| !amount.is_zero());
+		if let Some(amount) = sweetener {
+			T::Currency::hold(&HoldReason::Swap.into(), &creator, amount)?;
+		}
+		if collectible.price.take().is_some() {
+			Self::note_unlisted(offered);
+		}
+		CollectibleMap::<T>::insert(&offered, collectible);
+		ListedAt::<T>::remove(&offered);
+		Self::release_listing_deposit(offered)?;
+		Self::do_freeze(offered, FreezeReason::Swap);
+		let swap = Swap { creator: creator.clone(), desired, sweetener, deadline };
+		PendingSwaps::<T>::insert(&offered, swap);
+
+		Self::deposit_event(Event::SwapCreated { offered, desired, creator, sweetener, deadline });
+		Ok(())
+	}
+
+	pub(crate) fn do_claim_swap(claimer: T::AccountId, offered: u64) -> DispatchResult {
+		let swap = PendingSwaps::<T>::take(&offered).ok_or(Error::<T>::NoSwap)?;
+		ensure!(
+			frame_system::Pallet::<T>::block_number() < swap.deadline,
+			Error::<T>::SwapExpired
+		);
+		let wanted = CollectibleMap::<T>::get(&swap.desired).ok_or(Error::<T>::NoCollectible)?;
+		ensure!(wanted.owner == claimer, Error::<T>::NotOwner);
+		// A swap is a pair of transfers, so royalty enforcement applies to both.
+		Self::ensure_transfer_allowed(offered, &swap.creator, &claimer)?;
+		Self::ensure_transfer_allowed(swap.desired, &claimer, &swap.creator)?;
+
+		Self::unschedule_swap(offered, &swap);
+		Self::do_thaw(offered, FreezeReason::Swap);
+		Self::do_transfer(offered, claimer.clone())?;
+		Self::do_transfer(swap.desired, swap.creator.clone())?;
+		if let Some(amount) = swap.sweetener {
+			T::Currency::transfer_on_hold(
+				&HoldReason::Swap.into(),
+				&swap.creator,
+				&claimer,
+				amount,
+				Precision::Exact,
+				Restriction::Free,
+				Fortitude::Polite,
+			)?;
+		}
+
+		Self::deposit_event(Event::SwapClaimed {
+			offered,
+			desired: swap.desired,
+			creator: swap.creator,
+			claimer,
+			sweetener: swap.sweetener,
+		});
+		Ok(())
+	}
+
+	pub(crate) fn do_cancel_swap(creator: T::AccountId, offered: u64) -> DispatchResult {
+		let swap = PendingSwaps::<T>::take(&offered).ok_or(Error::<T>::NoSwap)?;
+		ensure!(swap.creator == creator, Error::<T>::NotOwner);
+		Self::unschedule_swap(offered, &swap);
+		Self::end_swap(offered, swap)?;
+		Self::deposit_event(Event::SwapCancelled { offered });
+		Ok(())
+	}
+
+	/// Expire the swaps whose deadline passed up to `now`, for as long as `limit` allows. Blocks
+	/// that do not fit are picked up on the next idle block.
+	pub(crate) fn expire_swaps(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+		let mut meter = WeightMeter::with_limit(limit);
+		let db = T::DbWeight::get();
+		if meter.try_consume(db.reads_writes(1, 1)).is_err() {
+			return Weight::zero()
+		}
+		let Some(mut block) = SwapCursor::<T>::get() else { return meter.consumed() };
+
+		while block <= now {
+			if meter.try_consume(db.reads_writes(1, 1)).is_err() {
+				break
+			}
+			let due = SwapDeadlines::<T>::get(block);
+			let expiries = T::WeightInfo::cancel_swap().saturating_mul(due.len() as u64);
+			if meter.try_consume(expiries).is_err() {
+				break
+			}
+			SwapDeadlines::<T>::remove(block);
+			for offered in due {
+				let Some(swap) = PendingSwaps::<T>::get(&offered) else { continue };
+				if swap.deadline == block {
+					let _ = with_storage_layer(|| {
+						PendingSwaps::<T>::remove(&offered);
+						Self::end_swap(offered, swap)?;
+						Self::deposit_event(Event::SwapExpired { offered });
+						Ok::<_, DispatchError>(())
+					});
+				}
+			}
+			block.saturating_inc();
+		}
+
+		SwapCursor::<T>::put(block);
+		meter.consumed()
+	}
+
+	/// Unfreeze the offered collectible of a swap that was taken out of `PendingSwaps` without
+	/// being claimed, and give its creator the sweetener back.
+	fn end_swap(offered: u64, swap: Swap<T>) -> DispatchResult {
+		Self::do_thaw(offered, FreezeReason::Swap);
+		if let Some(amount) = swap.sweetener {
+			T::Currency::release(
+				&HoldReason::Swap.into(),
+				&swap.creator,
+				amount,
+				Precision::BestEffort,
+			)?;
+		}
+		Ok(())
+	}
+
+	/// Take a swap that ends before its deadline off the expiry schedule.
+	fn unschedule_swap(offered: u64, swap: &Swap<T>) {
+		SwapDeadlines::<T>::mutate(swap.deadline, |due| due.retain(|id| *id != offered));
+	}
+}
@@ -0,0 +1,56 @@
+//! A background sweep for ownership records that point at collectibles which no longer exist,
+//! such as a faulty burn leaves behind. `on_idle` walks `OwnerOfCollectibles` a few owners at a
+//! time, resuming after the last owner it checked, and starts over once it reaches the end.
+
+use crate::*;
+use frame_support::{pallet_prelude::*, sp_runtime::traits::Saturating, weights::WeightMeter};
+
+impl<T: Config> Pallet<T> {
+	/// Check up to `SweepBatch` owners for stale ids, for as long as `limit` allows.
+	pub(crate) fn sweep_stale_references(limit: Weight) -> Weight {
+		let mut meter = WeightMeter::with_limit(limit);
+		let db = T::DbWeight::get();
+		if meter.try_consume(db.reads_writes(1, 1)).is_err() {
+			return Weight::zero()
+		}
+		// An owner costs a read of every id it holds, and a write if any of them is stale.
+		let per_owner = db.reads_writes(u64::from(T::MaximumOwned::get()).saturating_add(1), 1);
+
+		let owners = match StaleSweepCursor::<T>::get() {
+			Some(last) => OwnerOfCollectibles::<T>::iter_from(
+				OwnerOfCollectibles::<T>::hashed_key_for(&last),
+			),
+			None => OwnerOfCollectibles::<T>::iter(),
+		};
+		let (mut checked, mut last, mut finished) = (0u32, None, true);
+		for (owner, mut owned) in owners {
+			if checked >= T::SweepBatch::get() || meter.try_consume(per_owner).is_err() {
+				finished = false;
+				break
+			}
+			let before = owned.len();
+			owned.retain(|unique_id| {
+				let exists = CollectibleMap::<T>::contains_key(&*unique_id);
+				if !exists {
+					Self::deposit_event(Event::StaleReferenceRemoved {
+						owner: owner.clone(),
+						collectible: *unique_id,
+					});
+				}
+				exists
+			});
+			if owned.len() != before {
+				OwnerOfCollectibles::<T>::insert(&owner, owned);
+			}
+			checked.saturating_inc();
+			last = Some(owner);
+		}
+
+		match (finished, last) {
+			(true, _) => StaleSweepCursor::<T>::kill(),
+			(false, Some(last)) => StaleSweepCursor::<T>::put(last),
+			(false, None) => {},
+		}
+		meter.consumed()
+	}
+}
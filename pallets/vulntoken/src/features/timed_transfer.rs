@@ -0,0 +1,81 @@
+//! Time-locked transfers, for grants that vest at a given block. The sender keeps the item,
+//! frozen and unlisted, until the transfer completes from `on_initialize` at the start of its
+//! block; until then the sender can call it off. A transfer that cannot complete when it falls
+//! due, for example because the recipient owns the maximum, leaves the item with the sender.
+//!
+//! The runtime has no scheduler, so due transfers are kept per block in `TimedTransfersDue`,
+//! which `MaxDeadlinesPerBlock` bounds, and that bounds the work of each block's hook.
+
+use crate::*;
+use frame_support::{pallet_prelude::*, storage::with_storage_layer};
+
+impl<T: Config> Pallet<T> {
+	/// The time-locked transfer waiting for `unique_id`, if any.
+	pub fn timed_transfer(unique_id: u64) -> Option<TimedTransfer<T>> {
+		TimedTransfers::<T>::get(&unique_id)
+	}
+
+	pub(crate) fn do_transfer_at(
+		from: T::AccountId,
+		to: T::AccountId,
+		unique_id: u64,
+		at: BlockNumberFor<T>,
+	) -> DispatchResult {
+		let mut collectible =
+			CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
+		ensure!(collectible.owner == from, Error::<T>::NotOwner);
+		ensure!(from != to, Error::<T>::TransferToSelf);
+		ensure!(
+			at > frame_system::Pallet::<T>::block_number(),
+			Error::<T>::TransferNotInFuture
+		);
+		Self::ensure_unlocked(unique_id)?;
+		TimedTransfersDue::<T>::try_append(at, unique_id)
+			.map_err(|_| Error::<T>::TooManyDeadlines)?;
+
+		if collectible.price.take().is_some() {
+			Self::note_unlisted(unique_id);
+		}
+		CollectibleMap::<T>::insert(&unique_id, collectible);
+		ListedAt::<T>::remove(&unique_id);
+		Self::release_listing_deposit(unique_id)?;
+		Self::do_freeze(unique_id, FreezeReason::TimedTransfer);
+		let transfer = TimedTransfer { from: from.clone(), to: to.clone(), at };
+		TimedTransfers::<T>::insert(&unique_id, transfer);
+
+		Self::deposit_event(Event::TransferScheduled { collectible: unique_id, from, to, at });
+		Ok(())
+	}
+
+	pub(crate) fn do_cancel_transfer_at(from: T::AccountId, unique_id: u64) -> DispatchResult {
+		let transfer = TimedTransfers::<T>::get(&unique_id).ok_or(Error::<T>::NoTimedTransfer)?;
+		ensure!(transfer.from == from, Error::<T>::NotOwner);
+		TimedTransfersDue::<T>::mutate(transfer.at, |due| due.retain(|id| *id != unique_id));
+		TimedTransfers::<T>::remove(&unique_id);
+		Self::do_thaw(unique_id, FreezeReason::TimedTransfer);
+		Self::deposit_event(Event::ScheduledTransferCancelled { collectible: unique_id, from });
+		Ok(())
+	}
+
+	/// Complete the transfers due at `now` and return the weight used.
+	pub(crate) fn complete_timed_transfers(now: BlockNumberFor<T>) -> Weight {
+		let due = TimedTransfersDue::<T>::take(now);
+		let mut weight = T::DbWeight::get().reads_writes(1, 1);
+		for unique_id in due {
+			Self::complete_timed_transfer(unique_id);
+			weight.saturating_accrue(T::WeightInfo::complete_timed_transfer());
+		}
+		weight
+	}
+
+	/// Hand `unique_id` over to the recipient of its time-locked transfer.
+	pub(crate) fn complete_timed_transfer(unique_id: u64) {
+		let Some(transfer) = TimedTransfers::<T>::take(&unique_id) else { return };
+		Self::do_thaw(unique_id, FreezeReason::TimedTransfer);
+		let TimedTransfer { from, to, .. } = transfer;
+		if with_storage_layer(|| Self::do_transfer(unique_id, to.clone())).is_err() {
+			let event = Event::ScheduledTransferFailed { collectible: unique_id, from, to };
+			Self::deposit_event(event);
+		}
+	}
+}
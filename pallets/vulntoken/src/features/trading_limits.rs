@@ -0,0 +1,138 @@
+//! Per-account trading limits. An account can cap the volume and the number of sales it takes
+//! part in, as buyer or seller, per `TradingLimitWindow` blocks, and `ForceOrigin` can impose
+//! caps on flagged accounts on top of that. Each cap is checked wherever a sale is agreed to and
+//! counted once it settles.
+//!
+//! An account's window opens with its first sale after the previous one closed. Only accounts
+//! with a limit keep a window. Tightening a self-imposed limit applies at once; loosening or
+//! lifting one only applies a window later, so a limit cannot be talked out of in the moment.
+//! Raffle wins count towards a window but are never refused, as nobody chooses to win one.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::{Saturating, Zero},
+};
+
+impl<T: Config> Pallet<T> {
+	/// The limit `who` set on themselves that currently applies, taking a loosened limit into
+	/// account once it is due.
+	pub fn self_trading_limit(who: &T::AccountId) -> Option<TradingLimit<BalanceOf<T>>> {
+		match PendingTradingLimits::<T>::get(who) {
+			Some((limit, at)) if frame_system::Pallet::<T>::block_number() >= at => limit,
+			_ => SelfTradingLimits::<T>::get(who),
+		}
+	}
+
+	pub(crate) fn do_set_trading_limit(
+		who: T::AccountId,
+		limit: Option<TradingLimit<BalanceOf<T>>>,
+	) -> DispatchResult {
+		let current = Self::self_trading_limit(&who);
+		PendingTradingLimits::<T>::remove(&who);
+		let now = frame_system::Pallet::<T>::block_number();
+		let tightens = match (&limit, &current) {
+			(_, None) => true,
+			(None, Some(_)) => false,
+			(Some(new), Some(old)) => new.within(old),
+		};
+		let effective_at = if tightens {
+			SelfTradingLimits::<T>::set(&who, limit.clone());
+			now
+		} else {
+			// The current limit keeps applying until the loosened one is due.
+			SelfTradingLimits::<T>::set(&who, current);
+			let at = now.saturating_add(T::TradingLimitWindow::get());
+			PendingTradingLimits::<T>::insert(&who, (limit.clone(), at));
+			at
+		};
+		Self::deposit_event(Event::TradingLimitSet { who, limit, effective_at });
+		Ok(())
+	}
+
+	pub(crate) fn do_impose_trading_limit(
+		who: T::AccountId,
+		limit: Option<TradingLimit<BalanceOf<T>>>,
+	) -> DispatchResult {
+		ImposedTradingLimits::<T>::set(&who, limit.clone());
+		Self::deposit_event(Event::TradingLimitImposed { who, limit });
+		Ok(())
+	}
+
+	/// Fail if a sale at `price` would take `seller` or `buyer` past a trading limit.
+	pub fn ensure_within_limits(
+		seller: &T::AccountId,
+		buyer: &T::AccountId,
+		price: BalanceOf<T>,
+	) -> DispatchResult {
+		for who in [seller, buyer] {
+			let limits = [Self::self_trading_limit(who), ImposedTradingLimits::<T>::get(who)];
+			if limits.iter().all(Option::is_none) {
+				continue
+			}
+			let (volume, trades) = Self::open_window(who)
+				.map_or((Zero::zero(), 0), |window| (window.volume, window.trades));
+			let volume = volume.saturating_add(price);
+			let trades = trades.saturating_add(1);
+			ensure!(
+				limits.iter().flatten().all(|limit| limit.allows(volume, trades)),
+				Error::<T>::TradingLimitExceeded
+			);
+		}
+		Ok(())
+	}
+
+	/// Count a sale at `price` towards the windows of `seller` and `buyer`.
+	pub(crate) fn note_limited_trade(
+		seller: &T::AccountId,
+		buyer: &T::AccountId,
+		price: BalanceOf<T>,
+	) {
+		let now = frame_system::Pallet::<T>::block_number();
+		for who in [seller, buyer] {
+			if Self::self_trading_limit(who).is_none() &&
+				!ImposedTradingLimits::<T>::contains_key(who)
+			{
+				continue
+			}
+			let mut window = Self::open_window(who).unwrap_or(TradingWindow {
+				started: now,
+				volume: Zero::zero(),
+				trades: 0,
+			});
+			window.volume.saturating_accrue(price);
+			window.trades.saturating_inc();
+			TradingWindows::<T>::insert(who, window);
+		}
+	}
+
+	/// The window of `who`, unless it closed.
+	fn open_window(who: &T::AccountId) -> Option<TradingWindow<T>> {
+		let now = frame_system::Pallet::<T>::block_number();
+		TradingWindows::<T>::get(who)
+			.filter(|window| now < window.started.saturating_add(T::TradingLimitWindow::get()))
+	}
+}
+
+impl<Balance: PartialOrd + Copy> TradingLimit<Balance> {
+	/// Whether an account that traded `volume` over `trades` sales stays within this limit.
+	pub fn allows(&self, volume: Balance, trades: u32) -> bool {
+		self.max_volume.map_or(true, |max| volume <= max) &&
+			self.max_trades.map_or(true, |max| trades <= max)
+	}
+
+	/// Whether this limit is at least as strict as `other` on both counts.
+	pub fn within(&self, other: &Self) -> bool {
+		let volume = match (self.max_volume, other.max_volume) {
+			(_, None) => true,
+			(None, Some(_)) => false,
+			(Some(new), Some(old)) => new <= old,
+		};
+		let trades = match (self.max_trades, other.max_trades) {
+			(_, None) => true,
+			(None, Some(_)) => false,
+			(Some(new), Some(old)) => new <= old,
+		};
+		volume && trades
+	}
+}
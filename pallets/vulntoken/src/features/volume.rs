@@ -0,0 +1,37 @@
+//! Sale volume over a rolling window. Sales are added up per epoch of `VolumeEpoch` blocks,
+//! and only the last `VolumeEpochs` epochs are kept, so the window needs no more history than
+//! that.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::{Saturating, Zero},
+};
+
+impl<T: Config> Pallet<T> {
+	/// Sale volume over the current epoch and the `VolumeEpochs - 1` before it.
+	pub fn rolling_volume() -> BalanceOf<T> {
+		let window = T::VolumeEpochs::get().saturating_sub(1);
+		let oldest = Self::volume_epoch().saturating_sub(window.into());
+		VolumeBuckets::<T>::get()
+			.iter()
+			.filter(|(epoch, _)| *epoch >= oldest)
+			.fold(Zero::zero(), |total, (_, volume)| total.saturating_add(*volume))
+	}
+
+	/// Add a sale at `price` to the current epoch, dropping the oldest epoch to make room for
+	/// a new one.
+	pub(crate) fn note_volume(price: BalanceOf<T>) {
+		let epoch = Self::volume_epoch();
+		VolumeBuckets::<T>::mutate(|buckets| match buckets.last_mut() {
+			Some((last, volume)) if *last == epoch => volume.saturating_accrue(price),
+			_ => {
+				buckets.force_push((epoch, price));
+			},
+		});
+	}
+
+	fn volume_epoch() -> BlockNumberFor<T> {
+		frame_system::Pallet::<T>::block_number() / T::VolumeEpoch::get().max(1u32.into())
+	}
+}
@@ -0,0 +1,52 @@
+//! Wash-trading detection. Each collectible remembers who sold it to whom over the last
+//! `WashTradeWindow` blocks. A sale back to someone who sold the collectible within the window,
+//! or to the seller themselves, closes a circle of trades, and flags it for good.
+//!
+//! Flagged collectibles still trade, but their sales add nothing to the rolling volume or the
+//! last sale of their color, and their listings stay out of the floor indices.
+
+use crate::*;
+use frame_support::sp_runtime::traits::Saturating;
+
+impl<T: Config> Pallet<T> {
+	/// Whether the collectible has been flagged for trading in a circle.
+	pub fn is_wash_traded(unique_id: u64) -> bool {
+		WashTraded::<T>::contains_key(&unique_id)
+	}
+
+	/// Remember the sale of the collectible from `seller` to `buyer`, flagging it if the sale
+	/// closes a circle. Returns whether the collectible is flagged.
+	pub(crate) fn note_trade_pair(
+		unique_id: u64,
+		seller: &T::AccountId,
+		buyer: &T::AccountId,
+	) -> bool {
+		let now = frame_system::Pallet::<T>::block_number();
+		let since = now.saturating_sub(T::WashTradeWindow::get());
+		let circular = RecentSales::<T>::mutate(&unique_id, |sales| {
+			sales.retain(|(_, _, at)| *at > since);
+			let circular = buyer == seller || sales.iter().any(|(earlier, _, _)| earlier == buyer);
+			// The oldest sale goes first to make room.
+			if sales.is_full() {
+				sales.remove(0);
+			}
+			let _ = sales.try_push((seller.clone(), buyer.clone(), now));
+			circular
+		});
+
+		if circular && !Self::is_wash_traded(unique_id) {
+			WashTraded::<T>::insert(&unique_id, now);
+			Self::note_unlisted(unique_id);
+			log::debug!(
+				target: LOG_TARGET,
+				"flagged wash trade collectible={unique_id} seller={seller:?} buyer={buyer:?}",
+			);
+			Self::deposit_event(Event::WashTradeFlagged {
+				collectible: unique_id,
+				seller: seller.clone(),
+				buyer: buyer.clone(),
+			});
+		}
+		Self::is_wash_traded(unique_id)
+	}
+}
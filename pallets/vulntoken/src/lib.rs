@@ -1,198 +1,3065 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
+pub use features::pinning::{crypto, KEY_TYPE, PINNING_ENDPOINT_KEY, PINNING_TOKEN_KEY};
+pub use traits::{
+	ColorStrategy, Custody, GenerateItemId, HashedIds, IdentityVerifier, Issuer, Marketplace,
+	MintPolicy, ParityColors, PricingPolicy, RandomColors, RandomIds, RarityTable, SaleSplit,
+	SequentialIds,
+};
+
+/// The target of the pallet's log lines. Run the node with `-lruntime::vulntoken=debug` to see
+/// them all.
+pub(crate) const LOG_TARGET: &str = "runtime::vulntoken";
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+mod features;
+pub mod migrations;
+pub mod traits;
+pub mod weights;
+pub use weights::*;
 
 #[frame_support::pallet(dev_mode)]
 pub mod pallet {
     use frame_support::pallet_prelude::*;
     use frame_system::pallet_prelude::*;
-	use frame_support::sp_runtime::SaturatedConversion;
+	use frame_support::sp_runtime::{
+		traits::Zero,
+		Perbill, Percent,
+	};
+
+	use frame_support::traits::{
+		fungible::{self, BalancedHold, MutateHold},
+		OnUnbalanced, Randomness, SortedMembers,
+	};
+	use frame_system::offchain::SendTransactionTypes;
+	use pallet_payment_streams::{PaymentStreams, StreamId};
+
+	pub type BalanceOf<T> = <<T as Config>::Currency as fungible::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+	pub type CreditOf<T> = fungible::Credit<<T as frame_system::Config>::AccountId, <T as Config>::Currency>;
+
+	#[derive(Clone, Encode, Decode, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Collectible<T: Config> {
+		pub unique_id: u64,
+		pub price: Option<BalanceOf<T>>,
+		pub color: Color,
+		pub owner: T::AccountId,
+		/// The account that minted the collectible.
+		pub creator: T::AccountId,
+		/// Blake2-256 hash of the collectible's off-chain metadata blob.
+		pub metadata: Option<[u8; 32]>,
+		pub flags: CollectibleFlags,
+		/// The collection it was minted into, if any.
+		pub collection_id: Option<CollectionId>,
+	}
+
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, Copy, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct CollectibleFlags {
+		/// Minted as a mystery box, whether revealed since or not.
+		pub mystery_box: bool,
+	}
+
+	/// Identifies a collection.
+	pub type CollectionId = u32;
+
+	/// Who may mint into a collection.
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum CollectionMinting {
+		/// Only the collection's owner.
+		Owner,
+		/// Anybody.
+		Public,
+	}
+
+	/// A series of collectibles minted under rules its owner sets.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Collection<T: Config> {
+		pub owner: T::AccountId,
+		/// Nothing more can be minted into the collection once this many have been.
+		pub max_supply: Option<u32>,
+		/// Collectibles minted into the collection, burned ones included.
+		pub minted: u32,
+		/// Collectibles in the collection that still exist.
+		pub items: u32,
+		pub minting: CollectionMinting,
+		/// CID of the collection's off-chain metadata.
+		pub metadata: Option<BoundedVec<u8, T::MaxCidLength>>,
+		/// Whether the collection is being destroyed. Nothing can be minted into it any more.
+		pub destroying: bool,
+		pub settings: CollectionSettings<BlockNumberFor<T>>,
+		/// How many founder badges of the collection have been awarded.
+		pub founder_badges_issued: u32,
+		/// The metadata schema version the collection's collectibles follow. Zero until its owner
+		/// publishes one.
+		pub metadata_version: u16,
+	}
+
+	/// What a collection's owner sets for the collectibles in it. Collectibles in no collection
+	/// follow the pallet-wide settings and those of their creator alone.
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, Copy, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct CollectionSettings<BlockNumber> {
+		/// The royalty of collectibles without one of their own, in place of their creator's
+		/// default.
+		pub royalty: Option<Perbill>,
+		/// Whether plain transfers must involve an approved market, as when royalties are
+		/// enforced across the pallet.
+		pub royalties_enforced: bool,
+		/// The cooldown after a mint or purchase, in place of the pallet-wide one.
+		pub transfer_cooldown: Option<BlockNumber>,
+		/// Whether every sale leaves the buyer a receipt, as if their creator asked for them.
+		pub issues_receipts: bool,
+		/// How many distinct minters into the collection earn a founder badge of it. Zero awards
+		/// none.
+		pub founder_badge_limit: u32,
+	}
+
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[derive(frame_support::Serialize, frame_support::Deserialize)]
+	#[serde(crate = "frame_support::__private::serde")]
+	pub enum Color {
+		Red,
+		Yellow,
+		Blue,
+		Green,
+		/// Minted as a mystery box and not revealed yet.
+		Hidden,
+	}
+
+	impl Color {
+		pub const ALL: [Color; 5] =
+			[Color::Red, Color::Yellow, Color::Blue, Color::Green, Color::Hidden];
+	}
+
+	/// A sealed-bid auction running for a single collectible.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct SealedAuction<T: Config> {
+		pub seller: T::AccountId,
+		pub min_bid: BalanceOf<T>,
+		/// Commitments are accepted strictly before this block.
+		pub commit_end: BlockNumberFor<T>,
+		/// Reveals are accepted from `commit_end` strictly until this block.
+		pub reveal_end: BlockNumberFor<T>,
+		/// Number of commitments received so far.
+		pub bids: u32,
+		/// Highest valid revealed bid. Its amount is held from the bidder.
+		pub leader: Option<(T::AccountId, BalanceOf<T>)>,
+	}
+
+	/// A bidder's commitment in a sealed-bid auction.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct SealedBid<T: Config> {
+		/// `hash(bidder, amount, salt)`, see [`Pallet::sealed_bid_commitment`].
+		pub commitment: T::Hash,
+		pub deposit: BalanceOf<T>,
+		pub revealed: bool,
+	}
+
+	/// An English auction running for a single collectible. Bids are open, and each must beat
+	/// the one before.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Auction<T: Config> {
+		pub seller: T::AccountId,
+		/// Bids below this are refused.
+		pub reserve: BalanceOf<T>,
+		/// The highest bid so far, and who placed it. Its amount is held from the bidder.
+		pub leader: Option<(T::AccountId, BalanceOf<T>)>,
+		/// Bids are accepted strictly before this block, at the start of which the auction
+		/// settles.
+		pub end: BlockNumberFor<T>,
+	}
+
+	/// A raffle for a single collectible. Tickets are numbered from zero in order of purchase.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Raffle<T: Config> {
+		pub owner: T::AccountId,
+		pub ticket_price: BalanceOf<T>,
+		/// The raffle is cancelled and all tickets refunded if fewer tickets are sold.
+		pub min_tickets: u32,
+		pub max_tickets: u32,
+		pub sold: u32,
+		/// Tickets can be bought strictly before this block.
+		pub end: BlockNumberFor<T>,
+	}
+
+	/// A collectible minted with a hidden color.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct MysteryBox<T: Config> {
+		pub minted_at: BlockNumberFor<T>,
+		/// The box can be revealed from this block on.
+		pub reveal_at: BlockNumberFor<T>,
+	}
+
+	/// The randomness round a mystery box was revealed with, kept so anyone can audit the color.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct RevealRecord<T: Config> {
+		/// Output of `Randomness` for the reveal subject.
+		pub seed: T::Hash,
+		/// Block since which `seed` could have been known, as reported by the randomness source.
+		pub known_since: BlockNumberFor<T>,
+		/// Block in which the reveal was executed.
+		pub revealed_at: BlockNumberFor<T>,
+	}
+
+	/// A sale whose payment is held until the buyer confirms or the dispute window closes.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Escrow<T: Config> {
+		pub seller: T::AccountId,
+		pub buyer: T::AccountId,
+		/// Held from the buyer.
+		pub price: BalanceOf<T>,
+		/// Disputes can be raised strictly before this block.
+		pub dispute_deadline: BlockNumberFor<T>,
+	}
+
+	/// Why a collectible is frozen. A frozen collectible cannot be transferred, listed, sold
+	/// or burned until every freeze on it is lifted.
+	#[derive(
+		Clone, Copy, Encode, Decode, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug, TypeInfo,
+		MaxEncodedLen,
+	)]
+	pub enum FreezeReason {
+		/// Up for a sealed-bid auction.
+		Auction,
+		/// Being raffled.
+		Raffle,
+		/// Sold into escrow and not released yet.
+		Escrow,
+		/// Its escrowed sale is under dispute.
+		Disputed,
+		/// Staked by its owner.
+		Staked,
+		/// Pledged as collateral.
+		Collateral,
+		/// Rented out.
+		Rented,
+		/// Being paid off in installments.
+		Installments,
+		/// Offered in the order book.
+		SellOrder,
+		/// A sale receipt or founder badge, which stays with the account it was issued to.
+		Soulbound,
+		/// Waiting for a time-locked transfer to complete.
+		TimedTransfer,
+		/// Offered in a pending swap.
+		Swap,
+	}
+
+	/// A dispute over an escrowed sale, decided by the juror set.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Dispute<T: Config> {
+		pub raised_by: T::AccountId,
+		/// Held from `raised_by`, slashed if the dispute is lost.
+		pub deposit: BalanceOf<T>,
+		/// Jurors can vote strictly before this block.
+		pub voting_end: BlockNumberFor<T>,
+		pub for_buyer: u32,
+		pub for_seller: u32,
+	}
+
+	/// A collectible rented out for a per-block rate. The rent streams from the renter's
+	/// prepayment to the owner through `T::Streams`.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Rental<T: Config> {
+		pub owner: T::AccountId,
+		pub renter: T::AccountId,
+		/// Rent per block.
+		pub rate: BalanceOf<T>,
+		/// The stream paying the rent.
+		pub stream: StreamId,
+	}
+
+	/// A sale paid off in equal installments, one per `InstallmentPeriod`. The seller keeps the
+	/// item, frozen, until the last installment is paid.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct InstallmentPlan<T: Config> {
+		pub seller: T::AccountId,
+		pub buyer: T::AccountId,
+		pub price: BalanceOf<T>,
+		pub installments: u32,
+		/// Number of installments paid so far.
+		pub paid: u32,
+		/// Paid so far, held from the buyer until the plan completes or defaults.
+		pub held: BalanceOf<T>,
+		/// The next installment must be paid strictly before this block.
+		pub due: BlockNumberFor<T>,
+	}
+
+	/// A standing order to buy `quantity` collectibles, any of them, at up to `price` each.
+	/// `price` is held from the bidder for every collectible still to buy.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct BuyOrder<T: Config> {
+		pub id: u64,
+		pub bidder: T::AccountId,
+		pub price: BalanceOf<T>,
+		pub quantity: u32,
+	}
+
+	/// A standing order to sell a collectible for at least `price`. The item is frozen while
+	/// the order stands.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct SellOrder<T: Config> {
+		pub collectible: u64,
+		pub seller: T::AccountId,
+		pub price: BalanceOf<T>,
+	}
+
+	/// An offer to swap a collectible, frozen until the swap ends, for collectible `desired`.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Swap<T: Config> {
+		pub creator: T::AccountId,
+		pub desired: u64,
+		/// Paid on top to whoever claims the swap, held from `creator` until then.
+		pub sweetener: Option<BalanceOf<T>>,
+		/// The swap can be claimed strictly before this block.
+		pub deadline: BlockNumberFor<T>,
+	}
+
+	/// The cheapest listings, kept in `FloorIndex` so the floor price can move up to the next
+	/// listing without a scan.
+	#[derive(
+		Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, DefaultNoBound,
+	)]
+	#[scale_info(skip_type_params(T))]
+	pub struct CheapestListings<T: Config> {
+		/// Prices and ids of listed collectibles, cheapest first.
+		pub cheapest: BoundedVec<(BalanceOf<T>, u64), T::MaxFloorIndex>,
+		/// Whether listings were left out since the index was last rebuilt. None of them is
+		/// cheaper than the last entry.
+		pub truncated: bool,
+		/// While `on_idle` rebuilds the index, the mint sequence number of the next collectible
+		/// it looks at.
+		pub rebuild_from: Option<u64>,
+	}
+
+	/// What [`Pallet::find_collectibles`] searches for. Unset fields match anything.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, Default)]
+	pub struct CollectibleFilter<Balance> {
+		pub color: Option<Color>,
+		pub min_price: Option<Balance>,
+		pub max_price: Option<Balance>,
+		/// Only match collectibles listed at a fixed price.
+		pub for_sale_only: bool,
+		/// Only match collectibles minted by one of the `VerifiedCreators`.
+		pub verified_creators_only: bool,
+	}
+
+	/// What a sale receipt records.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct SaleReceipt<T: Config> {
+		/// The collectible sold.
+		pub collectible: u64,
+		pub seller: T::AccountId,
+		pub price: BalanceOf<T>,
+		/// The block the sale completed in.
+		pub sold_at: BlockNumberFor<T>,
+	}
+
+	/// Where the original of a collectible registered from another chain is.
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct ForeignSource {
+		/// The chain the collectible arrived from.
+		pub chain: u32,
+		/// The id of the original on that chain.
+		pub original_id: u64,
+	}
+
+	/// A transfer the sender scheduled for a later block. The sender keeps the item, frozen, until
+	/// then.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct TimedTransfer<T: Config> {
+		pub from: T::AccountId,
+		pub to: T::AccountId,
+		/// The block the transfer completes at.
+		pub at: BlockNumberFor<T>,
+	}
+
+	/// The heir an owner named, and the last the pallet saw of the owner.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Inheritance<T: Config> {
+		pub heir: T::AccountId,
+		/// Number of blocks the owner must stay inactive before the heir can claim.
+		pub inactivity_period: BlockNumberFor<T>,
+		/// The block the owner was last seen active at.
+		pub last_seen: BlockNumberFor<T>,
+		/// The owner's account nonce as of `last_seen`.
+		pub nonce: T::Nonce,
+		/// The block the heir started claiming at, while the claim waits out the grace period.
+		pub claim_started: Option<BlockNumberFor<T>>,
+	}
+
+	/// Caps on what an account trades within `TradingLimitWindow` blocks. `None` leaves that
+	/// count uncapped.
+	#[derive(
+		Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default,
+	)]
+	pub struct TradingLimit<Balance> {
+		/// Most sale volume, bought and sold.
+		pub max_volume: Option<Balance>,
+		/// Most sales, bought and sold.
+		pub max_trades: Option<u32>,
+	}
+
+	/// What an account with a trading limit traded in its current window.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct TradingWindow<T: Config> {
+		/// The block the window opened at.
+		pub started: BlockNumberFor<T>,
+		pub volume: BalanceOf<T>,
+		pub trades: u32,
+	}
+
+	/// Market statistics of the collectibles of one color.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+	pub struct ColorStats<Balance> {
+		/// How many collectibles of the color exist.
+		pub count: u64,
+		/// The price of the cheapest fixed-price listing of the color.
+		pub floor: Option<Balance>,
+		/// The price the last collectible of the color sold at.
+		pub last_sale: Option<Balance>,
+	}
+
+	/// What buying a listed collectible would cost and where the money would go.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+	pub struct SaleQuote<AccountId, Balance> {
+		/// The owner selling the collectible.
+		pub seller: AccountId,
+		/// What the buyer pays.
+		pub price: Balance,
+		/// How the pricing policy splits the price.
+		pub split: crate::SaleSplit<AccountId, Balance>,
+		/// The royalty the creator gets out of the seller's part of the split, on top of their own.
+		pub royalty: Balance,
+		/// The beneficiary of a listing that gives to charity, and what they get out of the
+		/// seller's part of the split.
+		pub charity: Option<(AccountId, Balance)>,
+	}
+
+	/// A collectible as the runtime API presents it.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+	pub struct CollectibleView<AccountId, Balance> {
+		pub unique_id: u64,
+		pub owner: AccountId,
+		pub creator: AccountId,
+		pub color: Color,
+		/// The fixed price it is listed at, if it is listed.
+		pub price: Option<Balance>,
+		/// The license it was minted with, if any.
+		pub license: Option<sp_std::vec::Vec<u8>>,
+		/// The metadata schema version its collection, or its creator if it is in none,
+		/// publishes under.
+		pub metadata_version: u16,
+		/// The collection it was minted into, if any.
+		pub collection_id: Option<CollectionId>,
+	}
+
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(14);
+
+    #[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+	pub trait Config: frame_system::Config + SendTransactionTypes<Call<Self>> {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Funds locked by the marketplace are held under a [`HoldReason`].
+		type Currency: fungible::Mutate<Self::AccountId>
+			+ MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+			+ BalancedHold<Self::AccountId>;
+		type RuntimeHoldReason: From<HoldReason>;
+		/// Background work anyone can have carried out through `frame_system::do_task`.
+		type RuntimeTask: frame_support::traits::Task
+			+ IsType<<Self as frame_system::Config>::RuntimeTask>
+			+ From<Task<Self>>;
+
+		#[pallet::constant]
+		type MaximumOwned: Get<u32>;
+
+		/// Deposit held with every sealed-bid commitment. Returned on reveal, slashed if the
+		/// bid is never revealed.
+		#[pallet::constant]
+		type SealedBidDeposit: Get<BalanceOf<Self>>;
+
+		/// Maximum number of commitments a single sealed-bid auction accepts.
+		#[pallet::constant]
+		type MaxSealedBids: Get<u32>;
+
+		/// Source of randomness used to draw raffle winners and reveal mystery boxes.
+		///
+		/// `pallet_insecure_randomness_collective_flip` is fine for development chains, but its
+		/// output can be biased by block authors withholding blocks. Production runtimes should
+		/// use a VRF-backed source such as
+		/// `pallet_babe::RandomnessFromOneEpochAgo`, whose output is checkable against the VRF
+		/// proofs in block headers, so reveals recorded in [`RevealRecords`] can be audited
+		/// against the block author.
+		type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+
+		/// Maximum number of tickets a single raffle can sell.
+		#[pallet::constant]
+		type MaxRaffleTickets: Get<u32>;
+
+		/// Number of blocks a mystery box stays hidden after mint.
+		#[pallet::constant]
+		type RevealDelay: Get<BlockNumberFor<Self>>;
+
+		/// Origin allowed to manage marketplace-wide settings.
+		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Number of blocks after an escrowed purchase during which either party can dispute it.
+		#[pallet::constant]
+		type EscrowDisputeWindow: Get<BlockNumberFor<Self>>;
+
+		/// Deposit held from whoever raises a dispute, slashed if they lose it.
+		#[pallet::constant]
+		type DisputeDeposit: Get<BalanceOf<Self>>;
+
+		/// Number of blocks jurors have to vote on a dispute.
+		#[pallet::constant]
+		type DisputeVotingPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Number of blocks past the point an escrow or sealed auction could be settled after
+		/// which its depositors can reclaim what it holds.
+		#[pallet::constant]
+		type EscrowTimeout: Get<BlockNumberFor<Self>>;
+
+		/// The accounts allowed to vote on disputes.
+		type Jurors: SortedMembers<Self::AccountId>;
+
+		/// The creators whose collectibles searches can be limited to.
+		type VerifiedCreators: SortedMembers<Self::AccountId>;
+
+		/// Origin allowed to register collectibles arriving from other chains.
+		type ForeignOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Origin allowed to pledge any collectible as collateral, and release it again.
+		type FreezeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Decides whether an account holds a positive identity judgement.
+		type IdentityVerifier: IdentityVerifier<Self::AccountId>;
+
+		/// Rules every mint must pass. `()` lets anybody mint.
+		type MintPolicy: MintPolicy<Self::AccountId>;
+
+		/// Picks the ids of new collectibles.
+		type ItemIdGenerator: GenerateItemId;
+
+		/// Picks the colors of new collectibles.
+		type ColorStrategy: ColorStrategy;
+
+		/// Sales at or above this price require both parties to pass `IdentityVerifier`.
+		#[pallet::constant]
+		type HighValueThreshold: Get<BalanceOf<Self>>;
+
+		/// Number of blocks a fixed-price listing stays executable.
+		#[pallet::constant]
+		type MaxListingDuration: Get<BlockNumberFor<Self>>;
+
+		/// Maximum length of a metadata CID.
+		#[pallet::constant]
+		type MaxCidLength: Get<u32>;
+
+		/// Maximum number of collectibles minted in a single block.
+		#[pallet::constant]
+		type MaxMintsPerBlock: Get<u32>;
+
+		/// Deposit held from whoever mints a collectible, until it is burned. Zero disables it.
+		#[pallet::constant]
+		type CollectibleDeposit: Get<BalanceOf<Self>>;
+
+		/// Deposit held from the owner for as long as a collectible is listed.
+		#[pallet::constant]
+		type ListingDeposit: Get<BalanceOf<Self>>;
+
+		/// Lowest price a collectible can be listed at. Zero is never accepted.
+		#[pallet::constant]
+		type MinListingPrice: Get<BalanceOf<Self>>;
+
+		/// Highest price a collectible can be listed at, leaving room for fees on top of it.
+		#[pallet::constant]
+		type MaxPrice: Get<BalanceOf<Self>>;
+
+		/// Receives listing deposits slashed when `ForceOrigin` flags a listing.
+		type Slashed: OnUnbalanced<CreditOf<Self>>;
+
+		/// Splits the price of fixed-price sales. `()` pays it all to the seller.
+		type PricingPolicy: PricingPolicy<Self::AccountId, BalanceOf<Self>>;
+
+		/// Receives the fees `PricingPolicy` takes from sales.
+		type FeeSink: OnUnbalanced<CreditOf<Self>>;
+
+		/// Number of blocks between installments.
+		#[pallet::constant]
+		type InstallmentPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of installments a sale can be split into.
+		#[pallet::constant]
+		type MaxInstallments: Get<u32>;
+
+		/// Portion of the installments paid so far that goes to the seller when the buyer
+		/// misses one. The rest is refunded.
+		#[pallet::constant]
+		type InstallmentForfeit: Get<Perbill>;
+
+		/// Maximum number of installments, and separately of time-locked transfers and of auction
+		/// endings, that can fall due in the same block.
+		#[pallet::constant]
+		type MaxDeadlinesPerBlock: Get<u32>;
+
+		/// Streams rent from renters to owners.
+		type Streams: PaymentStreams<Self::AccountId, BalanceOf<Self>>;
+
+		/// Maximum number of orders on each side of the order book.
+		#[pallet::constant]
+		type MaxBookDepth: Get<u32>;
+
+		/// Number of the cheapest listings kept to find the next floor price.
+		#[pallet::constant]
+		type MaxFloorIndex: Get<u32>;
+
+		/// Number of blocks sale volume is added up over.
+		#[pallet::constant]
+		type VolumeEpoch: Get<BlockNumberFor<Self>>;
+
+		/// Number of epochs, the current one included, the rolling sale volume covers.
+		#[pallet::constant]
+		type VolumeEpochs: Get<u32>;
+
+		/// Number of collectibles a floor index rebuild task scans.
+		#[pallet::constant]
+		type FloorRebuildBatch: Get<u32>;
+
+		/// Number of owners `on_idle` checks for stale collectible ids per block.
+		#[pallet::constant]
+		type SweepBatch: Get<u32>;
+
+		/// Number of blocks a sale counts towards wash-trading detection.
+		#[pallet::constant]
+		type WashTradeWindow: Get<BlockNumberFor<Self>>;
+
+		/// Number of sales of each collectible remembered for wash-trading detection.
+		#[pallet::constant]
+		type MaxRecentSales: Get<u32>;
+
+		/// Shortest inactivity period an owner can name an heir with.
+		#[pallet::constant]
+		type MinInactivityPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Number of blocks an owner has to veto an heir's claim.
+		#[pallet::constant]
+		type InheritanceGracePeriod: Get<BlockNumberFor<Self>>;
+
+		/// Highest royalty a creator can ask for.
+		#[pallet::constant]
+		type MaxRoyalty: Get<Perbill>;
+
+		/// Number of blocks trading limits count sales over.
+		#[pallet::constant]
+		type TradingLimitWindow: Get<BlockNumberFor<Self>>;
+
+		/// Maximum length of a collectible's license.
+		#[pallet::constant]
+		type MaxLicenseLength: Get<u32>;
+
+		/// Maximum length of the metadata stored on chain for a collectible.
+		#[pallet::constant]
+		type MetadataLimit: Get<u32>;
+
+		/// Maximum length of the key of a collectible attribute.
+		#[pallet::constant]
+		type AttributeKeyLimit: Get<u32>;
+
+		/// Maximum length of the value of a collectible attribute.
+		#[pallet::constant]
+		type AttributeValueLimit: Get<u32>;
+
+		/// Maximum number of attributes a collectible can have.
+		#[pallet::constant]
+		type MaxAttributes: Get<u32>;
+
+		/// Deposit held for every byte of on-chain metadata and attributes, keys included.
+		#[pallet::constant]
+		type MetadataDepositPerByte: Get<BalanceOf<Self>>;
+
+		/// Number of collectibles `destroy_collection_items` burns or releases per call.
+		#[pallet::constant]
+		type CollectionTeardownBatch: Get<u32>;
+
+		/// Maximum number of standing offers a collectible can have, so burning it can refund
+		/// every one of them.
+		#[pallet::constant]
+		type MaxOffers: Get<u32>;
+
+		/// A type representing the weights required by the dispatchables of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Why the pallet holds an account's funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// Deposit for a minted collectible.
+		MintDeposit,
+		/// A sealed-bid commitment deposit, or the amount of the leading revealed bid.
+		Bid,
+		/// Funds offered for a collectible.
+		Offer,
+		/// The price of an escrowed sale, until it is released or refunded.
+		Escrow,
+		/// Deposit for raising an escrow dispute.
+		DisputeDeposit,
+		/// The price of raffle tickets, until the draw.
+		RaffleTicket,
+		/// Deposit for a fixed-price listing, slashed if the listing is flagged.
+		ListingDeposit,
+		/// Rent prepaid by a renter, from before rent moved to `T::Streams`. Nothing is held
+		/// under it any more.
+		Rental,
+		/// Installments paid towards a purchase that has not completed yet.
+		Installment,
+		/// The price of the collectibles a standing buy order has yet to buy.
+		BuyOrder,
+		/// Deposit for the on-chain metadata and attributes of a collectible.
+		MetadataDeposit,
+		/// The sweetener of a pending swap.
+		Swap,
+	}
+
+	/// Number of collectibles in existence.
+	#[pallet::storage]
+	pub(super) type CollectiblesCount<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// The mint sequence number of the next collectible, which is how many have been minted,
+	/// burned ones included. Under `SequentialIds` it is also the next id.
+	#[pallet::storage]
+	pub(super) type NextCollectibleId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// The id each collectible was minted with, by mint sequence number, for the ids that
+	/// differ from their sequence number. Ids without an entry equal it.
+	#[pallet::storage]
+	pub(super) type ItemIdAt<T: Config> = StorageMap<_, Twox64Concat, u64, u64>;
+
+	/// The price of the dearest fixed-price listing, zero when nothing is listed. While
+	/// `HighestPriceScan` is set, only the collectibles scanned so far and those listed since
+	/// count.
+	#[pallet::storage]
+	pub(super) type HighestPrice<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// The collectible listed at `HighestPrice`.
+	#[pallet::storage]
+	pub(super) type HighestListing<T: Config> = StorageValue<_, u64>;
+
+	/// Mint sequence number from which `on_idle` goes on looking for the dearest listing, set
+	/// when the one at `HighestPrice` is unlisted or repriced lower.
+	#[pallet::storage]
+	pub(super) type HighestPriceScan<T: Config> = StorageValue<_, u64>;
+
+	/// Maps the Collectible struct to the unique_id.
+	#[pallet::storage]
+	pub(super) type CollectibleMap<T: Config> = StorageMap<_, Twox64Concat, u64, Collectible<T>>;
+
+	/// Track the collectibles owned by each account.
+	#[pallet::storage]
+	pub(super) type OwnerOfCollectibles<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<u64, T::MaximumOwned>,
+		ValueQuery,
+	>;
+
+	/// Sealed-bid auctions by collectible id.
+	#[pallet::storage]
+	pub(super) type SealedAuctions<T: Config> = StorageMap<_, Twox64Concat, u64, SealedAuction<T>>;
+
+	/// Sealed-bid commitments by collectible id and bidder.
+	#[pallet::storage]
+	pub(super) type SealedBids<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		u64,
+		Blake2_128Concat,
+		T::AccountId,
+		SealedBid<T>,
+	>;
+
+	/// English auctions by collectible id.
+	#[pallet::storage]
+	pub(super) type Auctions<T: Config> = StorageMap<_, Twox64Concat, u64, Auction<T>>;
+
+	/// Collectibles whose English auction ends at each block.
+	#[pallet::storage]
+	pub(super) type AuctionsEnding<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<u64, T::MaxDeadlinesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Collectibles whose sealed-bid auction ends its reveal phase at each block.
+	#[pallet::storage]
+	pub(super) type SealedAuctionsEnding<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<u64, T::MaxDeadlinesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Auctions that are over but not settled yet: sealed-bid auctions past their reveal phase,
+	/// and English auctions that could not settle when they ended. The offchain worker settles
+	/// them.
+	#[pallet::storage]
+	pub(super) type UnsettledAuctions<T: Config> = StorageMap<_, Twox64Concat, u64, ()>;
+
+	/// Raffles by collectible id.
+	#[pallet::storage]
+	pub(super) type Raffles<T: Config> = StorageMap<_, Twox64Concat, u64, Raffle<T>>;
+
+	/// Holder of each raffle ticket, by collectible id and ticket number.
+	#[pallet::storage]
+	pub(super) type RaffleTickets<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, u64, Twox64Concat, u32, T::AccountId>;
+
+	/// Collectibles that were minted hidden and are waiting to be revealed.
+	#[pallet::storage]
+	pub(super) type MysteryBoxes<T: Config> = StorageMap<_, Twox64Concat, u64, MysteryBox<T>>;
+
+	/// The randomness each revealed mystery box was assigned its color from.
+	#[pallet::storage]
+	pub(super) type RevealRecords<T: Config> = StorageMap<_, Twox64Concat, u64, RevealRecord<T>>;
+
+	/// When set, plain transfers are only allowed to or from an approved market, so items can
+	/// only change hands through sales that honor the creator's terms.
+	#[pallet::storage]
+	pub(super) type RoyaltiesEnforced<T> = StorageValue<_, bool, ValueQuery>;
+
+	/// Market accounts that may receive and deliver items while royalties are enforced.
+	#[pallet::storage]
+	pub(super) type ApprovedMarkets<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+	/// Number of blocks after a mint or purchase during which an item cannot be listed again.
+	/// Zero disables the cooldown.
+	#[pallet::storage]
+	pub(super) type TransferCooldown<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+	/// Block from which a collectible may be listed again. Entries in the past are stale and are
+	/// overwritten by the next mint or purchase.
+	#[pallet::storage]
+	pub(super) type CooldownUntil<T: Config> = StorageMap<_, Twox64Concat, u64, BlockNumberFor<T>>;
+
+	/// Block at which each collectible was last listed. Listings without an entry predate this
+	/// record and are treated as listed at genesis.
+	#[pallet::storage]
+	pub(super) type ListedAt<T: Config> = StorageMap<_, Twox64Concat, u64, BlockNumberFor<T>>;
+
+	/// Escrowed sales by collectible id.
+	#[pallet::storage]
+	pub(super) type Escrows<T: Config> = StorageMap<_, Twox64Concat, u64, Escrow<T>>;
+
+	/// The account that paid each listing's deposit, and the amount held.
+	#[pallet::storage]
+	pub(super) type ListingDeposits<T: Config> =
+		StorageMap<_, Twox64Concat, u64, (T::AccountId, BalanceOf<T>)>;
+
+	/// The account that paid each collectible's mint deposit, and the amount held.
+	#[pallet::storage]
+	pub(super) type MintDeposits<T: Config> =
+		StorageMap<_, Twox64Concat, u64, (T::AccountId, BalanceOf<T>)>;
+
+	/// Open disputes by collectible id.
+	#[pallet::storage]
+	pub(super) type Disputes<T: Config> = StorageMap<_, Twox64Concat, u64, Dispute<T>>;
+
+	/// Freezes on each collectible, by collectible id and reason.
+	#[pallet::storage]
+	pub(super) type Frozen<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, u64, Twox64Concat, FreezeReason, ()>;
+
+	/// Juror votes by collectible id and juror. `true` sides with the buyer.
+	#[pallet::storage]
+	pub(super) type DisputeVotes<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, u64, Blake2_128Concat, T::AccountId, bool>;
+
+	/// Accounts that accept collectibles minted into them by others.
+	#[pallet::storage]
+	pub(super) type AcceptsMints<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+	/// Creators whose collectibles leave the buyer a receipt whenever they sell.
+	#[pallet::storage]
+	pub(super) type IssuesReceipts<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+	/// The sale each receipt records, by the receipt's collectible id.
+	#[pallet::storage]
+	pub(super) type SaleReceipts<T: Config> = StorageMap<_, Twox64Concat, u64, SaleReceipt<T>>;
+
+	/// How many distinct minters earn a founder badge. Zero awards none.
+	#[pallet::storage]
+	pub(super) type FounderBadgeLimit<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// How many founder badges have been awarded.
+	#[pallet::storage]
+	pub(super) type FounderBadgesIssued<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The founder badge of each account that earned one.
+	#[pallet::storage]
+	pub(super) type FounderBadges<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u64>;
+
+	/// Collectibles others minted for an account that it has not claimed yet, oldest first. Each
+	/// records its minter and the mint deposit held from them.
+	#[pallet::storage]
+	pub(super) type PendingMints<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<(T::AccountId, BalanceOf<T>), T::MaximumOwned>,
+		ValueQuery,
+	>;
+
+	/// Number of collectibles minted in the current block, reset in `on_initialize`.
+	#[pallet::storage]
+	pub(super) type MintsThisBlock<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// IPFS CID of each collectible's metadata.
+	#[pallet::storage]
+	pub(super) type MetadataCids<T: Config> =
+		StorageMap<_, Twox64Concat, u64, BoundedVec<u8, T::MaxCidLength>>;
+
+	/// Collectibles whose metadata the offchain worker has not confirmed as pinned yet.
+	#[pallet::storage]
+	pub(super) type PinQueue<T: Config> = StorageMap<_, Twox64Concat, u64, ()>;
+
+	/// Per-block rent each collectible is offered for.
+	#[pallet::storage]
+	pub(super) type RentalRates<T: Config> = StorageMap<_, Twox64Concat, u64, BalanceOf<T>>;
+
+	/// Running rentals by collectible id.
+	#[pallet::storage]
+	pub(super) type Rentals<T: Config> = StorageMap<_, Twox64Concat, u64, Rental<T>>;
+
+	/// Open installment plans by collectible id.
+	#[pallet::storage]
+	pub(super) type InstallmentPlans<T: Config> =
+		StorageMap<_, Twox64Concat, u64, InstallmentPlan<T>>;
+
+	/// Collectibles whose next installment falls due at each block. Entries are not removed
+	/// when an installment is paid; `on_idle` skips those whose plan moved on.
+	#[pallet::storage]
+	pub(super) type InstallmentDeadlines<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<u64, T::MaxDeadlinesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// The first block whose deadlines `on_idle` has not enforced yet. Unset until the first
+	/// installment plan is opened.
+	#[pallet::storage]
+	pub(super) type InstallmentCursor<T: Config> = StorageValue<_, BlockNumberFor<T>>;
+
+	/// The last owner the stale reference sweep checked. Unset when the next sweep starts from
+	/// the first owner.
+	#[pallet::storage]
+	pub(super) type StaleSweepCursor<T: Config> = StorageValue<_, T::AccountId>;
+
+	/// Standing buy orders, best price first and oldest first among equal prices.
+	#[pallet::storage]
+	pub(super) type BuyBook<T: Config> =
+		StorageValue<_, BoundedVec<BuyOrder<T>, T::MaxBookDepth>, ValueQuery>;
+
+	/// Standing sell orders, cheapest first and oldest first among equal prices.
+	#[pallet::storage]
+	pub(super) type SellBook<T: Config> =
+		StorageValue<_, BoundedVec<SellOrder<T>, T::MaxBookDepth>, ValueQuery>;
+
+	/// The id the next buy order is placed with.
+	#[pallet::storage]
+	pub(super) type NextOrderId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// The price of the cheapest fixed-price listing, unset when nothing is listed. While
+	/// `FloorIndex` is being rebuilt, only the collectibles scanned so far count.
+	#[pallet::storage]
+	pub type FloorPrice<T: Config> = StorageValue<_, BalanceOf<T>>;
+
+	/// The cheapest listings, from which `FloorPrice` is kept up to date.
+	#[pallet::storage]
+	pub(super) type FloorIndex<T: Config> = StorageValue<_, CheapestListings<T>, ValueQuery>;
+
+	/// Market statistics per color. The floor follows `ColorFloorIndex`.
+	#[pallet::storage]
+	pub(super) type ColorStatistics<T: Config> =
+		StorageMap<_, Twox64Concat, Color, ColorStats<BalanceOf<T>>, ValueQuery>;
+
+	/// The cheapest listings of each color, as `FloorIndex` keeps them for the whole pallet.
+	#[pallet::storage]
+	pub(super) type ColorFloorIndex<T: Config> =
+		StorageMap<_, Twox64Concat, Color, CheapestListings<T>, ValueQuery>;
+
+	/// Sale volume of the most recent epochs that saw a sale, oldest first.
+	#[pallet::storage]
+	pub(super) type VolumeBuckets<T: Config> = StorageValue<
+		_,
+		BoundedVec<(BlockNumberFor<T>, BalanceOf<T>), T::VolumeEpochs>,
+		ValueQuery,
+	>;
+
+	/// Sales of each collectible within `WashTradeWindow`, as (seller, buyer, block), oldest
+	/// first. Entries older than the window are dropped at the next sale.
+	#[pallet::storage]
+	pub(super) type RecentSales<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		u64,
+		BoundedVec<(T::AccountId, T::AccountId, BlockNumberFor<T>), T::MaxRecentSales>,
+		ValueQuery,
+	>;
+
+	/// Block at which each collectible was flagged for trading in a circle.
+	#[pallet::storage]
+	pub(super) type WashTraded<T: Config> = StorageMap<_, Twox64Concat, u64, BlockNumberFor<T>>;
+
+	/// Where the original of each derivative of a collectible from another chain is.
+	#[pallet::storage]
+	pub(super) type ForeignSources<T: Config> = StorageMap<_, Twox64Concat, u64, ForeignSource>;
+
+	/// The local derivative of each registered collectible from another chain, by chain and the
+	/// id of the original there.
+	#[pallet::storage]
+	pub(super) type ForeignDerivatives<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, u32, Twox64Concat, u64, u64>;
+
+	/// Time-locked transfers waiting to complete, by collectible id.
+	#[pallet::storage]
+	pub(super) type TimedTransfers<T: Config> =
+		StorageMap<_, Twox64Concat, u64, TimedTransfer<T>>;
+
+	/// Collectibles whose time-locked transfer completes at each block.
+	#[pallet::storage]
+	pub(super) type TimedTransfersDue<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<u64, T::MaxDeadlinesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// The beneficiary and share of the price of each listing that gives to charity.
+	#[pallet::storage]
+	pub(super) type ListingCharities<T: Config> =
+		StorageMap<_, Twox64Concat, u64, (T::AccountId, Percent)>;
+
+	/// The heir each owner named.
+	#[pallet::storage]
+	pub(super) type Inheritances<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, Inheritance<T>>;
+
+	/// The royalty each creator asks on sales of the collectibles they minted, unless their
+	/// collection sets one.
+	#[pallet::storage]
+	pub(super) type DefaultRoyalties<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, Perbill>;
+
+	/// Royalties set on single collectibles, taking precedence over their creator's default.
+	#[pallet::storage]
+	pub(super) type ItemRoyalties<T: Config> = StorageMap<_, Twox64Concat, u64, Perbill>;
+
+	/// The trading limit each account set on itself.
+	#[pallet::storage]
+	pub(super) type SelfTradingLimits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, TradingLimit<BalanceOf<T>>>;
+
+	/// Loosened self-imposed trading limits, or `None` for lifted ones, and the block from which
+	/// they apply.
+	#[pallet::storage]
+	pub(super) type PendingTradingLimits<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		(Option<TradingLimit<BalanceOf<T>>>, BlockNumberFor<T>),
+	>;
+
+	/// Trading limits `ForceOrigin` imposed on flagged accounts.
+	#[pallet::storage]
+	pub(super) type ImposedTradingLimits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, TradingLimit<BalanceOf<T>>>;
+
+	/// What each account with a trading limit traded in its current window.
+	#[pallet::storage]
+	pub(super) type TradingWindows<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, TradingWindow<T>>;
+
+	/// The license each collectible minted with one was minted with.
+	#[pallet::storage]
+	pub(super) type Licenses<T: Config> =
+		StorageMap<_, Twox64Concat, u64, BoundedVec<u8, T::MaxLicenseLength>>;
+
+	/// The metadata schema version each creator publishes the metadata of their collectibles
+	/// outside collections under.
+	#[pallet::storage]
+	pub(super) type MetadataVersions<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u16, ValueQuery>;
+
+	/// Collections by id.
+	#[pallet::storage]
+	pub(super) type Collections<T: Config> =
+		StorageMap<_, Twox64Concat, CollectionId, Collection<T>>;
+
+	/// The id the next collection is created with.
+	#[pallet::storage]
+	pub(super) type NextCollectionId<T: Config> = StorageValue<_, CollectionId, ValueQuery>;
+
+	/// The collectibles in each collection, by collection and collectible id.
+	#[pallet::storage]
+	pub(super) type CollectionItems<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, CollectionId, Twox64Concat, u64, ()>;
+
+	/// The founder badge of a collection each account that earned one holds, by collection and
+	/// account.
+	#[pallet::storage]
+	pub(super) type CollectionFounders<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, CollectionId, Blake2_128Concat, T::AccountId, u64>;
+
+	/// The account each collectible's owner approved to transfer or list it, until it next
+	/// changes hands.
+	#[pallet::storage]
+	pub(super) type Approvals<T: Config> = StorageMap<_, Twox64Concat, u64, T::AccountId>;
+
+	/// Operators each owner approved to transfer or list any of their collectibles.
+	#[pallet::storage]
+	pub(super) type OperatorApprovals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+	>;
+
+	/// Standing offers by collectible id and bidder, each held from the bidder.
+	#[pallet::storage]
+	pub(super) type Offers<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		u64,
+		Blake2_128Concat,
+		T::AccountId,
+		BalanceOf<T>,
+	>;
+
+	/// Number of standing offers on each collectible, up to `MaxOffers`.
+	#[pallet::storage]
+	pub(super) type OfferCounts<T: Config> = StorageMap<_, Twox64Concat, u64, u32>;
+
+	/// Metadata stored on chain by collectible id.
+	#[pallet::storage]
+	pub(super) type OnChainMetadata<T: Config> =
+		StorageMap<_, Twox64Concat, u64, BoundedVec<u8, T::MetadataLimit>>;
+
+	/// Attribute values by collectible id and key.
+	#[pallet::storage]
+	pub(super) type Attributes<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		u64,
+		Blake2_128Concat,
+		BoundedVec<u8, T::AttributeKeyLimit>,
+		BoundedVec<u8, T::AttributeValueLimit>,
+	>;
+
+	/// Number of attributes each collectible has.
+	#[pallet::storage]
+	pub(super) type AttributeCounts<T: Config> = StorageMap<_, Twox64Concat, u64, u32, ValueQuery>;
+
+	/// The account holding the deposit for each collectible's on-chain metadata and attributes,
+	/// and the amount held.
+	#[pallet::storage]
+	pub(super) type MetadataDeposits<T: Config> =
+		StorageMap<_, Twox64Concat, u64, (T::AccountId, BalanceOf<T>)>;
+
+	/// Pending swaps by the id of the collectible they offer.
+	#[pallet::storage]
+	pub(super) type PendingSwaps<T: Config> = StorageMap<_, Twox64Concat, u64, Swap<T>>;
+
+	/// Collectibles whose swap expires at each block.
+	#[pallet::storage]
+	pub(super) type SwapDeadlines<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<u64, T::MaxDeadlinesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// The first block whose swaps `on_idle` has not expired yet. Unset until the first swap is
+	/// created.
+	#[pallet::storage]
+	pub(super) type SwapCursor<T: Config> = StorageValue<_, BlockNumberFor<T>>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// The collectibles minted at genesis as `(owner, color, price)`, one collectible per
+		/// entry. Each owner is also the creator of their collectibles, and those with a price are
+		/// listed at it without a listing deposit.
+		pub collectibles: sp_std::vec::Vec<(T::AccountId, Color, Option<BalanceOf<T>>)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (owner, color, price) in &self.collectibles {
+				assert!(*color != Color::Hidden, "genesis collectibles cannot be mystery boxes");
+				// The per-block mint limit is for extrinsics, not for the genesis allocation.
+				MintsThisBlock::<T>::kill();
+				let (unique_id, _) = Pallet::<T>::gen_unique_id();
+				Pallet::<T>::mint(owner, owner, unique_id, *color)
+					.expect("genesis collectibles must pass the mint policy and `MaximumOwned`");
+				let Some(price) = *price else { continue };
+				assert!(
+					!price.is_zero() &&
+						price >= T::MinListingPrice::get() &&
+						price <= T::MaxPrice::get(),
+					"genesis listings must be priced between `MinListingPrice` and `MaxPrice`"
+				);
+				CollectibleMap::<T>::mutate(&unique_id, |collectible| {
+					if let Some(collectible) = collectible {
+						collectible.price = Some(price);
+					}
+				});
+				Pallet::<T>::note_listed(unique_id, price);
+			}
+			MintsThisBlock::<T>::kill();
+		}
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		DuplicateCollectible,
+		MaximumCollectiblesOwned,
+		BoundsOverflow,
+		NoCollectible,
+		NotOwner,
+		TransferToSelf,
+		BidPriceTooLow,
+		NotForSale,
+		/// The collectible is currently being auctioned.
+		InAuction,
+		/// There is no auction for this collectible.
+		NoAuction,
+		/// Auction phases must last at least one block.
+		InvalidAuctionPeriod,
+		/// The auction is not accepting commitments.
+		NotCommitPhase,
+		/// The auction is not accepting reveals.
+		NotRevealPhase,
+		/// The auction has not ended yet.
+		AuctionNotFinished,
+		/// The seller cannot bid on their own auction.
+		SellerCannotBid,
+		/// The account already committed a bid for this auction.
+		AlreadyCommitted,
+		/// The auction has reached `MaxSealedBids` commitments.
+		TooManyBids,
+		/// The account has no commitment for this auction.
+		NoCommitment,
+		/// The bid was already revealed.
+		AlreadyRevealed,
+		/// The revealed amount and salt do not match the commitment.
+		InvalidReveal,
+		/// The collectible is currently being raffled.
+		InRaffle,
+		/// There is no raffle for this collectible.
+		NoRaffle,
+		/// Raffle duration and ticket counts must be non-zero, with `min_tickets <= max_tickets`
+		/// and `max_tickets` within `MaxRaffleTickets`.
+		InvalidRaffle,
+		/// The raffle no longer sells tickets.
+		RaffleEnded,
+		/// The raffle is still selling tickets.
+		RaffleNotFinished,
+		/// Not enough tickets are left.
+		SoldOut,
+		/// The raffle owner cannot buy tickets.
+		RaffleOwnerCannotEnter,
+		/// The collectible is not a hidden mystery box.
+		NotHidden,
+		/// The reveal block has not been reached yet.
+		RevealTooEarly,
+		/// The randomness source has not produced output unknown at mint time yet.
+		RandomnessNotReady,
+		/// Royalties are enforced and neither side of the transfer is an approved market.
+		TransferRestricted,
+		/// The account is not an approved market.
+		MarketNotApproved,
+		/// The collectible was minted or bought too recently to be listed again.
+		CooldownActive,
+		/// The collectible is held in escrow.
+		InEscrow,
+		/// There is no escrow for this collectible.
+		NoEscrow,
+		/// Only the buyer or the seller of an escrowed sale can do this.
+		NotEscrowParty,
+		/// The dispute window of this escrow has closed.
+		DisputeWindowClosed,
+		/// The escrow can still be disputed, only the buyer can release it early.
+		DisputeWindowOpen,
+		/// The escrow is under dispute.
+		Disputed,
+		/// There is no dispute for this collectible.
+		NoDispute,
+		/// Only jurors can vote on disputes.
+		NotJuror,
+		/// The juror already voted on this dispute.
+		AlreadyVoted,
+		/// Voting on the dispute has ended.
+		VotingClosed,
+		/// No majority was reached and voting is still open.
+		DisputeUnresolved,
+		/// Trades of this value require both parties to have a verified identity.
+		IdentityRequired,
+		/// The listing is older than `MaxListingDuration`.
+		ListingExpired,
+		/// The collectible is not listed or its listing has not expired yet.
+		ListingNotExpired,
+		/// CIDs must be non-empty and alphanumeric.
+		InvalidCid,
+		/// The collectible's metadata is not waiting to be pinned.
+		NotPinPending,
+		/// `MaxMintsPerBlock` collectibles were already minted in this block.
+		MintRateExceeded,
+		/// The recipient does not accept collectibles minted by others.
+		RecipientNotAccepting,
+		/// Fewer collectibles are waiting to be claimed than requested.
+		NoPendingMints,
+		/// The collectible is staked or pledged as collateral.
+		Frozen,
+		/// The collectible is not offered for rent.
+		NotForRent,
+		/// Rent must be non-zero.
+		InvalidRentalRate,
+		/// The owner cannot rent their own collectible.
+		OwnerCannotRent,
+		/// The prepayment does not cover a single block of rent.
+		InsufficientPrepayment,
+		/// The collectible is rented out.
+		Rented,
+		/// There is no rental for this collectible.
+		NotRented,
+		/// Only the owner or the renter of a rental can do this.
+		NotRentalParty,
+		/// The renter's prepayment has run out.
+		RentalExpired,
+		/// The collectible is being paid off in installments.
+		InInstallments,
+		/// A sale must be split into at least two and at most `MaxInstallments` installments.
+		InvalidInstallments,
+		/// There is no installment plan for this collectible.
+		NoInstallmentPlan,
+		/// Only the buyer of an installment plan can pay it.
+		NotInstallmentBuyer,
+		/// The installment was not paid in time.
+		InstallmentOverdue,
+		/// The next installment is not overdue yet.
+		InstallmentNotDue,
+		/// `MaxDeadlinesPerBlock` installments, time-locked transfers or auctions already fall due
+		/// in that block.
+		TooManyDeadlines,
+		/// The collectible is offered in the order book.
+		InOrderBook,
+		/// That side of the order book already holds `MaxBookDepth` orders.
+		BookFull,
+		/// Orders need a non-zero price and quantity.
+		InvalidOrder,
+		/// There is no such order in the book.
+		NoOrder,
+		/// Only the account that placed an order can cancel it.
+		NotOrderOwner,
+		/// Listings must be priced at `MinListingPrice` or more, and above zero.
+		PriceTooLow,
+		/// Listings must be priced at `MaxPrice` or less.
+		PriceTooHigh,
+		/// The floor index is not being rebuilt.
+		NoRebuild,
+		/// The escrow has not timed out yet.
+		EscrowNotExpired,
+		/// Sale receipts and founder badges stay with the account they were issued to.
+		Soulbound,
+		/// The pricing policy split a sale into parts that do not add up to its price.
+		InvalidSplit,
+		/// The collectible is listed above the most the buyer would pay.
+		AboveMaxPrice,
+		/// The collectible from the other chain already has a derivative here.
+		ForeignAlreadyRegistered,
+		/// The collectible did not come from another chain.
+		NotForeign,
+		/// Collectibles from other chains are redeemed, not burned.
+		ForeignCollectible,
+		/// Only mystery boxes are minted hidden.
+		HiddenColor,
+		/// The collectible is waiting for a time-locked transfer.
+		InTimedTransfer,
+		/// Time-locked transfers complete at a later block.
+		TransferNotInFuture,
+		/// The collectible has no time-locked transfer waiting.
+		NoTimedTransfer,
+		/// The owner has not named an heir.
+		NoHeir,
+		/// Only the heir the owner named can claim.
+		NotHeir,
+		/// Heirs must be named with at least `MinInactivityPeriod` blocks of inactivity.
+		InactivityPeriodTooShort,
+		/// The owner was active within their inactivity period.
+		OwnerActive,
+		/// The heir already started claiming.
+		ClaimInProgress,
+		/// The heir has not started claiming.
+		NoInheritanceClaim,
+		/// The owner can still veto the claim.
+		GracePeriodOpen,
+		/// Charity shares must be above zero and go to someone other than the seller.
+		InvalidCharity,
+		/// Listings that give to charity can only be bought outright.
+		CharityListing,
+		/// Only the creator of the collectible can do this.
+		NotCreator,
+		/// The royalty is above `MaxRoyalty`.
+		RoyaltyTooHigh,
+		/// The sale would take the buyer or the seller past their trading limit.
+		TradingLimitExceeded,
+		/// Licenses must be printable SPDX-style identifiers or expressions.
+		InvalidLicense,
+		/// Metadata schema versions must increase.
+		StaleMetadataVersion,
+		/// The auction is no longer accepting bids.
+		AuctionEnded,
+		/// There is no collection with this id.
+		NoCollection,
+		/// Only the owner of the collection can do this.
+		NotCollectionOwner,
+		/// The collection has reached its maximum supply.
+		CollectionSoldOut,
+		/// Nobody is approved to manage the collectible.
+		NoApproval,
+		/// Owners need no approval to manage their own collectibles.
+		ApproveSelf,
+		/// The bidder has no offer on the collectible.
+		NoOffer,
+		/// The collectible has `MaxOffers` standing offers already.
+		TooManyOffers,
+		/// The collectible has no metadata stored on chain.
+		NoMetadata,
+		/// The collectible has no attribute with this key.
+		NoAttribute,
+		/// The collectible already has `MaxAttributes` attributes.
+		TooManyAttributes,
+		/// The collectible is already frozen for this reason.
+		AlreadyFrozen,
+		/// The collectible is not frozen for this reason.
+		NotFrozen,
+		/// The collectible is offered in a pending swap.
+		InSwap,
+		/// There is no pending swap offering this collectible.
+		NoSwap,
+		/// A swap's deadline must be in the future.
+		InvalidSwapDeadline,
+		/// The swap's deadline has passed.
+		SwapExpired,
+		/// The witness does not match the collection.
+		BadWitness,
+		/// The collection is being destroyed.
+		CollectionDestroying,
+		/// The collection is not being destroyed.
+		CollectionNotDestroying,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		CollectibleCreated { collectible: u64, owner: T::AccountId },
+		TransferSucceeded { from: T::AccountId, to: T::AccountId, collectible: u64 },
+		PriceSet { collectible: u64, price: Option<BalanceOf<T>> },
+		/// `charity` is the beneficiary of a listing that gives to charity and what they got out
+		/// of the seller's part.
+		Sold {
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			collectible: u64,
+			price: BalanceOf<T>,
+			charity: Option<(T::AccountId, BalanceOf<T>)>,
+		},
+		SealedAuctionStarted {
+			collectible: u64,
+			seller: T::AccountId,
+			commit_end: BlockNumberFor<T>,
+			reveal_end: BlockNumberFor<T>,
+		},
+		BidCommitted { collectible: u64, bidder: T::AccountId },
+		BidRevealed { collectible: u64, bidder: T::AccountId, amount: BalanceOf<T> },
+		CommitmentSlashed { collectible: u64, bidder: T::AccountId, amount: BalanceOf<T> },
+		SealedAuctionSettled {
+			collectible: u64,
+			winner: Option<T::AccountId>,
+			price: Option<BalanceOf<T>>,
+		},
+		RaffleStarted {
+			collectible: u64,
+			owner: T::AccountId,
+			ticket_price: BalanceOf<T>,
+			end: BlockNumberFor<T>,
+		},
+		TicketsBought { collectible: u64, buyer: T::AccountId, first_ticket: u32, count: u32 },
+		RaffleWon { collectible: u64, winner: T::AccountId, ticket: u32, proceeds: BalanceOf<T> },
+		/// Too few tickets were sold, every ticket was refunded.
+		RaffleCancelled { collectible: u64, tickets_sold: u32 },
+		MysteryBoxMinted { collectible: u64, owner: T::AccountId, reveal_at: BlockNumberFor<T> },
+		CollectibleRevealed {
+			collectible: u64,
+			color: Color,
+			seed: T::Hash,
+			known_since: BlockNumberFor<T>,
+		},
+		RoyaltyEnforcementSet { enforced: bool },
+		MarketApproved { market: T::AccountId },
+		MarketRemoved { market: T::AccountId },
+		TransferCooldownSet { blocks: BlockNumberFor<T> },
+		EscrowOpened {
+			collectible: u64,
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			price: BalanceOf<T>,
+			dispute_deadline: BlockNumberFor<T>,
+		},
+		EscrowReleased { collectible: u64, seller: T::AccountId, buyer: T::AccountId, price: BalanceOf<T> },
+		EscrowRefunded { collectible: u64, buyer: T::AccountId, price: BalanceOf<T> },
+		DisputeRaised { collectible: u64, raised_by: T::AccountId, voting_end: BlockNumberFor<T> },
+		DisputeVoted { collectible: u64, juror: T::AccountId, for_buyer: bool },
+		DisputeResolved { collectible: u64, for_buyer: bool, deposit_slashed: bool },
+		/// An expired listing was cleared.
+		ListingExpired { collectible: u64 },
+		/// The owner took a collectible off the market.
+		ListingCancelled { collectible: u64, owner: T::AccountId },
+		/// Governance took an abusive listing off the market and slashed its deposit.
+		ListingFlagged { collectible: u64, owner: T::AccountId, slashed: BalanceOf<T> },
+		/// The owner committed to a metadata hash, or cleared it with `None`.
+		MetadataHashSet { collectible: u64, hash: Option<[u8; 32]> },
+		MetadataCidSet { collectible: u64, cid: BoundedVec<u8, T::MaxCidLength> },
+		/// The offchain worker pinned the collectible's metadata.
+		MetadataPinned { collectible: u64 },
+		/// A collectible minted for `to` waits for `to` to claim it.
+		MintPending { to: T::AccountId, minter: T::AccountId },
+		MintAcceptanceSet { who: T::AccountId, accept: bool },
+		/// The owner offered a collectible for rent, or withdrew the offer with `None`.
+		RentalOffered { collectible: u64, rate: Option<BalanceOf<T>> },
+		Rented {
+			collectible: u64,
+			owner: T::AccountId,
+			renter: T::AccountId,
+			rate: BalanceOf<T>,
+			prepaid: BalanceOf<T>,
+		},
+		RentalToppedUp { collectible: u64, amount: BalanceOf<T> },
+		/// Accrued rent was paid to the owner.
+		RentPaid { collectible: u64, owner: T::AccountId, amount: BalanceOf<T> },
+		/// The rental ended and what was left of the prepayment went back to the renter.
+		RentalEnded { collectible: u64, renter: T::AccountId, refunded: BalanceOf<T> },
+		InstallmentPlanOpened {
+			collectible: u64,
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			price: BalanceOf<T>,
+			installments: u32,
+		},
+		InstallmentPaid {
+			collectible: u64,
+			amount: BalanceOf<T>,
+			paid: u32,
+			due: BlockNumberFor<T>,
+		},
+		/// The buyer missed an installment. The seller keeps the item and `forfeited`.
+		InstallmentPlanDefaulted {
+			collectible: u64,
+			buyer: T::AccountId,
+			forfeited: BalanceOf<T>,
+			refunded: BalanceOf<T>,
+		},
+		/// A buy order was placed for what it could not fill on placement.
+		BuyOrderPlaced { order: u64, bidder: T::AccountId, price: BalanceOf<T>, quantity: u32 },
+		SellOrderPlaced { collectible: u64, seller: T::AccountId, price: BalanceOf<T> },
+		/// A buy and a sell order crossed. `price` is that of the order already in the book.
+		OrdersMatched {
+			collectible: u64,
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			price: BalanceOf<T>,
+		},
+		BuyOrderCancelled { order: u64, cancelled: u32, remaining: u32 },
+		SellOrderCancelled { collectible: u64 },
+		/// Several collectibles of `owner` were repriced at once, each to the price given.
+		PricesSet {
+			owner: T::AccountId,
+			prices: BoundedVec<(u64, Option<BalanceOf<T>>), T::MaximumOwned>,
+		},
+		/// `owner` was recorded as holding a collectible that no longer exists.
+		StaleReferenceRemoved { owner: T::AccountId, collectible: u64 },
+		/// The sale from `seller` to `buyer` closed a circle of trades. The collectible no
+		/// longer counts towards volume and floor statistics.
+		WashTradeFlagged { collectible: u64, seller: T::AccountId, buyer: T::AccountId },
+		/// An escrow timed out and `depositor` took back the `amount` it held from them.
+		EscrowReclaimed { collectible: u64, depositor: T::AccountId, amount: BalanceOf<T> },
+		/// `creator` switched receipts for sales of the collectibles they minted on or off.
+		SaleReceiptsSet { creator: T::AccountId, enabled: bool },
+		/// `buyer` was issued `receipt` for buying `collectible`.
+		ReceiptIssued { receipt: u64, collectible: u64, buyer: T::AccountId },
+		FounderBadgeLimitSet { limit: u32 },
+		/// `minter` was among the first to mint and was awarded founder badge `badge`.
+		FounderBadgeAwarded { minter: T::AccountId, badge: u64 },
+		/// `owner` was minted `collectible` for collectible `original_id` arriving from `chain`.
+		ForeignRegistered { collectible: u64, chain: u32, original_id: u64, owner: T::AccountId },
+		/// `owner` burned `collectible` to have collectible `original_id` released to them on
+		/// `chain`.
+		ForeignRedeemed { collectible: u64, chain: u32, original_id: u64, owner: T::AccountId },
+		/// `from` scheduled `collectible` to go to `to` at block `at`.
+		TransferScheduled {
+			collectible: u64,
+			from: T::AccountId,
+			to: T::AccountId,
+			at: BlockNumberFor<T>,
+		},
+		/// `from` called off the scheduled transfer of `collectible`.
+		ScheduledTransferCancelled { collectible: u64, from: T::AccountId },
+		/// The scheduled transfer of `collectible` could not complete, for example because `to`
+		/// owns the maximum already. `from` keeps it.
+		ScheduledTransferFailed { collectible: u64, from: T::AccountId, to: T::AccountId },
+		/// `owner` named `heir`, or stopped naming one with `None`.
+		HeirSet { owner: T::AccountId, heir: Option<T::AccountId> },
+		/// The heir of `owner` found them active, so their inactivity period starts over.
+		OwnerSeenActive { owner: T::AccountId },
+		/// `heir` started claiming the collectibles of `owner`, who can veto until `completes_at`.
+		InheritanceClaimStarted {
+			owner: T::AccountId,
+			heir: T::AccountId,
+			completes_at: BlockNumberFor<T>,
+		},
+		/// `owner` called off their heir's claim.
+		InheritanceVetoed { owner: T::AccountId },
+		/// `heir` took over `collectibles` of the collectibles of `owner`.
+		InheritanceClaimed { owner: T::AccountId, heir: T::AccountId, collectibles: u32 },
+		/// The listing of `collectible` gives `share` of its price to `beneficiary`.
+		CharitySet { collectible: u64, beneficiary: T::AccountId, share: Percent },
+		/// `creator` set the royalty on the collectibles they minted, or removed it with `None`.
+		DefaultRoyaltySet { creator: T::AccountId, royalty: Option<Perbill> },
+		/// The creator of `collectible` overrode its royalty, or went back to their default
+		/// with `None`.
+		ItemRoyaltySet { collectible: u64, royalty: Option<Perbill> },
+		/// `who` set a trading limit on themselves, or lifted theirs with `None`, applying from
+		/// `effective_at`.
+		TradingLimitSet {
+			who: T::AccountId,
+			limit: Option<TradingLimit<BalanceOf<T>>>,
+			effective_at: BlockNumberFor<T>,
+		},
+		/// A trading limit was imposed on `who`, or lifted with `None`.
+		TradingLimitImposed { who: T::AccountId, limit: Option<TradingLimit<BalanceOf<T>>> },
+		/// `collectible` was minted under `license`.
+		LicenseAttached { collectible: u64, license: BoundedVec<u8, T::MaxLicenseLength> },
+		/// The metadata of the collectibles `creator` minted follows schema `version` from now on.
+		MetadataVersionPublished { creator: T::AccountId, version: u16 },
+		/// `collectibles` of the collectibles of `old`, and all of its orders, were moved to
+		/// `new`. `remaining` collectibles are left to move.
+		OwnerRemapped { old: T::AccountId, new: T::AccountId, collectibles: u32, remaining: u32 },
+		/// `from` sent `collectibles` collectibles to their recipients in one go.
+		Distributed { from: T::AccountId, collectibles: u32 },
+		AuctionStarted {
+			collectible: u64,
+			seller: T::AccountId,
+			reserve: BalanceOf<T>,
+			end: BlockNumberFor<T>,
+		},
+		BidPlaced { collectible: u64, bidder: T::AccountId, amount: BalanceOf<T> },
+		AuctionSettled {
+			collectible: u64,
+			winner: Option<T::AccountId>,
+			price: Option<BalanceOf<T>>,
+		},
+		/// The auction of `collectible` ended but could not settle. It stays as it is until
+		/// somebody settles it.
+		AuctionSettlementFailed { collectible: u64 },
+		CollectionCreated {
+			collection: CollectionId,
+			owner: T::AccountId,
+			max_supply: Option<u32>,
+			minting: CollectionMinting,
+		},
+		/// The metadata of `collection` was set, or cleared with `None`.
+		CollectionMetadataSet {
+			collection: CollectionId,
+			metadata: Option<BoundedVec<u8, T::MaxCidLength>>,
+		},
+		/// `collection` is being destroyed, with `items` collectibles left in it.
+		CollectionDestroying { collection: CollectionId, items: u32 },
+		CollectionDestroyed { collection: CollectionId },
+		/// `collectible` was released from `collection`, which is being destroyed, and belongs
+		/// to no collection any more.
+		CollectibleOrphaned { collectible: u64, collection: CollectionId },
+		/// `collectible` was minted into `collection`.
+		MintedIntoCollection { collectible: u64, collection: CollectionId },
+		CollectionSettingsSet {
+			collection: CollectionId,
+			settings: CollectionSettings<BlockNumberFor<T>>,
+		},
+		/// The collectibles of `collection` follow metadata schema `version` from now on.
+		CollectionMetadataVersionPublished { collection: CollectionId, version: u16 },
+		/// `minter` earned founder badge `badge` of `collection` by minting into it.
+		CollectionFounderBadgeAwarded {
+			collection: CollectionId,
+			minter: T::AccountId,
+			badge: u64,
+		},
+		/// `owner` approved `delegate` to transfer or list `collectible`.
+		Approved { collectible: u64, owner: T::AccountId, delegate: T::AccountId },
+		ApprovalCancelled { collectible: u64, owner: T::AccountId },
+		/// `owner` approved `operator` to transfer or list any of their collectibles, or
+		/// withdrew that approval.
+		ApprovalForAll { owner: T::AccountId, operator: T::AccountId, approved: bool },
+		/// `bidder` offered `amount` for `collectible`, replacing any offer they made before.
+		OfferMade { collectible: u64, bidder: T::AccountId, amount: BalanceOf<T> },
+		OfferAccepted {
+			collectible: u64,
+			seller: T::AccountId,
+			bidder: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		OfferWithdrawn { collectible: u64, bidder: T::AccountId },
+		/// `by` sent `collectibles` collectibles to `to` in one go.
+		TransferredMany { by: T::AccountId, to: T::AccountId, collectibles: u32 },
+		/// `minter` minted `count` collectibles for `to` in one go.
+		MintedBatch { minter: T::AccountId, to: T::AccountId, count: u32 },
+		/// The owner stored `metadata` on chain for the collectible.
+		MetadataSet { collectible: u64, metadata: BoundedVec<u8, T::MetadataLimit> },
+		MetadataCleared { collectible: u64 },
+		AttributeSet {
+			collectible: u64,
+			key: BoundedVec<u8, T::AttributeKeyLimit>,
+			value: BoundedVec<u8, T::AttributeValueLimit>,
+		},
+		AttributeCleared { collectible: u64, key: BoundedVec<u8, T::AttributeKeyLimit> },
+		/// The collectible was staked by its owner, or pledged as collateral by `FreezeOrigin`.
+		CollectibleFrozen { collectible: u64, reason: FreezeReason },
+		CollectibleThawed { collectible: u64, reason: FreezeReason },
+		SwapCreated {
+			offered: u64,
+			desired: u64,
+			creator: T::AccountId,
+			sweetener: Option<BalanceOf<T>>,
+			deadline: BlockNumberFor<T>,
+		},
+		/// `claimer` gave `desired` for `offered` and the sweetener.
+		SwapClaimed {
+			offered: u64,
+			desired: u64,
+			creator: T::AccountId,
+			claimer: T::AccountId,
+			sweetener: Option<BalanceOf<T>>,
+		},
+		SwapCancelled { offered: u64 },
+		/// The swap was not claimed before its deadline.
+		SwapExpired { offered: u64 },
+		/// The collectible was burned and its deposits released.
+		CollectibleBurned { collectible: u64, owner: T::AccountId },
+		/// The collectible's listing was dropped because it changed hands or was burned.
+		PriceRemoved { collectible: u64 },
+	}
+
+	#[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            MintsThisBlock::<T>::kill();
+			let transfers = Self::complete_timed_transfers(n);
+			let auctions = Self::settle_due_auctions(n);
+			let sealed = Self::queue_ended_sealed_auctions(n);
+			T::WeightInfo::on_initialize()
+				.saturating_add(transfers)
+				.saturating_add(auctions)
+				.saturating_add(sealed)
+        }
+
+		fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut used = Self::enforce_installment_deadlines(n, remaining_weight);
+			let left = remaining_weight.saturating_sub(used);
+			used.saturating_accrue(Self::expire_swaps(n, left));
+			let left = remaining_weight.saturating_sub(used);
+			used.saturating_accrue(Self::rebuild_floor_indices(left));
+			let left = remaining_weight.saturating_sub(used);
+			used.saturating_accrue(Self::sweep_stale_references(left));
+			let left = remaining_weight.saturating_sub(used);
+			used.saturating_add(Self::scan_highest_price(left))
+		}
+
+		fn offchain_worker(n: BlockNumberFor<T>) {
+			Self::pin_queued_metadata(n);
+			Self::settle_ended_auctions(n);
+		}
+	}
+
+	#[pallet::tasks_experimental]
+	impl<T: Config> Pallet<T> {
+		/// Scan the next `FloorRebuildBatch` collectibles into the floor index of `scope`, the
+		/// pallet-wide one for `None`, while it is being rebuilt. `on_idle` makes progress on its
+		/// own when blocks leave room; this lets anyone pay to finish a rebuild sooner.
+		#[pallet::task_list([None].into_iter().chain(Color::ALL.into_iter().map(Some)))]
+		#[pallet::task_condition(|scope| Pallet::<T>::floor_rebuild_pending(scope))]
+		#[pallet::task_weight(T::WeightInfo::rebuild_floor_index(T::FloorRebuildBatch::get()))]
+		#[pallet::task_index(0)]
+		pub fn rebuild_floor_index(scope: Option<Color>) -> DispatchResult {
+			ensure!(Self::floor_rebuild_pending(scope), Error::<T>::NoRebuild);
+			Self::do_rebuild_floor_index(scope, T::FloorRebuildBatch::get());
+			Ok(())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			match call {
+				Call::confirm_pin { unique_id } => Self::validate_confirm_pin(source, *unique_id),
+				Call::settle_auction { unique_id } => {
+					let end = Auctions::<T>::get(unique_id).map(|auction| auction.end);
+					Self::validate_settlement(source, *unique_id, end)
+				},
+				Call::settle_sealed_auction { unique_id } => {
+					let end =
+						SealedAuctions::<T>::get(unique_id).map(|auction| auction.reveal_end);
+					Self::validate_settlement(source, *unique_id, end)
+				},
+				_ => InvalidTransaction::Call.into(),
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Mint a collectible to `to`. Unless `to` is the caller or accepts mints from others,
+		/// the collectible is only recorded as pending until `to` claims it. The caller pays the
+		/// mint deposit and counts against the mint limits either way.
+		#[pallet::weight(T::WeightInfo::create_collectible())]
+		pub fn create_collectible(origin: OriginFor<T>, to: T::AccountId) -> DispatchResult {
+			let minter = ensure_signed(origin)?;
+			Self::do_create_collectible(minter, to)
+		}
+
+		/// Transfer a collectible to another account.
+		/// Any account that holds a collectible can send it to another account, and so can the
+		/// accounts its owner approved.
+		/// Transfer resets the price of the collectible, marking it not for sale.
+		#[pallet::weight(T::WeightInfo::transfer())]
+		pub fn transfer(
+			origin: OriginFor<T>,
+			to: T::AccountId,
+			unique_id: u64,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let collectible = CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
+			let from = collectible.owner;
+			ensure!(Self::may_manage(&who, &from, unique_id), Error::<T>::NotOwner);
+			Self::ensure_transfer_allowed(unique_id, &from, &to)?;
+			Self::do_transfer(unique_id, to)?;
+			Ok(())
+		}
+
+		/// Burn a collectible of the caller, releasing every deposit held for it and every
+		/// offer standing on it.
+		#[pallet::weight(T::WeightInfo::burn())]
+		pub fn burn(origin: OriginFor<T>, unique_id: u64) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+			let collectible = CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
+			ensure!(collectible.owner == from, Error::<T>::NotOwner);
+			ensure!(!ForeignSources::<T>::contains_key(&unique_id), Error::<T>::ForeignCollectible);
+			// Receipts can only be burned, so nothing else ever freezes one.
+			if !Self::is_frozen(unique_id, FreezeReason::Soulbound) {
+				Self::ensure_unlocked(unique_id)?;
+			}
+			Self::do_burn(unique_id, &from)
+		}
+
+		/// Update the collectible price and write to storage. The caller must be `owner` or an
+		/// account `owner` approved; the listing deposit is taken from `owner` either way.
+		#[pallet::weight(T::WeightInfo::set_price())]
+		pub fn set_price(
+			origin: OriginFor<T>,
+			owner: T::AccountId,
+			unique_id: u64,
+			new_price: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::may_manage(&who, &owner, unique_id), Error::<T>::NotOwner);
+			Self::do_set_price(&owner, unique_id, new_price)?;
+			Self::deposit_event(Event::PriceSet { collectible: unique_id, price: new_price });
+			Ok(())
+		}
+
+		/// Buy a collectible at the price set by its owner, paid by the caller. Fails if the price
+		/// is above `max_price`, so a listing repriced before the purchase is included cannot
+		/// charge the buyer more than they agreed to. Payments that are not made and a receipt
+		/// that is not minted are refunded.
+		#[pallet::weight(T::WeightInfo::buy_collectible())]
+		pub fn buy_collectible(
+			origin: OriginFor<T>,
+			unique_id: u64,
+			max_price: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let buyer = ensure_signed(origin)?;
+			Self::do_buy_collectible(unique_id, buyer, max_price)
+		}
+
+		/// Put a collectible up for a sealed-bid auction.
+		/// Bidders commit to a hidden bid for `commit_period` blocks and then reveal it during
+		/// the following `reveal_period` blocks. The highest revealed bid of at least `min_bid` wins.
+		#[pallet::weight(T::WeightInfo::start_sealed_auction())]
+		pub fn start_sealed_auction(
+			origin: OriginFor<T>,
+			unique_id: u64,
+			min_bid: BalanceOf<T>,
+			commit_period: BlockNumberFor<T>,
+			reveal_period: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			Self::do_start_sealed_auction(seller, unique_id, min_bid, commit_period, reveal_period)
+		}
+
+		/// Commit to a sealed bid. `commitment` must equal
+		/// [`Pallet::sealed_bid_commitment`] of the bid that will be revealed.
+		/// Reserves `SealedBidDeposit` from the bidder.
+		#[pallet::weight(T::WeightInfo::commit_bid())]
+		pub fn commit_bid(origin: OriginFor<T>, unique_id: u64, commitment: T::Hash) -> DispatchResult {
+			let bidder = ensure_signed(origin)?;
+			Self::do_commit_bid(bidder, unique_id, commitment)
+		}
+
+		/// Reveal a previously committed bid. A bid that becomes the highest has its amount
+		/// held until the auction is settled or it is outbid.
+		#[pallet::weight(T::WeightInfo::reveal_bid())]
+		pub fn reveal_bid(
+			origin: OriginFor<T>,
+			unique_id: u64,
+			amount: BalanceOf<T>,
+			salt: [u8; 32],
+		) -> DispatchResult {
+			let bidder = ensure_signed(origin)?;
+			Self::do_reveal_bid(bidder, unique_id, amount, salt)
+		}
+
+		/// Settle a sealed-bid auction once its reveal phase is over. Callable by anyone, and
+		/// submitted unsigned by offchain workers once it is over.
+		#[pallet::weight(T::WeightInfo::settle_sealed_auction(T::MaxSealedBids::get()))]
+		pub fn settle_sealed_auction(
+			origin: OriginFor<T>,
+			unique_id: u64,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin.clone()).map(|_| ()).or_else(|_| ensure_none(origin))?;
+			Self::do_settle_sealed_auction(unique_id)
+		}
+
+		/// Raffle off a collectible. The item is locked until the raffle is drawn, which can
+		/// happen once `duration` blocks have passed.
+		#[pallet::weight(T::WeightInfo::start_raffle())]
+		pub fn start_raffle(
+			origin: OriginFor<T>,
+			unique_id: u64,
+			ticket_price: BalanceOf<T>,
+			min_tickets: u32,
+			max_tickets: u32,
+			duration: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::do_start_raffle(owner, unique_id, ticket_price, min_tickets, max_tickets, duration)
+		}
+
+		/// Buy `count` raffle tickets. The ticket price is held until the draw.
+		#[pallet::weight(T::WeightInfo::buy_tickets(*count))]
+		pub fn buy_tickets(origin: OriginFor<T>, unique_id: u64, count: u32) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+			Self::do_buy_tickets(buyer, unique_id, count)
+		}
+
+		/// Draw the winner of a finished raffle, or refund every ticket if `min_tickets` was
+		/// not reached. Callable by anyone.
+		#[pallet::weight(T::WeightInfo::draw_raffle(T::MaxRaffleTickets::get()))]
+		pub fn draw_raffle(origin: OriginFor<T>, unique_id: u64) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			Self::do_draw_raffle(unique_id)
+		}
+
+		/// Mint a mystery box: a collectible whose color stays hidden until it is revealed
+		/// `RevealDelay` blocks later.
+		#[pallet::weight(T::WeightInfo::mint_mystery_box())]
+		pub fn mint_mystery_box(origin: OriginFor<T>, to: T::AccountId) -> DispatchResult {
+			let minter = ensure_signed(origin)?;
+			ensure!(Self::accepts_mint_from(&minter, &to), Error::<T>::RecipientNotAccepting);
+			Self::do_mint_mystery_box(&minter, &to)?;
+			Ok(())
+		}
+
+		/// Reveal the color of a mystery box from randomness produced after it was minted.
+		/// Callable by anyone.
+		#[pallet::weight(T::WeightInfo::reveal())]
+		pub fn reveal(origin: OriginFor<T>, unique_id: u64) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_reveal(unique_id)
+		}
+
+		/// Switch royalty enforcement on or off. While enforced, plain transfers must involve an
+		/// approved market; sales through this pallet are unaffected. Collections can enforce
+		/// royalties on their own collectibles either way.
+		#[pallet::weight((T::WeightInfo::set_royalty_enforcement(), DispatchClass::Operational))]
+		pub fn set_royalty_enforcement(origin: OriginFor<T>, enforced: bool) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			RoyaltiesEnforced::<T>::put(enforced);
+			Self::deposit_event(Event::RoyaltyEnforcementSet { enforced });
+			Ok(())
+		}
+
+		/// Add an account to the approved market registry.
+		#[pallet::weight((T::WeightInfo::approve_market(), DispatchClass::Operational))]
+		pub fn approve_market(origin: OriginFor<T>, market: T::AccountId) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ApprovedMarkets::<T>::insert(&market, ());
+			Self::deposit_event(Event::MarketApproved { market });
+			Ok(())
+		}
+
+		/// Remove an account from the approved market registry.
+		#[pallet::weight((T::WeightInfo::remove_market(), DispatchClass::Operational))]
+		pub fn remove_market(origin: OriginFor<T>, market: T::AccountId) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(ApprovedMarkets::<T>::contains_key(&market), Error::<T>::MarketNotApproved);
+			ApprovedMarkets::<T>::remove(&market);
+			Self::deposit_event(Event::MarketRemoved { market });
+			Ok(())
+		}
+
+		/// Set the number of blocks after a mint or purchase during which an item cannot be
+		/// listed, auctioned or raffled. Zero disables the cooldown. Collections can set a
+		/// cooldown of their own in its place.
+		#[pallet::weight((T::WeightInfo::set_transfer_cooldown(), DispatchClass::Operational))]
+		pub fn set_transfer_cooldown(origin: OriginFor<T>, blocks: BlockNumberFor<T>) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			TransferCooldown::<T>::put(blocks);
+			Self::deposit_event(Event::TransferCooldownSet { blocks });
+			Ok(())
+		}
+
+		/// Buy a listed collectible through escrow. The price is held from the buyer and the
+		/// item is locked until the sale is released or a dispute is resolved.
+		#[pallet::weight(T::WeightInfo::buy_with_escrow())]
+		pub fn buy_with_escrow(origin: OriginFor<T>, unique_id: u64) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+			Self::do_buy_with_escrow(buyer, unique_id)
+		}
+
+		/// Complete an escrowed sale. The buyer can release at any time, anybody else once the
+		/// dispute window has closed without a dispute.
+		#[pallet::weight(T::WeightInfo::release_escrow())]
+		pub fn release_escrow(origin: OriginFor<T>, unique_id: u64) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_release_escrow(who, unique_id)
+		}
+
+		/// Dispute an escrowed sale as its buyer or seller. Reserves `DisputeDeposit`.
+		#[pallet::weight(T::WeightInfo::raise_dispute())]
+		pub fn raise_dispute(origin: OriginFor<T>, unique_id: u64) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_raise_dispute(who, unique_id)
+		}
+
+		/// Vote on a dispute as a juror, siding with the buyer (refund) or the seller (sale).
+		#[pallet::weight(T::WeightInfo::vote_dispute())]
+		pub fn vote_dispute(origin: OriginFor<T>, unique_id: u64, for_buyer: bool) -> DispatchResult {
+			let juror = ensure_signed(origin)?;
+			Self::do_vote_dispute(juror, unique_id, for_buyer)
+		}
+
+		/// Resolve a dispute once a majority of jurors agrees or voting has ended.
+		/// Callable by anyone.
+		#[pallet::weight(T::WeightInfo::resolve_dispute())]
+		pub fn resolve_dispute(origin: OriginFor<T>, unique_id: u64) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_resolve_dispute(unique_id)
+		}
+
+		/// Take a listed collectible off the market.
+		#[pallet::weight(T::WeightInfo::cancel_listing())]
+		pub fn cancel_listing(origin: OriginFor<T>, unique_id: u64) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let mut collectible = CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
+			ensure!(collectible.owner == owner, Error::<T>::NotOwner);
+			ensure!(collectible.price.take().is_some(), Error::<T>::NotForSale);
+			CollectibleMap::<T>::insert(&unique_id, collectible);
+			ListedAt::<T>::remove(&unique_id);
+			Self::note_unlisted(unique_id);
+			Self::release_listing_deposit(unique_id)?;
+			Self::deposit_event(Event::ListingCancelled { collectible: unique_id, owner });
+			Ok(())
+		}
+
+		/// Clear a listing that is older than `MaxListingDuration`. Callable by anyone.
+		#[pallet::weight(T::WeightInfo::clear_expired_listing())]
+		pub fn clear_expired_listing(origin: OriginFor<T>, unique_id: u64) -> DispatchResult {
+			ensure_signed(origin)?;
+			let mut collectible = CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
+			ensure!(
+				collectible.price.is_some() && Self::listing_expired(unique_id),
+				Error::<T>::ListingNotExpired
+			);
+			collectible.price = None;
+			CollectibleMap::<T>::insert(&unique_id, collectible);
+			ListedAt::<T>::remove(&unique_id);
+			Self::note_unlisted(unique_id);
+			Self::release_listing_deposit(unique_id)?;
+			Self::deposit_event(Event::ListingExpired { collectible: unique_id });
+			Ok(())
+		}
+
+		/// Commit to the blake2-256 `hash` of the collectible's metadata, which is stored
+		/// off-chain. `None` removes the commitment.
+		#[pallet::weight(T::WeightInfo::set_metadata_hash())]
+		pub fn set_metadata_hash(
+			origin: OriginFor<T>,
+			unique_id: u64,
+			hash: Option<[u8; 32]>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::do_set_metadata_hash(owner, unique_id, hash)
+		}
+
+		/// Set the IPFS CID of the collectible's metadata and queue it for pinning.
+		#[pallet::weight(T::WeightInfo::set_metadata_cid())]
+		pub fn set_metadata_cid(
+			origin: OriginFor<T>,
+			unique_id: u64,
+			cid: BoundedVec<u8, T::MaxCidLength>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::do_set_metadata_cid(owner, unique_id, cid)
+		}
+
+		/// Accept or refuse collectibles minted into the caller's account by others.
+		#[pallet::weight(T::WeightInfo::set_accept_mints())]
+		pub fn set_accept_mints(origin: OriginFor<T>, accept: bool) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			if accept {
+				AcceptsMints::<T>::insert(&who, ());
+			} else {
+				AcceptsMints::<T>::remove(&who);
+			}
+			Self::deposit_event(Event::MintAcceptanceSet { who, accept });
+			Ok(())
+		}
+
+		/// Mint `count` of the collectibles others minted for the caller, oldest first. Their
+		/// minters stay their creators and keep paying their deposits.
+		#[pallet::weight(T::WeightInfo::claim_mints(*count))]
+		pub fn claim_mints(origin: OriginFor<T>, count: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_claim_mints(who, count)
+		}
+
+		/// Record that the offchain worker pinned the collectible's metadata.
+		#[pallet::weight(T::WeightInfo::confirm_pin())]
+		pub fn confirm_pin(origin: OriginFor<T>, unique_id: u64) -> DispatchResult {
+			ensure_none(origin)?;
+			Self::do_confirm_pin(unique_id)
+		}
+
+		/// Take a spam or abusive listing off the market, slashing its deposit to `Slashed`.
+		#[pallet::weight((T::WeightInfo::flag_listing(), DispatchClass::Operational))]
+		pub fn flag_listing(origin: OriginFor<T>, unique_id: u64) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			Self::do_flag_listing(unique_id)
+		}
+
+		/// Offer a collectible for rent at `rate` per block, or withdraw the offer with `None`.
+		/// A running rental keeps the rate it started with.
+		#[pallet::weight(T::WeightInfo::offer_rental())]
+		pub fn offer_rental(
+			origin: OriginFor<T>,
+			unique_id: u64,
+			rate: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::do_offer_rental(owner, unique_id, rate)
+		}
+
+		/// Rent a collectible offered for rent. `prepaid` is held and the rent is streamed
+		/// out of it to the owner block by block; the item is locked while it is rented.
+		#[pallet::weight(T::WeightInfo::rent())]
+		pub fn rent(
+			origin: OriginFor<T>,
+			unique_id: u64,
+			prepaid: BalanceOf<T>,
+		) -> DispatchResult {
+			let renter = ensure_signed(origin)?;
+			Self::do_rent(renter, unique_id, prepaid)
+		}
+
+		/// Add to the prepayment of a rental that has not run out.
+		#[pallet::weight(T::WeightInfo::top_up_rental())]
+		pub fn top_up_rental(
+			origin: OriginFor<T>,
+			unique_id: u64,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let renter = ensure_signed(origin)?;
+			Self::do_top_up_rental(renter, unique_id, amount)
+		}
+
+		/// Pay the owner the rent accrued so far, ending the rental if the prepayment ran
+		/// out. Callable by anyone.
+		#[pallet::weight(T::WeightInfo::settle_rental())]
+		pub fn settle_rental(origin: OriginFor<T>, unique_id: u64) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_settle_rental(unique_id)
+		}
+
+		/// End a rental early as its owner or renter. The owner is paid for the blocks the
+		/// rental ran and the rest of the prepayment goes back to the renter.
+		#[pallet::weight(T::WeightInfo::end_rental())]
+		pub fn end_rental(origin: OriginFor<T>, unique_id: u64) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_end_rental(who, unique_id)
+		}
+
+		/// Buy a listed collectible in `installments` equal installments, the first of them
+		/// now. The item stays with the seller, locked, until the last one is paid.
+		#[pallet::weight(T::WeightInfo::buy_in_installments())]
+		pub fn buy_in_installments(
+			origin: OriginFor<T>,
+			unique_id: u64,
+			installments: u32,
+		) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+			Self::do_buy_in_installments(buyer, unique_id, installments)
+		}
+
+		/// Pay the next installment. Paying the last one completes the sale.
+		#[pallet::weight(T::WeightInfo::pay_installment())]
+		pub fn pay_installment(origin: OriginFor<T>, unique_id: u64) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+			Self::do_pay_installment(buyer, unique_id)
+		}
+
+		/// Default an installment plan whose next installment is overdue, should `on_idle` not
+		/// have got to it yet. Callable by anyone.
+		#[pallet::weight(T::WeightInfo::enforce_installment_default())]
+		pub fn enforce_installment_default(origin: OriginFor<T>, unique_id: u64) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_enforce_installment_default(unique_id)
+		}
+
+		/// Offer a collectible in the order book for at least `price`. It sells at once to the
+		/// best buy order at or above `price`, at that order's price; otherwise it stays frozen
+		/// in the book until it matches or is cancelled. `price` is bounded like a listing's.
+		#[pallet::weight(T::WeightInfo::place_sell_order())]
+		pub fn place_sell_order(
+			origin: OriginFor<T>,
+			unique_id: u64,
+			price: BalanceOf<T>,
+		) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			Self::do_place_sell_order(seller, unique_id, price)
+		}
+
+		/// Place an order to buy `quantity` collectibles, whichever they are, for up to `price`
+		/// each. It buys the cheapest items in the book at or below `price` at once, at their
+		/// asking prices, and the rest of the order stands with its price held.
+		#[pallet::weight(T::WeightInfo::place_buy_order((*quantity).min(T::MaxBookDepth::get())))]
+		pub fn place_buy_order(
+			origin: OriginFor<T>,
+			price: BalanceOf<T>,
+			quantity: u32,
+		) -> DispatchResult {
+			let bidder = ensure_signed(origin)?;
+			Self::do_place_buy_order(bidder, price, quantity)
+		}
+
+		/// Cancel up to `quantity` of the collectibles a buy order has yet to buy, releasing
+		/// their price.
+		#[pallet::weight(T::WeightInfo::cancel_buy_order())]
+		pub fn cancel_buy_order(origin: OriginFor<T>, order: u64, quantity: u32) -> DispatchResult {
+			let bidder = ensure_signed(origin)?;
+			Self::do_cancel_buy_order(bidder, order, quantity)
+		}
+
+		/// Take a collectible out of the order book.
+		#[pallet::weight(T::WeightInfo::cancel_sell_order())]
+		pub fn cancel_sell_order(origin: OriginFor<T>, unique_id: u64) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			Self::do_cancel_sell_order(seller, unique_id)
+		}
+
+		/// Set the price of several collectibles of the caller at once, listing or delisting
+		/// each as `set_price` would. Fails as a whole if any of them cannot be repriced.
+		#[pallet::weight(T::WeightInfo::set_prices(prices.len() as u32))]
+		pub fn set_prices(
+			origin: OriginFor<T>,
+			prices: BoundedVec<(u64, Option<BalanceOf<T>>), T::MaximumOwned>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			for (unique_id, new_price) in &prices {
+				Self::do_set_price(&owner, *unique_id, *new_price)?;
+				Self::deposit_event(Event::PriceSet { collectible: *unique_id, price: *new_price });
+			}
+			Self::deposit_event(Event::PricesSet { owner, prices });
+			Ok(())
+		}
+
+		/// Take back funds held by an escrowed sale or a sealed auction that timed out without
+		/// being settled: the price held from the buyer of an escrow, or the deposit and bid of a
+		/// sealed auction bidder. Reclaiming from an auction ends it for every bidder.
+		#[pallet::weight(T::WeightInfo::reclaim_escrow(T::MaxSealedBids::get()))]
+		pub fn reclaim_escrow(origin: OriginFor<T>, unique_id: u64) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_reclaim_escrow(who, unique_id)
+		}
+
+		/// Have every sale of a collectible the caller minted leave the buyer a soulbound receipt
+		/// of the sale, or stop doing so.
+		#[pallet::weight(T::WeightInfo::set_sale_receipts())]
+		pub fn set_sale_receipts(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+			let creator = ensure_signed(origin)?;
+			if enabled {
+				IssuesReceipts::<T>::insert(&creator, ());
+			} else {
+				IssuesReceipts::<T>::remove(&creator);
+			}
+			Self::deposit_event(Event::SaleReceiptsSet { creator, enabled });
+			Ok(())
+		}
+
+		/// Award founder badges to the first `limit` distinct minters, counting those awarded
+		/// already. Lowering the limit awards no more badges but takes none back. Collections
+		/// award founder badges of their own on top.
+		#[pallet::weight((T::WeightInfo::set_founder_badge_limit(), DispatchClass::Operational))]
+		pub fn set_founder_badge_limit(origin: OriginFor<T>, limit: u32) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			FounderBadgeLimit::<T>::put(limit);
+			Self::deposit_event(Event::FounderBadgeLimitSet { limit });
+			Ok(())
+		}
+
+		/// Mint `owner` a derivative of collectible `original_id`, which arrived from `chain`.
+		/// Each collectible of another chain has at most one derivative at a time.
+		#[pallet::weight(T::WeightInfo::register_foreign())]
+		pub fn register_foreign(
+			origin: OriginFor<T>,
+			chain: u32,
+			original_id: u64,
+			owner: T::AccountId,
+		) -> DispatchResult {
+			T::ForeignOrigin::ensure_origin(origin)?;
+			Self::do_register_foreign(chain, original_id, owner)
+		}
+
+		/// Burn a derivative of a collectible from another chain, to have the original released
+		/// to the caller on its own chain.
+		#[pallet::weight(T::WeightInfo::redeem_foreign())]
+		pub fn redeem_foreign(origin: OriginFor<T>, unique_id: u64) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::do_redeem_foreign(owner, unique_id)
+		}
+
+		/// Transfer a collectible to `to` at `block`. The item stays with the caller, frozen,
+		/// until the transfer completes at the start of that block, and the caller can call the
+		/// transfer off with `cancel_transfer_at` until then.
+		#[pallet::weight(T::WeightInfo::transfer_at())]
+		pub fn transfer_at(
+			origin: OriginFor<T>,
+			to: T::AccountId,
+			unique_id: u64,
+			block: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+			Self::ensure_transfer_allowed(unique_id, &from, &to)?;
+			Self::do_transfer_at(from, to, unique_id, block)
+		}
+
+		/// Call off the caller's time-locked transfer of a collectible before it completes.
+		#[pallet::weight(T::WeightInfo::cancel_transfer_at())]
+		pub fn cancel_transfer_at(origin: OriginFor<T>, unique_id: u64) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+			Self::do_cancel_transfer_at(from, unique_id)
+		}
 
-	use frame_support::traits::{Currency};
+		/// Name `heir` to take over the caller's collectibles once the caller has sent no
+		/// transaction for `inactivity_period` blocks, or stop naming one with `None`. Naming an
+		/// heir replaces the previous one and calls off their claim.
+		#[pallet::weight(T::WeightInfo::set_heir())]
+		pub fn set_heir(
+			origin: OriginFor<T>,
+			heir: Option<(T::AccountId, BlockNumberFor<T>)>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::do_set_heir(owner, heir)
+		}
 
-	type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+		/// Start claiming the collectibles of `owner` as their heir. If `owner` was active since
+		/// last seen, this only records it and their inactivity period starts over.
+		#[pallet::weight(T::WeightInfo::start_inheritance())]
+		pub fn start_inheritance(origin: OriginFor<T>, owner: T::AccountId) -> DispatchResult {
+			let heir = ensure_signed(origin)?;
+			Self::do_start_inheritance(heir, owner)
+		}
 
-	#[derive(Clone, Encode, Decode, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-	#[scale_info(skip_type_params(T))]
-	pub struct Collectible<T: Config> {
-		pub unique_id: u64,
-		pub price: Option<BalanceOf<T>>,
-		pub color: Color,
-		pub owner: T::AccountId,
-	}
+		/// Call off the claim the caller's heir started.
+		#[pallet::weight(T::WeightInfo::veto_inheritance())]
+		pub fn veto_inheritance(origin: OriginFor<T>) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::do_veto_inheritance(owner)
+		}
 
-	#[derive(Clone, Encode, Decode, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-	pub enum Color {
-		Red,
-		Yellow,
-		Blue,
-		Green
-	}
+		/// Take over the collectibles of `owner` once the claim has waited out
+		/// `InheritanceGracePeriod`. Frozen collectibles stay with `owner`.
+		#[pallet::weight(T::WeightInfo::complete_inheritance(T::MaximumOwned::get()))]
+		pub fn complete_inheritance(
+			origin: OriginFor<T>,
+			owner: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let heir = ensure_signed(origin)?;
+			let moved = Self::do_complete_inheritance(heir, owner)?;
+			Ok(Some(T::WeightInfo::complete_inheritance(moved)).into())
+		}
 
-    #[pallet::pallet]
-    pub struct Pallet<T>(_);
+		/// List a collectible at `price`, like `set_price`, with `share` of the price going to
+		/// `beneficiary` out of what the caller is paid when it sells. Such listings can only be
+		/// bought outright, not through escrow or in installments.
+		#[pallet::weight(T::WeightInfo::list_with_charity())]
+		pub fn list_with_charity(
+			origin: OriginFor<T>,
+			unique_id: u64,
+			price: BalanceOf<T>,
+			beneficiary: T::AccountId,
+			share: Percent,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::do_list_with_charity(owner, unique_id, price, beneficiary, share)
+		}
 
-    #[pallet::config]
-	pub trait Config: frame_system::Config {
-		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
-		type Currency: Currency<Self::AccountId>;
+		/// Set the royalty sales of the caller's collectibles pay them, or remove it with `None`.
+		/// Collectibles with a royalty of their own are unaffected.
+		#[pallet::weight(T::WeightInfo::set_default_royalty())]
+		pub fn set_default_royalty(
+			origin: OriginFor<T>,
+			royalty: Option<Perbill>,
+		) -> DispatchResult {
+			let creator = ensure_signed(origin)?;
+			Self::do_set_default_royalty(creator, royalty)
+		}
 
-		#[pallet::constant]
-		type MaximumOwned: Get<u32>;
-	}
+		/// Override the royalty of a collectible the caller minted, or go back to the caller's
+		/// default with `None`.
+		#[pallet::weight(T::WeightInfo::set_item_royalty())]
+		pub fn set_item_royalty(
+			origin: OriginFor<T>,
+			unique_id: u64,
+			royalty: Option<Perbill>,
+		) -> DispatchResult {
+			let creator = ensure_signed(origin)?;
+			Self::do_set_item_royalty(creator, unique_id, royalty)
+		}
 
-	#[pallet::storage]
-	pub(super) type CollectiblesCount<T: Config> = StorageValue<_, u64, ValueQuery>;
+		/// Cap what the caller trades per `TradingLimitWindow`, or lift their cap with `None`.
+		/// A stricter limit applies at once; a looser one only after a window.
+		#[pallet::weight(T::WeightInfo::set_trading_limit())]
+		pub fn set_trading_limit(
+			origin: OriginFor<T>,
+			limit: Option<TradingLimit<BalanceOf<T>>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_set_trading_limit(who, limit)
+		}
 
-	#[pallet::storage]
-	pub(super) type HighestPrice<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+		/// Impose a trading limit on `who` on top of their own, or lift it with `None`.
+		#[pallet::weight((T::WeightInfo::impose_trading_limit(), DispatchClass::Operational))]
+		pub fn impose_trading_limit(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			limit: Option<TradingLimit<BalanceOf<T>>>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			Self::do_impose_trading_limit(who, limit)
+		}
 
-	/// Maps the Collectible struct to the unique_id.
-	#[pallet::storage]
-	pub(super) type CollectibleMap<T: Config> = StorageMap<_, Twox64Concat, u64, Collectible<T>>;
+		/// Mint a collectible to `to` under `license`, which can never be changed. `to` must be
+		/// the caller or accept mints from others.
+		#[pallet::weight(T::WeightInfo::create_licensed_collectible())]
+		pub fn create_licensed_collectible(
+			origin: OriginFor<T>,
+			to: T::AccountId,
+			license: BoundedVec<u8, T::MaxLicenseLength>,
+		) -> DispatchResult {
+			let minter = ensure_signed(origin)?;
+			Self::do_create_licensed_collectible(minter, to, license)
+		}
 
-	/// Track the collectibles owned by each account.
-	#[pallet::storage]
-	pub(super) type OwnerOfCollectibles<T: Config> = StorageMap<
-		_,
-		Twox64Concat,
-		T::AccountId,
-		BoundedVec<u64, T::MaximumOwned>,
-		ValueQuery,
-	>;
+		/// Announce that the metadata of the collectibles the caller minted outside collections
+		/// follows schema `version`, which must be newer than the one they published before.
+		#[pallet::weight(T::WeightInfo::publish_metadata_version())]
+		pub fn publish_metadata_version(origin: OriginFor<T>, version: u16) -> DispatchResult {
+			let creator = ensure_signed(origin)?;
+			Self::do_publish_metadata_version(creator, version)
+		}
 
-	#[pallet::error]
-	pub enum Error<T> {
-		DuplicateCollectible,
-		MaximumCollectiblesOwned,
-		BoundsOverflow,
-		NoCollectible,
-		NotOwner,
-		TransferToSelf,
-		BidPriceTooLow,
-		NotForSale,
-	}
+		/// Move up to `limit` of the collectibles of `old` to `new`, with their listings, and
+		/// all of the orders of `old` with the funds held for them. Call again until no
+		/// collectibles remain; frozen ones stay with `old` until the freeze lifts.
+		#[pallet::weight((
+			T::WeightInfo::force_remap_owner(limit.min(T::MaximumOwned::get())),
+			DispatchClass::Operational,
+		))]
+		pub fn force_remap_owner(
+			origin: OriginFor<T>,
+			old: T::AccountId,
+			new: T::AccountId,
+			limit: u32,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let moved = Self::do_force_remap_owner(old, new, limit)?;
+			Ok(Some(T::WeightInfo::force_remap_owner(moved)).into())
+		}
 
-	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config> {
-		CollectibleCreated { collectible: u64, owner: T::AccountId },
-		TransferSucceeded { from: T::AccountId, to: T::AccountId, collectible: u64 },
-		PriceSet { collectible: u64, price: Option<BalanceOf<T>> },
-		Sold { seller: T::AccountId, buyer: T::AccountId, collectible: u64, price: BalanceOf<T> },
-	}
+		/// Send each of the caller's collectibles in `grants` to the account paired with it. If
+		/// any of them cannot be sent, none are.
+		#[pallet::weight(T::WeightInfo::distribute(grants.len() as u32))]
+		pub fn distribute(
+			origin: OriginFor<T>,
+			grants: BoundedVec<(u64, T::AccountId), T::MaximumOwned>,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+			Self::do_distribute(from, grants)
+		}
 
-	#[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
-            let collectibles_len = CollectiblesCount::<T>::get();
-			let mut max_price = HighestPrice::<T>::get();
-			for i in 0..collectibles_len {
-				let collectible = CollectibleMap::<T>::get(&i).unwrap();
-				if collectible.price > Some(max_price) {
-					max_price = collectible.price.unwrap();
-				}
-			}
-			HighestPrice::<T>::set(max_price);
-			Weight::zero()
-        }
-	}
+		/// Put a collectible up for an English auction lasting `duration` blocks. The highest
+		/// bid of at least `reserve` when it ends buys the collectible.
+		#[pallet::weight(T::WeightInfo::start_auction())]
+		pub fn start_auction(
+			origin: OriginFor<T>,
+			unique_id: u64,
+			reserve: BalanceOf<T>,
+			duration: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			Self::do_start_auction(seller, unique_id, reserve, duration)
+		}
 
-	#[pallet::call]
-	impl<T: Config> Pallet<T> {
-		#[pallet::weight(0)]
-		pub fn create_collectible(origin: OriginFor<T>, to: T::AccountId) -> DispatchResult {
+		/// Bid `amount` in an English auction. The bid must beat the highest one so far, and is
+		/// held until it is outbid or the auction settles.
+		#[pallet::weight(T::WeightInfo::place_bid())]
+		pub fn place_bid(
+			origin: OriginFor<T>,
+			unique_id: u64,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let bidder = ensure_signed(origin)?;
+			Self::do_place_bid(bidder, unique_id, amount)
+		}
+
+		/// Settle an English auction that has ended. Auctions settle on their own when they end;
+		/// this retries one that could not. Callable by anyone, and submitted unsigned by
+		/// offchain workers.
+		#[pallet::weight(T::WeightInfo::settle_auction())]
+		pub fn settle_auction(origin: OriginFor<T>, unique_id: u64) -> DispatchResult {
+			ensure_signed(origin.clone()).map(|_| ()).or_else(|_| ensure_none(origin))?;
+			Self::do_settle_auction(unique_id)
+		}
+
+		/// Create a collection owned by the caller. At most `max_supply` collectibles can ever
+		/// be minted into it, by its owner alone or by anybody, as `minting` says.
+		#[pallet::weight(T::WeightInfo::create_collection())]
+		pub fn create_collection(
+			origin: OriginFor<T>,
+			max_supply: Option<u32>,
+			minting: CollectionMinting,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::do_create_collection(owner, max_supply, minting)
+		}
+
+		/// Set the CID of a collection's off-chain metadata, or clear it with `None`. Only the
+		/// collection's owner can.
+		#[pallet::weight(T::WeightInfo::set_collection_metadata())]
+		pub fn set_collection_metadata(
+			origin: OriginFor<T>,
+			collection: CollectionId,
+			metadata: Option<BoundedVec<u8, T::MaxCidLength>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_set_collection_metadata(who, collection, metadata)
+		}
+
+		/// Set the royalty, royalty enforcement, cooldown, receipts and founder badges of the
+		/// collectibles in a collection. Only the collection's owner can.
+		#[pallet::weight(T::WeightInfo::set_collection_settings())]
+		pub fn set_collection_settings(
+			origin: OriginFor<T>,
+			collection: CollectionId,
+			settings: CollectionSettings<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_set_collection_settings(who, collection, settings)
+		}
+
+		/// Announce that the metadata of the collectibles in a collection follows schema
+		/// `version`, which must be newer than the one published before. Only the collection's
+		/// owner can.
+		#[pallet::weight(T::WeightInfo::publish_collection_metadata_version())]
+		pub fn publish_collection_metadata_version(
+			origin: OriginFor<T>,
+			collection: CollectionId,
+			version: u16,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_publish_collection_metadata_version(who, collection, version)
+		}
+
+		/// Start destroying a collection, as its owner or `ForceOrigin`. `items` must be the
+		/// number of collectibles in it. Nothing can be minted into the collection from then on.
+		/// An empty collection that awarded no founder badges is removed at once;
+		/// `destroy_collection_items` clears the others.
+		#[pallet::weight(T::WeightInfo::destroy_collection())]
+		pub fn destroy_collection(
+			origin: OriginFor<T>,
+			collection: CollectionId,
+			items: u32,
+		) -> DispatchResult {
+			let who = match T::ForceOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+			Self::do_destroy_collection(who, collection, items)
+		}
+
+		/// Clear up to `CollectionTeardownBatch` collectibles out of a collection being
+		/// destroyed, and remove the collection once it is empty. Collectibles its owner holds
+		/// are burned, and the deposits held for them released; the rest stay with their owners
+		/// in no collection. Anybody can call this.
+		#[pallet::weight(
+			T::WeightInfo::destroy_collection_items(T::CollectionTeardownBatch::get())
+		)]
+		pub fn destroy_collection_items(
+			origin: OriginFor<T>,
+			collection: CollectionId,
+		) -> DispatchResultWithPostInfo {
 			ensure_signed(origin)?;
-			let (collectible_gen_unique_id, color) = Self::gen_unique_id();
-			Self::mint(&to, collectible_gen_unique_id, color)?;
-			Ok(())
+			let cleared =
+				Self::do_destroy_collection_items(collection, T::CollectionTeardownBatch::get())?;
+			Ok(Some(T::WeightInfo::destroy_collection_items(cleared)).into())
 		}
 
-		/// Transfer a collectible to another account.
-		/// Any account that holds a collectible can send it to another account. 
-		/// Transfer resets the price of the collectible, marking it not for sale.
-		#[pallet::weight(0)]
-		pub fn transfer(
+		/// Mint a collectible into `collection`, to `to`. `to` must be the caller or accept mints
+		/// from others.
+		#[pallet::weight(T::WeightInfo::create_collectible_in())]
+		pub fn create_collectible_in(
 			origin: OriginFor<T>,
+			collection: CollectionId,
 			to: T::AccountId,
+		) -> DispatchResult {
+			let minter = ensure_signed(origin)?;
+			Self::do_create_collectible_in(minter, collection, to)
+		}
+
+		/// Approve `delegate` to transfer or list a collectible until it next changes hands,
+		/// replacing any account approved before. The owner and their operators can.
+		#[pallet::weight(T::WeightInfo::approve())]
+		pub fn approve(
+			origin: OriginFor<T>,
 			unique_id: u64,
+			delegate: T::AccountId,
 		) -> DispatchResult {
-			let from = ensure_signed(origin)?;
-			let collectible = CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
-			ensure!(collectible.owner == from, Error::<T>::NotOwner);
-			Self::do_transfer(unique_id, to)?;
-			Ok(())
+			let who = ensure_signed(origin)?;
+			Self::do_approve(who, unique_id, delegate)
 		}
 
-		/// Delete collection
-		#[pallet::weight(0)]
-		pub fn burn(origin: OriginFor<T>, unique_id: u64) -> DispatchResult {
-			let from = ensure_signed(origin)?;
-			let collectible = CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
-			ensure!(collectible.owner == from, Error::<T>::NotOwner);
-			CollectibleMap::<T>::remove(&unique_id);
-			Ok(())
+		/// Withdraw the approval of whoever was approved to manage a collectible. The owner and
+		/// their operators can.
+		#[pallet::weight(T::WeightInfo::cancel_approval())]
+		pub fn cancel_approval(origin: OriginFor<T>, unique_id: u64) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_cancel_approval(who, unique_id)
 		}
 
-		/// Update the collectible price and write to storage.
-		#[pallet::weight(0)]
-		pub fn set_price(
+		/// Approve `operator` to transfer or list any collectible the caller owns, now or later,
+		/// or withdraw that approval.
+		#[pallet::weight(T::WeightInfo::set_approval_for_all())]
+		pub fn set_approval_for_all(
+			origin: OriginFor<T>,
+			operator: T::AccountId,
+			approved: bool,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::do_set_approval_for_all(owner, operator, approved)
+		}
+
+		/// Offer `amount` for a collectible, whether it is listed or not. The amount is held
+		/// until the owner accepts the offer or the caller withdraws it. A new offer on the
+		/// same collectible replaces the caller's previous one. A collectible takes at most
+		/// `MaxOffers` offers at once.
+		#[pallet::weight(T::WeightInfo::make_offer())]
+		pub fn make_offer(
 			origin: OriginFor<T>,
-			owner: T::AccountId,
 			unique_id: u64,
-			new_price: Option<BalanceOf<T>>,
+			amount: BalanceOf<T>,
 		) -> DispatchResult {
-			ensure_signed(origin)?;
-			let mut collectible = CollectibleMap::<T>::get(&unique_id).unwrap();
-			ensure!(collectible.owner == owner, Error::<T>::NotOwner);
-			collectible.price = new_price;
-			CollectibleMap::<T>::insert(&unique_id, collectible);
-			Self::deposit_event(Event::PriceSet { collectible: unique_id, price: new_price });
-			Ok(())
+			let bidder = ensure_signed(origin)?;
+			Self::do_make_offer(bidder, unique_id, amount)
 		}
 
-		/// Buy a collectible. The bid price must be greater than or equal to the price
-		/// set by the collectible owner.
-		#[pallet::weight(0)]
-		pub fn buy_collectible(
+		/// Sell a collectible to `bidder` for the amount they offered. The owner and the
+		/// accounts they approved can.
+		#[pallet::weight(T::WeightInfo::accept_offer())]
+		pub fn accept_offer(
 			origin: OriginFor<T>,
-			buyer: T::AccountId,
 			unique_id: u64,
-			extra_fee: u128,
+			bidder: T::AccountId,
 		) -> DispatchResult {
-			ensure_signed(origin)?;
-			Self::do_buy_collectible(unique_id, buyer, extra_fee)?;
-			Ok(())
+			let who = ensure_signed(origin)?;
+			Self::do_accept_offer(who, unique_id, bidder)
+		}
+
+		/// Withdraw the caller's offer on a collectible and release the amount held for it.
+		#[pallet::weight(T::WeightInfo::withdraw_offer())]
+		pub fn withdraw_offer(origin: OriginFor<T>, unique_id: u64) -> DispatchResult {
+			let bidder = ensure_signed(origin)?;
+			Self::do_withdraw_offer(bidder, unique_id)
+		}
+
+		/// Send each of `unique_ids` to `to`, as `transfer` would. The caller must own or be
+		/// approved for every one of them. If any of them cannot be sent, none are.
+		#[pallet::weight(T::WeightInfo::transfer_many(unique_ids.len() as u32))]
+		pub fn transfer_many(
+			origin: OriginFor<T>,
+			to: T::AccountId,
+			unique_ids: BoundedVec<u64, T::MaximumOwned>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_transfer_many(who, to, unique_ids)
+		}
+
+		/// Mint `count` collectibles to `to`, as `create_collectible` would. Batches larger than
+		/// `MaxMintsPerBlock` are rejected, even when `to` only gets pending claims for them.
+		#[pallet::weight(T::WeightInfo::mint_batch((*count).min(T::MaxMintsPerBlock::get())))]
+		pub fn mint_batch(origin: OriginFor<T>, to: T::AccountId, count: u32) -> DispatchResult {
+			let minter = ensure_signed(origin)?;
+			Self::do_mint_batch(minter, to, count)
+		}
+
+		/// Store `metadata` on chain for a collectible of the caller, replacing what was stored
+		/// before. The caller pays a deposit for every byte of it.
+		#[pallet::weight(T::WeightInfo::set_metadata())]
+		pub fn set_metadata(
+			origin: OriginFor<T>,
+			unique_id: u64,
+			metadata: BoundedVec<u8, T::MetadataLimit>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::do_set_metadata(owner, unique_id, metadata)
+		}
+
+		/// Remove the on-chain metadata of a collectible of the caller, releasing its deposit.
+		#[pallet::weight(T::WeightInfo::clear_metadata())]
+		pub fn clear_metadata(origin: OriginFor<T>, unique_id: u64) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::do_clear_metadata(owner, unique_id)
+		}
+
+		/// Set attribute `key` of a collectible of the caller to `value`. The caller pays a
+		/// deposit for every byte of both.
+		#[pallet::weight(T::WeightInfo::set_attribute())]
+		pub fn set_attribute(
+			origin: OriginFor<T>,
+			unique_id: u64,
+			key: BoundedVec<u8, T::AttributeKeyLimit>,
+			value: BoundedVec<u8, T::AttributeValueLimit>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::do_set_attribute(owner, unique_id, key, value)
+		}
+
+		/// Remove attribute `key` of a collectible of the caller, releasing its deposit.
+		#[pallet::weight(T::WeightInfo::clear_attribute())]
+		pub fn clear_attribute(
+			origin: OriginFor<T>,
+			unique_id: u64,
+			key: BoundedVec<u8, T::AttributeKeyLimit>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::do_clear_attribute(owner, unique_id, key)
+		}
+
+		/// Lock a collectible so it cannot be moved, sold or burned until it is thawed. Its owner
+		/// stakes it, and `FreezeOrigin` pledges it as collateral, each lock lifted only by
+		/// whoever placed it.
+		#[pallet::weight(T::WeightInfo::freeze())]
+		pub fn freeze(origin: OriginFor<T>, unique_id: u64) -> DispatchResult {
+			let reason = Self::freeze_reason_of(origin, unique_id)?;
+			Self::do_lock(unique_id, reason)
+		}
+
+		/// Lift the lock the caller placed with `freeze`. Other locks on the collectible stay.
+		#[pallet::weight(T::WeightInfo::thaw())]
+		pub fn thaw(origin: OriginFor<T>, unique_id: u64) -> DispatchResult {
+			let reason = Self::freeze_reason_of(origin, unique_id)?;
+			Self::do_unlock(unique_id, reason)
+		}
+
+		/// Offer a collectible of the caller in exchange for collectible `desired_id`, plus
+		/// `sweetener` held from the caller, until `deadline`. The offered collectible is delisted
+		/// and frozen until the swap ends.
+		#[pallet::weight(T::WeightInfo::create_swap())]
+		pub fn create_swap(
+			origin: OriginFor<T>,
+			offered_id: u64,
+			desired_id: u64,
+			sweetener: Option<BalanceOf<T>>,
+			deadline: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let creator = ensure_signed(origin)?;
+			Self::do_create_swap(creator, offered_id, desired_id, sweetener, deadline)
+		}
+
+		/// Give the desired collectible of the swap offering `offered_id` for the offered one and
+		/// the sweetener. Only the owner of the desired collectible can claim the swap.
+		#[pallet::weight(T::WeightInfo::claim_swap())]
+		pub fn claim_swap(origin: OriginFor<T>, offered_id: u64) -> DispatchResult {
+			let claimer = ensure_signed(origin)?;
+			Self::do_claim_swap(claimer, offered_id)
+		}
+
+		/// Call off a swap the caller created, unfreezing the offered collectible and releasing
+		/// the sweetener.
+		#[pallet::weight(T::WeightInfo::cancel_swap())]
+		pub fn cancel_swap(origin: OriginFor<T>, offered_id: u64) -> DispatchResult {
+			let creator = ensure_signed(origin)?;
+			Self::do_cancel_swap(creator, offered_id)
 		}
 	}
 
 	// Pallet internal functions
 	impl<T: Config> Pallet<T> {
-		fn gen_unique_id() -> (u64, Color) {
-			let collectibles_count = CollectiblesCount::<T>::get();
-			
-			if collectibles_count % 2 == 0 {
-					(collectibles_count, Color::Red)
+		/// List the collectible at `new_price`, or delist it for `None`, taking or releasing the
+		/// listing deposit.
+		pub(crate) fn do_set_price(
+			owner: &T::AccountId,
+			unique_id: u64,
+			new_price: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			let mut collectible =
+				CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
+			ensure!(collectible.owner == *owner, Error::<T>::NotOwner);
+			Self::ensure_unlocked(unique_id)?;
+			if let Some(price) = new_price {
+				ensure!(
+					!price.is_zero() && price >= T::MinListingPrice::get(),
+					Error::<T>::PriceTooLow
+				);
+				ensure!(price <= T::MaxPrice::get(), Error::<T>::PriceTooHigh);
+				Self::ensure_cooled_down(unique_id)?;
+			}
+			if new_price.is_some() {
+				Self::take_listing_deposit(owner, unique_id)?;
+				ListingCharities::<T>::remove(&unique_id);
 			} else {
-					(collectibles_count, Color::Yellow)
-			} 
+				Self::release_listing_deposit(unique_id)?;
+			}
+			collectible.price = new_price;
+			CollectibleMap::<T>::insert(&unique_id, collectible);
+			match new_price {
+				Some(price) => {
+					ListedAt::<T>::insert(&unique_id, frame_system::Pallet::<T>::block_number());
+					Self::note_listed(unique_id, price);
+				},
+				None => {
+					ListedAt::<T>::remove(&unique_id);
+					Self::note_unlisted(unique_id);
+				},
+			}
+			Ok(())
+		}
+
+		/// Whether the collectible's listing is older than `MaxListingDuration`.
+		pub fn listing_expired(unique_id: u64) -> bool {
+			let listed_at = ListedAt::<T>::get(&unique_id).unwrap_or_default();
+			frame_system::Pallet::<T>::block_number() >=
+				listed_at.saturating_add(T::MaxListingDuration::get())
+		}
+
+		/// The price a collectible can currently be bought at, if its listing has not expired.
+		pub fn listing_price(unique_id: u64) -> Option<BalanceOf<T>> {
+			let price = CollectibleMap::<T>::get(&unique_id)?.price?;
+			(!Self::listing_expired(unique_id)).then_some(price)
+		}
+
+		/// Fail if royalty enforcement, across the pallet or by its collection, keeps `from` from
+		/// sending collectible `unique_id` to `to` outside a sale.
+		pub fn ensure_transfer_allowed(
+			unique_id: u64,
+			from: &T::AccountId,
+			to: &T::AccountId,
+		) -> DispatchResult {
+			let enforced = RoyaltiesEnforced::<T>::get() ||
+				Self::collection_settings_of(unique_id)
+					.is_some_and(|settings| settings.royalties_enforced);
+			ensure!(
+				!enforced ||
+					ApprovedMarkets::<T>::contains_key(from) ||
+					ApprovedMarkets::<T>::contains_key(to),
+				Error::<T>::TransferRestricted
+			);
+			Ok(())
+		}
+
+		/// Fail if a sale at `price` requires identities that `seller` or `buyer` lack.
+		pub fn ensure_identity(
+			seller: &T::AccountId,
+			buyer: &T::AccountId,
+			price: BalanceOf<T>,
+		) -> DispatchResult {
+			if price >= T::HighValueThreshold::get() {
+				ensure!(
+					T::IdentityVerifier::is_verified(seller) &&
+						T::IdentityVerifier::is_verified(buyer),
+					Error::<T>::IdentityRequired
+				);
+			}
+			Ok(())
+		}
+
+		/// Remove a collectible and every record kept about it.
+		///
+		/// Auctions, raffles and escrows are not cleaned up here; callers must make sure the
+		/// collectible is not frozen, see [`Self::ensure_unlocked`].
+		pub(crate) fn do_burn(unique_id: u64, owner: &T::AccountId) -> DispatchResult {
+			let listed = CollectibleMap::<T>::get(&unique_id)
+				.map_or(false, |collectible| collectible.price.is_some());
+			Self::release_listing_deposit(unique_id)?;
+			Self::release_mint_deposit(unique_id)?;
+			Self::release_offers(unique_id)?;
+			Self::clear_onchain_data(unique_id)?;
+			ListedAt::<T>::remove(&unique_id);
+			Self::note_unlisted(unique_id);
+			Self::note_burned(unique_id);
+			Self::note_left_collection(unique_id);
+			CollectibleMap::<T>::remove(&unique_id);
+			Approvals::<T>::remove(&unique_id);
+			CollectiblesCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+			OwnerOfCollectibles::<T>::mutate(owner, |owned| owned.retain(|id| *id != unique_id));
+			MysteryBoxes::<T>::remove(&unique_id);
+			RevealRecords::<T>::remove(&unique_id);
+			CooldownUntil::<T>::remove(&unique_id);
+			MetadataCids::<T>::remove(&unique_id);
+			PinQueue::<T>::remove(&unique_id);
+			RentalRates::<T>::remove(&unique_id);
+			RecentSales::<T>::remove(&unique_id);
+			WashTraded::<T>::remove(&unique_id);
+			SaleReceipts::<T>::remove(&unique_id);
+			ItemRoyalties::<T>::remove(&unique_id);
+			Licenses::<T>::remove(&unique_id);
+			let _ = Frozen::<T>::clear_prefix(&unique_id, u32::MAX, None);
+			log::debug!(
+				target: super::LOG_TARGET,
+				"burned collectible={unique_id} owner={owner:?}",
+			);
+			if listed {
+				Self::deposit_event(Event::PriceRemoved { collectible: unique_id });
+			}
+			Self::deposit_event(Event::CollectibleBurned {
+				collectible: unique_id,
+				owner: owner.clone(),
+			});
+			Ok(())
+		}
+
+		pub(crate) fn gen_unique_id() -> (u64, Color) {
+			let sequence = NextCollectibleId::<T>::get();
+			let next_id = T::ItemIdGenerator::item_id(sequence);
+			(next_id, T::ColorStrategy::color(sequence, next_id))
+		}
+
+		/// The id of the collectible minted `sequence`-th, whether it still exists or not.
+		pub(crate) fn item_id_at(sequence: u64) -> u64 {
+			ItemIdAt::<T>::get(&sequence).unwrap_or(sequence)
 		}
 
 		// Function to mint a collectible
 		pub fn mint(
+			creator: &T::AccountId,
+			owner: &T::AccountId,
+			unique_id: u64,
+			color: Color,
+		) -> Result<u64, DispatchError> {
+			Self::mint_in(creator, owner, unique_id, color, None)
+		}
+
+		/// Mint a collectible into `collection`, or into none. The caller checks the collection
+		/// takes it and counts it in.
+		pub(crate) fn mint_in(
+			creator: &T::AccountId,
 			owner: &T::AccountId,
 			unique_id: u64,
 			color: Color,
+			collection: Option<CollectionId>,
 		) -> Result<u64, DispatchError> {
+			T::MintPolicy::check_mint(creator, owner)?;
+
 			// Create a new object
-			let collectible = Collectible::<T> { unique_id, price: None, color, owner: owner.clone() };
+			let collectible = Collectible::<T> {
+				unique_id,
+				price: None,
+				color,
+				owner: owner.clone(),
+				creator: creator.clone(),
+				metadata: None,
+				flags: CollectibleFlags { mystery_box: color == Color::Hidden },
+				collection_id: collection,
+			};
 			
 			// Check if the collectible exists in the storage map
 			ensure!(!CollectibleMap::<T>::contains_key(&collectible.unique_id), Error::<T>::DuplicateCollectible);
 			
 			// Check that a new collectible can be created
+			let minted = MintsThisBlock::<T>::get();
+			ensure!(minted < T::MaxMintsPerBlock::get(), Error::<T>::MintRateExceeded);
 			let count = CollectiblesCount::<T>::get();
 			let new_count = count.checked_add(1).ok_or(Error::<T>::BoundsOverflow)?;
+			let sequence = NextCollectibleId::<T>::get();
+			let next_id = sequence.checked_add(1).ok_or(Error::<T>::BoundsOverflow)?;
 			
 			// Append collectible to OwnerOfCollectibles map
 			OwnerOfCollectibles::<T>::try_append(&owner, collectible.unique_id)
@@ -201,6 +3068,18 @@ pub mod pallet {
 			// Write new collectible to storage and update the count
 			CollectibleMap::<T>::insert(collectible.unique_id, collectible);
 			CollectiblesCount::<T>::put(new_count);
+			Self::note_minted(color);
+			if unique_id != sequence {
+				ItemIdAt::<T>::insert(sequence, unique_id);
+			}
+			NextCollectibleId::<T>::put(next_id);
+			MintsThisBlock::<T>::put(minted + 1);
+			Self::start_cooldown(unique_id);
+			log::debug!(
+				target: super::LOG_TARGET,
+				"minted collectible={unique_id} sequence={sequence} color={color:?} \
+				 owner={owner:?}",
+			);
 			
 			// Deposit the "CollectibleCreated" event.
 			Self::deposit_event(Event::CollectibleCreated { collectible: unique_id, owner: owner.clone() });
@@ -219,6 +3098,7 @@ pub mod pallet {
 			let from = collectible.owner;
 			
 			ensure!(from != to, Error::<T>::TransferToSelf);
+			Self::ensure_unlocked(collectible_id)?;
 			let mut from_owned = OwnerOfCollectibles::<T>::get(&from);
 			
 			// Remove collectible from list of owned collectible.
@@ -233,12 +3113,24 @@ pub mod pallet {
 			
 			// Transfer succeeded, update the owner and reset the price to `None`.
 			collectible.owner = to.clone();
-			collectible.price = None;
+			let was_listed = collectible.price.take().is_some();
 
 			// Write updates to storage
 			CollectibleMap::<T>::insert(&collectible_id, collectible);
+			ListedAt::<T>::remove(&collectible_id);
+			Approvals::<T>::remove(&collectible_id);
+			if was_listed {
+				Self::note_unlisted(collectible_id);
+				Self::deposit_event(Event::PriceRemoved { collectible: collectible_id });
+			}
+			RentalRates::<T>::remove(&collectible_id);
+			Self::release_listing_deposit(collectible_id)?;
 			OwnerOfCollectibles::<T>::insert(&to, to_owned);
 			OwnerOfCollectibles::<T>::insert(&from, from_owned);
+			log::debug!(
+				target: super::LOG_TARGET,
+				"transferred collectible={collectible_id} from={from:?} to={to:?}",
+			);
 			
 			Self::deposit_event(Event::TransferSucceeded { from, to, collectible: collectible_id });
 			Ok(())
@@ -248,12 +3140,13 @@ pub mod pallet {
 		pub fn do_buy_collectible(
 			unique_id: u64,
 			to: T::AccountId,
-			extra_fee: u128,
-		) -> DispatchResult {
+			max_price: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
 			// Get the collectible from the storage map
 			let mut collectible = CollectibleMap::<T>::get(&unique_id).ok_or(Error::<T>::NoCollectible)?;
 			let from = collectible.owner;
 			ensure!(from != to, Error::<T>::TransferToSelf);
+			Self::ensure_unlocked(unique_id)?;
 			let mut from_owned = OwnerOfCollectibles::<T>::get(&from);
 			
 			// Remove collectible from owned collectibles.
@@ -266,31 +3159,56 @@ pub mod pallet {
 			let mut to_owned = OwnerOfCollectibles::<T>::get(&to);
 			to_owned.try_push(unique_id).map_err(|_id| Error::<T>::MaximumCollectiblesOwned)?;
 			// Mutating state with a balance transfer, so nothing is allowed to fail after this.
-			if let Some(price) = collectible.price {
-				//ensure!(bid_price >= price, Error::<T>::BidPriceTooLow);
+			let (final_price, paid) = if let Some(price) = collectible.price {
+				ensure!(price <= max_price, Error::<T>::AboveMaxPrice);
+				ensure!(!Self::listing_expired(unique_id), Error::<T>::ListingExpired);
+				let final_price = price;
+				Self::ensure_identity(&from, &to, final_price)?;
+				Self::ensure_within_limits(&from, &to, final_price)?;
 				// Transfer the amount from buyer to seller
-				let final_price = extra_fee + price.saturated_into::<u128>();
-				T::Currency::transfer(&to, &from, final_price.saturated_into(), frame_support::traits::ExistenceRequirement::KeepAlive)?;
+				let (paid, charity) =
+					Self::pay_for_sale(unique_id, &from, &to, &collectible.creator, final_price)?;
 				// Deposit sold event
 				Self::deposit_event(Event::Sold {
 					seller: from.clone(),
 					buyer: to.clone(),
 					collectible: unique_id,
-					price: final_price.saturated_into()
+					price: final_price,
+					charity,
 				});
+				(final_price, paid)
 			} else {
 				return Err(Error::<T>::NotForSale.into())
-			}
+			};
 
 			// Transfer succeeded, update the collectible owner and reset the price to `None`.
 			collectible.owner = to.clone();
 			collectible.price = None;
 			// Write updates to storage
 			CollectibleMap::<T>::insert(&unique_id, collectible);
+			ListedAt::<T>::remove(&unique_id);
+			Approvals::<T>::remove(&unique_id);
+			Self::note_unlisted(unique_id);
+			RentalRates::<T>::remove(&unique_id);
+			Self::release_listing_deposit(unique_id)?;
 			OwnerOfCollectibles::<T>::insert(&to, to_owned);
 			OwnerOfCollectibles::<T>::insert(&from, from_owned);
+			Self::start_cooldown(unique_id);
+			let receipt = Self::note_sale(unique_id, &from, &to, final_price);
+			log::debug!(
+				target: super::LOG_TARGET,
+				"sold collectible={unique_id} seller={from:?} buyer={to:?} price={final_price:?}",
+			);
 			Self::deposit_event(Event::TransferSucceeded { from, to, collectible: unique_id });
-			Ok(())
+
+			// The worst case pays the creator, a referrer and a charity and mints the buyer a
+			// receipt.
+			let db = T::DbWeight::get();
+			let mut unused = db.reads_writes(1, 1).saturating_mul(3u64.saturating_sub(paid.into()));
+			if !receipt {
+				unused.saturating_accrue(db.reads_writes(3, 4));
+			}
+			Ok(Some(T::WeightInfo::buy_collectible().saturating_sub(unused)).into())
 		}
 	}
 }
\ No newline at end of file
@@ -0,0 +1,71 @@
+//! Storage migrations.
+//!
+//! Every migration implements [`StorageMigration`] rather than `OnRuntimeUpgrade` directly, so
+//! the try-runtime checks cannot be left out: `pre_upgrade` snapshots whatever the migration
+//! needs to compare against, and `post_upgrade` asserts the migrated state against that
+//! snapshot. Runtimes schedule a migration as [`Checked<M>`].
+//!
+//! `legacy_state` writes storage in the layout the migration expects to find, so the unit
+//! tests can run each migration end to end without a live chain.
+
+pub mod v1;
+pub mod v2;
+pub mod v3;
+pub mod v4;
+pub mod v5;
+pub mod v6;
+pub mod v7;
+pub mod v8;
+pub mod v9;
+pub mod v10;
+pub mod v11;
+pub mod v12;
+pub mod v13;
+pub mod v14;
+
+use frame_support::{sp_runtime::TryRuntimeError, traits::OnRuntimeUpgrade, weights::Weight};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+pub trait StorageMigration {
+	/// Translate storage to the new layout, returning the weight used.
+	fn migrate() -> Weight;
+
+	/// Snapshot the state `post_upgrade` checks against. Runs before `migrate`.
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError>;
+
+	/// Check the migrated state against the snapshot taken by `pre_upgrade`.
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError>;
+
+	/// Populate storage as a chain awaiting this migration would have it.
+	#[cfg(test)]
+	fn legacy_state();
+}
+
+/// Schedules a [`StorageMigration`] as an `OnRuntimeUpgrade`, forwarding its checks to
+/// try-runtime.
+pub struct Checked<M>(PhantomData<M>);
+
+impl<M: StorageMigration> OnRuntimeUpgrade for Checked<M> {
+	fn on_runtime_upgrade() -> Weight {
+		M::migrate()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		M::pre_upgrade()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		M::post_upgrade(state)
+	}
+}
+
+/// Run `M` against its legacy state, failing if either check does.
+#[cfg(test)]
+pub(crate) fn check<M: StorageMigration>() -> Result<(), TryRuntimeError> {
+	M::legacy_state();
+	let state = M::pre_upgrade()?;
+	M::migrate();
+	M::post_upgrade(state)
+}
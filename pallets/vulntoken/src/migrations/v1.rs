@@ -0,0 +1,141 @@
+//! v0 -> v1: collectibles record their creator, metadata hash and flags, and
+//! `CollectiblesCount` becomes the live supply, with id allocation moving to
+//! `NextCollectibleId`.
+
+use super::StorageMigration;
+use crate::*;
+use codec::{Decode, Encode};
+use frame_support::{pallet_prelude::*, sp_runtime::TryRuntimeError, storage_alias};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// The storage layout before v1.
+pub mod v0 {
+	use super::*;
+
+	#[derive(Encode, Decode, RuntimeDebug)]
+	pub struct Collectible<T: Config> {
+		pub unique_id: u64,
+		pub price: Option<BalanceOf<T>>,
+		pub color: Color,
+		pub owner: T::AccountId,
+	}
+
+	#[storage_alias]
+	pub type CollectibleMap<T: Config> = StorageMap<Pallet<T>, Twox64Concat, u64, Collectible<T>>;
+
+	#[storage_alias]
+	pub type MetadataHashes<T: Config> = StorageMap<Pallet<T>, Twox64Concat, u64, [u8; 32]>;
+}
+
+pub struct MigrateToV1<T>(PhantomData<T>);
+
+impl<T: Config> MigrateToV1<T> {
+	fn was_mystery_box(unique_id: u64) -> bool {
+		MysteryBoxes::<T>::contains_key(&unique_id) || RevealRecords::<T>::contains_key(&unique_id)
+	}
+}
+
+impl<T: Config> StorageMigration for MigrateToV1<T> {
+	fn migrate() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() != 0 {
+			log::info!(target: LOG_TARGET, "skipping v1 migration: already applied");
+			return T::DbWeight::get().reads(1)
+		}
+
+		// The creator was never recorded, so the current owner stands in for it.
+		let mut migrated = 0u64;
+		CollectibleMap::<T>::translate::<v0::Collectible<T>, _>(|unique_id, old| {
+			migrated += 1;
+			Some(Collectible {
+				unique_id: old.unique_id,
+				price: old.price,
+				color: old.color,
+				owner: old.owner.clone(),
+				creator: old.owner,
+				metadata: v0::MetadataHashes::<T>::take(&unique_id),
+				flags: CollectibleFlags { mystery_box: Self::was_mystery_box(unique_id) },
+				collection_id: None,
+			})
+		});
+		// Hashes of collectibles burned before burning cleaned them up.
+		let orphans = v0::MetadataHashes::<T>::clear(u32::MAX, None).unique as u64;
+
+		NextCollectibleId::<T>::put(CollectiblesCount::<T>::get());
+		CollectiblesCount::<T>::put(migrated);
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		log::info!(target: LOG_TARGET, "migrated {migrated} collectibles to v1");
+		T::DbWeight::get()
+			.reads_writes(migrated.saturating_mul(4).saturating_add(2), migrated.saturating_mul(2))
+			.saturating_add(T::DbWeight::get().writes(orphans.saturating_add(3)))
+	}
+
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		ensure!(Pallet::<T>::on_chain_storage_version() == 0, "vulntoken is not at v0");
+		let collectibles = v0::CollectibleMap::<T>::iter()
+			.map(|(unique_id, old)| {
+				(old, v0::MetadataHashes::<T>::get(&unique_id), Self::was_mystery_box(unique_id))
+			})
+			.collect::<Vec<_>>();
+		Ok((CollectiblesCount::<T>::get(), collectibles).encode())
+	}
+
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		type Snapshot<T> = (u64, Vec<(v0::Collectible<T>, Option<[u8; 32]>, bool)>);
+		let (minted, collectibles) =
+			Snapshot::<T>::decode(&mut &state[..]).map_err(|_| "undecodable v0 snapshot")?;
+
+		ensure!(Pallet::<T>::on_chain_storage_version() == 1, "vulntoken is not at v1");
+		ensure!(NextCollectibleId::<T>::get() == minted, "id allocation moved");
+		ensure!(
+			CollectiblesCount::<T>::get() == collectibles.len() as u64,
+			"supply does not match the collectibles in existence"
+		);
+		ensure!(CollectibleMap::<T>::iter().count() == collectibles.len(), "collectibles were lost");
+		ensure!(v0::MetadataHashes::<T>::iter_keys().next().is_none(), "metadata hashes left over");
+		for (old, metadata, mystery_box) in collectibles {
+			let new = CollectibleMap::<T>::get(&old.unique_id).ok_or("collectible was dropped")?;
+			ensure!(
+				new.unique_id == old.unique_id &&
+					new.price == old.price && new.color == old.color &&
+					new.owner == old.owner && new.creator == old.owner,
+				"collectible changed"
+			);
+			ensure!(new.metadata == metadata, "metadata hash was not carried over");
+			ensure!(new.flags.mystery_box == mystery_box, "mystery box flag is wrong");
+		}
+		Ok(())
+	}
+
+	#[cfg(test)]
+	fn legacy_state() {
+		use frame_support::sp_runtime::traits::{TrailingZeroInput, Zero};
+
+		let alice = T::AccountId::decode(&mut TrailingZeroInput::new(&[1])).unwrap();
+		let bob = T::AccountId::decode(&mut TrailingZeroInput::new(&[2])).unwrap();
+		let collectible = |unique_id, owner: &T::AccountId, color| v0::Collectible::<T> {
+			unique_id,
+			price: None,
+			color,
+			owner: owner.clone(),
+		};
+
+		// Four minted, the one with id 1 since burned, leaving its metadata hash behind.
+		v0::CollectibleMap::<T>::insert(0, collectible(0, &alice, Color::Red));
+		v0::CollectibleMap::<T>::insert(2, collectible(2, &bob, Color::Hidden));
+		v0::CollectibleMap::<T>::insert(
+			3,
+			v0::Collectible { price: Some(10u32.into()), ..collectible(3, &bob, Color::Yellow) },
+		);
+		OwnerOfCollectibles::<T>::insert(&alice, BoundedVec::truncate_from(vec![0]));
+		OwnerOfCollectibles::<T>::insert(&bob, BoundedVec::truncate_from(vec![2, 3]));
+		v0::MetadataHashes::<T>::insert(0, [0; 32]);
+		v0::MetadataHashes::<T>::insert(1, [1; 32]);
+		MysteryBoxes::<T>::insert(
+			2,
+			MysteryBox { minted_at: Zero::zero(), reveal_at: T::RevealDelay::get() },
+		);
+		CollectiblesCount::<T>::put(4);
+		StorageVersion::new(0).put::<Pallet<T>>();
+	}
+}
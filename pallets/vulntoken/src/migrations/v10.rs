@@ -0,0 +1,92 @@
+//! v9 -> v10: pending mints record who minted them and the deposit held for them, where they
+//! were only counted before. Nothing records who minted the claims already pending, so each is
+//! kept as a mint of the claimant's own without a deposit, which is how claiming treated them
+//! until now. Counts beyond `MaximumOwned` could never be claimed at once and are capped.
+
+use super::StorageMigration;
+use crate::*;
+use codec::{Decode, Encode};
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::{
+		traits::{Saturating, Zero},
+		TryRuntimeError,
+	},
+	storage_alias,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// The storage layout before v10.
+pub mod v9 {
+	use super::*;
+
+	#[storage_alias]
+	pub type PendingMints<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		u32,
+		ValueQuery,
+	>;
+}
+
+pub struct RecordPendingMinters<T>(PhantomData<T>);
+
+impl<T: Config> StorageMigration for RecordPendingMinters<T> {
+	fn migrate() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() != 9 {
+			log::info!(target: LOG_TARGET, "skipping v10 migration: not at v9");
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut migrated = 0u64;
+		PendingMints::<T>::translate::<u32, _>(|account, count| {
+			migrated.saturating_inc();
+			let count = count.min(T::MaximumOwned::get());
+			(count > 0).then(|| {
+				let claims = (0..count).map(|_| (account.clone(), Zero::zero()));
+				BoundedVec::truncate_from(claims.collect())
+			})
+		});
+		StorageVersion::new(10).put::<Pallet<T>>();
+
+		log::info!(target: LOG_TARGET, "migrated pending mints of {migrated} accounts to v10");
+		T::DbWeight::get().reads_writes(migrated.saturating_add(1), migrated.saturating_add(1))
+	}
+
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		ensure!(Pallet::<T>::on_chain_storage_version() == 9, "vulntoken is not at v9");
+		Ok(v9::PendingMints::<T>::iter().collect::<Vec<_>>().encode())
+	}
+
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let pending = Vec::<(T::AccountId, u32)>::decode(&mut &state[..])
+			.map_err(|_| "undecodable v9 snapshot")?;
+
+		ensure!(Pallet::<T>::on_chain_storage_version() == 10, "vulntoken is not at v10");
+		let kept = pending.iter().filter(|(_, count)| *count > 0).count();
+		ensure!(PendingMints::<T>::iter().count() == kept, "pending mints were lost");
+		for (account, count) in pending {
+			let claims = PendingMints::<T>::get(&account);
+			ensure!(
+				claims.len() as u32 == count.min(T::MaximumOwned::get()),
+				"pending mints changed"
+			);
+			ensure!(
+				claims.iter().all(|(minter, deposit)| *minter == account && deposit.is_zero()),
+				"pending mint was given a minter or deposit"
+			);
+		}
+		Ok(())
+	}
+
+	#[cfg(test)]
+	fn legacy_state() {
+		use frame_support::sp_runtime::traits::TrailingZeroInput;
+
+		let account = |n: u8| T::AccountId::decode(&mut TrailingZeroInput::new(&[n])).unwrap();
+		v9::PendingMints::<T>::insert(account(2), 2);
+		v9::PendingMints::<T>::insert(account(3), 1);
+		StorageVersion::new(9).put::<Pallet<T>>();
+	}
+}
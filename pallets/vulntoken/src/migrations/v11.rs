@@ -0,0 +1,132 @@
+//! v10 -> v11: collections record whether they are being destroyed, and the collectibles in each
+//! collection are indexed, so a collection can be torn down without scanning every collectible.
+//! No collection was being destroyed before, and the index is built from every collectible.
+
+use super::{v12::v11, StorageMigration};
+use crate::*;
+use codec::{Decode, Encode};
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::{traits::Saturating, TryRuntimeError},
+	storage_alias,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// The storage layout before v11.
+pub mod v10 {
+	use super::*;
+
+	#[derive(Encode, Decode, RuntimeDebug)]
+	pub struct Collection<T: Config> {
+		pub owner: T::AccountId,
+		pub max_supply: Option<u32>,
+		pub minted: u32,
+		pub items: u32,
+		pub minting: CollectionMinting,
+		pub metadata: Option<BoundedVec<u8, T::MaxCidLength>>,
+	}
+
+	#[storage_alias]
+	pub type Collections<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, CollectionId, Collection<T>>;
+}
+
+pub struct IndexCollectionItems<T>(PhantomData<T>);
+
+impl<T: Config> StorageMigration for IndexCollectionItems<T> {
+	fn migrate() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() != 10 {
+			log::info!(target: LOG_TARGET, "skipping v11 migration: not at v10");
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut collections = 0u64;
+		v11::Collections::<T>::translate::<v10::Collection<T>, _>(|_, old| {
+			collections.saturating_inc();
+			Some(v11::Collection {
+				owner: old.owner,
+				max_supply: old.max_supply,
+				minted: old.minted,
+				items: old.items,
+				minting: old.minting,
+				metadata: old.metadata,
+				destroying: false,
+			})
+		});
+		let (mut scanned, mut indexed) = (0u64, 0u64);
+		for (unique_id, collectible) in CollectibleMap::<T>::iter() {
+			scanned.saturating_inc();
+			if let Some(collection) = collectible.collection_id {
+				CollectionItems::<T>::insert(collection, unique_id, ());
+				indexed.saturating_inc();
+			}
+		}
+		StorageVersion::new(11).put::<Pallet<T>>();
+
+		log::info!(target: LOG_TARGET, "indexed {indexed} collectibles in collections for v11");
+		T::DbWeight::get().reads_writes(
+			collections.saturating_add(scanned).saturating_add(1),
+			collections.saturating_add(indexed).saturating_add(1),
+		)
+	}
+
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		ensure!(Pallet::<T>::on_chain_storage_version() == 10, "vulntoken is not at v10");
+		ensure!(CollectionItems::<T>::iter().next().is_none(), "collectibles indexed before v11");
+		let collections = v10::Collections::<T>::iter().map(|(id, old)| (id, old.items));
+		Ok(collections.collect::<Vec<_>>().encode())
+	}
+
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let collections = Vec::<(CollectionId, u32)>::decode(&mut &state[..])
+			.map_err(|_| "undecodable v10 snapshot")?;
+
+		ensure!(Pallet::<T>::on_chain_storage_version() == 11, "vulntoken is not at v11");
+		ensure!(
+			v11::Collections::<T>::iter().count() == collections.len(),
+			"collections were lost"
+		);
+		for (collection, items) in collections {
+			let entry = v11::Collections::<T>::get(collection).ok_or("collection was dropped")?;
+			ensure!(entry.items == items && !entry.destroying, "collection changed");
+			let indexed = CollectionItems::<T>::iter_key_prefix(collection).count() as u32;
+			ensure!(indexed == items, "collectibles of a collection were not indexed");
+		}
+		Ok(())
+	}
+
+	#[cfg(test)]
+	fn legacy_state() {
+		use frame_support::sp_runtime::traits::TrailingZeroInput;
+
+		let owner = T::AccountId::decode(&mut TrailingZeroInput::new(&[1])).unwrap();
+		let collectible = |unique_id, collection_id| Collectible::<T> {
+			unique_id,
+			price: None,
+			color: Color::Red,
+			owner: owner.clone(),
+			creator: owner.clone(),
+			metadata: None,
+			flags: Default::default(),
+			collection_id,
+		};
+
+		// Collection 0 holds collectibles 0 and 2, of three minted into it; 1 is in none.
+		v10::Collections::<T>::insert(
+			0,
+			v10::Collection {
+				owner: owner.clone(),
+				max_supply: None,
+				minted: 3,
+				items: 2,
+				minting: CollectionMinting::Owner,
+				metadata: None,
+			},
+		);
+		CollectibleMap::<T>::insert(0, collectible(0, Some(0)));
+		CollectibleMap::<T>::insert(1, collectible(1, None));
+		CollectibleMap::<T>::insert(2, collectible(2, Some(0)));
+		NextCollectibleId::<T>::put(4);
+		StorageVersion::new(10).put::<Pallet<T>>();
+	}
+}
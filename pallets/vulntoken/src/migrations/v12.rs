@@ -0,0 +1,124 @@
+//! v11 -> v12: collections carry the settings of the collectibles in them, the count of founder
+//! badges they awarded, and the metadata schema version their collectibles follow. Collections
+//! start out setting nothing, so their collectibles keep following the pallet-wide and
+//! per-creator settings, and each starts at the version its owner published before.
+
+use super::StorageMigration;
+use crate::*;
+use codec::{Decode, Encode};
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::{traits::Saturating, TryRuntimeError},
+	storage_alias,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// The storage layout before v12.
+pub mod v11 {
+	use super::*;
+
+	#[derive(Encode, Decode, RuntimeDebug)]
+	pub struct Collection<T: Config> {
+		pub owner: T::AccountId,
+		pub max_supply: Option<u32>,
+		pub minted: u32,
+		pub items: u32,
+		pub minting: CollectionMinting,
+		pub metadata: Option<BoundedVec<u8, T::MaxCidLength>>,
+		pub destroying: bool,
+	}
+
+	#[storage_alias]
+	pub type Collections<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, CollectionId, Collection<T>>;
+}
+
+pub struct AddCollectionSettings<T>(PhantomData<T>);
+
+impl<T: Config> StorageMigration for AddCollectionSettings<T> {
+	fn migrate() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() != 11 {
+			log::info!(target: LOG_TARGET, "skipping v12 migration: not at v11");
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut collections = 0u64;
+		Collections::<T>::translate::<v11::Collection<T>, _>(|_, old| {
+			collections.saturating_inc();
+			Some(Collection {
+				metadata_version: MetadataVersions::<T>::get(&old.owner),
+				owner: old.owner,
+				max_supply: old.max_supply,
+				minted: old.minted,
+				items: old.items,
+				minting: old.minting,
+				metadata: old.metadata,
+				destroying: old.destroying,
+				settings: Default::default(),
+				founder_badges_issued: 0,
+			})
+		});
+		StorageVersion::new(12).put::<Pallet<T>>();
+
+		log::info!(target: LOG_TARGET, "added settings to {collections} collections for v12");
+		T::DbWeight::get().reads_writes(
+			collections.saturating_mul(2).saturating_add(1),
+			collections.saturating_add(1),
+		)
+	}
+
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		ensure!(Pallet::<T>::on_chain_storage_version() == 11, "vulntoken is not at v11");
+		let collections = v11::Collections::<T>::iter().map(|(id, old)| {
+			let version = MetadataVersions::<T>::get(&old.owner);
+			(id, old.items, old.destroying, version)
+		});
+		Ok(collections.collect::<Vec<_>>().encode())
+	}
+
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let collections = Vec::<(CollectionId, u32, bool, u16)>::decode(&mut &state[..])
+			.map_err(|_| "undecodable v11 snapshot")?;
+
+		ensure!(Pallet::<T>::on_chain_storage_version() == 12, "vulntoken is not at v12");
+		ensure!(
+			Collections::<T>::iter().count() == collections.len(),
+			"collections were lost"
+		);
+		for (collection, items, destroying, version) in collections {
+			let entry = Collections::<T>::get(collection).ok_or("collection was dropped")?;
+			ensure!(
+				entry.items == items && entry.destroying == destroying,
+				"collection changed"
+			);
+			ensure!(
+				entry.settings == Default::default() && entry.founder_badges_issued == 0,
+				"collection starts out with settings"
+			);
+			ensure!(entry.metadata_version == version, "metadata version was not carried over");
+		}
+		Ok(())
+	}
+
+	#[cfg(test)]
+	fn legacy_state() {
+		use frame_support::sp_runtime::traits::TrailingZeroInput;
+
+		let account = |byte| T::AccountId::decode(&mut TrailingZeroInput::new(&[byte])).unwrap();
+		let collection = |owner, destroying| v11::Collection::<T> {
+			owner,
+			max_supply: Some(10),
+			minted: 2,
+			items: 1,
+			minting: CollectionMinting::Public,
+			metadata: None,
+			destroying,
+		};
+
+		// The owner of collection 0 published metadata version 3; that of 1 published none.
+		MetadataVersions::<T>::insert(account(1), 3);
+		v11::Collections::<T>::insert(0, collection(account(1), false));
+		v11::Collections::<T>::insert(1, collection(account(2), true));
+		StorageVersion::new(11).put::<Pallet<T>>();
+	}
+}
@@ -0,0 +1,76 @@
+//! v12 -> v13: the standing offers on each collectible are counted, so their number can be
+//! capped at `MaxOffers` and a burn can release them all. Counts are taken from every offer.
+//! A collectible with more offers than the cap keeps them, and takes no new ones until enough
+//! are accepted or withdrawn.
+
+use super::StorageMigration;
+use crate::*;
+use codec::{Decode, Encode};
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::{traits::Saturating, TryRuntimeError},
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+pub struct CountOffers<T>(PhantomData<T>);
+
+impl<T: Config> StorageMigration for CountOffers<T> {
+	fn migrate() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() != 12 {
+			log::info!(target: LOG_TARGET, "skipping v13 migration: not at v12");
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut offers = 0u64;
+		for (unique_id, _) in Offers::<T>::iter_keys() {
+			offers.saturating_inc();
+			OfferCounts::<T>::mutate(unique_id, |count| {
+				*count = Some(count.unwrap_or_default().saturating_add(1));
+			});
+		}
+		StorageVersion::new(13).put::<Pallet<T>>();
+
+		log::info!(target: LOG_TARGET, "counted {offers} offers for v13");
+		T::DbWeight::get().reads_writes(
+			offers.saturating_mul(2).saturating_add(1),
+			offers.saturating_add(1),
+		)
+	}
+
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		ensure!(Pallet::<T>::on_chain_storage_version() == 12, "vulntoken is not at v12");
+		ensure!(OfferCounts::<T>::iter().next().is_none(), "offers counted before v13");
+		Ok((Offers::<T>::iter().count() as u32).encode())
+	}
+
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let offers = u32::decode(&mut &state[..]).map_err(|_| "undecodable v12 snapshot")?;
+
+		ensure!(Pallet::<T>::on_chain_storage_version() == 13, "vulntoken is not at v13");
+		ensure!(Offers::<T>::iter().count() as u32 == offers, "offers changed");
+		let mut counted = 0u32;
+		for (unique_id, count) in OfferCounts::<T>::iter() {
+			let standing = Offers::<T>::iter_key_prefix(unique_id).count() as u32;
+			ensure!(count == standing, "offers on a collectible were miscounted");
+			counted.saturating_accrue(count);
+		}
+		ensure!(counted == offers, "offers were not counted");
+		Ok(())
+	}
+
+	#[cfg(test)]
+	fn legacy_state() {
+		use frame_support::sp_runtime::traits::TrailingZeroInput;
+
+		let account = |byte| T::AccountId::decode(&mut TrailingZeroInput::new(&[byte])).unwrap();
+		let amount = |value: u32| BalanceOf::<T>::from(value);
+
+		// Collectible 0 has an offer from each of four bidders, one past the cap of the mock;
+		// collectible 1 has one.
+		for bidder in 1..=4 {
+			Offers::<T>::insert(0, account(bidder), amount(10));
+		}
+		Offers::<T>::insert(1, account(2), amount(20));
+		StorageVersion::new(12).put::<Pallet<T>>();
+	}
+}
@@ -0,0 +1,112 @@
+//! v13 -> v14: sealed-bid auctions are indexed by the block their reveal phase ends at, and the
+//! auctions that are over but not settled are kept in `UnsettledAuctions` for the offchain
+//! worker, so it no longer scans every sealed-bid auction each block. Sealed-bid auctions past
+//! their reveal phase and English auctions past their end, which could not settle then, go
+//! straight to `UnsettledAuctions`, as does a sealed-bid auction ending at a block already
+//! holding `MaxDeadlinesPerBlock` others.
+
+use super::StorageMigration;
+use crate::*;
+use codec::{Decode, Encode};
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::{traits::Saturating, TryRuntimeError},
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+pub struct IndexUnsettledAuctions<T>(PhantomData<T>);
+
+impl<T: Config> StorageMigration for IndexUnsettledAuctions<T> {
+	fn migrate() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() != 13 {
+			log::info!(target: LOG_TARGET, "skipping v14 migration: not at v13");
+			return T::DbWeight::get().reads(1)
+		}
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let (mut scanned, mut unsettled) = (0u64, 0u64);
+		for (unique_id, auction) in SealedAuctions::<T>::iter() {
+			scanned.saturating_inc();
+			let indexed = auction.reveal_end >= now &&
+				SealedAuctionsEnding::<T>::try_append(auction.reveal_end, unique_id).is_ok();
+			if !indexed {
+				UnsettledAuctions::<T>::insert(unique_id, ());
+				unsettled.saturating_inc();
+			}
+		}
+		for (unique_id, auction) in Auctions::<T>::iter() {
+			scanned.saturating_inc();
+			if auction.end < now {
+				UnsettledAuctions::<T>::insert(unique_id, ());
+				unsettled.saturating_inc();
+			}
+		}
+		StorageVersion::new(14).put::<Pallet<T>>();
+
+		log::info!(target: LOG_TARGET, "found {unsettled} unsettled auctions for v14");
+		T::DbWeight::get().reads_writes(
+			scanned.saturating_mul(2).saturating_add(2),
+			scanned.saturating_add(1),
+		)
+	}
+
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		ensure!(Pallet::<T>::on_chain_storage_version() == 13, "vulntoken is not at v13");
+		ensure!(
+			UnsettledAuctions::<T>::iter().next().is_none() &&
+				SealedAuctionsEnding::<T>::iter().next().is_none(),
+			"auctions indexed before v14"
+		);
+		let sealed = SealedAuctions::<T>::iter()
+			.map(|(unique_id, auction)| (unique_id, auction.reveal_end, true));
+		let english =
+			Auctions::<T>::iter().map(|(unique_id, auction)| (unique_id, auction.end, false));
+		Ok(sealed.chain(english).collect::<Vec<_>>().encode())
+	}
+
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let auctions = Vec::<(u64, BlockNumberFor<T>, bool)>::decode(&mut &state[..])
+			.map_err(|_| "undecodable v13 snapshot")?;
+
+		ensure!(Pallet::<T>::on_chain_storage_version() == 14, "vulntoken is not at v14");
+		let now = frame_system::Pallet::<T>::block_number();
+		for (unique_id, end, sealed) in auctions {
+			let unsettled = UnsettledAuctions::<T>::contains_key(unique_id);
+			let indexed = sealed && SealedAuctionsEnding::<T>::get(end).contains(&unique_id);
+			if end < now {
+				ensure!(unsettled && !indexed, "an auction that is over was not queued");
+			} else if sealed {
+				ensure!(unsettled != indexed, "a sealed-bid auction was not indexed once");
+			} else {
+				ensure!(!unsettled, "an English auction still to end was queued");
+			}
+		}
+		Ok(())
+	}
+
+	#[cfg(test)]
+	fn legacy_state() {
+		use frame_support::sp_runtime::traits::TrailingZeroInput;
+
+		let seller = T::AccountId::decode(&mut TrailingZeroInput::new(&[1])).unwrap();
+		let block = |number: u32| BlockNumberFor::<T>::from(number);
+		let sealed = |reveal_end| SealedAuction::<T> {
+			seller: seller.clone(),
+			min_bid: 10u32.into(),
+			commit_end: block(1),
+			reveal_end,
+			bids: 0,
+			leader: None,
+		};
+		let english =
+			|end| Auction::<T> { seller: seller.clone(), reserve: 10u32.into(), leader: None, end };
+
+		// At block 10, sealed-bid auction 0 and English auction 2 are over; 1 and 3 are not.
+		frame_system::Pallet::<T>::set_block_number(block(10));
+		SealedAuctions::<T>::insert(0, sealed(block(5)));
+		SealedAuctions::<T>::insert(1, sealed(block(15)));
+		Auctions::<T>::insert(2, english(block(5)));
+		Auctions::<T>::insert(3, english(block(15)));
+		StorageVersion::new(13).put::<Pallet<T>>();
+	}
+}
@@ -0,0 +1,165 @@
+//! v1 -> v2: funds the pallet used to reserve are moved into holds under a [`HoldReason`].
+//!
+//! `C` is the currency the pallet reserved with before, the same balances pallet that now
+//! backs `T::Currency`.
+
+use super::StorageMigration;
+use crate::*;
+use codec::{Decode, Encode};
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::{traits::Saturating, TryRuntimeError},
+	traits::{
+		fungible::{InspectHold, MutateHold},
+		ReservableCurrency,
+	},
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+pub struct MigrateReservesToHolds<T, C>(PhantomData<(T, C)>);
+
+type Holds<T> = Vec<(<T as frame_system::Config>::AccountId, HoldReason, BalanceOf<T>)>;
+
+impl<T, C> MigrateReservesToHolds<T, C>
+where
+	T: Config,
+	C: ReservableCurrency<T::AccountId, Balance = BalanceOf<T>>,
+{
+	/// Everything the pallet has reserved, summed per account and reason.
+	fn reserves() -> Holds<T> {
+		let mut holds = Holds::<T>::new();
+		let mut add = |who: T::AccountId, reason: HoldReason, amount: BalanceOf<T>| {
+			match holds.iter_mut().find(|(w, r, _)| *w == who && *r == reason) {
+				Some((_, _, total)) => total.saturating_accrue(amount),
+				None => holds.push((who, reason, amount)),
+			}
+		};
+		for (_, bidder, bid) in SealedBids::<T>::iter() {
+			add(bidder, HoldReason::Bid, bid.deposit);
+		}
+		for (_, auction) in SealedAuctions::<T>::iter() {
+			if let Some((leader, amount)) = auction.leader {
+				add(leader, HoldReason::Bid, amount);
+			}
+		}
+		for (_, escrow) in Escrows::<T>::iter() {
+			add(escrow.buyer, HoldReason::Escrow, escrow.price);
+		}
+		for (_, dispute) in Disputes::<T>::iter() {
+			add(dispute.raised_by, HoldReason::DisputeDeposit, dispute.deposit);
+		}
+		for (unique_id, _, holder) in RaffleTickets::<T>::iter() {
+			if let Some(raffle) = Raffles::<T>::get(&unique_id) {
+				add(holder, HoldReason::RaffleTicket, raffle.ticket_price);
+			}
+		}
+		holds
+	}
+}
+
+impl<T, C> StorageMigration for MigrateReservesToHolds<T, C>
+where
+	T: Config,
+	C: ReservableCurrency<T::AccountId, Balance = BalanceOf<T>>,
+{
+	fn migrate() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() != 1 {
+			log::info!(target: LOG_TARGET, "skipping v2 migration: not at v1");
+			return T::DbWeight::get().reads(1)
+		}
+
+		let holds = Self::reserves();
+		for (who, reason, amount) in &holds {
+			let missing = C::unreserve(who, *amount);
+			let amount = amount.saturating_sub(missing);
+			if let Err(e) = T::Currency::hold(&(*reason).into(), who, amount) {
+				// The funds stay free rather than stuck in a reserve nothing releases.
+				log::error!(target: LOG_TARGET, "holding {amount:?} for {reason:?} failed: {e:?}");
+			}
+		}
+		StorageVersion::new(2).put::<Pallet<T>>();
+
+		log::info!(target: LOG_TARGET, "moved {} reserves into holds", holds.len());
+		let records = SealedBids::<T>::iter_keys().count() +
+			SealedAuctions::<T>::iter_keys().count() +
+			Escrows::<T>::iter_keys().count() +
+			Disputes::<T>::iter_keys().count() +
+			RaffleTickets::<T>::iter_keys().count();
+		let holds = holds.len() as u64;
+		T::DbWeight::get()
+			.reads((records as u64).saturating_add(holds.saturating_mul(2)).saturating_add(1))
+			.saturating_add(T::DbWeight::get().writes(holds.saturating_mul(3).saturating_add(1)))
+	}
+
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		ensure!(Pallet::<T>::on_chain_storage_version() == 1, "vulntoken is not at v1");
+		let holds = Self::reserves();
+		for (who, _, amount) in &holds {
+			ensure!(C::reserved_balance(who) >= *amount, "less reserved than the pallet expects");
+		}
+		Ok(holds.encode())
+	}
+
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let holds = Holds::<T>::decode(&mut &state[..]).map_err(|_| "undecodable v1 snapshot")?;
+		ensure!(Pallet::<T>::on_chain_storage_version() == 2, "vulntoken is not at v2");
+		for (who, reason, amount) in holds {
+			ensure!(
+				T::Currency::balance_on_hold(&reason.into(), &who) == amount,
+				"reserve was not moved into its hold"
+			);
+		}
+		Ok(())
+	}
+
+	#[cfg(test)]
+	fn legacy_state() {
+		use frame_support::{
+			sp_runtime::traits::{TrailingZeroInput, Zero},
+			traits::fungible::Mutate,
+		};
+
+		let account = |n: u8| T::AccountId::decode(&mut TrailingZeroInput::new(&[n])).unwrap();
+		let (seller, bidder, buyer) = (account(1), account(2), account(3));
+		for who in [&seller, &bidder, &buyer] {
+			T::Currency::set_balance(who, 1_000u32.into());
+		}
+		let deposit: BalanceOf<T> = 10u32.into();
+		let price: BalanceOf<T> = 100u32.into();
+
+		// A revealed leading bid of 100 with its deposit, and a disputed escrow of 100.
+		SealedBids::<T>::insert(
+			0,
+			&bidder,
+			SealedBid { commitment: Default::default(), deposit, revealed: true },
+		);
+		SealedAuctions::<T>::insert(
+			0,
+			SealedAuction {
+				seller: seller.clone(),
+				min_bid: Zero::zero(),
+				commit_end: Zero::zero(),
+				reveal_end: 1u32.into(),
+				bids: 1,
+				leader: Some((bidder.clone(), price)),
+			},
+		);
+		C::reserve(&bidder, deposit.saturating_add(price)).unwrap();
+		Escrows::<T>::insert(
+			1,
+			Escrow { seller, buyer: buyer.clone(), price, dispute_deadline: 1u32.into() },
+		);
+		Disputes::<T>::insert(
+			1,
+			Dispute {
+				raised_by: buyer.clone(),
+				deposit,
+				voting_end: 1u32.into(),
+				for_buyer: 0,
+				for_seller: 0,
+			},
+		);
+		C::reserve(&buyer, deposit.saturating_add(price)).unwrap();
+		StorageVersion::new(1).put::<Pallet<T>>();
+	}
+}
@@ -0,0 +1,111 @@
+//! v2 -> v3: collectibles in an auction, raffle, escrow or dispute are recorded in `Frozen`,
+//! which replaces looking each of those up to tell whether an item is locked.
+
+use super::StorageMigration;
+use crate::*;
+use codec::{Decode, Encode};
+use frame_support::{pallet_prelude::*, sp_runtime::TryRuntimeError};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+pub struct PopulateFreezes<T>(PhantomData<T>);
+
+impl<T: Config> PopulateFreezes<T> {
+	/// The freezes implied by the features' own records.
+	fn freezes() -> Vec<(u64, FreezeReason)> {
+		let mut freezes = SealedAuctions::<T>::iter_keys()
+			.map(|unique_id| (unique_id, FreezeReason::Auction))
+			.chain(Raffles::<T>::iter_keys().map(|unique_id| (unique_id, FreezeReason::Raffle)))
+			.chain(Escrows::<T>::iter_keys().map(|unique_id| (unique_id, FreezeReason::Escrow)))
+			.chain(Disputes::<T>::iter_keys().map(|unique_id| (unique_id, FreezeReason::Disputed)))
+			.collect::<Vec<_>>();
+		freezes.sort();
+		freezes
+	}
+}
+
+impl<T: Config> StorageMigration for PopulateFreezes<T> {
+	fn migrate() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() != 2 {
+			log::info!(target: LOG_TARGET, "skipping v3 migration: not at v2");
+			return T::DbWeight::get().reads(1)
+		}
+
+		let freezes = Self::freezes();
+		for (unique_id, reason) in &freezes {
+			Pallet::<T>::do_freeze(*unique_id, *reason);
+		}
+		StorageVersion::new(3).put::<Pallet<T>>();
+
+		log::info!(target: LOG_TARGET, "recorded {} freezes", freezes.len());
+		let freezes = freezes.len() as u64;
+		T::DbWeight::get().reads_writes(freezes.saturating_add(1), freezes.saturating_add(1))
+	}
+
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		ensure!(Pallet::<T>::on_chain_storage_version() == 2, "vulntoken is not at v2");
+		ensure!(Frozen::<T>::iter_keys().next().is_none(), "freezes recorded before v3");
+		Ok(Self::freezes().encode())
+	}
+
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let freezes = Vec::<(u64, FreezeReason)>::decode(&mut &state[..])
+			.map_err(|_| "undecodable v2 snapshot")?;
+		ensure!(Pallet::<T>::on_chain_storage_version() == 3, "vulntoken is not at v3");
+		let mut frozen = Frozen::<T>::iter_keys().collect::<Vec<_>>();
+		frozen.sort();
+		ensure!(frozen == freezes, "freezes do not match the locked collectibles");
+		Ok(())
+	}
+
+	#[cfg(test)]
+	fn legacy_state() {
+		use frame_support::sp_runtime::traits::{TrailingZeroInput, Zero};
+
+		let account = |n: u8| T::AccountId::decode(&mut TrailingZeroInput::new(&[n])).unwrap();
+		let (seller, buyer) = (account(1), account(2));
+
+		// Collectible 0 is up for auction, 1 is raffled and 2 is in a disputed escrow.
+		SealedAuctions::<T>::insert(
+			0,
+			SealedAuction {
+				seller: seller.clone(),
+				min_bid: Zero::zero(),
+				commit_end: 1u32.into(),
+				reveal_end: 2u32.into(),
+				bids: 0,
+				leader: None,
+			},
+		);
+		Raffles::<T>::insert(
+			1,
+			Raffle {
+				owner: seller.clone(),
+				ticket_price: 1u32.into(),
+				min_tickets: 1,
+				max_tickets: 1,
+				sold: 0,
+				end: 1u32.into(),
+			},
+		);
+		Escrows::<T>::insert(
+			2,
+			Escrow {
+				seller,
+				buyer: buyer.clone(),
+				price: Zero::zero(),
+				dispute_deadline: 1u32.into(),
+			},
+		);
+		Disputes::<T>::insert(
+			2,
+			Dispute {
+				raised_by: buyer,
+				deposit: Zero::zero(),
+				voting_end: 1u32.into(),
+				for_buyer: 0,
+				for_seller: 0,
+			},
+		);
+		StorageVersion::new(2).put::<Pallet<T>>();
+	}
+}
@@ -0,0 +1,172 @@
+//! v3 -> v4: running rentals move from a prepayment the pallet held itself onto payment
+//! streams opened through `T::Streams`.
+//!
+//! Rent accrued up to the upgrade is paid to the owner first and the rest of the prepayment is
+//! released, then held again by the stream. A rental whose prepayment no longer covers a block
+//! ends at the upgrade.
+
+use super::StorageMigration;
+use crate::*;
+use codec::{Decode, Encode};
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::{
+		traits::{Saturating, Zero},
+		SaturatedConversion, TryRuntimeError,
+	},
+	storage_alias,
+	traits::{
+		fungible::{InspectHold, MutateHold},
+		tokens::{Fortitude, Precision, Restriction},
+	},
+};
+use pallet_payment_streams::PaymentStreams;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// The storage layout before v4.
+pub mod v3 {
+	use super::*;
+
+	#[derive(Encode, Decode, RuntimeDebug)]
+	pub struct Rental<T: Config> {
+		pub owner: T::AccountId,
+		pub renter: T::AccountId,
+		pub rate: BalanceOf<T>,
+		pub prepaid: BalanceOf<T>,
+		pub settled_at: BlockNumberFor<T>,
+	}
+
+	#[storage_alias]
+	pub type Rentals<T: Config> = StorageMap<Pallet<T>, Twox64Concat, u64, Rental<T>>;
+}
+
+pub struct MoveRentalsToStreams<T>(PhantomData<T>);
+
+impl<T: Config> MoveRentalsToStreams<T> {
+	/// Pay the owner the rent accrued under the old layout and release the rest of the
+	/// prepayment, returning what was released.
+	fn settle(old: &v3::Rental<T>) -> BalanceOf<T> {
+		let reason = HoldReason::Rental.into();
+		let elapsed: u128 = frame_system::Pallet::<T>::block_number()
+			.saturating_sub(old.settled_at)
+			.saturated_into();
+		let due = old.rate.saturating_mul(elapsed.saturated_into()).min(old.prepaid);
+		let paid = T::Currency::transfer_on_hold(
+			&reason,
+			&old.renter,
+			&old.owner,
+			due,
+			Precision::BestEffort,
+			Restriction::Free,
+			Fortitude::Polite,
+		)
+		.unwrap_or_else(|e| {
+			log::error!(target: LOG_TARGET, "paying {due:?} of rent failed: {e:?}");
+			Zero::zero()
+		});
+		let left = old.prepaid.saturating_sub(paid);
+		T::Currency::release(&reason, &old.renter, left, Precision::BestEffort).unwrap_or_else(|e| {
+			log::error!(target: LOG_TARGET, "releasing {left:?} of rent failed: {e:?}");
+			Zero::zero()
+		})
+	}
+}
+
+impl<T: Config> StorageMigration for MoveRentalsToStreams<T> {
+	fn migrate() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() != 3 {
+			log::info!(target: LOG_TARGET, "skipping v4 migration: not at v3");
+			return T::DbWeight::get().reads(1)
+		}
+
+		let (mut moved, mut ended) = (0u64, 0u64);
+		Rentals::<T>::translate::<v3::Rental<T>, _>(|unique_id, old| {
+			let left = Self::settle(&old);
+			let stream = if left < old.rate {
+				None
+			} else {
+				T::Streams::open(&old.renter, &old.owner, old.rate, left)
+					.map_err(|e| {
+						log::error!(target: LOG_TARGET, "opening a stream failed: {e:?}");
+					})
+					.ok()
+			};
+			match stream {
+				Some(stream) => {
+					moved += 1;
+					Some(Rental { owner: old.owner, renter: old.renter, rate: old.rate, stream })
+				},
+				None => {
+					ended += 1;
+					Pallet::<T>::do_thaw(unique_id, FreezeReason::Rented);
+					None
+				},
+			}
+		});
+		StorageVersion::new(4).put::<Pallet<T>>();
+
+		log::info!(target: LOG_TARGET, "moved {moved} rentals to streams, ended {ended}");
+		let rentals = moved.saturating_add(ended);
+		T::DbWeight::get()
+			.reads(rentals.saturating_mul(4).saturating_add(1))
+			.saturating_add(T::DbWeight::get().writes(
+				rentals.saturating_mul(3).saturating_add(moved.saturating_mul(3)).saturating_add(1),
+			))
+	}
+
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		ensure!(Pallet::<T>::on_chain_storage_version() == 3, "vulntoken is not at v3");
+		let rentals = v3::Rentals::<T>::iter()
+			.map(|(unique_id, old)| (unique_id, old.renter))
+			.collect::<Vec<_>>();
+		Ok(rentals.encode())
+	}
+
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let rentals = Vec::<(u64, T::AccountId)>::decode(&mut &state[..])
+			.map_err(|_| "undecodable v3 snapshot")?;
+		ensure!(Pallet::<T>::on_chain_storage_version() == 4, "vulntoken is not at v4");
+		for (unique_id, renter) in rentals {
+			ensure!(
+				T::Currency::balance_on_hold(&HoldReason::Rental.into(), &renter).is_zero(),
+				"rent is still held by the pallet"
+			);
+			match Rentals::<T>::get(&unique_id) {
+				Some(rental) => ensure!(
+					T::Streams::remaining(rental.stream).is_some(),
+					"rental has no stream"
+				),
+				None => ensure!(
+					!Pallet::<T>::is_frozen(unique_id, FreezeReason::Rented),
+					"ended rental is still frozen"
+				),
+			}
+		}
+		Ok(())
+	}
+
+	#[cfg(test)]
+	fn legacy_state() {
+		use frame_support::{sp_runtime::traits::TrailingZeroInput, traits::fungible::Mutate};
+
+		let account = |n: u8| T::AccountId::decode(&mut TrailingZeroInput::new(&[n])).unwrap();
+		let (owner, renter) = (account(1), account(2));
+		T::Currency::set_balance(&renter, 1_000u32.into());
+		let rental = |prepaid: u32| v3::Rental::<T> {
+			owner: owner.clone(),
+			renter: renter.clone(),
+			rate: 10u32.into(),
+			prepaid: prepaid.into(),
+			settled_at: Zero::zero(),
+		};
+
+		// Five blocks in, collectible 0 has 50 of its 100 prepaid left and collectible 1 ran out.
+		frame_system::Pallet::<T>::set_block_number(5u32.into());
+		for (unique_id, prepaid) in [(0, 100), (1, 30)] {
+			T::Currency::hold(&HoldReason::Rental.into(), &renter, prepaid.into()).unwrap();
+			v3::Rentals::<T>::insert(unique_id, rental(prepaid));
+			Pallet::<T>::do_freeze(unique_id, FreezeReason::Rented);
+		}
+		StorageVersion::new(3).put::<Pallet<T>>();
+	}
+}
@@ -0,0 +1,65 @@
+//! v4 -> v5: the floor index starts out empty, so the upgrade schedules a rebuild from every
+//! collectible. `on_idle` carries it out over as many blocks as it takes.
+
+use super::StorageMigration;
+use crate::*;
+use frame_support::{pallet_prelude::*, sp_runtime::TryRuntimeError};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+pub struct RebuildFloorIndex<T>(PhantomData<T>);
+
+impl<T: Config> StorageMigration for RebuildFloorIndex<T> {
+	fn migrate() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() != 4 {
+			log::info!(target: LOG_TARGET, "skipping v5 migration: not at v4");
+			return T::DbWeight::get().reads(1)
+		}
+
+		FloorIndex::<T>::put(CheapestListings::<T> { rebuild_from: Some(0), ..Default::default() });
+		FloorPrice::<T>::kill();
+		StorageVersion::new(5).put::<Pallet<T>>();
+
+		log::info!(target: LOG_TARGET, "scheduled a floor index rebuild");
+		T::DbWeight::get().reads_writes(1, 3)
+	}
+
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		ensure!(Pallet::<T>::on_chain_storage_version() == 4, "vulntoken is not at v4");
+		ensure!(!FloorIndex::<T>::exists(), "floor index built before v5");
+		Ok(Vec::new())
+	}
+
+	fn post_upgrade(_: Vec<u8>) -> Result<(), TryRuntimeError> {
+		ensure!(Pallet::<T>::on_chain_storage_version() == 5, "vulntoken is not at v5");
+		ensure!(
+			FloorIndex::<T>::get().rebuild_from == Some(0),
+			"floor index rebuild not scheduled"
+		);
+		Ok(())
+	}
+
+	#[cfg(test)]
+	fn legacy_state() {
+		use codec::Decode;
+		use frame_support::sp_runtime::traits::TrailingZeroInput;
+
+		let owner = T::AccountId::decode(&mut TrailingZeroInput::new(&[1])).unwrap();
+		let collectible = |unique_id, price: Option<u32>| Collectible::<T> {
+			unique_id,
+			price: price.map(Into::into),
+			color: Color::Red,
+			owner: owner.clone(),
+			creator: owner.clone(),
+			metadata: None,
+			flags: Default::default(),
+			collection_id: None,
+		};
+
+		// Collectibles 0 and 2 are listed; 1 is not.
+		CollectibleMap::<T>::insert(0, collectible(0, Some(30)));
+		CollectibleMap::<T>::insert(1, collectible(1, None));
+		CollectibleMap::<T>::insert(2, collectible(2, Some(20)));
+		NextCollectibleId::<T>::put(3);
+		StorageVersion::new(4).put::<Pallet<T>>();
+	}
+}
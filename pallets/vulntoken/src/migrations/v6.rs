@@ -0,0 +1,93 @@
+//! v5 -> v6: per-color statistics. Counts are taken from every collectible, and a rebuild of
+//! each color's floor index is scheduled for `on_idle`, as v5 did for the pallet-wide one.
+//! Nothing records past sales by color, so last sale prices start out unset.
+
+use super::StorageMigration;
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::{traits::Saturating, TryRuntimeError},
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+pub struct PopulateColorStatistics<T>(PhantomData<T>);
+
+impl<T: Config> StorageMigration for PopulateColorStatistics<T> {
+	fn migrate() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() != 5 {
+			log::info!(target: LOG_TARGET, "skipping v6 migration: not at v5");
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut collectibles = 0u64;
+		for collectible in CollectibleMap::<T>::iter_values() {
+			Pallet::<T>::note_minted(collectible.color);
+			collectibles.saturating_inc();
+		}
+		for color in Color::ALL {
+			ColorFloorIndex::<T>::insert(
+				color,
+				CheapestListings::<T> { rebuild_from: Some(0), ..Default::default() },
+			);
+		}
+		StorageVersion::new(6).put::<Pallet<T>>();
+
+		log::info!(target: LOG_TARGET, "counted {} collectibles by color", collectibles);
+		let colors = Color::ALL.len() as u64;
+		T::DbWeight::get().reads_writes(
+			collectibles.saturating_mul(2).saturating_add(1),
+			collectibles.saturating_add(colors).saturating_add(1),
+		)
+	}
+
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		ensure!(Pallet::<T>::on_chain_storage_version() == 5, "vulntoken is not at v5");
+		ensure!(
+			ColorStatistics::<T>::iter_keys().next().is_none(),
+			"color statistics kept before v6"
+		);
+		Ok(Vec::new())
+	}
+
+	fn post_upgrade(_: Vec<u8>) -> Result<(), TryRuntimeError> {
+		ensure!(Pallet::<T>::on_chain_storage_version() == 6, "vulntoken is not at v6");
+		let counted: u64 =
+			Color::ALL.into_iter().map(|color| ColorStatistics::<T>::get(color).count).sum();
+		ensure!(
+			counted == CollectibleMap::<T>::iter_keys().count() as u64,
+			"color counts do not add up to the collectibles"
+		);
+		ensure!(
+			Color::ALL
+				.into_iter()
+				.all(|color| ColorFloorIndex::<T>::get(color).rebuild_from == Some(0)),
+			"color floor index rebuilds not scheduled"
+		);
+		Ok(())
+	}
+
+	#[cfg(test)]
+	fn legacy_state() {
+		use codec::Decode;
+		use frame_support::sp_runtime::traits::TrailingZeroInput;
+
+		let owner = T::AccountId::decode(&mut TrailingZeroInput::new(&[1])).unwrap();
+		let collectible = |unique_id, color, price: Option<u32>| Collectible::<T> {
+			unique_id,
+			price: price.map(Into::into),
+			color,
+			owner: owner.clone(),
+			creator: owner.clone(),
+			metadata: None,
+			flags: Default::default(),
+			collection_id: None,
+		};
+
+		// Two red collectibles, one listed, and a listed yellow one.
+		CollectibleMap::<T>::insert(0, collectible(0, Color::Red, Some(30)));
+		CollectibleMap::<T>::insert(1, collectible(1, Color::Red, None));
+		CollectibleMap::<T>::insert(2, collectible(2, Color::Yellow, Some(20)));
+		NextCollectibleId::<T>::put(3);
+		StorageVersion::new(5).put::<Pallet<T>>();
+	}
+}
@@ -0,0 +1,191 @@
+//! v6 -> v7: maps keyed by account ids move from `Twox64Concat` to `Blake2_128Concat`. Anyone
+//! can choose the account ids that end up in these keys, and Twox is not collision resistant
+//! against chosen keys, so accounts could be ground to unbalance the trie.
+
+use super::{v10::v9, StorageMigration};
+use crate::*;
+use codec::{Decode, Encode};
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::{traits::Saturating, TryRuntimeError},
+	storage_alias,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// The storage layout before v7.
+pub mod v6 {
+	use super::*;
+
+	#[storage_alias]
+	pub type OwnerOfCollectibles<T: Config> = StorageMap<
+		Pallet<T>,
+		Twox64Concat,
+		<T as frame_system::Config>::AccountId,
+		BoundedVec<u64, <T as Config>::MaximumOwned>,
+		ValueQuery,
+	>;
+
+	#[storage_alias]
+	pub type SealedBids<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Twox64Concat,
+		u64,
+		Twox64Concat,
+		<T as frame_system::Config>::AccountId,
+		SealedBid<T>,
+	>;
+
+	#[storage_alias]
+	pub type ApprovedMarkets<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, <T as frame_system::Config>::AccountId, ()>;
+
+	#[storage_alias]
+	pub type DisputeVotes<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Twox64Concat,
+		u64,
+		Twox64Concat,
+		<T as frame_system::Config>::AccountId,
+		bool,
+	>;
+
+	#[storage_alias]
+	pub type AcceptsMints<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, <T as frame_system::Config>::AccountId, ()>;
+
+	#[storage_alias]
+	pub type PendingMints<T: Config> = StorageMap<
+		Pallet<T>,
+		Twox64Concat,
+		<T as frame_system::Config>::AccountId,
+		u32,
+		ValueQuery,
+	>;
+}
+
+pub struct RehashAccountKeys<T>(PhantomData<T>);
+
+/// Entries of every map this migration rehashes, in the same order in both layouts.
+type Snapshot<T> = (
+	Vec<(<T as frame_system::Config>::AccountId, BoundedVec<u64, <T as Config>::MaximumOwned>)>,
+	Vec<(u64, <T as frame_system::Config>::AccountId, SealedBid<T>)>,
+	Vec<<T as frame_system::Config>::AccountId>,
+	Vec<(u64, <T as frame_system::Config>::AccountId, bool)>,
+	Vec<<T as frame_system::Config>::AccountId>,
+	Vec<(<T as frame_system::Config>::AccountId, u32)>,
+);
+
+impl<T: Config> StorageMigration for RehashAccountKeys<T> {
+	fn migrate() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() != 6 {
+			log::info!(target: LOG_TARGET, "skipping v7 migration: not at v6");
+			return T::DbWeight::get().reads(1)
+		}
+
+		// Every old entry is drained before any new one is written under the same prefix.
+		let mut moved = 0u64;
+		let owners = v6::OwnerOfCollectibles::<T>::drain().collect::<Vec<_>>();
+		let bids = v6::SealedBids::<T>::drain().collect::<Vec<_>>();
+		let markets = v6::ApprovedMarkets::<T>::drain().collect::<Vec<_>>();
+		let votes = v6::DisputeVotes::<T>::drain().collect::<Vec<_>>();
+		let accepting = v6::AcceptsMints::<T>::drain().collect::<Vec<_>>();
+		let pending = v6::PendingMints::<T>::drain().collect::<Vec<_>>();
+		for (owner, owned) in owners {
+			OwnerOfCollectibles::<T>::insert(owner, owned);
+			moved.saturating_inc();
+		}
+		for (unique_id, bidder, bid) in bids {
+			SealedBids::<T>::insert(unique_id, bidder, bid);
+			moved.saturating_inc();
+		}
+		for (market, ()) in markets {
+			ApprovedMarkets::<T>::insert(market, ());
+			moved.saturating_inc();
+		}
+		for (unique_id, juror, vote) in votes {
+			DisputeVotes::<T>::insert(unique_id, juror, vote);
+			moved.saturating_inc();
+		}
+		for (account, ()) in accepting {
+			AcceptsMints::<T>::insert(account, ());
+			moved.saturating_inc();
+		}
+		for (account, count) in pending {
+			v9::PendingMints::<T>::insert(account, count);
+			moved.saturating_inc();
+		}
+		StorageVersion::new(7).put::<Pallet<T>>();
+
+		log::info!(target: LOG_TARGET, "rehashed {moved} account-keyed entries");
+		T::DbWeight::get()
+			.reads_writes(moved.saturating_add(1), moved.saturating_mul(2).saturating_add(1))
+	}
+
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		ensure!(Pallet::<T>::on_chain_storage_version() == 6, "vulntoken is not at v6");
+		let snapshot: Snapshot<T> = (
+			v6::OwnerOfCollectibles::<T>::iter().collect(),
+			v6::SealedBids::<T>::iter().collect(),
+			v6::ApprovedMarkets::<T>::iter_keys().collect(),
+			v6::DisputeVotes::<T>::iter().collect(),
+			v6::AcceptsMints::<T>::iter_keys().collect(),
+			v6::PendingMints::<T>::iter().collect(),
+		);
+		Ok(snapshot.encode())
+	}
+
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let (owners, bids, markets, votes, accepting, pending) =
+			Snapshot::<T>::decode(&mut &state[..]).map_err(|_| "undecodable v6 snapshot")?;
+
+		ensure!(Pallet::<T>::on_chain_storage_version() == 7, "vulntoken is not at v7");
+		ensure!(OwnerOfCollectibles::<T>::iter().count() == owners.len(), "owners were lost");
+		for (owner, owned) in owners {
+			ensure!(OwnerOfCollectibles::<T>::get(&owner) == owned, "owned collectibles changed");
+		}
+		ensure!(SealedBids::<T>::iter().count() == bids.len(), "sealed bids were lost");
+		for (unique_id, bidder, bid) in bids {
+			ensure!(SealedBids::<T>::get(unique_id, &bidder) == Some(bid), "sealed bid changed");
+		}
+		ensure!(ApprovedMarkets::<T>::iter().count() == markets.len(), "markets were lost");
+		ensure!(
+			markets.iter().all(|market| ApprovedMarkets::<T>::contains_key(market)),
+			"market approval was dropped"
+		);
+		ensure!(DisputeVotes::<T>::iter().count() == votes.len(), "votes were lost");
+		for (unique_id, juror, vote) in votes {
+			ensure!(DisputeVotes::<T>::get(unique_id, &juror) == Some(vote), "vote changed");
+		}
+		ensure!(AcceptsMints::<T>::iter().count() == accepting.len(), "mint consent was lost");
+		ensure!(
+			accepting.iter().all(|account| AcceptsMints::<T>::contains_key(account)),
+			"mint consent was dropped"
+		);
+		ensure!(v9::PendingMints::<T>::iter().count() == pending.len(), "pending mints were lost");
+		for (account, count) in pending {
+			ensure!(v9::PendingMints::<T>::get(&account) == count, "pending mints changed");
+		}
+		Ok(())
+	}
+
+	#[cfg(test)]
+	fn legacy_state() {
+		use frame_support::sp_runtime::traits::TrailingZeroInput;
+
+		let account = |n: u8| T::AccountId::decode(&mut TrailingZeroInput::new(&[n])).unwrap();
+		let (alice, bob, market) = (account(1), account(2), account(3));
+
+		v6::OwnerOfCollectibles::<T>::insert(&alice, BoundedVec::truncate_from(vec![0, 2]));
+		v6::OwnerOfCollectibles::<T>::insert(&bob, BoundedVec::truncate_from(vec![1]));
+		v6::SealedBids::<T>::insert(
+			0,
+			&bob,
+			SealedBid { commitment: Default::default(), deposit: 5u32.into(), revealed: false },
+		);
+		v6::ApprovedMarkets::<T>::insert(&market, ());
+		v6::DisputeVotes::<T>::insert(1, &alice, true);
+		v6::AcceptsMints::<T>::insert(&bob, ());
+		v6::PendingMints::<T>::insert(&bob, 2);
+		StorageVersion::new(6).put::<Pallet<T>>();
+	}
+}
@@ -0,0 +1,117 @@
+//! v7 -> v8: collectibles record the collection they were minted into. Collections did not exist
+//! before, so every collectible starts out in none.
+
+use super::StorageMigration;
+use crate::*;
+use codec::{Decode, Encode};
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::{traits::Saturating, TryRuntimeError},
+	storage_alias,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// The storage layout before v8.
+pub mod v7 {
+	use super::*;
+
+	#[derive(Encode, Decode, RuntimeDebug)]
+	pub struct Collectible<T: Config> {
+		pub unique_id: u64,
+		pub price: Option<BalanceOf<T>>,
+		pub color: Color,
+		pub owner: T::AccountId,
+		pub creator: T::AccountId,
+		pub metadata: Option<[u8; 32]>,
+		pub flags: CollectibleFlags,
+	}
+
+	#[storage_alias]
+	pub type CollectibleMap<T: Config> = StorageMap<Pallet<T>, Twox64Concat, u64, Collectible<T>>;
+}
+
+pub struct AddCollectionIds<T>(PhantomData<T>);
+
+impl<T: Config> StorageMigration for AddCollectionIds<T> {
+	fn migrate() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() != 7 {
+			log::info!(target: LOG_TARGET, "skipping v8 migration: not at v7");
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut migrated = 0u64;
+		CollectibleMap::<T>::translate::<v7::Collectible<T>, _>(|_, old| {
+			migrated.saturating_inc();
+			Some(Collectible {
+				unique_id: old.unique_id,
+				price: old.price,
+				color: old.color,
+				owner: old.owner,
+				creator: old.creator,
+				metadata: old.metadata,
+				flags: old.flags,
+				collection_id: None,
+			})
+		});
+		StorageVersion::new(8).put::<Pallet<T>>();
+
+		log::info!(target: LOG_TARGET, "migrated {migrated} collectibles to v8");
+		T::DbWeight::get().reads_writes(migrated.saturating_add(1), migrated.saturating_add(1))
+	}
+
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		ensure!(Pallet::<T>::on_chain_storage_version() == 7, "vulntoken is not at v7");
+		Ok(v7::CollectibleMap::<T>::iter_values().collect::<Vec<_>>().encode())
+	}
+
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let collectibles = Vec::<v7::Collectible<T>>::decode(&mut &state[..])
+			.map_err(|_| "undecodable v7 snapshot")?;
+
+		ensure!(Pallet::<T>::on_chain_storage_version() == 8, "vulntoken is not at v8");
+		ensure!(
+			CollectibleMap::<T>::iter().count() == collectibles.len(),
+			"collectibles were lost"
+		);
+		for old in collectibles {
+			let new = CollectibleMap::<T>::get(&old.unique_id).ok_or("collectible was dropped")?;
+			ensure!(
+				new.price == old.price &&
+					new.color == old.color && new.owner == old.owner &&
+					new.creator == old.creator && new.metadata == old.metadata &&
+					new.flags == old.flags,
+				"collectible changed"
+			);
+			ensure!(new.collection_id.is_none(), "collectible was put in a collection");
+		}
+		Ok(())
+	}
+
+	#[cfg(test)]
+	fn legacy_state() {
+		use frame_support::sp_runtime::traits::TrailingZeroInput;
+
+		let owner = T::AccountId::decode(&mut TrailingZeroInput::new(&[1])).unwrap();
+		let creator = T::AccountId::decode(&mut TrailingZeroInput::new(&[2])).unwrap();
+		let collectible = |unique_id, color| v7::Collectible::<T> {
+			unique_id,
+			price: None,
+			color,
+			owner: owner.clone(),
+			creator: creator.clone(),
+			metadata: None,
+			flags: Default::default(),
+		};
+
+		v7::CollectibleMap::<T>::insert(0, collectible(0, Color::Red));
+		v7::CollectibleMap::<T>::insert(
+			1,
+			v7::Collectible {
+				price: Some(20u32.into()),
+				metadata: Some([1; 32]),
+				..collectible(1, Color::Blue)
+			},
+		);
+		StorageVersion::new(7).put::<Pallet<T>>();
+	}
+}
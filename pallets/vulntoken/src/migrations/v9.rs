@@ -0,0 +1,67 @@
+//! v8 -> v9: `HighestPrice` is no longer recomputed from every collectible each block, and
+//! what it held may be the price of a listing that has since gone. The upgrade clears it and
+//! schedules a scan for the dearest listing, which `on_idle` carries out.
+
+use super::StorageMigration;
+use crate::*;
+use frame_support::{pallet_prelude::*, sp_runtime::TryRuntimeError};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+pub struct ScanHighestPrice<T>(PhantomData<T>);
+
+impl<T: Config> StorageMigration for ScanHighestPrice<T> {
+	fn migrate() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() != 8 {
+			log::info!(target: LOG_TARGET, "skipping v9 migration: not at v8");
+			return T::DbWeight::get().reads(1)
+		}
+
+		HighestPrice::<T>::kill();
+		HighestListing::<T>::kill();
+		HighestPriceScan::<T>::put(0);
+		StorageVersion::new(9).put::<Pallet<T>>();
+
+		log::info!(target: LOG_TARGET, "scheduled a scan for the highest price");
+		T::DbWeight::get().reads_writes(1, 4)
+	}
+
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		ensure!(Pallet::<T>::on_chain_storage_version() == 8, "vulntoken is not at v8");
+		ensure!(!HighestPriceScan::<T>::exists(), "highest price scanned before v9");
+		Ok(Vec::new())
+	}
+
+	fn post_upgrade(_: Vec<u8>) -> Result<(), TryRuntimeError> {
+		ensure!(Pallet::<T>::on_chain_storage_version() == 9, "vulntoken is not at v9");
+		ensure!(HighestPriceScan::<T>::get() == Some(0), "highest price scan not scheduled");
+		ensure!(!HighestListing::<T>::exists(), "stale highest listing kept");
+		Ok(())
+	}
+
+	#[cfg(test)]
+	fn legacy_state() {
+		use codec::Decode;
+		use frame_support::sp_runtime::traits::TrailingZeroInput;
+
+		let owner = T::AccountId::decode(&mut TrailingZeroInput::new(&[1])).unwrap();
+		let collectible = |unique_id, price: Option<u32>| Collectible::<T> {
+			unique_id,
+			price: price.map(Into::into),
+			color: Color::Red,
+			owner: owner.clone(),
+			creator: owner.clone(),
+			metadata: None,
+			flags: Default::default(),
+			collection_id: None,
+		};
+
+		// Collectibles 0 and 2 are listed; 1 is not. The highest price is that of a listing
+		// long gone.
+		CollectibleMap::<T>::insert(0, collectible(0, Some(30)));
+		CollectibleMap::<T>::insert(1, collectible(1, None));
+		CollectibleMap::<T>::insert(2, collectible(2, Some(20)));
+		NextCollectibleId::<T>::put(3);
+		HighestPrice::<T>::put(BalanceOf::<T>::from(500u32));
+		StorageVersion::new(8).put::<Pallet<T>>();
+	}
+}
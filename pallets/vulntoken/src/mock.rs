@@ -0,0 +1,279 @@
+use crate as vulntoken;
+use frame_support::{
+	derive_impl, dispatch::DispatchResult, ensure, parameter_types,
+	traits::{fungible::Balanced, ConstU16, ConstU32, ConstU64, OnUnbalanced, WithdrawReasons},
+};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, ConvertInto, Hash, IdentityLookup},
+	BuildStorage, DispatchError, Perbill,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		VulnModule: vulntoken,
+		PaymentStreams: pallet_payment_streams,
+		Recovery: pallet_recovery,
+		Vesting: pallet_vesting,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ConstU16<42>;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = ();
+}
+
+/// Deterministic randomness derived from the subject and the current block.
+pub struct TestRandomness;
+impl frame_support::traits::Randomness<H256, u64> for TestRandomness {
+	fn random(subject: &[u8]) -> (H256, u64) {
+		let block = System::block_number();
+		(BlakeTwo256::hash_of(&(subject, block)), block)
+	}
+}
+
+/// Accounts 10, 11 and 12 act as jurors.
+pub struct TestJurors;
+impl frame_support::traits::SortedMembers<u64> for TestJurors {
+	fn sorted_members() -> Vec<u64> {
+		vec![10, 11, 12]
+	}
+}
+
+/// Accounts 1 and 2 are verified creators.
+pub struct TestCreators;
+impl frame_support::traits::SortedMembers<u64> for TestCreators {
+	fn sorted_members() -> Vec<u64> {
+		vec![1, 2]
+	}
+}
+
+/// Every account except 4 has a verified identity.
+pub struct TestIdentity;
+impl vulntoken::IdentityVerifier<u64> for TestIdentity {
+	fn is_verified(who: &u64) -> bool {
+		*who != 4
+	}
+}
+
+/// Account 9 may not mint.
+pub struct TestMintPolicy;
+impl vulntoken::MintPolicy<u64> for TestMintPolicy {
+	fn check_mint(minter: &u64, _owner: &u64) -> DispatchResult {
+		ensure!(*minter != 9, DispatchError::Other("minting closed"));
+		Ok(())
+	}
+}
+
+/// Ids follow the mint sequence unless a test turns on `HashIds`.
+pub struct TestIds;
+impl vulntoken::GenerateItemId for TestIds {
+	const ASCENDING: bool = false;
+
+	fn item_id(sequence: u64) -> u64 {
+		if HashIds::get() {
+			<vulntoken::HashedIds<Test> as vulntoken::GenerateItemId>::item_id(sequence)
+		} else {
+			sequence
+		}
+	}
+}
+
+/// Purchases by account 3 pay a tenth of the price to the creator, a tenth in fees and a tenth
+/// to account 5 for referring them.
+pub struct TestPricing;
+impl vulntoken::PricingPolicy<u64, u64> for TestPricing {
+	fn split(
+		_seller: &u64,
+		buyer: &u64,
+		_creator: &u64,
+		price: u64,
+	) -> vulntoken::SaleSplit<u64, u64> {
+		let tenth = if *buyer == 3 { price / 10 } else { 0 };
+		vulntoken::SaleSplit {
+			seller: price - 3 * tenth,
+			creator: tenth,
+			fee: tenth,
+			referrer: Some((5, tenth)),
+		}
+	}
+}
+
+/// Account 100 collects slashed listing deposits.
+pub const TREASURY: u64 = 100;
+pub struct ToTreasury;
+impl OnUnbalanced<vulntoken::CreditOf<Test>> for ToTreasury {
+	fn on_nonzero_unbalanced(amount: vulntoken::CreditOf<Test>) {
+		let _ = Balances::resolve(&TREASURY, amount);
+	}
+}
+
+parameter_types! {
+	pub const InstallmentForfeit: Perbill = Perbill::from_percent(50);
+	pub const MaxRoyalty: Perbill = Perbill::from_percent(50);
+	pub static HashIds: bool = false;
+	pub static CollectibleDeposit: u64 = 0;
+	pub const BlueOrGreen: [u32; 4] = [0, 0, 1, 3];
+}
+
+impl vulntoken::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeTask = RuntimeTask;
+	type MaximumOwned = ConstU32<10>;
+	type SealedBidDeposit = ConstU64<10>;
+	type MaxSealedBids = ConstU32<3>;
+	type Randomness = TestRandomness;
+	type MaxRaffleTickets = ConstU32<10>;
+	type RevealDelay = ConstU64<5>;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type EscrowDisputeWindow = ConstU64<5>;
+	type DisputeDeposit = ConstU64<50>;
+	type DisputeVotingPeriod = ConstU64<5>;
+	type EscrowTimeout = ConstU64<10>;
+	type Jurors = TestJurors;
+	type VerifiedCreators = TestCreators;
+	type ForeignOrigin = frame_system::EnsureRoot<u64>;
+	type FreezeOrigin = frame_system::EnsureRoot<u64>;
+	type IdentityVerifier = TestIdentity;
+	type MintPolicy = TestMintPolicy;
+	type ItemIdGenerator = TestIds;
+	type ColorStrategy = vulntoken::ParityColors;
+	type HighValueThreshold = ConstU64<500>;
+	type MaxListingDuration = ConstU64<20>;
+	type MaxCidLength = ConstU32<64>;
+	type MaxMintsPerBlock = ConstU32<5>;
+	type CollectibleDeposit = CollectibleDeposit;
+	type ListingDeposit = ConstU64<5>;
+	type MinListingPrice = ConstU64<10>;
+	type MaxPrice = ConstU64<{ u64::MAX / 2 }>;
+	type Slashed = ToTreasury;
+	type PricingPolicy = TestPricing;
+	type FeeSink = ToTreasury;
+	type InstallmentPeriod = ConstU64<5>;
+	type MaxInstallments = ConstU32<4>;
+	type InstallmentForfeit = InstallmentForfeit;
+	type MaxDeadlinesPerBlock = ConstU32<4>;
+	type Streams = PaymentStreams;
+	type MaxBookDepth = ConstU32<3>;
+	type MaxFloorIndex = ConstU32<2>;
+	type FloorRebuildBatch = ConstU32<2>;
+	type SweepBatch = ConstU32<1>;
+	type WashTradeWindow = ConstU64<20>;
+	type MaxRecentSales = ConstU32<4>;
+	type VolumeEpoch = ConstU64<10>;
+	type VolumeEpochs = ConstU32<3>;
+	type MinInactivityPeriod = ConstU64<10>;
+	type InheritanceGracePeriod = ConstU64<5>;
+	type MaxRoyalty = MaxRoyalty;
+	type TradingLimitWindow = ConstU64<10>;
+	type MaxLicenseLength = ConstU32<32>;
+	type MetadataLimit = ConstU32<16>;
+	type AttributeKeyLimit = ConstU32<8>;
+	type AttributeValueLimit = ConstU32<8>;
+	type MaxAttributes = ConstU32<2>;
+	type MetadataDepositPerByte = ConstU64<1>;
+	type CollectionTeardownBatch = ConstU32<2>;
+	type MaxOffers = ConstU32<3>;
+	type WeightInfo = ();
+}
+
+impl pallet_payment_streams::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type WeightInfo = ();
+}
+
+impl pallet_recovery::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type ConfigDepositBase = ConstU64<10>;
+	type FriendDepositFactor = ConstU64<5>;
+	type MaxFriends = ConstU32<3>;
+	type RecoveryDeposit = ConstU64<10>;
+}
+
+parameter_types! {
+	pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
+		WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
+}
+
+impl pallet_vesting::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type BlockNumberToBalance = ConvertInto;
+	type MinVestedTransfer = ConstU64<10>;
+	type WeightInfo = ();
+	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+	const MAX_VESTING_SCHEDULES: u32 = 3;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
+where
+	RuntimeCall: From<C>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = sp_runtime::testing::TestXt<RuntimeCall, ()>;
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(1, 1_000), (2, 1_000), (3, 1_000), (4, 1_000)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	let mut ext: sp_io::TestExternalities = t.into();
+	// Go past genesis block so events get deposited.
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,3139 @@
+use crate::{
+	migrations::{
+		self, v1::MigrateToV1, v10::RecordPendingMinters, v11::IndexCollectionItems,
+		v12::AddCollectionSettings, v13::CountOffers, v14::IndexUnsettledAuctions,
+		v2::MigrateReservesToHolds, v3::PopulateFreezes, v4::MoveRentalsToStreams,
+		v5::RebuildFloorIndex, v6::PopulateColorStatistics, v7::RehashAccountKeys,
+		v8::AddCollectionIds, v9::ScanHighestPrice, StorageMigration,
+	},
+	mock::*, BuyBook, CollectibleFilter, CollectibleMap, CollectionMinting, CollectionSettings,
+	Color, ColorStats, Error, Event, FreezeReason, HoldReason, Issuer, Marketplace, MetadataCids,
+	MysteryBoxes, PinQueue, RaffleTickets, Raffles, RevealRecords, SealedAuctions, SealedBids,
+	SellBook,
+};
+use codec::{Decode, Encode};
+use frame_support::{
+	assert_noop, assert_ok, ensure,
+	pallet_prelude::{TransactionSource, ValidateUnsigned},
+	traits::{fungible::InspectHold, Hooks},
+	weights::Weight,
+};
+use pallet_payment_streams::PaymentStreams as _;
+use sp_runtime::{DispatchError, Perbill, TokenError, TryRuntimeError};
+
+/// Mint a collectible to `owner` and return its id.
+fn mint_to(owner: u64) -> u64 {
+	assert_ok!(VulnModule::create_collectible(RuntimeOrigin::signed(owner), owner));
+	crate::NextCollectibleId::<Test>::get() - 1
+}
+
+/// Every vulntoken storage key that embeds `unique_id` as a map key.
+fn keys_containing(unique_id: u64) -> Vec<Vec<u8>> {
+	let prefix = sp_io::hashing::twox_128(b"VulnModule");
+	let id = unique_id.encode();
+	let needle = [sp_io::hashing::twox_64(&id).to_vec(), id].concat();
+	let mut keys = vec![];
+	let mut key = prefix.to_vec();
+	while let Some(next) = sp_io::storage::next_key(&key) {
+		if !next.starts_with(&prefix) {
+			break
+		}
+		if next.windows(needle.len()).any(|window| window == needle) {
+			keys.push(next.clone());
+		}
+		key = next;
+	}
+	keys
+}
+
+fn commit(bidder: u64, id: u64, amount: u64, salt: u8) {
+	let commitment = VulnModule::sealed_bid_commitment(&bidder, amount, &[salt; 32]);
+	assert_ok!(VulnModule::commit_bid(RuntimeOrigin::signed(bidder), id, commitment));
+}
+
+#[test]
+fn sealed_auction_highest_reveal_wins() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_ok!(VulnModule::start_sealed_auction(RuntimeOrigin::signed(1), id, 50, 5, 5));
+		commit(2, id, 100, 2);
+		commit(3, id, 200, 3);
+		assert_eq!(Balances::reserved_balance(2), 10);
+
+		System::set_block_number(6);
+		assert_ok!(VulnModule::reveal_bid(RuntimeOrigin::signed(2), id, 100, [2; 32]));
+		assert_ok!(VulnModule::reveal_bid(RuntimeOrigin::signed(3), id, 200, [3; 32]));
+		// The outbid amount is released straight away, only the deposit stays reserved.
+		assert_eq!(Balances::reserved_balance(2), 10);
+		assert_eq!(Balances::reserved_balance(3), 210);
+
+		System::set_block_number(11);
+		assert_ok!(VulnModule::settle_sealed_auction(RuntimeOrigin::signed(4), id));
+		System::assert_last_event(
+			Event::SealedAuctionSettled { collectible: id, winner: Some(3), price: Some(200) }
+				.into(),
+		);
+		assert_eq!(CollectibleMap::<Test>::get(id).unwrap().owner, 3);
+		assert_eq!(Balances::free_balance(1), 1_200);
+		assert_eq!(Balances::free_balance(2), 1_000);
+		assert_eq!(Balances::free_balance(3), 800);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert!(SealedAuctions::<Test>::get(id).is_none());
+		assert_eq!(SealedBids::<Test>::iter_prefix(id).count(), 0);
+	});
+}
+
+#[test]
+fn unrevealed_commitment_is_slashed() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_ok!(VulnModule::start_sealed_auction(RuntimeOrigin::signed(1), id, 50, 5, 5));
+		commit(2, id, 100, 2);
+
+		System::set_block_number(11);
+		assert_ok!(VulnModule::settle_sealed_auction(RuntimeOrigin::signed(1), id));
+		assert_eq!(Balances::free_balance(2), 990);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		// Nobody revealed, so the seller keeps the item.
+		assert_eq!(CollectibleMap::<Test>::get(id).unwrap().owner, 1);
+	});
+}
+
+#[test]
+fn bids_of_an_unsettled_auction_are_reclaimed_after_the_timeout() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_ok!(VulnModule::start_sealed_auction(RuntimeOrigin::signed(1), id, 50, 5, 5));
+		commit(2, id, 100, 2);
+		commit(3, id, 200, 3);
+		System::set_block_number(6);
+		assert_ok!(VulnModule::reveal_bid(RuntimeOrigin::signed(2), id, 100, [2; 32]));
+		assert_ok!(VulnModule::reveal_bid(RuntimeOrigin::signed(3), id, 200, [3; 32]));
+
+		// The reveal phase ends at block 11 and the auction times out ten blocks later.
+		System::set_block_number(20);
+		assert_noop!(
+			VulnModule::reclaim_escrow(RuntimeOrigin::signed(2), id),
+			Error::<Test>::EscrowNotExpired
+		);
+		System::set_block_number(21);
+		assert_noop!(
+			VulnModule::reclaim_escrow(RuntimeOrigin::signed(4), id),
+			Error::<Test>::NoCommitment
+		);
+		assert_ok!(VulnModule::reclaim_escrow(RuntimeOrigin::signed(2), id));
+		System::assert_has_event(
+			Event::EscrowReclaimed { collectible: id, depositor: 3, amount: 210 }.into(),
+		);
+		assert_eq!(Balances::free_balance(2), 1_000);
+		assert_eq!(Balances::free_balance(3), 1_000);
+		assert!(SealedAuctions::<Test>::get(id).is_none());
+		assert_eq!(SealedBids::<Test>::iter_prefix(id).count(), 0);
+		assert_ok!(VulnModule::transfer(RuntimeOrigin::signed(1), 4, id));
+	});
+}
+
+#[test]
+fn sealed_auction_enforces_phases() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_ok!(VulnModule::start_sealed_auction(RuntimeOrigin::signed(1), id, 50, 5, 5));
+		commit(2, id, 100, 2);
+		assert_noop!(
+			VulnModule::reveal_bid(RuntimeOrigin::signed(2), id, 100, [2; 32]),
+			Error::<Test>::NotRevealPhase
+		);
+		assert_noop!(
+			VulnModule::settle_sealed_auction(RuntimeOrigin::signed(2), id),
+			Error::<Test>::AuctionNotFinished
+		);
+
+		System::set_block_number(6);
+		let commitment = VulnModule::sealed_bid_commitment(&3, 100, &[3; 32]);
+		assert_noop!(
+			VulnModule::commit_bid(RuntimeOrigin::signed(3), id, commitment),
+			Error::<Test>::NotCommitPhase
+		);
+		assert_noop!(
+			VulnModule::reveal_bid(RuntimeOrigin::signed(2), id, 101, [2; 32]),
+			Error::<Test>::InvalidReveal
+		);
+	});
+}
+
+#[test]
+fn item_in_sealed_auction_is_locked() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_ok!(VulnModule::start_sealed_auction(RuntimeOrigin::signed(1), id, 50, 5, 5));
+		assert_noop!(
+			VulnModule::transfer(RuntimeOrigin::signed(1), 2, id),
+			Error::<Test>::InAuction
+		);
+		assert_noop!(VulnModule::burn(RuntimeOrigin::signed(1), id), Error::<Test>::InAuction);
+		assert_noop!(
+			VulnModule::commit_bid(
+				RuntimeOrigin::signed(1),
+				id,
+				VulnModule::sealed_bid_commitment(&1, 100, &[1; 32])
+			),
+			Error::<Test>::SellerCannotBid
+		);
+	});
+}
+
+#[test]
+fn raffle_pays_owner_and_transfers_to_winner() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_ok!(VulnModule::start_raffle(RuntimeOrigin::signed(1), id, 10, 2, 5, 10));
+		assert_ok!(VulnModule::buy_tickets(RuntimeOrigin::signed(2), id, 2));
+		assert_ok!(VulnModule::buy_tickets(RuntimeOrigin::signed(3), id, 3));
+		assert_noop!(
+			VulnModule::buy_tickets(RuntimeOrigin::signed(4), id, 1),
+			Error::<Test>::SoldOut
+		);
+		assert_eq!(RaffleTickets::<Test>::get(id, 4), Some(3));
+		assert_noop!(
+			VulnModule::draw_raffle(RuntimeOrigin::signed(4), id),
+			Error::<Test>::RaffleNotFinished
+		);
+
+		System::set_block_number(11);
+		assert_ok!(VulnModule::draw_raffle(RuntimeOrigin::signed(4), id));
+		let winner = CollectibleMap::<Test>::get(id).unwrap().owner;
+		assert!(winner == 2 || winner == 3);
+		assert_eq!(Balances::free_balance(1), 1_050);
+		assert_eq!(Balances::free_balance(2), 980);
+		assert_eq!(Balances::free_balance(3), 970);
+		assert!(Raffles::<Test>::get(id).is_none());
+		assert_eq!(RaffleTickets::<Test>::iter_prefix(id).count(), 0);
+	});
+}
+
+#[test]
+fn raffle_below_minimum_refunds_tickets() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_ok!(VulnModule::start_raffle(RuntimeOrigin::signed(1), id, 10, 3, 5, 10));
+		assert_ok!(VulnModule::buy_tickets(RuntimeOrigin::signed(2), id, 2));
+		assert_eq!(Balances::reserved_balance(2), 20);
+		assert_noop!(
+			VulnModule::transfer(RuntimeOrigin::signed(1), 2, id),
+			Error::<Test>::InRaffle
+		);
+
+		System::set_block_number(11);
+		assert_noop!(
+			VulnModule::buy_tickets(RuntimeOrigin::signed(3), id, 1),
+			Error::<Test>::RaffleEnded
+		);
+		assert_ok!(VulnModule::draw_raffle(RuntimeOrigin::signed(2), id));
+		System::assert_last_event(Event::RaffleCancelled { collectible: id, tickets_sold: 2 }.into());
+		assert_eq!(Balances::free_balance(2), 1_000);
+		assert_eq!(CollectibleMap::<Test>::get(id).unwrap().owner, 1);
+	});
+}
+
+#[test]
+fn mystery_box_is_revealed_after_delay() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(VulnModule::set_accept_mints(RuntimeOrigin::signed(2), true));
+		assert_ok!(VulnModule::mint_mystery_box(RuntimeOrigin::signed(1), 2));
+		let id = crate::NextCollectibleId::<Test>::get() - 1;
+		assert_eq!(CollectibleMap::<Test>::get(id).unwrap().color, Color::Hidden);
+		assert_noop!(
+			VulnModule::reveal(RuntimeOrigin::signed(2), id),
+			Error::<Test>::RevealTooEarly
+		);
+		// Hidden boxes can change hands before the reveal.
+		assert_ok!(VulnModule::transfer(RuntimeOrigin::signed(2), 3, id));
+
+		System::set_block_number(6);
+		assert_ok!(VulnModule::reveal(RuntimeOrigin::signed(3), id));
+		let color = CollectibleMap::<Test>::get(id).unwrap().color;
+		assert_ne!(color, Color::Hidden);
+		let record = RevealRecords::<Test>::get(id).unwrap();
+		assert_eq!(record.revealed_at, 6);
+		System::assert_last_event(
+			Event::CollectibleRevealed {
+				collectible: id,
+				color,
+				seed: record.seed,
+				known_since: record.known_since,
+			}
+			.into(),
+		);
+		assert_eq!(VulnModule::audit_reveal(id), Some(true));
+		assert!(MysteryBoxes::<Test>::get(id).is_none());
+		assert_noop!(VulnModule::reveal(RuntimeOrigin::signed(3), id), Error::<Test>::NotHidden);
+	});
+}
+
+#[test]
+fn enforced_royalties_restrict_plain_transfers() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_noop!(
+			VulnModule::set_royalty_enforcement(RuntimeOrigin::signed(1), true),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(VulnModule::set_royalty_enforcement(RuntimeOrigin::root(), true));
+		assert_noop!(
+			VulnModule::transfer(RuntimeOrigin::signed(1), 2, id),
+			Error::<Test>::TransferRestricted
+		);
+
+		// Approved markets can take custody of items and deliver them onwards.
+		assert_ok!(VulnModule::approve_market(RuntimeOrigin::root(), 4));
+		assert_ok!(VulnModule::transfer(RuntimeOrigin::signed(1), 4, id));
+		assert_ok!(VulnModule::transfer(RuntimeOrigin::signed(4), 2, id));
+
+		assert_ok!(VulnModule::remove_market(RuntimeOrigin::root(), 4));
+		assert_noop!(
+			VulnModule::transfer(RuntimeOrigin::signed(2), 4, id),
+			Error::<Test>::TransferRestricted
+		);
+		assert_ok!(VulnModule::set_royalty_enforcement(RuntimeOrigin::root(), false));
+		assert_ok!(VulnModule::transfer(RuntimeOrigin::signed(2), 3, id));
+	});
+}
+
+#[test]
+fn cooldown_blocks_relisting_after_mint_and_purchase() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(VulnModule::set_transfer_cooldown(RuntimeOrigin::root(), 3));
+		let id = mint_to(1);
+		assert_noop!(
+			VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(10)),
+			Error::<Test>::CooldownActive
+		);
+		assert_noop!(
+			VulnModule::start_raffle(RuntimeOrigin::signed(1), id, 10, 1, 5, 10),
+			Error::<Test>::CooldownActive
+		);
+		// Delisting is always possible.
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, None));
+
+		System::set_block_number(4);
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(10)));
+		assert_ok!(VulnModule::buy_collectible(RuntimeOrigin::signed(2), id, u64::MAX));
+		assert_noop!(
+			VulnModule::set_price(RuntimeOrigin::signed(2), 2, id, Some(20)),
+			Error::<Test>::CooldownActive
+		);
+		System::set_block_number(7);
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(2), 2, id, Some(20)));
+	});
+}
+
+#[test]
+fn escrow_is_released_after_dispute_window() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(100)));
+		assert_ok!(VulnModule::buy_with_escrow(RuntimeOrigin::signed(2), id));
+		assert_eq!(Balances::reserved_balance(2), 100);
+		assert_noop!(
+			VulnModule::transfer(RuntimeOrigin::signed(1), 3, id),
+			Error::<Test>::InEscrow
+		);
+		assert_noop!(
+			VulnModule::release_escrow(RuntimeOrigin::signed(3), id),
+			Error::<Test>::DisputeWindowOpen
+		);
+
+		System::set_block_number(6);
+		assert_noop!(
+			VulnModule::raise_dispute(RuntimeOrigin::signed(2), id),
+			Error::<Test>::DisputeWindowClosed
+		);
+		assert_ok!(VulnModule::release_escrow(RuntimeOrigin::signed(3), id));
+		assert_eq!(CollectibleMap::<Test>::get(id).unwrap().owner, 2);
+		assert_eq!(Balances::free_balance(1), 1_100);
+		assert_eq!(Balances::free_balance(2), 900);
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
+
+#[test]
+fn jurors_can_refund_the_buyer() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(100)));
+		assert_ok!(VulnModule::buy_with_escrow(RuntimeOrigin::signed(2), id));
+		assert_noop!(
+			VulnModule::raise_dispute(RuntimeOrigin::signed(3), id),
+			Error::<Test>::NotEscrowParty
+		);
+		assert_ok!(VulnModule::raise_dispute(RuntimeOrigin::signed(2), id));
+		assert_eq!(Balances::reserved_balance(2), 150);
+		assert_eq!(Balances::balance_on_hold(&HoldReason::Escrow.into(), &2), 100);
+		assert_eq!(Balances::balance_on_hold(&HoldReason::DisputeDeposit.into(), &2), 50);
+
+		assert_noop!(
+			VulnModule::vote_dispute(RuntimeOrigin::signed(3), id, true),
+			Error::<Test>::NotJuror
+		);
+		assert_ok!(VulnModule::vote_dispute(RuntimeOrigin::signed(10), id, true));
+		assert_noop!(
+			VulnModule::vote_dispute(RuntimeOrigin::signed(10), id, true),
+			Error::<Test>::AlreadyVoted
+		);
+		assert_noop!(
+			VulnModule::resolve_dispute(RuntimeOrigin::signed(3), id),
+			Error::<Test>::DisputeUnresolved
+		);
+		assert_ok!(VulnModule::vote_dispute(RuntimeOrigin::signed(11), id, true));
+		assert_ok!(VulnModule::resolve_dispute(RuntimeOrigin::signed(3), id));
+
+		assert_eq!(CollectibleMap::<Test>::get(id).unwrap().owner, 1);
+		assert_eq!(Balances::free_balance(2), 1_000);
+		assert_eq!(Balances::free_balance(1), 1_000);
+		assert!(crate::Escrows::<Test>::get(id).is_none());
+		assert_eq!(crate::DisputeVotes::<Test>::iter_prefix(id).count(), 0);
+	});
+}
+
+#[test]
+fn frivolous_dispute_is_slashed() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(100)));
+		assert_ok!(VulnModule::buy_with_escrow(RuntimeOrigin::signed(2), id));
+		assert_ok!(VulnModule::raise_dispute(RuntimeOrigin::signed(2), id));
+		assert_ok!(VulnModule::vote_dispute(RuntimeOrigin::signed(10), id, false));
+
+		// Voting ends without a majority, so the sale goes ahead.
+		System::set_block_number(6);
+		assert_ok!(VulnModule::resolve_dispute(RuntimeOrigin::signed(1), id));
+		System::assert_has_event(
+			Event::DisputeResolved { collectible: id, for_buyer: false, deposit_slashed: true }
+				.into(),
+		);
+		assert_eq!(CollectibleMap::<Test>::get(id).unwrap().owner, 2);
+		assert_eq!(Balances::free_balance(2), 850);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(1), 1_100);
+	});
+}
+
+#[test]
+fn buyer_reclaims_an_escrow_nobody_settled() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(100)));
+		assert_ok!(VulnModule::buy_with_escrow(RuntimeOrigin::signed(2), id));
+		assert_ok!(VulnModule::raise_dispute(RuntimeOrigin::signed(1), id));
+
+		// Voting ends at block 6 and the escrow times out ten blocks later.
+		System::set_block_number(15);
+		assert_noop!(
+			VulnModule::reclaim_escrow(RuntimeOrigin::signed(2), id),
+			Error::<Test>::EscrowNotExpired
+		);
+		System::set_block_number(16);
+		assert_noop!(
+			VulnModule::reclaim_escrow(RuntimeOrigin::signed(1), id),
+			Error::<Test>::NotEscrowParty
+		);
+		assert_ok!(VulnModule::reclaim_escrow(RuntimeOrigin::signed(2), id));
+		System::assert_last_event(
+			Event::EscrowReclaimed { collectible: id, depositor: 2, amount: 100 }.into(),
+		);
+		// The unresolved dispute is dropped and its deposit returned.
+		assert_eq!(Balances::free_balance(1), 1_000);
+		assert_eq!(Balances::free_balance(2), 1_000);
+		assert!(crate::Escrows::<Test>::get(id).is_none());
+		assert!(crate::Disputes::<Test>::get(id).is_none());
+		assert_ok!(VulnModule::transfer(RuntimeOrigin::signed(1), 3, id));
+	});
+}
+
+#[test]
+fn high_value_sales_require_identity() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(500)));
+		assert_noop!(
+			VulnModule::buy_collectible(RuntimeOrigin::signed(4), id, u64::MAX),
+			Error::<Test>::IdentityRequired
+		);
+		assert_noop!(
+			VulnModule::buy_with_escrow(RuntimeOrigin::signed(4), id),
+			Error::<Test>::IdentityRequired
+		);
+		assert_ok!(VulnModule::buy_collectible(RuntimeOrigin::signed(2), id, u64::MAX));
+
+		// Below the threshold nobody needs an identity.
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(2), 2, id, Some(499)));
+		assert_ok!(VulnModule::buy_collectible(RuntimeOrigin::signed(4), id, u64::MAX));
+	});
+}
+
+#[test]
+fn expired_listings_cannot_be_bought() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(10)));
+		assert_eq!(VulnModule::listing_price(id), Some(10));
+		assert_noop!(
+			VulnModule::clear_expired_listing(RuntimeOrigin::signed(3), id),
+			Error::<Test>::ListingNotExpired
+		);
+
+		System::set_block_number(21);
+		assert_eq!(VulnModule::listing_price(id), None);
+		assert_noop!(
+			VulnModule::buy_collectible(RuntimeOrigin::signed(2), id, u64::MAX),
+			Error::<Test>::ListingExpired
+		);
+		assert_ok!(VulnModule::clear_expired_listing(RuntimeOrigin::signed(3), id));
+		System::assert_last_event(Event::ListingExpired { collectible: id }.into());
+		assert_eq!(CollectibleMap::<Test>::get(id).unwrap().price, None);
+
+		// Relisting starts a fresh lifetime.
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(10)));
+		assert_ok!(VulnModule::buy_collectible(RuntimeOrigin::signed(2), id, u64::MAX));
+	});
+}
+
+#[test]
+fn cancel_listing_clears_price() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_noop!(
+			VulnModule::cancel_listing(RuntimeOrigin::signed(1), id),
+			Error::<Test>::NotForSale
+		);
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(10)));
+		assert_noop!(
+			VulnModule::cancel_listing(RuntimeOrigin::signed(2), id),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(VulnModule::cancel_listing(RuntimeOrigin::signed(1), id));
+		System::assert_last_event(Event::ListingCancelled { collectible: id, owner: 1 }.into());
+		assert_eq!(CollectibleMap::<Test>::get(id).unwrap().price, None);
+		assert_noop!(
+			VulnModule::buy_collectible(RuntimeOrigin::signed(2), id, u64::MAX),
+			Error::<Test>::NotForSale
+		);
+	});
+}
+
+#[test]
+fn listing_deposit_is_held_while_listed() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		let deposit = || Balances::balance_on_hold(&HoldReason::ListingDeposit.into(), &1);
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(10)));
+		assert_eq!(deposit(), 5);
+		// Repricing keeps the deposit already held.
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(20)));
+		assert_eq!(deposit(), 5);
+		assert_ok!(VulnModule::cancel_listing(RuntimeOrigin::signed(1), id));
+		assert_eq!(deposit(), 0);
+
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(10)));
+		assert_ok!(VulnModule::buy_collectible(RuntimeOrigin::signed(2), id, u64::MAX));
+		assert_eq!(deposit(), 0);
+		assert_eq!(Balances::free_balance(1), 1_010);
+	});
+}
+
+#[test]
+fn flagged_listing_loses_its_deposit() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(10)));
+		assert_noop!(
+			VulnModule::flag_listing(RuntimeOrigin::signed(2), id),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(VulnModule::flag_listing(RuntimeOrigin::root(), id));
+		System::assert_last_event(
+			Event::ListingFlagged { collectible: id, owner: 1, slashed: 5 }.into(),
+		);
+		assert_eq!(CollectibleMap::<Test>::get(id).unwrap().price, None);
+		assert_eq!(Balances::balance_on_hold(&HoldReason::ListingDeposit.into(), &1), 0);
+		assert_eq!(Balances::free_balance(1), 995);
+		assert_eq!(Balances::free_balance(TREASURY), 5);
+		assert_noop!(
+			VulnModule::flag_listing(RuntimeOrigin::root(), id),
+			Error::<Test>::NotForSale
+		);
+	});
+}
+
+#[test]
+fn rent_streams_to_the_owner_block_by_block() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		let held = || {
+			Balances::balance_on_hold(&pallet_payment_streams::HoldReason::StreamDeposit.into(), &2)
+		};
+		assert_noop!(
+			VulnModule::rent(RuntimeOrigin::signed(2), id, 100),
+			Error::<Test>::NotForRent
+		);
+		assert_ok!(VulnModule::offer_rental(RuntimeOrigin::signed(1), id, Some(10)));
+		assert_noop!(
+			VulnModule::rent(RuntimeOrigin::signed(1), id, 100),
+			Error::<Test>::OwnerCannotRent
+		);
+		assert_noop!(
+			VulnModule::rent(RuntimeOrigin::signed(2), id, 9),
+			Error::<Test>::InsufficientPrepayment
+		);
+		assert_ok!(VulnModule::rent(RuntimeOrigin::signed(2), id, 100));
+		assert_eq!(held(), 100);
+		assert_eq!(VulnModule::renter_of(id), Some(2));
+		assert_noop!(VulnModule::transfer(RuntimeOrigin::signed(1), 3, id), Error::<Test>::Rented);
+
+		// Rent accrues without anything moving until somebody settles.
+		System::set_block_number(4);
+		assert_eq!(Balances::free_balance(1), 1_000);
+		assert_ok!(VulnModule::settle_rental(RuntimeOrigin::signed(3), id));
+		assert_eq!(Balances::free_balance(1), 1_030);
+		assert_eq!(held(), 70);
+
+		assert_ok!(VulnModule::top_up_rental(RuntimeOrigin::signed(2), id, 50));
+		assert_eq!(held(), 120);
+
+		// The owner ends the rental early and is paid for exactly the blocks it ran.
+		System::set_block_number(6);
+		assert_noop!(
+			VulnModule::end_rental(RuntimeOrigin::signed(3), id),
+			Error::<Test>::NotRentalParty
+		);
+		assert_ok!(VulnModule::end_rental(RuntimeOrigin::signed(1), id));
+		System::assert_last_event(
+			Event::RentalEnded { collectible: id, renter: 2, refunded: 100 }.into(),
+		);
+		assert_eq!(Balances::free_balance(1), 1_050);
+		assert_eq!(Balances::free_balance(2), 950);
+		assert_eq!(held(), 0);
+
+		// The offer does not carry over to the next owner.
+		assert_ok!(VulnModule::transfer(RuntimeOrigin::signed(1), 3, id));
+		assert_noop!(
+			VulnModule::rent(RuntimeOrigin::signed(2), id, 100),
+			Error::<Test>::NotForRent
+		);
+	});
+}
+
+#[test]
+fn rental_ends_when_the_prepayment_runs_out() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_ok!(VulnModule::offer_rental(RuntimeOrigin::signed(1), id, Some(10)));
+		assert_ok!(VulnModule::rent(RuntimeOrigin::signed(2), id, 30));
+
+		System::set_block_number(10);
+		assert_eq!(VulnModule::renter_of(id), None);
+		assert_noop!(
+			VulnModule::top_up_rental(RuntimeOrigin::signed(2), id, 50),
+			Error::<Test>::RentalExpired
+		);
+		assert_ok!(VulnModule::settle_rental(RuntimeOrigin::signed(3), id));
+		assert!(!crate::Rentals::<Test>::contains_key(id));
+		assert_eq!(Balances::free_balance(1), 1_030);
+		assert_eq!(Balances::free_balance(2), 970);
+
+		// The offer still stands.
+		assert_ok!(VulnModule::rent(RuntimeOrigin::signed(3), id, 20));
+
+		// Closing the stream behind the rental's back leaves it to be cleaned up.
+		let stream = crate::Rentals::<Test>::get(id).unwrap().stream;
+		System::set_block_number(11);
+		assert_ok!(PaymentStreams::close(RuntimeOrigin::signed(3), stream));
+		assert_eq!(VulnModule::renter_of(id), None);
+		assert_ok!(VulnModule::settle_rental(RuntimeOrigin::signed(2), id));
+		System::assert_last_event(
+			Event::RentalEnded { collectible: id, renter: 3, refunded: 0 }.into(),
+		);
+		assert_eq!(Balances::free_balance(1), 1_040);
+		assert_eq!(Balances::free_balance(3), 990);
+		assert_ok!(VulnModule::transfer(RuntimeOrigin::signed(1), 4, id));
+	});
+}
+
+#[test]
+fn other_pallets_buy_through_the_marketplace_trait() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_eq!(<VulnModule as Marketplace<_, _>>::price(id), None);
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(100)));
+		assert_eq!(<VulnModule as Marketplace<_, _>>::price(id), Some(100));
+
+		assert_ok!(<VulnModule as Marketplace<_, _>>::buy(id, &2));
+		assert_eq!(<VulnModule as Marketplace<_, _>>::owner(id), Some(2));
+		assert_eq!(<VulnModule as Marketplace<_, _>>::price(id), None);
+		assert_eq!(Balances::free_balance(1), 1_100);
+	});
+}
+
+#[test]
+fn paying_every_installment_completes_the_sale() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		let held = || Balances::balance_on_hold(&HoldReason::Installment.into(), &2);
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(100)));
+		assert_noop!(
+			VulnModule::buy_in_installments(RuntimeOrigin::signed(2), id, 1),
+			Error::<Test>::InvalidInstallments
+		);
+		assert_noop!(
+			VulnModule::buy_in_installments(RuntimeOrigin::signed(2), id, 5),
+			Error::<Test>::InvalidInstallments
+		);
+		assert_ok!(VulnModule::buy_in_installments(RuntimeOrigin::signed(2), id, 3));
+		assert_eq!(held(), 33);
+		assert_noop!(
+			VulnModule::transfer(RuntimeOrigin::signed(1), 3, id),
+			Error::<Test>::InInstallments
+		);
+
+		System::set_block_number(5);
+		assert_noop!(
+			VulnModule::pay_installment(RuntimeOrigin::signed(3), id),
+			Error::<Test>::NotInstallmentBuyer
+		);
+		assert_ok!(VulnModule::pay_installment(RuntimeOrigin::signed(2), id));
+		System::assert_last_event(
+			Event::InstallmentPaid { collectible: id, amount: 33, paid: 2, due: 10 }.into(),
+		);
+		assert_eq!(held(), 66);
+
+		// The last installment makes up for rounding and completes the sale.
+		System::set_block_number(9);
+		assert_ok!(VulnModule::pay_installment(RuntimeOrigin::signed(2), id));
+		assert_eq!(CollectibleMap::<Test>::get(id).unwrap().owner, 2);
+		assert_eq!(held(), 0);
+		assert_eq!(Balances::free_balance(1), 1_100);
+		assert_eq!(Balances::free_balance(2), 900);
+	});
+}
+
+#[test]
+fn missed_installment_defaults_the_plan() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(100)));
+		assert_ok!(VulnModule::buy_in_installments(RuntimeOrigin::signed(2), id, 4));
+
+		System::set_block_number(5);
+		assert_noop!(
+			VulnModule::enforce_installment_default(RuntimeOrigin::signed(3), id),
+			Error::<Test>::InstallmentNotDue
+		);
+		assert_ok!(VulnModule::pay_installment(RuntimeOrigin::signed(2), id));
+
+		System::set_block_number(10);
+		assert_noop!(
+			VulnModule::pay_installment(RuntimeOrigin::signed(2), id),
+			Error::<Test>::InstallmentOverdue
+		);
+		// The deadline the buyer met at block 6 is skipped, the one at block 10 is enforced.
+		VulnModule::on_idle(10, Weight::MAX);
+		System::assert_last_event(
+			Event::InstallmentPlanDefaulted {
+				collectible: id,
+				buyer: 2,
+				forfeited: 25,
+				refunded: 25,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::balance_on_hold(&HoldReason::Installment.into(), &2), 0);
+		assert_eq!(Balances::free_balance(1), 1_025);
+		assert_eq!(Balances::free_balance(2), 975);
+		assert_noop!(
+			VulnModule::enforce_installment_default(RuntimeOrigin::signed(3), id),
+			Error::<Test>::NoInstallmentPlan
+		);
+
+		// The seller kept the item and can move it again.
+		assert_ok!(VulnModule::transfer(RuntimeOrigin::signed(1), 3, id));
+	});
+}
+
+#[test]
+fn orders_match_on_insertion_at_the_resting_price() {
+	new_test_ext().execute_with(|| {
+		let (a, b, c) = (mint_to(1), mint_to(1), mint_to(3));
+		let held = || Balances::balance_on_hold(&HoldReason::BuyOrder.into(), &2);
+		assert_noop!(
+			VulnModule::place_sell_order(RuntimeOrigin::signed(1), a, 0),
+			Error::<Test>::InvalidOrder
+		);
+		// Asks are bounded like listings.
+		assert_noop!(
+			VulnModule::place_sell_order(RuntimeOrigin::signed(1), a, 9),
+			Error::<Test>::PriceTooLow
+		);
+		assert_noop!(
+			VulnModule::place_sell_order(RuntimeOrigin::signed(1), a, u64::MAX),
+			Error::<Test>::PriceTooHigh
+		);
+		assert_noop!(
+			VulnModule::place_sell_order(RuntimeOrigin::signed(2), a, 10),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(VulnModule::place_sell_order(RuntimeOrigin::signed(1), a, 30));
+		assert_ok!(VulnModule::place_sell_order(RuntimeOrigin::signed(1), b, 20));
+		assert_ok!(VulnModule::place_sell_order(RuntimeOrigin::signed(3), c, 50));
+		assert_noop!(
+			VulnModule::transfer(RuntimeOrigin::signed(1), 3, a),
+			Error::<Test>::InOrderBook
+		);
+
+		// The cheapest items go first, at their asking prices, and the rest of the order stands.
+		assert_ok!(VulnModule::place_buy_order(RuntimeOrigin::signed(2), 40, 3));
+		System::assert_last_event(
+			Event::BuyOrderPlaced { order: 0, bidder: 2, price: 40, quantity: 1 }.into(),
+		);
+		assert_eq!(CollectibleMap::<Test>::get(a).unwrap().owner, 2);
+		assert_eq!(CollectibleMap::<Test>::get(b).unwrap().owner, 2);
+		assert_eq!(held(), 40);
+		assert_eq!(Balances::free_balance(1), 1_050);
+		assert_eq!(Balances::free_balance(2), 910);
+		let sells = SellBook::<Test>::get();
+		assert_eq!(sells.iter().map(|order| order.collectible).collect::<Vec<_>>(), vec![c]);
+
+		assert_noop!(
+			VulnModule::cancel_sell_order(RuntimeOrigin::signed(1), c),
+			Error::<Test>::NotOrderOwner
+		);
+		assert_ok!(VulnModule::cancel_sell_order(RuntimeOrigin::signed(3), c));
+		assert!(!VulnModule::is_frozen(c, FreezeReason::SellOrder));
+		// An ask below the standing bid sells at the bid.
+		assert_ok!(VulnModule::place_sell_order(RuntimeOrigin::signed(3), c, 35));
+		System::assert_last_event(
+			Event::OrdersMatched { collectible: c, seller: 3, buyer: 2, price: 40 }.into(),
+		);
+		assert_eq!(CollectibleMap::<Test>::get(c).unwrap().owner, 2);
+		assert_eq!(held(), 0);
+		assert_eq!(Balances::free_balance(3), 1_040);
+		assert!(BuyBook::<Test>::get().is_empty());
+	});
+}
+
+#[test]
+fn buy_orders_cancel_in_part_and_the_book_is_bounded() {
+	new_test_ext().execute_with(|| {
+		let held = || Balances::balance_on_hold(&HoldReason::BuyOrder.into(), &2);
+		let ids = || BuyBook::<Test>::get().iter().map(|order| order.id).collect::<Vec<_>>();
+		assert_noop!(
+			VulnModule::place_buy_order(RuntimeOrigin::signed(2), 10, 0),
+			Error::<Test>::InvalidOrder
+		);
+		assert_noop!(
+			VulnModule::place_buy_order(RuntimeOrigin::signed(2), u64::MAX / 2, 3),
+			Error::<Test>::BoundsOverflow
+		);
+		assert_ok!(VulnModule::place_buy_order(RuntimeOrigin::signed(2), 10, 5));
+		assert_eq!(held(), 50);
+
+		assert_noop!(
+			VulnModule::cancel_buy_order(RuntimeOrigin::signed(3), 0, 1),
+			Error::<Test>::NotOrderOwner
+		);
+		assert_noop!(
+			VulnModule::cancel_buy_order(RuntimeOrigin::signed(2), 9, 1),
+			Error::<Test>::NoOrder
+		);
+		assert_ok!(VulnModule::cancel_buy_order(RuntimeOrigin::signed(2), 0, 2));
+		System::assert_last_event(
+			Event::BuyOrderCancelled { order: 0, cancelled: 2, remaining: 3 }.into(),
+		);
+		assert_eq!(held(), 30);
+
+		// Better prices go first, and equal prices keep their arrival order.
+		assert_ok!(VulnModule::place_buy_order(RuntimeOrigin::signed(3), 20, 1));
+		assert_ok!(VulnModule::place_buy_order(RuntimeOrigin::signed(4), 10, 1));
+		assert_eq!(ids(), vec![1, 0, 2]);
+		assert_noop!(
+			VulnModule::place_buy_order(RuntimeOrigin::signed(3), 5, 1),
+			Error::<Test>::BookFull
+		);
+
+		let id = mint_to(1);
+		assert_ok!(VulnModule::place_sell_order(RuntimeOrigin::signed(1), id, 10));
+		assert_eq!(CollectibleMap::<Test>::get(id).unwrap().owner, 3);
+		assert_eq!(Balances::free_balance(1), 1_020);
+		assert_eq!(ids(), vec![0, 2]);
+
+		// Cancelling more than is left cancels the rest of the order.
+		assert_ok!(VulnModule::cancel_buy_order(RuntimeOrigin::signed(2), 0, 10));
+		System::assert_last_event(
+			Event::BuyOrderCancelled { order: 0, cancelled: 3, remaining: 0 }.into(),
+		);
+		assert_eq!(held(), 0);
+		assert_eq!(ids(), vec![2]);
+	});
+}
+
+#[test]
+fn floor_price_follows_the_cheapest_listing() {
+	new_test_ext().execute_with(|| {
+		let (a, b, c) = (mint_to(1), mint_to(1), mint_to(1));
+		let list = |id, price| VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, price);
+		assert_eq!(VulnModule::floor_price(), None);
+		assert_ok!(list(a, Some(30)));
+		assert_ok!(list(b, Some(20)));
+		assert_eq!(VulnModule::floor_price(), Some(20));
+		// The index only keeps the two cheapest listings.
+		assert_ok!(list(c, Some(40)));
+		assert_eq!(VulnModule::floor_price(), Some(20));
+
+		assert_ok!(VulnModule::buy_collectible(RuntimeOrigin::signed(2), b, u64::MAX));
+		assert_eq!(VulnModule::floor_price(), Some(30));
+
+		// The index ran out with a listing left out of it, so it is rebuilt when idle.
+		assert_ok!(VulnModule::cancel_listing(RuntimeOrigin::signed(1), a));
+		assert_eq!(VulnModule::floor_price(), None);
+		VulnModule::on_idle(1, Weight::MAX);
+		assert_eq!(VulnModule::floor_price(), Some(40));
+
+		assert_ok!(list(c, Some(10)));
+		assert_eq!(VulnModule::floor_price(), Some(10));
+		assert_ok!(list(c, None));
+		assert_eq!(VulnModule::floor_price(), None);
+	});
+}
+
+#[test]
+fn floor_index_is_rebuilt_by_tasks_in_batches() {
+	use frame_support::traits::Task as _;
+
+	new_test_ext().execute_with(|| {
+		let (a, b, c) = (mint_to(1), mint_to(1), mint_to(1));
+		for (id, price) in [(a, 30), (b, 20), (c, 10)] {
+			assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(price)));
+		}
+		let task = crate::Task::<Test>::RebuildFloorIndex { scope: None };
+		assert!(!task.is_valid());
+		assert_noop!(task.run(), Error::<Test>::NoRebuild);
+
+		crate::FloorIndex::<Test>::put(crate::CheapestListings::<Test> {
+			rebuild_from: Some(0),
+			..Default::default()
+		});
+		assert!(task.is_valid());
+		assert!(crate::Task::<Test>::iter().any(|listed| listed == task));
+		// Each run scans two collectibles.
+		assert_ok!(task.run());
+		assert_eq!(VulnModule::floor_price(), Some(20));
+		assert_ok!(task.run());
+		assert_eq!(VulnModule::floor_price(), Some(10));
+		assert!(!task.is_valid());
+	});
+}
+
+#[test]
+fn idle_sweep_drops_ids_of_collectibles_that_no_longer_exist() {
+	new_test_ext().execute_with(|| {
+		let (a, b) = (mint_to(1), mint_to(2));
+		// Ids 7 and 8 were never minted.
+		let owned = |ids: Vec<u64>| frame_support::BoundedVec::truncate_from(ids);
+		crate::OwnerOfCollectibles::<Test>::insert(1, owned(vec![a, 7]));
+		crate::OwnerOfCollectibles::<Test>::insert(2, owned(vec![8, b]));
+
+		// One owner is checked per block, resuming from the cursor.
+		VulnModule::on_idle(1, Weight::MAX);
+		assert!(crate::StaleSweepCursor::<Test>::get().is_some());
+		VulnModule::on_idle(1, Weight::MAX);
+		assert!(crate::StaleSweepCursor::<Test>::get().is_none());
+
+		assert_eq!(crate::OwnerOfCollectibles::<Test>::get(1).into_inner(), vec![a]);
+		assert_eq!(crate::OwnerOfCollectibles::<Test>::get(2).into_inner(), vec![b]);
+		System::assert_has_event(Event::StaleReferenceRemoved { owner: 1, collectible: 7 }.into());
+		System::assert_has_event(Event::StaleReferenceRemoved { owner: 2, collectible: 8 }.into());
+	});
+}
+
+#[test]
+fn rolling_volume_forgets_old_epochs() {
+	new_test_ext().execute_with(|| {
+		let sell = |price| {
+			let id = mint_to(1);
+			assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(price)));
+			assert_ok!(VulnModule::buy_collectible(RuntimeOrigin::signed(2), id, u64::MAX));
+		};
+		sell(100);
+		System::set_block_number(15);
+		sell(50);
+		sell(10);
+		assert_eq!(VulnModule::rolling_volume(), 160);
+
+		// Epochs are ten blocks long and the window spans three of them.
+		System::set_block_number(29);
+		assert_eq!(VulnModule::rolling_volume(), 160);
+		System::set_block_number(30);
+		assert_eq!(VulnModule::rolling_volume(), 60);
+		System::set_block_number(45);
+		sell(30);
+		assert_eq!(VulnModule::rolling_volume(), 30);
+	});
+}
+
+#[test]
+fn listings_below_the_minimum_price_are_rejected() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		let list = |price| VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(price));
+		assert_noop!(list(0), Error::<Test>::PriceTooLow);
+		assert_noop!(list(9), Error::<Test>::PriceTooLow);
+		assert_ok!(list(10));
+		assert_eq!(VulnModule::floor_price(), Some(10));
+	});
+}
+
+#[test]
+fn prices_at_the_numeric_extremes_are_rejected() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		let max = u64::MAX / 2;
+		assert_noop!(
+			VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(max + 1)),
+			Error::<Test>::PriceTooHigh
+		);
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(max)));
+	});
+}
+
+#[test]
+fn prices_are_set_in_batches() {
+	new_test_ext().execute_with(|| {
+		let (a, b, c) = (mint_to(1), mint_to(1), mint_to(2));
+		let prices = |prices: Vec<(u64, Option<u64>)>| prices.try_into().unwrap();
+		let listed = vec![(a, Some(10)), (b, Some(20))];
+		assert_ok!(VulnModule::set_prices(RuntimeOrigin::signed(1), prices(listed.clone())));
+		System::assert_last_event(Event::PricesSet { owner: 1, prices: prices(listed) }.into());
+		assert_eq!(VulnModule::floor_price(), Some(10));
+
+		// One collectible the caller does not own fails the whole batch.
+		assert_noop!(
+			VulnModule::set_prices(RuntimeOrigin::signed(1), prices(vec![(a, None), (c, Some(5))])),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(VulnModule::set_prices(RuntimeOrigin::signed(1), prices(vec![(a, None)])));
+		assert_eq!(CollectibleMap::<Test>::get(a).unwrap().price, None);
+		assert_eq!(VulnModule::floor_price(), Some(20));
+	});
+}
+
+#[test]
+fn color_stats_follow_mints_listings_sales_and_burns() {
+	new_test_ext().execute_with(|| {
+		// Even ids mint red, odd ids yellow.
+		let (a, b, c) = (mint_to(1), mint_to(1), mint_to(1));
+		let list = |id, price| VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(price));
+		assert_ok!(list(a, 30));
+		assert_ok!(list(b, 20));
+		assert_ok!(list(c, 10));
+		let stats = |count, floor, last_sale| ColorStats { count, floor, last_sale };
+		assert_eq!(VulnModule::color_stats(Color::Red), stats(2, Some(10), None));
+		assert_eq!(VulnModule::color_stats(Color::Yellow), stats(1, Some(20), None));
+		assert_eq!(VulnModule::floor_price(), Some(10));
+
+		assert_ok!(VulnModule::buy_collectible(RuntimeOrigin::signed(2), c, u64::MAX));
+		assert_eq!(VulnModule::color_stats(Color::Red), stats(2, Some(30), Some(10)));
+
+		assert_ok!(VulnModule::burn(RuntimeOrigin::signed(1), b));
+		assert_eq!(VulnModule::color_stats(Color::Yellow), stats(0, None, None));
+		assert_eq!(VulnModule::color_stats(Color::Blue), stats(0, None, None));
+	});
+}
+
+#[test]
+fn buyers_get_a_soulbound_receipt_when_the_creator_asks_for_one() {
+	new_test_ext().execute_with(|| {
+		let (plain, receipted) = (mint_to(3), mint_to(1));
+		assert_ok!(VulnModule::set_sale_receipts(RuntimeOrigin::signed(1), true));
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(3), 3, plain, Some(40)));
+		assert_ok!(VulnModule::buy_collectible(RuntimeOrigin::signed(2), plain, u64::MAX));
+		assert_eq!(crate::OwnerOfCollectibles::<Test>::get(2).into_inner(), vec![plain]);
+
+		System::set_block_number(3);
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, receipted, Some(50)));
+		assert_ok!(VulnModule::buy_collectible(RuntimeOrigin::signed(2), receipted, u64::MAX));
+		let receipt = receipted + 1;
+		System::assert_has_event(
+			Event::ReceiptIssued { receipt, collectible: receipted, buyer: 2 }.into(),
+		);
+		assert_eq!(
+			crate::OwnerOfCollectibles::<Test>::get(2).into_inner(),
+			vec![plain, receipted, receipt]
+		);
+		let sale = VulnModule::sale_receipt(receipt).unwrap();
+		assert_eq!((sale.collectible, sale.seller), (receipted, 1));
+		assert_eq!((sale.price, sale.sold_at), (50, 3));
+
+		assert_noop!(
+			VulnModule::transfer(RuntimeOrigin::signed(2), 3, receipt),
+			Error::<Test>::Soulbound
+		);
+		assert_noop!(
+			VulnModule::set_price(RuntimeOrigin::signed(2), 2, receipt, Some(50)),
+			Error::<Test>::Soulbound
+		);
+		assert_ok!(VulnModule::burn(RuntimeOrigin::signed(2), receipt));
+		assert!(VulnModule::sale_receipt(receipt).is_none());
+	});
+}
+
+#[test]
+fn sale_proceeds_are_split_by_the_pricing_policy() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(100)));
+		assert_ok!(VulnModule::buy_collectible(RuntimeOrigin::signed(2), id, u64::MAX));
+		assert_eq!(Balances::free_balance(1), 1_100);
+
+		// Account 3 buys under a policy that pays the creator, a fee and a referrer.
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(2), 2, id, Some(200)));
+		assert_ok!(VulnModule::buy_collectible(RuntimeOrigin::signed(3), id, u64::MAX));
+		assert_eq!(Balances::free_balance(3), 800);
+		assert_eq!(Balances::free_balance(2), 1_040);
+		assert_eq!(Balances::free_balance(1), 1_120);
+		assert_eq!(Balances::free_balance(5), 20);
+		assert_eq!(Balances::free_balance(TREASURY), 20);
+	});
+}
+
+#[test]
+fn dry_run_buys_quote_the_sale_without_making_it() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(200)));
+		let split = crate::SaleSplit { seller: 140, creator: 20, fee: 20, referrer: Some((5, 20)) };
+		assert_eq!(
+			VulnModule::dry_run_buy(3, id, 250),
+			Ok(crate::SaleQuote { seller: 1, price: 200, split, royalty: 0, charity: None })
+		);
+		assert_eq!(CollectibleMap::<Test>::get(id).unwrap().owner, 1);
+		assert_eq!(Balances::free_balance(3), 1_000);
+
+		assert_eq!(VulnModule::dry_run_buy(3, id, 199), Err(Error::<Test>::AboveMaxPrice.into()));
+		assert_eq!(VulnModule::dry_run_buy(1, id, 200), Err(Error::<Test>::TransferToSelf.into()));
+	});
+}
+
+#[test]
+fn draws_refund_the_tickets_that_were_not_sold() {
+	use crate::WeightInfo;
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_ok!(VulnModule::start_raffle(RuntimeOrigin::signed(1), id, 10, 2, 5, 10));
+		assert_ok!(VulnModule::buy_tickets(RuntimeOrigin::signed(2), id, 2));
+
+		System::set_block_number(11);
+		let info = VulnModule::draw_raffle(RuntimeOrigin::signed(2), id).unwrap();
+		assert_eq!(info.actual_weight, Some(<() as WeightInfo>::draw_raffle(2)));
+		assert!(info.actual_weight.unwrap().all_lt(<() as WeightInfo>::draw_raffle(10)));
+	});
+}
+
+#[test]
+fn mints_the_policy_refuses_fail() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(VulnModule::set_accept_mints(RuntimeOrigin::signed(1), true));
+		assert_noop!(
+			VulnModule::create_collectible(RuntimeOrigin::signed(9), 1),
+			DispatchError::Other("minting closed")
+		);
+		assert_noop!(
+			VulnModule::mint_mystery_box(RuntimeOrigin::signed(9), 9),
+			DispatchError::Other("minting closed")
+		);
+		mint_to(1);
+	});
+}
+
+#[test]
+fn recovered_accounts_can_move_their_collectibles() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_ok!(Recovery::create_recovery(RuntimeOrigin::signed(1), vec![3, 4], 2, 0));
+		assert_ok!(Recovery::initiate_recovery(RuntimeOrigin::signed(2), 1));
+		assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(3), 1, 2));
+		let as_lost = || {
+			let call = crate::Call::<Test>::transfer { to: 2, unique_id: id };
+			Recovery::as_recovered(RuntimeOrigin::signed(2), 1, Box::new(call.into()))
+		};
+		// Not enough friends vouched yet.
+		assert!(as_lost().is_err());
+
+		assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(4), 1, 2));
+		assert_ok!(Recovery::claim_recovery(RuntimeOrigin::signed(2), 1));
+		assert_ok!(as_lost());
+		assert_eq!(crate::OwnerOfCollectibles::<Test>::get(2).into_inner(), vec![id]);
+		assert!(crate::OwnerOfCollectibles::<Test>::get(1).is_empty());
+	});
+}
+
+#[test]
+fn vested_accounts_pay_marketplace_fees_from_unlocked_funds() {
+	use pallet_vesting::VestingInfo;
+	new_test_ext().execute_with(|| {
+		// 500 unlocking at 5 a block from block 1, on top of 100 that is free to spend.
+		let schedule = VestingInfo::new(500, 5, 1);
+		assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(1), 6, schedule));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), 6, 100));
+		let listed = mint_to(6);
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(6), 6, listed, Some(50)));
+		assert_eq!(Balances::reserved_balance(6), 5);
+
+		let (first, second) = (mint_to(3), mint_to(3));
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(3), 3, first, Some(60)));
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(3), 3, second, Some(60)));
+		assert_ok!(VulnModule::buy_collectible(RuntimeOrigin::signed(6), first, u64::MAX));
+		// The rest is still locked.
+		assert!(VulnModule::buy_collectible(RuntimeOrigin::signed(6), second, u64::MAX).is_err());
+
+		System::set_block_number(5);
+		assert_ok!(Vesting::vest(RuntimeOrigin::signed(6)));
+		assert_ok!(VulnModule::buy_collectible(RuntimeOrigin::signed(6), second, u64::MAX));
+		assert_eq!(Balances::free_balance(6), 475);
+	});
+}
+
+#[test]
+fn genesis_mints_a_collectible_per_entry() {
+	use sp_runtime::BuildStorage;
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	// More than `MaxMintsPerBlock`, which only limits extrinsics.
+	let mut collectibles = vec![(1, Color::Red, None), (2, Color::Blue, Some(30))];
+	collectibles.extend([(1, Color::Green, None); 4]);
+	crate::GenesisConfig::<Test> { collectibles }.assimilate_storage(&mut t).unwrap();
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		assert_eq!(crate::CollectiblesCount::<Test>::get(), 6);
+		assert_eq!(crate::OwnerOfCollectibles::<Test>::get(1).into_inner(), vec![0, 2, 3, 4, 5]);
+		let collectible = CollectibleMap::<Test>::get(1).unwrap();
+		assert_eq!((collectible.owner, collectible.creator), (2, 2));
+		assert_eq!(collectible.color, Color::Blue);
+		assert_eq!(CollectibleMap::<Test>::get(0).unwrap().color, Color::Red);
+		// Priced entries are listed, and count towards the floor.
+		assert_eq!(VulnModule::listing_price(1), Some(30));
+		assert_eq!(VulnModule::listing_price(0), None);
+		assert_eq!(VulnModule::floor_price(), Some(30));
+		// Collectibles can still be minted in the first block.
+		System::set_block_number(1);
+		mint_to(3);
+	});
+}
+
+#[test]
+fn storage_dumps_page_through_the_pallet_storage() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		let dump = VulnModule::dump_storage(None, 1_000);
+		let prefix = sp_io::hashing::twox_128(b"VulnModule");
+		assert!(dump.iter().all(|(key, _)| key.starts_with(&prefix)));
+		let collectible = CollectibleMap::<Test>::hashed_key_for(id);
+		let value = CollectibleMap::<Test>::get(id).unwrap().encode();
+		assert!(dump.contains(&(collectible, value)));
+
+		let first = VulnModule::dump_storage(None, 1);
+		let rest = VulnModule::dump_storage(Some(first[0].0.clone()), 1_000);
+		assert_eq!([first, rest].concat(), dump);
+	});
+}
+
+#[test]
+fn hashed_ids_are_found_through_the_mint_sequence() {
+	new_test_ext().execute_with(|| {
+		HashIds::set(true);
+		let first = crate::NextCollectibleId::<Test>::get();
+		for _ in 0..3 {
+			assert_ok!(VulnModule::create_collectible(RuntimeOrigin::signed(1), 1));
+		}
+		let ids: Vec<u64> =
+			(first..first + 3).map(|seq| crate::ItemIdAt::<Test>::get(seq).unwrap()).collect();
+		for (id, price) in ids.iter().zip([30, 20, 40]) {
+			assert!(CollectibleMap::<Test>::contains_key(id));
+			assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, *id, Some(price)));
+		}
+		assert_eq!(VulnModule::floor_price(), Some(20));
+
+		// Unordered ids still come back sorted, so paging by the last id works.
+		let mut sorted = ids.clone();
+		sorted.sort_unstable();
+		let found: Vec<u64> = VulnModule::find_collectibles(Default::default(), None, 100)
+			.into_iter()
+			.map(|(id, _, _)| id)
+			.filter(|id| ids.contains(id))
+			.collect();
+		assert_eq!(found, sorted);
+
+		crate::FloorIndex::<Test>::put(crate::CheapestListings {
+			rebuild_from: Some(0),
+			..Default::default()
+		});
+		VulnModule::on_idle(1, Weight::MAX);
+		assert_eq!(VulnModule::floor_price(), Some(20));
+	});
+}
+
+#[test]
+fn color_strategies_only_pick_colors_they_allow() {
+	use crate::{ColorStrategy, ParityColors, RandomColors, RarityTable};
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_eq!(CollectibleMap::<Test>::get(id).unwrap().color, ParityColors::color(id, id));
+		assert_eq!(ParityColors::color(3, 3), Color::Yellow);
+		for sequence in 0..20 {
+			let color = RarityTable::<Test, BlueOrGreen>::color(sequence, sequence);
+			assert!(matches!(color, Color::Blue | Color::Green));
+			assert_ne!(RandomColors::<Test>::color(sequence, sequence), Color::Hidden);
+		}
+	});
+}
+
+#[test]
+fn the_first_distinct_minters_earn_founder_badges() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(VulnModule::set_founder_badge_limit(RuntimeOrigin::root(), 2));
+		let first = mint_to(1);
+		let badge = first + 1;
+		System::assert_has_event(Event::FounderBadgeAwarded { minter: 1, badge }.into());
+		assert_eq!(VulnModule::founder_badge(&1), Some(badge));
+		assert_eq!(crate::OwnerOfCollectibles::<Test>::get(1).into_inner(), vec![first, badge]);
+
+		// Minting again earns no second badge, and places run out after the second minter.
+		mint_to(1);
+		mint_to(2);
+		System::set_block_number(2);
+		VulnModule::on_initialize(2);
+		mint_to(3);
+		assert_eq!(crate::FounderBadgesIssued::<Test>::get(), 2);
+		assert!(VulnModule::founder_badge(&2).is_some());
+		assert!(VulnModule::founder_badge(&3).is_none());
+
+		assert_noop!(
+			VulnModule::transfer(RuntimeOrigin::signed(1), 2, badge),
+			Error::<Test>::Soulbound
+		);
+	});
+}
+
+#[test]
+fn collectibles_traded_in_a_circle_are_left_out_of_market_statistics() {
+	new_test_ext().execute_with(|| {
+		let list = |id, seller: u64, price| {
+			VulnModule::set_price(RuntimeOrigin::signed(seller), seller, id, Some(price))
+		};
+		let sell = |id, seller: u64, buyer: u64, price| {
+			assert_ok!(list(id, seller, price));
+			assert_ok!(VulnModule::buy_collectible(RuntimeOrigin::signed(buyer), id, u64::MAX));
+		};
+		let (washed, fair) = (mint_to(1), mint_to(1));
+		sell(washed, 1, 2, 100);
+		sell(washed, 2, 1, 100);
+		System::assert_has_event(
+			Event::WashTradeFlagged { collectible: washed, seller: 2, buyer: 1 }.into(),
+		);
+		assert!(VulnModule::is_wash_traded(washed));
+		assert_eq!(VulnModule::rolling_volume(), 100);
+		assert_eq!(VulnModule::color_stats(Color::Red).last_sale, Some(100));
+
+		// A sale back after the window has passed is no circle.
+		sell(fair, 1, 2, 40);
+		System::set_block_number(30);
+		sell(fair, 2, 1, 40);
+		assert!(!VulnModule::is_wash_traded(fair));
+
+		// The flagged collectible still lists and sells, out of sight of the floor.
+		assert_ok!(list(washed, 1, 10));
+		assert_ok!(list(fair, 1, 20));
+		assert_eq!(VulnModule::floor_price(), Some(20));
+		let for_sale = CollectibleFilter { for_sale_only: true, ..Default::default() };
+		let found = VulnModule::find_collectibles(for_sale, None, 10);
+		assert_eq!(found, vec![(washed, Color::Red, Some(10)), (fair, Color::Yellow, Some(20))]);
+	});
+}
+
+#[test]
+fn searches_can_be_limited_to_verified_creators() {
+	new_test_ext().execute_with(|| {
+		let verified = [mint_to(1), mint_to(2)];
+		let unverified = mint_to(3);
+		assert!(VulnModule::is_verified_creator(&2));
+		assert!(!VulnModule::is_verified_creator(&3));
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(2), 2, verified[1], Some(20)));
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(3), 3, unverified, Some(10)));
+		let find = |filter| {
+			VulnModule::find_collectibles(filter, None, 10)
+				.into_iter()
+				.map(|(id, _, _)| id)
+				.collect::<Vec<_>>()
+		};
+
+		let by_verified = CollectibleFilter { verified_creators_only: true, ..Default::default() };
+		assert_eq!(find(by_verified.clone()), verified.to_vec());
+		let for_sale = CollectibleFilter { for_sale_only: true, ..by_verified };
+		assert_eq!(find(for_sale), vec![verified[1]]);
+		assert_eq!(find(Default::default()), vec![verified[0], verified[1], unverified]);
+	});
+}
+
+#[test]
+fn collectibles_are_looked_up_by_id_and_owner() {
+	new_test_ext().execute_with(|| {
+		let ids = [mint_to(1), mint_to(1)];
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, ids[1], Some(30)));
+		assert_ok!(VulnModule::transfer(RuntimeOrigin::signed(1), 2, ids[0]));
+
+		let view = VulnModule::collectible(ids[0]).unwrap();
+		assert_eq!((view.unique_id, view.owner, view.creator, view.price), (ids[0], 2, 1, None));
+		assert_eq!(VulnModule::collectible(ids[1]).unwrap().price, Some(30));
+		assert_eq!(VulnModule::collectible(ids[1] + 1), None);
+		assert_eq!(VulnModule::collectibles_of(&1), vec![ids[1]]);
+		assert_eq!(VulnModule::collectibles_of(&2), vec![ids[0]]);
+		assert!(VulnModule::collectibles_of(&3).is_empty());
+	});
+}
+
+#[test]
+fn proof_keys_hold_the_collectible() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		let stored = sp_io::storage::get(&VulnModule::collectible_proof_keys(id)[0]).unwrap();
+		assert_eq!(crate::Collectible::<Test>::decode(&mut &stored[..]).unwrap().owner, 1);
+		assert_eq!(sp_io::storage::get(&VulnModule::collectible_proof_keys(id + 1)[0]), None);
+	});
+}
+
+#[test]
+fn collectibles_are_found_by_color_and_price() {
+	new_test_ext().execute_with(|| {
+		// Even ids mint red, odd ids yellow.
+		let ids = [mint_to(1), mint_to(1), mint_to(1), mint_to(1), mint_to(1)];
+		for (id, price) in ids.into_iter().zip([30, 20, 10, 40]) {
+			assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(price)));
+		}
+		let find = |filter, start_after, limit| {
+			VulnModule::find_collectibles(filter, start_after, limit)
+				.into_iter()
+				.map(|(id, _, _)| id)
+				.collect::<Vec<_>>()
+		};
+		let red = CollectibleFilter { color: Some(Color::Red), ..Default::default() };
+		assert_eq!(find(red.clone(), None, 10), vec![0, 2, 4]);
+		assert_eq!(find(red.clone(), Some(0), 1), vec![2]);
+
+		let red_for_sale = CollectibleFilter { for_sale_only: true, ..red };
+		assert_eq!(find(red_for_sale, None, 10), vec![0, 2]);
+		let cheap = CollectibleFilter { max_price: Some(25), ..Default::default() };
+		assert_eq!(find(cheap, None, 10), vec![1, 2]);
+		// Only two listings fit the index, so this range is answered by scanning.
+		let dear =
+			CollectibleFilter { min_price: Some(25), for_sale_only: true, ..Default::default() };
+		assert_eq!(find(dear, None, 10), vec![0, 3]);
+		assert_eq!(
+			VulnModule::find_collectibles(Default::default(), Some(3), 10),
+			vec![(4, Color::Red, None)]
+		);
+	});
+}
+
+#[test]
+fn metadata_blob_is_checked_against_commitment() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		let blob = b"{\"name\":\"vulntoken #0\"}";
+		assert!(!VulnModule::verify_metadata(id, blob));
+		let hash = sp_io::hashing::blake2_256(blob);
+		assert_noop!(
+			VulnModule::set_metadata_hash(RuntimeOrigin::signed(2), id, Some(hash)),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(VulnModule::set_metadata_hash(RuntimeOrigin::signed(1), id, Some(hash)));
+		System::assert_last_event(Event::MetadataHashSet { collectible: id, hash: Some(hash) }.into());
+		assert!(VulnModule::verify_metadata(id, blob));
+		assert!(!VulnModule::verify_metadata(id, b"tampered"));
+
+		assert_ok!(VulnModule::burn(RuntimeOrigin::signed(1), id));
+		assert!(!VulnModule::verify_metadata(id, blob));
+	});
+}
+
+#[test]
+fn metadata_cid_is_queued_until_pinned() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		let cid: frame_support::BoundedVec<u8, _> =
+			b"bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku".to_vec().try_into().unwrap();
+		let url = b"ipfs://x".to_vec().try_into().unwrap();
+		assert_noop!(
+			VulnModule::set_metadata_cid(RuntimeOrigin::signed(1), id, url),
+			Error::<Test>::InvalidCid
+		);
+		assert_noop!(
+			VulnModule::confirm_pin(RuntimeOrigin::none(), id),
+			Error::<Test>::NotPinPending
+		);
+		assert_ok!(VulnModule::set_metadata_cid(RuntimeOrigin::signed(1), id, cid.clone()));
+		assert!(PinQueue::<Test>::contains_key(id));
+
+		// Only locally produced confirmations are valid.
+		let call = crate::Call::confirm_pin { unique_id: id };
+		assert!(VulnModule::validate_unsigned(TransactionSource::External, &call).is_err());
+		assert!(VulnModule::validate_unsigned(TransactionSource::Local, &call).is_ok());
+
+		assert_noop!(
+			VulnModule::confirm_pin(RuntimeOrigin::signed(1), id),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(VulnModule::confirm_pin(RuntimeOrigin::none(), id));
+		System::assert_last_event(Event::MetadataPinned { collectible: id }.into());
+		assert!(!PinQueue::<Test>::contains_key(id));
+		assert_eq!(MetadataCids::<Test>::get(id), Some(cid));
+	});
+}
+
+#[test]
+fn foreign_collectibles_are_registered_once_and_redeemed_back() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			VulnModule::register_foreign(RuntimeOrigin::signed(1), 1_000, 7, 1),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(VulnModule::register_foreign(RuntimeOrigin::root(), 1_000, 7, 1));
+		let id = VulnModule::foreign_derivative(1_000, 7).unwrap();
+		System::assert_last_event(
+			Event::ForeignRegistered { collectible: id, chain: 1_000, original_id: 7, owner: 1 }
+				.into(),
+		);
+		let source = VulnModule::foreign_source(id).unwrap();
+		assert_eq!((source.chain, source.original_id), (1_000, 7));
+		assert_noop!(
+			VulnModule::register_foreign(RuntimeOrigin::root(), 1_000, 7, 2),
+			Error::<Test>::ForeignAlreadyRegistered
+		);
+
+		// Derivatives trade like native collectibles, but leave only by being redeemed.
+		assert_ok!(VulnModule::transfer(RuntimeOrigin::signed(1), 2, id));
+		assert_noop!(
+			VulnModule::redeem_foreign(RuntimeOrigin::signed(1), id),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			VulnModule::burn(RuntimeOrigin::signed(2), id),
+			Error::<Test>::ForeignCollectible
+		);
+		let native = mint_to(2);
+		assert_noop!(
+			VulnModule::redeem_foreign(RuntimeOrigin::signed(2), native),
+			Error::<Test>::NotForeign
+		);
+
+		assert_ok!(VulnModule::redeem_foreign(RuntimeOrigin::signed(2), id));
+		System::assert_last_event(
+			Event::ForeignRedeemed { collectible: id, chain: 1_000, original_id: 7, owner: 2 }
+				.into(),
+		);
+		assert!(CollectibleMap::<Test>::get(id).is_none());
+		assert_eq!(VulnModule::foreign_source(id), None);
+		assert_eq!(VulnModule::foreign_derivative(1_000, 7), None);
+		// The original can come back again.
+		assert_ok!(VulnModule::register_foreign(RuntimeOrigin::root(), 1_000, 7, 3));
+	});
+}
+
+#[test]
+fn holders_are_paged() {
+	new_test_ext().execute_with(|| {
+		mint_to(1);
+		mint_to(1);
+		mint_to(2);
+		let id = mint_to(3);
+		assert_ok!(VulnModule::transfer(RuntimeOrigin::signed(3), 2, id));
+
+		let mut holders = VulnModule::holders(None, 10);
+		holders.sort();
+		assert_eq!(holders, vec![(1, 2), (2, 2)]);
+
+		let first = VulnModule::holders(None, 1);
+		assert_eq!(first.len(), 1);
+		let rest = VulnModule::holders(Some(first[0].0), 10);
+		assert_eq!(rest.len(), 1);
+		assert_ne!(first[0].0, rest[0].0);
+	});
+}
+
+#[test]
+fn mints_are_rate_limited_per_block() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..5 {
+			mint_to(1);
+		}
+		assert_noop!(
+			VulnModule::create_collectible(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::MintRateExceeded
+		);
+		assert_noop!(
+			VulnModule::mint_mystery_box(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::MintRateExceeded
+		);
+
+		System::set_block_number(2);
+		VulnModule::on_initialize(2);
+		mint_to(1);
+	});
+}
+
+#[test]
+fn mints_into_other_accounts_need_consent() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(VulnModule::create_collectible(RuntimeOrigin::signed(1), 2));
+		System::assert_last_event(Event::MintPending { to: 2, minter: 1 }.into());
+		assert!(crate::OwnerOfCollectibles::<Test>::get(2).is_empty());
+		assert_noop!(
+			VulnModule::mint_mystery_box(RuntimeOrigin::signed(1), 2),
+			Error::<Test>::RecipientNotAccepting
+		);
+
+		assert_noop!(
+			VulnModule::claim_mints(RuntimeOrigin::signed(2), 2),
+			Error::<Test>::NoPendingMints
+		);
+		assert_ok!(VulnModule::claim_mints(RuntimeOrigin::signed(2), 1));
+		let claimed = crate::OwnerOfCollectibles::<Test>::get(2);
+		assert_eq!(claimed.len(), 1);
+		assert_eq!(CollectibleMap::<Test>::get(claimed[0]).unwrap().creator, 1);
+		assert!(crate::PendingMints::<Test>::get(2).is_empty());
+
+		// Pending mints answer to the mint policy and rate limit like any other.
+		assert_noop!(
+			VulnModule::create_collectible(RuntimeOrigin::signed(9), 2),
+			DispatchError::Other("minting closed")
+		);
+		crate::MintsThisBlock::<Test>::put(5);
+		assert_noop!(
+			VulnModule::create_collectible(RuntimeOrigin::signed(1), 2),
+			Error::<Test>::MintRateExceeded
+		);
+		crate::MintsThisBlock::<Test>::kill();
+
+		assert_ok!(VulnModule::set_accept_mints(RuntimeOrigin::signed(2), true));
+		assert_ok!(VulnModule::create_collectible(RuntimeOrigin::signed(1), 2));
+		assert_eq!(crate::OwnerOfCollectibles::<Test>::get(2).len(), 2);
+	});
+}
+
+#[test]
+fn burn_leaves_no_trace() {
+	new_test_ext().execute_with(|| {
+		// Account keys are made the same way as id keys, so keep the owner apart from the id.
+		let other = mint_to(3);
+		assert_ok!(VulnModule::set_transfer_cooldown(RuntimeOrigin::root(), 10));
+		assert_ok!(VulnModule::mint_mystery_box(RuntimeOrigin::signed(3), 3));
+		let id = crate::NextCollectibleId::<Test>::get() - 1;
+		System::set_block_number(6);
+		assert_ok!(VulnModule::reveal(RuntimeOrigin::signed(3), id));
+		assert_ok!(VulnModule::set_metadata_hash(RuntimeOrigin::signed(3), id, Some([1; 32])));
+		let cid = b"bafkreihdwd".to_vec().try_into().unwrap();
+		assert_ok!(VulnModule::set_metadata_cid(RuntimeOrigin::signed(3), id, cid));
+		System::set_block_number(11);
+		let ten = sp_runtime::Percent::from_percent(10);
+		assert_ok!(VulnModule::list_with_charity(RuntimeOrigin::signed(3), id, 20, 4, ten));
+		// Listing ended the mint's cooldown; leave one running for the burn to clear.
+		VulnModule::start_cooldown(id);
+		let key = b"artist".to_vec().try_into().unwrap();
+		let value = b"anon".to_vec().try_into().unwrap();
+		assert_ok!(VulnModule::set_attribute(RuntimeOrigin::signed(3), id, key, value));
+		assert_ok!(VulnModule::offer_rental(RuntimeOrigin::signed(3), id, Some(1)));
+		assert_ok!(VulnModule::make_offer(RuntimeOrigin::signed(2), id, 50));
+		assert!(keys_containing(id).len() >= 12);
+
+		assert_ok!(VulnModule::burn(RuntimeOrigin::signed(3), id));
+		assert_eq!(crate::OwnerOfCollectibles::<Test>::get(3).into_inner(), vec![other]);
+		for reason in [HoldReason::ListingDeposit, HoldReason::MetadataDeposit] {
+			assert_eq!(Balances::balance_on_hold(&reason.into(), &3), 0);
+		}
+		// The offer was released to its bidder along with everything else.
+		assert_eq!(keys_containing(id), Vec::<Vec<u8>>::new());
+		assert_eq!(Balances::free_balance(2), 1_000);
+		// The hook skips the gap the burn left behind.
+		VulnModule::on_initialize(7);
+	});
+}
+
+/// A migration that moves every `HighestPrice` into a fresh `CollectiblesCount`, or loses it
+/// when `LOSSY`, to show the harness catches a migration its own checks reject.
+struct ResetHighestPrice<const LOSSY: bool>;
+
+impl<const LOSSY: bool> StorageMigration for ResetHighestPrice<LOSSY> {
+	fn migrate() -> Weight {
+		let price = crate::HighestPrice::<Test>::take();
+		if !LOSSY {
+			crate::CollectiblesCount::<Test>::put(price);
+		}
+		Weight::zero()
+	}
+
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		Ok(crate::HighestPrice::<Test>::get().encode())
+	}
+
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let price = u64::decode(&mut &state[..]).map_err(|_| "undecodable snapshot")?;
+		ensure!(crate::CollectiblesCount::<Test>::get() == price, "price was not carried over");
+		Ok(())
+	}
+
+	fn legacy_state() {
+		crate::HighestPrice::<Test>::put(42);
+	}
+}
+
+#[test]
+fn migration_checks_run_against_legacy_state() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(migrations::check::<ResetHighestPrice<false>>());
+	});
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			migrations::check::<ResetHighestPrice<true>>(),
+			Err("price was not carried over".into())
+		);
+	});
+}
+
+#[test]
+fn migration_to_v1_keeps_collectibles_and_counts_supply() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(migrations::check::<MigrateToV1<Test>>());
+
+		let revealed = CollectibleMap::<Test>::get(2).unwrap();
+		assert_eq!((revealed.owner, revealed.creator), (2, 2));
+		assert!(revealed.flags.mystery_box);
+		assert_eq!(CollectibleMap::<Test>::get(0).unwrap().metadata, Some([0; 32]));
+		assert_eq!(crate::CollectiblesCount::<Test>::get(), 3);
+		assert_eq!(mint_to(1), 4);
+		assert_eq!(crate::CollectiblesCount::<Test>::get(), 4);
+
+		// A second run is a no-op.
+		let before = CollectibleMap::<Test>::iter().collect::<Vec<_>>();
+		MigrateToV1::<Test>::migrate();
+		assert_eq!(CollectibleMap::<Test>::iter().collect::<Vec<_>>(), before);
+	});
+}
+
+#[test]
+fn migration_to_v2_moves_reserves_into_holds() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(migrations::check::<MigrateReservesToHolds<Test, Balances>>());
+
+		assert_eq!(Balances::balance_on_hold(&HoldReason::Bid.into(), &2), 110);
+		assert_eq!(Balances::balance_on_hold(&HoldReason::Escrow.into(), &3), 100);
+		assert_eq!(Balances::balance_on_hold(&HoldReason::DisputeDeposit.into(), &3), 10);
+		// Nothing is left reserved outside a hold.
+		assert_eq!(Balances::reserved_balance(2), 110);
+		assert_eq!(Balances::reserved_balance(3), 110);
+	});
+}
+
+#[test]
+fn staked_or_collateralized_item_is_frozen_until_every_freeze_is_lifted() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(10)));
+		VulnModule::do_freeze(id, FreezeReason::Staked);
+		VulnModule::do_freeze(id, FreezeReason::Collateral);
+
+		assert_noop!(VulnModule::transfer(RuntimeOrigin::signed(1), 2, id), Error::<Test>::Frozen);
+		assert_noop!(
+			VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, None),
+			Error::<Test>::Frozen
+		);
+		assert_noop!(
+			VulnModule::buy_collectible(RuntimeOrigin::signed(2), id, u64::MAX),
+			Error::<Test>::Frozen
+		);
+		assert_noop!(VulnModule::burn(RuntimeOrigin::signed(1), id), Error::<Test>::Frozen);
+
+		VulnModule::do_thaw(id, FreezeReason::Staked);
+		assert_noop!(VulnModule::transfer(RuntimeOrigin::signed(1), 2, id), Error::<Test>::Frozen);
+		VulnModule::do_thaw(id, FreezeReason::Collateral);
+		assert_ok!(VulnModule::buy_collectible(RuntimeOrigin::signed(2), id, u64::MAX));
+	});
+}
+
+#[test]
+fn migration_to_v3_freezes_locked_collectibles() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(migrations::check::<PopulateFreezes<Test>>());
+
+		assert!(VulnModule::is_frozen(0, FreezeReason::Auction));
+		assert!(VulnModule::is_frozen(1, FreezeReason::Raffle));
+		assert!(VulnModule::is_frozen(2, FreezeReason::Escrow));
+		assert!(VulnModule::is_frozen(2, FreezeReason::Disputed));
+		assert_eq!(VulnModule::ensure_unlocked(2), Err(Error::<Test>::InEscrow.into()));
+	});
+}
+
+#[test]
+fn migration_to_v4_moves_rentals_to_streams() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(migrations::check::<MoveRentalsToStreams<Test>>());
+
+		// The owner was paid the rent due at the upgrade and the rest now streams.
+		assert_eq!(Balances::free_balance(1), 1_080);
+		assert_eq!(Balances::balance_on_hold(&HoldReason::Rental.into(), &2), 0);
+		let rental = crate::Rentals::<Test>::get(0).unwrap();
+		assert_eq!(PaymentStreams::remaining(rental.stream), Some(50));
+		assert_eq!(VulnModule::renter_of(0), Some(2));
+		assert!(VulnModule::is_frozen(0, FreezeReason::Rented));
+
+		// The rental that ran out ended.
+		assert!(!crate::Rentals::<Test>::contains_key(1));
+		assert!(!VulnModule::is_frozen(1, FreezeReason::Rented));
+	});
+}
+
+#[test]
+fn migration_to_v5_rebuilds_the_floor_index() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(migrations::check::<RebuildFloorIndex<Test>>());
+		assert_eq!(VulnModule::floor_price(), None);
+
+		VulnModule::on_idle(1, Weight::MAX);
+		assert_eq!(VulnModule::floor_price(), Some(20));
+		assert_eq!(crate::FloorIndex::<Test>::get().rebuild_from, None);
+	});
+}
+
+#[test]
+fn migration_to_v6_counts_colors_and_rebuilds_their_floors() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(migrations::check::<PopulateColorStatistics<Test>>());
+		assert_eq!(VulnModule::color_stats(Color::Red).count, 2);
+		assert_eq!(VulnModule::color_stats(Color::Yellow).count, 1);
+		assert_eq!(VulnModule::color_stats(Color::Red).floor, None);
+
+		VulnModule::on_idle(1, Weight::MAX);
+		assert_eq!(VulnModule::color_stats(Color::Red).floor, Some(30));
+		assert_eq!(VulnModule::color_stats(Color::Yellow).floor, Some(20));
+	});
+}
+
+#[test]
+fn migration_to_v7_rehashes_account_keys() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(migrations::check::<RehashAccountKeys<Test>>());
+		assert_eq!(crate::OwnerOfCollectibles::<Test>::get(1).into_inner(), vec![0, 2]);
+		assert_eq!(migrations::v10::v9::PendingMints::<Test>::get(2), 2);
+		assert_eq!(crate::DisputeVotes::<Test>::get(1, 1), Some(true));
+		assert!(SealedBids::<Test>::contains_key(0, 2));
+	});
+}
+
+#[test]
+fn migration_to_v8_puts_collectibles_in_no_collection() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(migrations::check::<AddCollectionIds<Test>>());
+		let collectible = CollectibleMap::<Test>::get(1).unwrap();
+		assert_eq!((collectible.owner, collectible.creator), (1, 2));
+		assert_eq!(collectible.metadata, Some([1; 32]));
+		assert_eq!(collectible.collection_id, None);
+	});
+}
+
+#[test]
+fn migration_to_v9_scans_for_the_highest_price() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(migrations::check::<ScanHighestPrice<Test>>());
+		assert_eq!(VulnModule::highest_price(), None);
+
+		VulnModule::on_idle(1, Weight::MAX);
+		assert_eq!(VulnModule::highest_price(), Some(30));
+		assert_eq!(crate::HighestPriceScan::<Test>::get(), None);
+	});
+}
+
+#[test]
+fn migration_to_v10_keeps_pending_mints_as_the_claimants_own() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(migrations::check::<RecordPendingMinters<Test>>());
+		assert_eq!(crate::PendingMints::<Test>::get(2).into_inner(), vec![(2, 0), (2, 0)]);
+
+		assert_ok!(VulnModule::claim_mints(RuntimeOrigin::signed(2), 2));
+		let claimed = VulnModule::collectibles_of(&2);
+		assert_eq!(CollectibleMap::<Test>::get(claimed[0]).unwrap().creator, 2);
+		assert_eq!(VulnModule::mint_deposit(claimed[0]), None);
+	});
+}
+
+#[test]
+fn migration_to_v11_indexes_the_collectibles_of_collections() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(migrations::check::<IndexCollectionItems<Test>>());
+		let mut items = crate::CollectionItems::<Test>::iter_key_prefix(0).collect::<Vec<_>>();
+		items.sort_unstable();
+		assert_eq!(items, vec![0, 2]);
+		assert!(!migrations::v12::v11::Collections::<Test>::get(0).unwrap().destroying);
+
+		AddCollectionSettings::<Test>::migrate();
+		assert_ok!(VulnModule::destroy_collection(RuntimeOrigin::signed(1), 0, 2));
+		assert_ok!(VulnModule::destroy_collection_items(RuntimeOrigin::signed(1), 0));
+		assert!(VulnModule::collection(0).is_none());
+	});
+}
+
+#[test]
+fn migration_to_v12_keeps_collections_on_the_settings_of_their_owners() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(migrations::check::<AddCollectionSettings<Test>>());
+		let first = VulnModule::collection(0).unwrap();
+		let second = VulnModule::collection(1).unwrap();
+		assert_eq!((first.metadata_version, second.metadata_version), (3, 0));
+		assert_eq!(first.settings, CollectionSettings::default());
+		assert!(second.destroying);
+	});
+}
+
+#[test]
+fn migration_to_v13_counts_the_offers_on_each_collectible() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(migrations::check::<CountOffers<Test>>());
+		assert_eq!(crate::OfferCounts::<Test>::get(0), Some(4));
+		assert_eq!(crate::OfferCounts::<Test>::get(1), Some(1));
+		assert_eq!(crate::OfferCounts::<Test>::get(2), None);
+	});
+}
+
+#[test]
+fn migration_to_v14_queues_the_auctions_that_are_over() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(migrations::check::<IndexUnsettledAuctions<Test>>());
+		let mut unsettled = crate::UnsettledAuctions::<Test>::iter_keys().collect::<Vec<_>>();
+		unsettled.sort_unstable();
+		assert_eq!(unsettled, vec![0, 2]);
+		assert_eq!(crate::SealedAuctionsEnding::<Test>::get(15).into_inner(), vec![1]);
+	});
+}
+
+#[test]
+fn issued_collectibles_have_the_color_asked_for() {
+	new_test_ext().execute_with(|| {
+		let id = <VulnModule as Issuer<u64>>::issue(&3, Color::Green).unwrap();
+		let collectible = CollectibleMap::<Test>::get(id).unwrap();
+		assert_eq!((collectible.owner, collectible.color), (3, Color::Green));
+		assert_noop!(
+			<VulnModule as Issuer<u64>>::issue(&3, Color::Hidden),
+			Error::<Test>::HiddenColor
+		);
+	});
+}
+
+#[test]
+fn timed_transfers_complete_at_their_block_unless_called_off() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(10)));
+		assert_noop!(
+			VulnModule::transfer_at(RuntimeOrigin::signed(2), 3, id, 5),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			VulnModule::transfer_at(RuntimeOrigin::signed(1), 3, id, 1),
+			Error::<Test>::TransferNotInFuture
+		);
+		assert_ok!(VulnModule::transfer_at(RuntimeOrigin::signed(1), 3, id, 5));
+		System::assert_last_event(
+			Event::TransferScheduled { collectible: id, from: 1, to: 3, at: 5 }.into(),
+		);
+		// The sender keeps the item, unlisted and frozen, until the transfer falls due.
+		let collectible = CollectibleMap::<Test>::get(id).unwrap();
+		assert_eq!((collectible.owner, collectible.price), (1, None));
+		assert_noop!(
+			VulnModule::transfer(RuntimeOrigin::signed(1), 2, id),
+			Error::<Test>::InTimedTransfer
+		);
+		assert_noop!(
+			VulnModule::cancel_transfer_at(RuntimeOrigin::signed(3), id),
+			Error::<Test>::NotOwner
+		);
+		VulnModule::on_initialize(4);
+		assert_eq!(CollectibleMap::<Test>::get(id).unwrap().owner, 1);
+		VulnModule::on_initialize(5);
+		assert_eq!(CollectibleMap::<Test>::get(id).unwrap().owner, 3);
+		assert!(VulnModule::timed_transfer(id).is_none());
+		assert_ok!(VulnModule::ensure_unlocked(id));
+
+		// Called off before it falls due.
+		let other = mint_to(2);
+		assert_ok!(VulnModule::transfer_at(RuntimeOrigin::signed(2), 4, other, 6));
+		assert_ok!(VulnModule::cancel_transfer_at(RuntimeOrigin::signed(2), other));
+		System::assert_last_event(
+			Event::ScheduledTransferCancelled { collectible: other, from: 2 }.into(),
+		);
+		assert_noop!(
+			VulnModule::cancel_transfer_at(RuntimeOrigin::signed(2), other),
+			Error::<Test>::NoTimedTransfer
+		);
+		VulnModule::on_initialize(6);
+		assert_eq!(CollectibleMap::<Test>::get(other).unwrap().owner, 2);
+		assert_ok!(VulnModule::ensure_unlocked(other));
+
+		// A recipient at the ownership cap leaves the item with the sender.
+		assert_ok!(VulnModule::transfer_at(RuntimeOrigin::signed(2), 4, other, 8));
+		let full = frame_support::BoundedVec::truncate_from((100..110).collect());
+		crate::OwnerOfCollectibles::<Test>::insert(4, full);
+		VulnModule::on_initialize(8);
+		System::assert_has_event(
+			Event::ScheduledTransferFailed { collectible: other, from: 2, to: 4 }.into(),
+		);
+		assert_eq!(CollectibleMap::<Test>::get(other).unwrap().owner, 2);
+		assert_ok!(VulnModule::ensure_unlocked(other));
+	});
+}
+
+#[test]
+fn heirs_claim_after_inactivity_unless_vetoed() {
+	new_test_ext().execute_with(|| {
+		let first = mint_to(1);
+		let second = mint_to(1);
+		assert_noop!(
+			VulnModule::set_heir(RuntimeOrigin::signed(1), Some((2, 9))),
+			Error::<Test>::InactivityPeriodTooShort
+		);
+		assert_ok!(VulnModule::set_heir(RuntimeOrigin::signed(1), Some((2, 10))));
+		System::assert_last_event(Event::HeirSet { owner: 1, heir: Some(2) }.into());
+		assert_noop!(
+			VulnModule::start_inheritance(RuntimeOrigin::signed(3), 1),
+			Error::<Test>::NotHeir
+		);
+		System::set_block_number(10);
+		assert_noop!(
+			VulnModule::start_inheritance(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::OwnerActive
+		);
+
+		// A transaction of the owner's starts the inactivity period over.
+		System::inc_account_nonce(1);
+		System::set_block_number(11);
+		assert_ok!(VulnModule::start_inheritance(RuntimeOrigin::signed(2), 1));
+		System::assert_last_event(Event::OwnerSeenActive { owner: 1 }.into());
+		System::set_block_number(20);
+		assert_noop!(
+			VulnModule::start_inheritance(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::OwnerActive
+		);
+		System::set_block_number(21);
+		assert_ok!(VulnModule::start_inheritance(RuntimeOrigin::signed(2), 1));
+		System::assert_last_event(
+			Event::InheritanceClaimStarted { owner: 1, heir: 2, completes_at: 26 }.into(),
+		);
+
+		// The owner vetoes.
+		assert_ok!(VulnModule::veto_inheritance(RuntimeOrigin::signed(1)));
+		System::assert_last_event(Event::InheritanceVetoed { owner: 1 }.into());
+		assert_noop!(
+			VulnModule::complete_inheritance(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::NoInheritanceClaim
+		);
+
+		// Any transaction during the grace period vetoes too.
+		System::set_block_number(31);
+		assert_ok!(VulnModule::start_inheritance(RuntimeOrigin::signed(2), 1));
+		System::inc_account_nonce(1);
+		System::set_block_number(36);
+		assert_ok!(VulnModule::complete_inheritance(RuntimeOrigin::signed(2), 1));
+		System::assert_last_event(Event::InheritanceVetoed { owner: 1 }.into());
+		assert_eq!(CollectibleMap::<Test>::get(first).unwrap().owner, 1);
+
+		// Otherwise the heir takes over everything that is not frozen.
+		System::set_block_number(46);
+		assert_ok!(VulnModule::start_inheritance(RuntimeOrigin::signed(2), 1));
+		assert_noop!(
+			VulnModule::complete_inheritance(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::GracePeriodOpen
+		);
+		VulnModule::do_freeze(second, FreezeReason::Staked);
+		System::set_block_number(51);
+		assert_ok!(VulnModule::complete_inheritance(RuntimeOrigin::signed(2), 1));
+		System::assert_last_event(
+			Event::InheritanceClaimed { owner: 1, heir: 2, collectibles: 1 }.into(),
+		);
+		assert_eq!(CollectibleMap::<Test>::get(first).unwrap().owner, 2);
+		assert_eq!(CollectibleMap::<Test>::get(second).unwrap().owner, 1);
+		assert!(VulnModule::inheritance(&1).is_none());
+	});
+}
+
+#[test]
+fn charity_listings_give_their_share_out_of_the_sellers_part() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		let ten = sp_runtime::Percent::from_percent(10);
+		assert_noop!(
+			VulnModule::list_with_charity(RuntimeOrigin::signed(1), id, 100, 1, ten),
+			Error::<Test>::InvalidCharity
+		);
+		assert_noop!(
+			VulnModule::list_with_charity(RuntimeOrigin::signed(1), id, 100, 6, Default::default()),
+			Error::<Test>::InvalidCharity
+		);
+		assert_ok!(VulnModule::list_with_charity(RuntimeOrigin::signed(1), id, 100, 6, ten));
+		System::assert_last_event(
+			Event::CharitySet { collectible: id, beneficiary: 6, share: ten }.into(),
+		);
+		assert_noop!(
+			VulnModule::buy_with_escrow(RuntimeOrigin::signed(2), id),
+			Error::<Test>::CharityListing
+		);
+		assert_noop!(
+			VulnModule::buy_in_installments(RuntimeOrigin::signed(2), id, 2),
+			Error::<Test>::CharityListing
+		);
+
+		assert_ok!(VulnModule::buy_collectible(RuntimeOrigin::signed(2), id, u64::MAX));
+		System::assert_has_event(
+			Event::Sold { seller: 1, buyer: 2, collectible: id, price: 100, charity: Some((6, 10)) }
+				.into(),
+		);
+		assert_eq!(Balances::free_balance(1), 1_090);
+		assert_eq!(Balances::free_balance(6), 10);
+		assert!(VulnModule::listing_charity(id).is_none());
+
+		// Relisting without a share forgets it.
+		let other = mint_to(1);
+		assert_ok!(VulnModule::list_with_charity(RuntimeOrigin::signed(1), other, 100, 6, ten));
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, other, Some(120)));
+		assert!(VulnModule::listing_charity(other).is_none());
+		assert_ok!(VulnModule::buy_collectible(RuntimeOrigin::signed(2), other, u64::MAX));
+		assert_eq!(Balances::free_balance(6), 10);
+	});
+}
+
+#[test]
+fn royalties_default_to_the_creators_unless_the_item_overrides_them() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		let ten = Perbill::from_percent(10);
+		let twenty = Perbill::from_percent(20);
+		assert_noop!(
+			VulnModule::set_default_royalty(
+				RuntimeOrigin::signed(1),
+				Some(Perbill::from_percent(60))
+			),
+			Error::<Test>::RoyaltyTooHigh
+		);
+		assert_ok!(VulnModule::set_default_royalty(RuntimeOrigin::signed(1), Some(ten)));
+		System::assert_last_event(
+			Event::DefaultRoyaltySet { creator: 1, royalty: Some(ten) }.into(),
+		);
+		assert_noop!(
+			VulnModule::set_item_royalty(RuntimeOrigin::signed(2), id, Some(twenty)),
+			Error::<Test>::NotCreator
+		);
+		assert_eq!(VulnModule::effective_royalty(id), Some(ten));
+		assert_eq!(VulnModule::effective_royalty(id + 1), None);
+
+		// The creator selling their own collectible pays themselves nothing.
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(100)));
+		assert_ok!(VulnModule::buy_collectible(RuntimeOrigin::signed(2), id, u64::MAX));
+		assert_eq!(Balances::free_balance(1), 1_100);
+
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(2), 2, id, Some(100)));
+		assert_ok!(VulnModule::buy_collectible(RuntimeOrigin::signed(4), id, u64::MAX));
+		assert_eq!(Balances::free_balance(1), 1_110);
+		assert_eq!(Balances::free_balance(2), 990);
+
+		// The item's own royalty wins, also when the sale settles from escrow.
+		assert_ok!(VulnModule::set_item_royalty(RuntimeOrigin::signed(1), id, Some(twenty)));
+		System::assert_last_event(
+			Event::ItemRoyaltySet { collectible: id, royalty: Some(twenty) }.into(),
+		);
+		assert_eq!(VulnModule::effective_royalty(id), Some(twenty));
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(4), 4, id, Some(100)));
+		assert_ok!(VulnModule::buy_with_escrow(RuntimeOrigin::signed(2), id));
+		System::set_block_number(6);
+		assert_ok!(VulnModule::release_escrow(RuntimeOrigin::signed(3), id));
+		assert_eq!(Balances::free_balance(1), 1_130);
+		assert_eq!(Balances::free_balance(4), 980);
+
+		// Going back to the default.
+		assert_ok!(VulnModule::set_item_royalty(RuntimeOrigin::signed(1), id, None));
+		assert_eq!(VulnModule::effective_royalty(id), Some(ten));
+	});
+}
+
+#[test]
+fn sealed_auctions_and_raffles_pay_the_creators_royalty() {
+	new_test_ext().execute_with(|| {
+		let auctioned = mint_to(1);
+		let raffled = mint_to(1);
+		assert_ok!(VulnModule::transfer(RuntimeOrigin::signed(1), 2, auctioned));
+		assert_ok!(VulnModule::transfer(RuntimeOrigin::signed(1), 2, raffled));
+		assert_ok!(VulnModule::set_default_royalty(
+			RuntimeOrigin::signed(1),
+			Some(Perbill::from_percent(10))
+		));
+
+		assert_ok!(VulnModule::start_sealed_auction(RuntimeOrigin::signed(2), auctioned, 50, 5, 5));
+		commit(3, auctioned, 100, 3);
+		assert_ok!(VulnModule::start_raffle(RuntimeOrigin::signed(2), raffled, 10, 2, 5, 10));
+		assert_ok!(VulnModule::buy_tickets(RuntimeOrigin::signed(4), raffled, 2));
+
+		System::set_block_number(6);
+		assert_ok!(VulnModule::reveal_bid(RuntimeOrigin::signed(3), auctioned, 100, [3; 32]));
+		System::set_block_number(11);
+		assert_ok!(VulnModule::settle_sealed_auction(RuntimeOrigin::signed(4), auctioned));
+		assert_eq!(Balances::free_balance(1), 1_010);
+		assert_eq!(Balances::free_balance(2), 1_090);
+		assert_eq!(Balances::free_balance(3), 900);
+
+		// Every ticket pays its share of the royalty.
+		assert_ok!(VulnModule::draw_raffle(RuntimeOrigin::signed(4), raffled));
+		assert_eq!(CollectibleMap::<Test>::get(raffled).unwrap().owner, 4);
+		assert_eq!(Balances::free_balance(1), 1_012);
+		assert_eq!(Balances::free_balance(2), 1_108);
+		assert_eq!(Balances::free_balance(4), 980);
+	});
+}
+
+#[test]
+fn trading_limits_cap_sales_per_window() {
+	new_test_ext().execute_with(|| {
+		let ids: Vec<u64> = (0..4).map(|_| mint_to(1)).collect();
+		for id in &ids {
+			assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, *id, Some(100)));
+		}
+		let one = crate::TradingLimit { max_volume: None, max_trades: Some(1) };
+		assert_ok!(VulnModule::set_trading_limit(RuntimeOrigin::signed(2), Some(one)));
+		System::assert_last_event(
+			Event::TradingLimitSet { who: 2, limit: Some(one), effective_at: 1 }.into(),
+		);
+		assert_ok!(VulnModule::buy_collectible(RuntimeOrigin::signed(2), ids[0], u64::MAX));
+		assert_noop!(
+			VulnModule::buy_collectible(RuntimeOrigin::signed(2), ids[1], u64::MAX),
+			Error::<Test>::TradingLimitExceeded
+		);
+		assert_noop!(
+			VulnModule::buy_with_escrow(RuntimeOrigin::signed(2), ids[1]),
+			Error::<Test>::TradingLimitExceeded
+		);
+
+		// Loosening waits out a window, by which time the old one closed anyway.
+		let three = crate::TradingLimit { max_volume: None, max_trades: Some(3) };
+		assert_ok!(VulnModule::set_trading_limit(RuntimeOrigin::signed(2), Some(three)));
+		System::assert_last_event(
+			Event::TradingLimitSet { who: 2, limit: Some(three), effective_at: 11 }.into(),
+		);
+		assert_eq!(VulnModule::self_trading_limit(&2), Some(one));
+		System::set_block_number(11);
+		assert_eq!(VulnModule::self_trading_limit(&2), Some(three));
+		assert_ok!(VulnModule::buy_collectible(RuntimeOrigin::signed(2), ids[1], u64::MAX));
+
+		// Imposed limits apply on top of the account's own, whoever sells.
+		let volume = crate::TradingLimit { max_volume: Some(150), max_trades: None };
+		assert_noop!(
+			VulnModule::impose_trading_limit(RuntimeOrigin::signed(4), 4, Some(volume)),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(VulnModule::impose_trading_limit(RuntimeOrigin::root(), 4, Some(volume)));
+		assert_ok!(VulnModule::buy_collectible(RuntimeOrigin::signed(4), ids[2], u64::MAX));
+		assert_noop!(
+			VulnModule::buy_collectible(RuntimeOrigin::signed(4), ids[3], u64::MAX),
+			Error::<Test>::TradingLimitExceeded
+		);
+		assert_ok!(VulnModule::impose_trading_limit(RuntimeOrigin::root(), 4, None));
+		assert_ok!(VulnModule::buy_collectible(RuntimeOrigin::signed(4), ids[3], u64::MAX));
+	});
+}
+
+#[test]
+fn licenses_are_fixed_at_mint() {
+	new_test_ext().execute_with(|| {
+		let license = |text: &[u8]| frame_support::BoundedVec::try_from(text.to_vec()).unwrap();
+		let mint = |to, license| {
+			VulnModule::create_licensed_collectible(RuntimeOrigin::signed(1), to, license)
+		};
+		for invalid in [&b""[..], &b"  "[..], &b"MIT; rm -rf"[..]] {
+			assert_noop!(mint(1, license(invalid)), Error::<Test>::InvalidLicense);
+		}
+		assert_noop!(mint(2, license(b"MIT")), Error::<Test>::RecipientNotAccepting);
+
+		let terms = license(b"MIT OR Apache-2.0");
+		assert_ok!(mint(1, terms.clone()));
+		let id = crate::NextCollectibleId::<Test>::get() - 1;
+		System::assert_last_event(Event::LicenseAttached { collectible: id, license: terms }.into());
+		assert_eq!(VulnModule::license(id), Some(b"MIT OR Apache-2.0".to_vec()));
+		assert_eq!(VulnModule::collectible(id).unwrap().license, Some(b"MIT OR Apache-2.0".to_vec()));
+		assert_eq!(VulnModule::collectible(mint_to(1)).unwrap().license, None);
+
+		assert_ok!(VulnModule::burn(RuntimeOrigin::signed(1), id));
+		assert_eq!(VulnModule::license(id), None);
+	});
+}
+
+#[test]
+fn creators_publish_increasing_metadata_versions() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_eq!(VulnModule::collectible(id).unwrap().metadata_version, 0);
+		assert_ok!(VulnModule::publish_metadata_version(RuntimeOrigin::signed(1), 2));
+		System::assert_last_event(Event::MetadataVersionPublished { creator: 1, version: 2 }.into());
+		for stale in [1, 2] {
+			assert_noop!(
+				VulnModule::publish_metadata_version(RuntimeOrigin::signed(1), stale),
+				Error::<Test>::StaleMetadataVersion
+			);
+		}
+
+		// The version follows the creator, whoever holds the collectible.
+		assert_ok!(VulnModule::transfer(RuntimeOrigin::signed(1), 2, id));
+		assert_eq!(VulnModule::collectible(id).unwrap().metadata_version, 2);
+		assert_eq!(VulnModule::collectible(mint_to(2)).unwrap().metadata_version, 0);
+	});
+}
+
+#[test]
+fn owners_are_remapped_in_batches_with_their_listings_and_orders() {
+	new_test_ext().execute_with(|| {
+		let ids = [mint_to(1), mint_to(1), mint_to(1)];
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, ids[0], Some(100)));
+		assert_ok!(VulnModule::place_sell_order(RuntimeOrigin::signed(1), ids[1], 200));
+		VulnModule::do_freeze(ids[2], FreezeReason::Staked);
+		assert_ok!(VulnModule::place_buy_order(RuntimeOrigin::signed(1), 50, 2));
+		assert_eq!(Balances::reserved_balance(1), 105);
+
+		assert_noop!(
+			VulnModule::force_remap_owner(RuntimeOrigin::signed(1), 1, 4, 1),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			VulnModule::force_remap_owner(RuntimeOrigin::root(), 1, 1, 1),
+			Error::<Test>::TransferToSelf
+		);
+
+		assert_ok!(VulnModule::force_remap_owner(RuntimeOrigin::root(), 1, 4, 1));
+		System::assert_last_event(
+			Event::OwnerRemapped { old: 1, new: 4, collectibles: 1, remaining: 2 }.into(),
+		);
+		let listed = CollectibleMap::<Test>::get(ids[0]).unwrap();
+		assert_eq!((listed.owner, listed.price), (4, Some(100)));
+		assert_eq!(BuyBook::<Test>::get()[0].bidder, 4);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(4), 105);
+
+		// The staked collectible stays behind.
+		assert_ok!(VulnModule::force_remap_owner(RuntimeOrigin::root(), 1, 4, 10));
+		System::assert_last_event(
+			Event::OwnerRemapped { old: 1, new: 4, collectibles: 1, remaining: 1 }.into(),
+		);
+		assert_eq!(SellBook::<Test>::get()[0].seller, 4);
+		assert_eq!(VulnModule::collectibles_of(&1), vec![ids[2]]);
+
+		// The remapped listing pays out and refunds its deposit to the new owner.
+		assert_ok!(VulnModule::buy_collectible(RuntimeOrigin::signed(2), ids[0], u64::MAX));
+		assert_eq!(Balances::free_balance(4), 1_105);
+		assert_eq!(Balances::reserved_balance(4), 100);
+	});
+}
+
+#[test]
+fn distributions_send_each_collectible_to_its_recipient_or_none() {
+	new_test_ext().execute_with(|| {
+		let ids = [mint_to(1), mint_to(1), mint_to(2)];
+		let distribute = |grants: Vec<(u64, u64)>| {
+			let grants = frame_support::BoundedVec::try_from(grants).unwrap();
+			VulnModule::distribute(RuntimeOrigin::signed(1), grants)
+		};
+		assert_noop!(distribute(vec![(ids[2], 4), (ids[0], 3)]), Error::<Test>::NotOwner);
+		assert_noop!(distribute(vec![(ids[1], 1), (ids[0], 3)]), Error::<Test>::TransferToSelf);
+
+		assert_ok!(distribute(vec![(ids[0], 3), (ids[1], 4)]));
+		System::assert_last_event(Event::Distributed { from: 1, collectibles: 2 }.into());
+		assert_eq!(VulnModule::collectibles_of(&3), vec![ids[0]]);
+		assert_eq!(VulnModule::collectibles_of(&4), vec![ids[1]]);
+		assert!(VulnModule::collectibles_of(&1).is_empty());
+	});
+}
+
+#[test]
+fn offchain_workers_settle_ended_auctions_unsigned() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_ok!(VulnModule::start_sealed_auction(RuntimeOrigin::signed(1), id, 50, 5, 5));
+		commit(2, id, 100, 2);
+		// The winner of the English auction owns the most they can when it ends.
+		let english = mint_to(1);
+		assert_ok!(VulnModule::start_auction(RuntimeOrigin::signed(1), english, 50, 10));
+		assert_ok!(VulnModule::place_bid(RuntimeOrigin::signed(3), english, 100));
+		for _ in 0..10 {
+			crate::MintsThisBlock::<Test>::kill();
+			mint_to(3);
+		}
+		System::set_block_number(6);
+		assert_ok!(VulnModule::reveal_bid(RuntimeOrigin::signed(2), id, 100, [2; 32]));
+
+		let call = crate::Call::settle_sealed_auction { unique_id: id };
+		let english_call = crate::Call::settle_auction { unique_id: english };
+		assert!(VulnModule::validate_unsigned(TransactionSource::Local, &call).is_err());
+		assert!(VulnModule::validate_unsigned(TransactionSource::Local, &english_call).is_err());
+		System::set_block_number(11);
+		VulnModule::on_initialize(11);
+		System::assert_has_event(Event::AuctionSettlementFailed { collectible: english }.into());
+		// Both are left for the worker, which only looks at auctions that are over.
+		let unsettled = |id| crate::UnsettledAuctions::<Test>::contains_key(id);
+		assert!(unsettled(id) && unsettled(english));
+		assert!(crate::SealedAuctionsEnding::<Test>::get(11).is_empty());
+		assert!(VulnModule::validate_unsigned(TransactionSource::External, &call).is_err());
+		assert!(VulnModule::validate_unsigned(TransactionSource::Local, &call).is_ok());
+		assert!(VulnModule::validate_unsigned(TransactionSource::Local, &english_call).is_ok());
+
+		assert_ok!(VulnModule::settle_sealed_auction(RuntimeOrigin::none(), id));
+		assert_eq!(CollectibleMap::<Test>::get(id).unwrap().owner, 2);
+		// Once settled, further submissions are stale.
+		assert!(VulnModule::validate_unsigned(TransactionSource::Local, &call).is_err());
+		assert_noop!(
+			VulnModule::settle_sealed_auction(RuntimeOrigin::none(), id),
+			Error::<Test>::NoAuction
+		);
+
+		let owned = VulnModule::collectibles_of(&3)[0];
+		assert_ok!(VulnModule::burn(RuntimeOrigin::signed(3), owned));
+		assert_ok!(VulnModule::settle_auction(RuntimeOrigin::none(), english));
+		assert_eq!(CollectibleMap::<Test>::get(english).unwrap().owner, 3);
+		assert!(VulnModule::validate_unsigned(TransactionSource::Local, &english_call).is_err());
+		assert!(!unsettled(id) && !unsettled(english));
+	});
+}
+
+#[test]
+fn english_auction_settles_to_the_highest_bidder_when_it_ends() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_ok!(VulnModule::start_auction(RuntimeOrigin::signed(1), id, 50, 5));
+		assert_noop!(
+			VulnModule::place_bid(RuntimeOrigin::signed(1), id, 100),
+			Error::<Test>::SellerCannotBid
+		);
+		assert_noop!(
+			VulnModule::place_bid(RuntimeOrigin::signed(2), id, 40),
+			Error::<Test>::BidPriceTooLow
+		);
+		assert_ok!(VulnModule::place_bid(RuntimeOrigin::signed(2), id, 100));
+		assert_noop!(
+			VulnModule::place_bid(RuntimeOrigin::signed(3), id, 100),
+			Error::<Test>::BidPriceTooLow
+		);
+		assert_ok!(VulnModule::place_bid(RuntimeOrigin::signed(3), id, 150));
+		// Only the leading bid is held.
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::reserved_balance(3), 150);
+
+		System::set_block_number(6);
+		assert_noop!(
+			VulnModule::place_bid(RuntimeOrigin::signed(2), id, 200),
+			Error::<Test>::AuctionEnded
+		);
+		VulnModule::on_initialize(6);
+		System::assert_last_event(
+			Event::AuctionSettled { collectible: id, winner: Some(3), price: Some(150) }.into(),
+		);
+		assert_eq!(CollectibleMap::<Test>::get(id).unwrap().owner, 3);
+		assert_eq!(Balances::free_balance(1), 1_150);
+		assert_eq!(Balances::free_balance(3), 850);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_noop!(
+			VulnModule::settle_auction(RuntimeOrigin::signed(4), id),
+			Error::<Test>::NoAuction
+		);
+	});
+}
+
+#[test]
+fn random_ids_are_spread_out_and_distinct() {
+	use crate::{GenerateItemId, RandomIds};
+	new_test_ext().execute_with(|| {
+		let ids: Vec<u64> = (0..20).map(RandomIds::<Test>::item_id).collect();
+		let mut distinct = ids.clone();
+		distinct.sort_unstable();
+		distinct.dedup();
+		assert_eq!(distinct.len(), ids.len());
+		assert!(ids.iter().any(|id| *id > u64::from(u32::MAX)));
+		// The same mint drawn in a later block gets a different id.
+		System::set_block_number(2);
+		assert_ne!(RandomIds::<Test>::item_id(0), ids[0]);
+	});
+}
+
+#[test]
+fn collections_enforce_their_minting_rules_and_supply() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(VulnModule::create_collection(
+			RuntimeOrigin::signed(1),
+			Some(2),
+			CollectionMinting::Owner
+		));
+		assert_noop!(
+			VulnModule::create_collectible_in(RuntimeOrigin::signed(2), 0, 2),
+			Error::<Test>::NotCollectionOwner
+		);
+		assert_ok!(VulnModule::create_collectible_in(RuntimeOrigin::signed(1), 0, 1));
+		let id = crate::NextCollectibleId::<Test>::get() - 1;
+		System::assert_last_event(
+			Event::MintedIntoCollection { collectible: id, collection: 0 }.into(),
+		);
+		assert_eq!(VulnModule::collectible(id).unwrap().collection_id, Some(0));
+		assert_ok!(VulnModule::create_collectible_in(RuntimeOrigin::signed(1), 0, 1));
+		assert_noop!(
+			VulnModule::create_collectible_in(RuntimeOrigin::signed(1), 0, 1),
+			Error::<Test>::CollectionSoldOut
+		);
+
+		let cid = frame_support::BoundedVec::truncate_from(b"bafy".to_vec());
+		assert_noop!(
+			VulnModule::set_collection_metadata(RuntimeOrigin::signed(2), 0, Some(cid.clone())),
+			Error::<Test>::NotCollectionOwner
+		);
+		assert_ok!(VulnModule::set_collection_metadata(RuntimeOrigin::signed(1), 0, Some(cid)));
+
+		// Burning frees no supply, and an empty collection is destroyed at once.
+		assert_noop!(
+			VulnModule::destroy_collection(RuntimeOrigin::signed(1), 0, 0),
+			Error::<Test>::BadWitness
+		);
+		for id in VulnModule::collectibles_of(&1) {
+			assert_ok!(VulnModule::burn(RuntimeOrigin::signed(1), id));
+		}
+		assert_noop!(
+			VulnModule::create_collectible_in(RuntimeOrigin::signed(1), 0, 1),
+			Error::<Test>::CollectionSoldOut
+		);
+		assert_ok!(VulnModule::destroy_collection(RuntimeOrigin::signed(1), 0, 0));
+		System::assert_last_event(Event::CollectionDestroyed { collection: 0 }.into());
+		assert!(VulnModule::collection(0).is_none());
+	});
+}
+
+#[test]
+fn destroying_a_collection_burns_what_its_owner_holds_and_releases_the_rest() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(VulnModule::create_collection(
+			RuntimeOrigin::signed(1),
+			None,
+			CollectionMinting::Public
+		));
+		for _ in 0..3 {
+			assert_ok!(VulnModule::create_collectible_in(RuntimeOrigin::signed(1), 0, 1));
+		}
+		assert_ok!(VulnModule::create_collectible_in(RuntimeOrigin::signed(2), 0, 2));
+		let (listed, with_data, staked) = match VulnModule::collectibles_of(&1)[..] {
+			[a, b, c] => (a, b, c),
+			_ => unreachable!(),
+		};
+		let theirs = VulnModule::collectibles_of(&2)[0];
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, listed, Some(10)));
+		let key = b"artist".to_vec().try_into().unwrap();
+		let value = b"anon".to_vec().try_into().unwrap();
+		assert_ok!(VulnModule::set_attribute(RuntimeOrigin::signed(1), with_data, key, value));
+		assert_ok!(VulnModule::freeze(RuntimeOrigin::signed(1), staked));
+
+		assert_noop!(
+			VulnModule::destroy_collection(RuntimeOrigin::signed(2), 0, 4),
+			Error::<Test>::NotCollectionOwner
+		);
+		assert_noop!(
+			VulnModule::destroy_collection(RuntimeOrigin::signed(1), 0, 3),
+			Error::<Test>::BadWitness
+		);
+		assert_noop!(
+			VulnModule::destroy_collection_items(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::CollectionNotDestroying
+		);
+		assert_ok!(VulnModule::destroy_collection(RuntimeOrigin::signed(1), 0, 4));
+		System::assert_last_event(Event::CollectionDestroying { collection: 0, items: 4 }.into());
+		assert_noop!(
+			VulnModule::create_collectible_in(RuntimeOrigin::signed(1), 0, 1),
+			Error::<Test>::CollectionDestroying
+		);
+		assert_noop!(
+			VulnModule::set_collection_metadata(RuntimeOrigin::signed(1), 0, None),
+			Error::<Test>::CollectionDestroying
+		);
+		assert_noop!(
+			VulnModule::destroy_collection(RuntimeOrigin::root(), 0, 4),
+			Error::<Test>::CollectionDestroying
+		);
+
+		// Anybody can carry the teardown on, two collectibles at a time.
+		assert_ok!(VulnModule::destroy_collection_items(RuntimeOrigin::signed(3), 0));
+		assert_eq!(VulnModule::collection(0).unwrap().items, 2);
+		assert_ok!(VulnModule::destroy_collection_items(RuntimeOrigin::signed(3), 0));
+		System::assert_last_event(Event::CollectionDestroyed { collection: 0 }.into());
+		assert!(VulnModule::collection(0).is_none());
+		assert_eq!(crate::CollectionItems::<Test>::iter_prefix(0).count(), 0);
+
+		// The owner's collectibles are burned and their deposits released. The locked one, and
+		// anybody else's, are left in no collection.
+		assert!(VulnModule::collectible(listed).is_none());
+		assert!(VulnModule::collectible(with_data).is_none());
+		for reason in [HoldReason::ListingDeposit, HoldReason::MetadataDeposit] {
+			assert_eq!(Balances::balance_on_hold(&reason.into(), &1), 0);
+		}
+		System::assert_has_event(
+			Event::CollectibleOrphaned { collectible: staked, collection: 0 }.into(),
+		);
+		assert_eq!(VulnModule::collectible(staked).unwrap().collection_id, None);
+		assert_eq!(VulnModule::collectible(theirs).unwrap().collection_id, None);
+		assert_eq!(VulnModule::collectibles_of(&2), vec![theirs]);
+		assert_noop!(
+			VulnModule::destroy_collection_items(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::NoCollection
+		);
+
+		// `ForceOrigin` can destroy a collection it does not own.
+		assert_ok!(VulnModule::create_collection(
+			RuntimeOrigin::signed(2),
+			None,
+			CollectionMinting::Owner
+		));
+		assert_ok!(VulnModule::destroy_collection(RuntimeOrigin::root(), 1, 0));
+		assert!(VulnModule::collection(1).is_none());
+	});
+}
+
+#[test]
+fn collection_settings_apply_to_the_collectibles_in_the_collection() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(VulnModule::create_collection(
+			RuntimeOrigin::signed(1),
+			None,
+			CollectionMinting::Public
+		));
+		let settings = CollectionSettings {
+			royalty: Some(Perbill::from_percent(10)),
+			royalties_enforced: true,
+			transfer_cooldown: Some(3),
+			issues_receipts: true,
+			founder_badge_limit: 1,
+		};
+		assert_noop!(
+			VulnModule::set_collection_settings(RuntimeOrigin::signed(2), 0, settings),
+			Error::<Test>::NotCollectionOwner
+		);
+		assert_noop!(
+			VulnModule::set_collection_settings(
+				RuntimeOrigin::signed(1),
+				0,
+				CollectionSettings { royalty: Some(Perbill::from_percent(60)), ..settings }
+			),
+			Error::<Test>::RoyaltyTooHigh
+		);
+		assert_ok!(VulnModule::set_collection_settings(RuntimeOrigin::signed(1), 0, settings));
+		System::assert_last_event(Event::CollectionSettingsSet { collection: 0, settings }.into());
+
+		// The first minter into the collection earns its only founder badge, which is in no
+		// collection itself.
+		assert_ok!(VulnModule::create_collectible_in(RuntimeOrigin::signed(2), 0, 2));
+		let item = VulnModule::collectibles_of(&2)[0];
+		let badge = item + 1;
+		System::assert_has_event(
+			Event::CollectionFounderBadgeAwarded { collection: 0, minter: 2, badge }.into(),
+		);
+		assert_eq!(VulnModule::collection_founder_badge(0, &2), Some(badge));
+		assert_eq!(VulnModule::collectible(badge).unwrap().collection_id, None);
+		assert_ok!(VulnModule::create_collectible_in(RuntimeOrigin::signed(1), 0, 1));
+		assert_eq!(VulnModule::collection_founder_badge(0, &1), None);
+		assert_eq!(VulnModule::collection(0).unwrap().founder_badges_issued, 1);
+		let outside = mint_to(2);
+
+		// Royalties are enforced on the collection's collectibles alone, and they pay the
+		// collection's royalty over their creator's default.
+		assert_noop!(
+			VulnModule::transfer(RuntimeOrigin::signed(2), 3, item),
+			Error::<Test>::TransferRestricted
+		);
+		assert_ok!(VulnModule::transfer(RuntimeOrigin::signed(2), 3, outside));
+		let five = Perbill::from_percent(5);
+		assert_ok!(VulnModule::set_default_royalty(RuntimeOrigin::signed(2), Some(five)));
+		assert_eq!(VulnModule::effective_royalty(item), Some(Perbill::from_percent(10)));
+		assert_eq!(VulnModule::effective_royalty(outside), Some(five));
+
+		// The collection's cooldown applies where the pallet-wide one is off, and its sales
+		// leave receipts although their creator asked for none.
+		assert_noop!(
+			VulnModule::set_price(RuntimeOrigin::signed(2), 2, item, Some(100)),
+			Error::<Test>::CooldownActive
+		);
+		System::set_block_number(4);
+		VulnModule::on_initialize(4);
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(2), 2, item, Some(100)));
+		assert_ok!(VulnModule::buy_collectible(RuntimeOrigin::signed(3), item, u64::MAX));
+		let receipt = crate::NextCollectibleId::<Test>::get() - 1;
+		System::assert_has_event(
+			Event::ReceiptIssued { receipt, collectible: item, buyer: 3 }.into(),
+		);
+
+		// The collection's collectibles follow its metadata version, not their creator's.
+		assert_ok!(VulnModule::publish_metadata_version(RuntimeOrigin::signed(2), 5));
+		assert_noop!(
+			VulnModule::publish_collection_metadata_version(RuntimeOrigin::signed(2), 0, 1),
+			Error::<Test>::NotCollectionOwner
+		);
+		assert_ok!(VulnModule::publish_collection_metadata_version(RuntimeOrigin::signed(1), 0, 2));
+		System::assert_last_event(
+			Event::CollectionMetadataVersionPublished { collection: 0, version: 2 }.into(),
+		);
+		assert_noop!(
+			VulnModule::publish_collection_metadata_version(RuntimeOrigin::signed(1), 0, 2),
+			Error::<Test>::StaleMetadataVersion
+		);
+		assert_eq!(VulnModule::collectible(item).unwrap().metadata_version, 2);
+		assert_eq!(VulnModule::collectible(outside).unwrap().metadata_version, 5);
+
+		// The record of its founders goes with the collection, once its collectibles have; the
+		// badge stays with its holder. Released collectibles follow the pallet-wide settings.
+		assert_ok!(VulnModule::destroy_collection(RuntimeOrigin::signed(1), 0, 2));
+		assert_ok!(VulnModule::destroy_collection_items(RuntimeOrigin::signed(4), 0));
+		assert!(VulnModule::collection(0).is_some());
+		assert_ok!(VulnModule::destroy_collection_items(RuntimeOrigin::signed(4), 0));
+		assert!(VulnModule::collection(0).is_none());
+		assert_eq!(VulnModule::collection_founder_badge(0, &2), None);
+		assert_eq!(VulnModule::collectibles_of(&2), vec![badge]);
+		assert_ok!(VulnModule::transfer(RuntimeOrigin::signed(3), 4, item));
+	});
+}
+
+#[test]
+fn approved_accounts_can_transfer_and_list_for_the_owner() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_noop!(
+			VulnModule::transfer(RuntimeOrigin::signed(2), 3, id),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			VulnModule::approve(RuntimeOrigin::signed(1), id, 1),
+			Error::<Test>::ApproveSelf
+		);
+		assert_ok!(VulnModule::approve(RuntimeOrigin::signed(1), id, 2));
+		System::assert_last_event(
+			Event::Approved { collectible: id, owner: 1, delegate: 2 }.into(),
+		);
+		// A single approval cannot be passed on.
+		assert_noop!(
+			VulnModule::approve(RuntimeOrigin::signed(2), id, 3),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(2), 1, id, Some(10)));
+		assert_eq!(VulnModule::collectible(id).unwrap().price, Some(10));
+		assert_ok!(VulnModule::transfer(RuntimeOrigin::signed(2), 3, id));
+		assert_eq!(VulnModule::collectible(id).unwrap().owner, 3);
+		// The approval ends when the collectible changes hands.
+		assert_eq!(VulnModule::approved(id), None);
+		assert_noop!(
+			VulnModule::transfer(RuntimeOrigin::signed(2), 1, id),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			VulnModule::cancel_approval(RuntimeOrigin::signed(3), id),
+			Error::<Test>::NoApproval
+		);
+
+		// Operators manage everything the owner holds until they are withdrawn.
+		assert_ok!(VulnModule::set_approval_for_all(RuntimeOrigin::signed(3), 4, true));
+		assert_ok!(VulnModule::approve(RuntimeOrigin::signed(4), id, 2));
+		assert_ok!(VulnModule::cancel_approval(RuntimeOrigin::signed(4), id));
+		assert_ok!(VulnModule::transfer(RuntimeOrigin::signed(4), 1, id));
+		assert_ok!(VulnModule::set_approval_for_all(RuntimeOrigin::signed(1), 4, true));
+		assert_ok!(VulnModule::set_approval_for_all(RuntimeOrigin::signed(1), 4, false));
+		System::assert_last_event(
+			Event::ApprovalForAll { owner: 1, operator: 4, approved: false }.into(),
+		);
+		assert_noop!(
+			VulnModule::transfer(RuntimeOrigin::signed(4), 3, id),
+			Error::<Test>::NotOwner
+		);
+	});
+}
+
+#[test]
+fn offers_are_held_until_accepted_or_withdrawn() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_noop!(
+			VulnModule::make_offer(RuntimeOrigin::signed(1), id, 50),
+			Error::<Test>::TransferToSelf
+		);
+		assert_ok!(VulnModule::make_offer(RuntimeOrigin::signed(2), id, 50));
+		assert_ok!(VulnModule::make_offer(RuntimeOrigin::signed(3), id, 80));
+		// Offering again replaces the earlier offer.
+		assert_ok!(VulnModule::make_offer(RuntimeOrigin::signed(2), id, 60));
+		assert_eq!(Balances::balance_on_hold(&HoldReason::Offer.into(), &2), 60);
+		assert_eq!(VulnModule::offer(id, &2), Some(60));
+
+		assert_noop!(
+			VulnModule::accept_offer(RuntimeOrigin::signed(2), id, 2),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			VulnModule::accept_offer(RuntimeOrigin::signed(1), id, 4),
+			Error::<Test>::NoOffer
+		);
+		assert_ok!(VulnModule::accept_offer(RuntimeOrigin::signed(1), id, 3));
+		System::assert_last_event(
+			Event::OfferAccepted { collectible: id, seller: 1, bidder: 3, amount: 80 }.into(),
+		);
+		assert_eq!(VulnModule::collectible(id).unwrap().owner, 3);
+		assert_eq!(Balances::free_balance(1), 1_080);
+		assert_eq!(Balances::balance_on_hold(&HoldReason::Offer.into(), &3), 0);
+
+		// The other offer stands for the new owner until it is withdrawn.
+		assert_eq!(VulnModule::offer(id, &2), Some(60));
+		assert_ok!(VulnModule::withdraw_offer(RuntimeOrigin::signed(2), id));
+		assert_eq!(Balances::free_balance(2), 1_000);
+		assert_noop!(
+			VulnModule::withdraw_offer(RuntimeOrigin::signed(2), id),
+			Error::<Test>::NoOffer
+		);
+	});
+}
+
+#[test]
+fn offers_are_capped_and_released_when_the_collectible_is_burned() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(4);
+		for bidder in 1..=3 {
+			assert_ok!(VulnModule::make_offer(RuntimeOrigin::signed(bidder), id, 50));
+		}
+		assert_noop!(
+			VulnModule::make_offer(RuntimeOrigin::signed(5), id, 50),
+			Error::<Test>::TooManyOffers
+		);
+		// Replacing an offer takes no room, and withdrawing one makes room.
+		assert_ok!(VulnModule::make_offer(RuntimeOrigin::signed(1), id, 60));
+		assert_ok!(VulnModule::withdraw_offer(RuntimeOrigin::signed(3), id));
+		assert_ok!(VulnModule::make_offer(RuntimeOrigin::signed(3), id, 70));
+		assert_eq!(crate::OfferCounts::<Test>::get(id), Some(3));
+
+		assert_ok!(VulnModule::burn(RuntimeOrigin::signed(4), id));
+		System::assert_has_event(Event::OfferWithdrawn { collectible: id, bidder: 3 }.into());
+		for bidder in 1..=3 {
+			assert_eq!(VulnModule::offer(id, &bidder), None);
+			assert_eq!(Balances::free_balance(bidder), 1_000);
+		}
+		assert_eq!(crate::OfferCounts::<Test>::get(id), None);
+	});
+}
+
+#[test]
+fn highest_price_follows_listings_without_scanning_each_block() {
+	new_test_ext().execute_with(|| {
+		let ids: Vec<u64> = [1, 2, 3].into_iter().map(mint_to).collect();
+		for (owner, price) in [(1, 10), (2, 30), (3, 20)] {
+			let id = ids[owner as usize - 1];
+			assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(owner), owner, id, Some(price)));
+		}
+		assert_eq!(VulnModule::highest_price(), Some(30));
+
+		// Repricing the dearest listing lower leaves the next one to be found by a scan.
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(2), 2, ids[1], Some(15)));
+		assert_eq!(VulnModule::highest_price(), Some(15));
+		VulnModule::on_initialize(2);
+		assert_eq!(crate::HighestPriceScan::<Test>::get(), Some(0));
+		VulnModule::on_idle(2, Weight::MAX);
+		assert_eq!(VulnModule::highest_price(), Some(20));
+
+		assert_ok!(VulnModule::buy_collectible(RuntimeOrigin::signed(4), ids[2], u64::MAX));
+		VulnModule::on_idle(3, Weight::MAX);
+		assert_eq!(VulnModule::highest_price(), Some(15));
+		assert_ok!(VulnModule::burn(RuntimeOrigin::signed(2), ids[1]));
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, ids[0], None));
+		VulnModule::on_idle(4, Weight::MAX);
+		assert_eq!(VulnModule::highest_price(), None);
+	});
+}
+
+#[test]
+fn minters_pay_a_deposit_until_the_collectible_is_burned() {
+	new_test_ext().execute_with(|| {
+		CollectibleDeposit::set(20);
+		let deposit = |who| Balances::balance_on_hold(&HoldReason::MintDeposit.into(), &who);
+		let id = mint_to(1);
+		assert_eq!(deposit(1), 20);
+		assert_eq!(VulnModule::mint_deposit(id), Some((1, 20)));
+
+		// The deposit goes back to the minter, whoever burns the collectible.
+		assert_ok!(VulnModule::transfer(RuntimeOrigin::signed(1), 2, id));
+		assert_ok!(VulnModule::burn(RuntimeOrigin::signed(2), id));
+		assert_eq!(deposit(1), 0);
+		assert_eq!(Balances::free_balance(1), 1_000);
+
+		// A pending mint holds the minter's deposit from the start, and claiming it keeps the
+		// deposit theirs.
+		assert_ok!(VulnModule::create_collectible(RuntimeOrigin::signed(1), 3));
+		assert_eq!(deposit(1), 20);
+		assert_ok!(VulnModule::claim_mints(RuntimeOrigin::signed(3), 1));
+		assert_eq!((deposit(1), deposit(3)), (20, 0));
+		let claimed = VulnModule::collectibles_of(&3)[0];
+		assert_eq!(VulnModule::mint_deposit(claimed), Some((1, 20)));
+
+		CollectibleDeposit::set(2_000);
+		assert_noop!(
+			VulnModule::create_collectible(RuntimeOrigin::signed(1), 1),
+			TokenError::FundsUnavailable
+		);
+	});
+}
+
+#[test]
+fn batches_go_through_for_every_item_or_none() {
+	new_test_ext().execute_with(|| {
+		// Only five mints fit in a block, so none of these six are minted.
+		assert_noop!(
+			VulnModule::mint_batch(RuntimeOrigin::signed(1), 1, 6),
+			Error::<Test>::MintRateExceeded
+		);
+		// Nor are they recorded as pending claims for a recipient that does not accept mints.
+		assert_noop!(
+			VulnModule::mint_batch(RuntimeOrigin::signed(1), 2, u32::MAX),
+			Error::<Test>::MintRateExceeded
+		);
+		assert_eq!(crate::PendingMints::<Test>::get(2), 0);
+		assert_ok!(VulnModule::mint_batch(RuntimeOrigin::signed(1), 1, 3));
+		System::assert_last_event(Event::MintedBatch { minter: 1, to: 1, count: 3 }.into());
+		let ids = VulnModule::collectibles_of(&1);
+		assert_eq!(ids.len(), 3);
+
+		let id = mint_to(2);
+		let transfer_many = |to, unique_ids: Vec<u64>| {
+			let unique_ids = frame_support::BoundedVec::try_from(unique_ids).unwrap();
+			VulnModule::transfer_many(RuntimeOrigin::signed(1), to, unique_ids)
+		};
+		assert_noop!(transfer_many(3, vec![ids[0], id]), Error::<Test>::NotOwner);
+		assert_ok!(VulnModule::set_approval_for_all(RuntimeOrigin::signed(2), 1, true));
+		assert_ok!(transfer_many(3, vec![ids[0], ids[1], id]));
+		System::assert_has_event(
+			Event::TransferSucceeded { from: 2, to: 3, collectible: id }.into(),
+		);
+		System::assert_last_event(Event::TransferredMany { by: 1, to: 3, collectibles: 3 }.into());
+		assert_eq!(VulnModule::collectibles_of(&3), vec![ids[0], ids[1], id]);
+
+		let prices = frame_support::BoundedVec::try_from(vec![(ids[2], Some(10))]).unwrap();
+		assert_ok!(VulnModule::set_prices(RuntimeOrigin::signed(1), prices.clone()));
+		System::assert_has_event(Event::PriceSet { collectible: ids[2], price: Some(10) }.into());
+		System::assert_last_event(Event::PricesSet { owner: 1, prices }.into());
+	});
+}
+
+/// `data` bounded by `S`, for calls taking bounded byte strings.
+fn bytes<S: frame_support::traits::Get<u32>>(data: &[u8]) -> frame_support::BoundedVec<u8, S> {
+	data.to_vec().try_into().unwrap()
+}
+
+#[test]
+fn onchain_metadata_and_attributes_hold_a_deposit_per_byte() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		let deposit = |who| Balances::balance_on_hold(&HoldReason::MetadataDeposit.into(), &who);
+		let set_attribute = |key: &[u8], value: &[u8]| {
+			VulnModule::set_attribute(RuntimeOrigin::signed(1), id, bytes(key), bytes(value))
+		};
+		assert_noop!(
+			VulnModule::set_metadata(RuntimeOrigin::signed(2), id, bytes(b"art")),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(VulnModule::set_metadata(RuntimeOrigin::signed(1), id, bytes(b"artwork")));
+		assert_eq!(VulnModule::onchain_metadata(id).unwrap().into_inner(), b"artwork".to_vec());
+		assert_eq!(deposit(1), 7);
+		assert_ok!(VulnModule::set_metadata(RuntimeOrigin::signed(1), id, bytes(b"art")));
+		assert_eq!(deposit(1), 3);
+
+		// Keys are paid for as well as values.
+		assert_ok!(set_attribute(b"eyes", b"blue"));
+		System::assert_last_event(
+			Event::AttributeSet { collectible: id, key: bytes(b"eyes"), value: bytes(b"blue") }
+				.into(),
+		);
+		assert_eq!(deposit(1), 11);
+		assert_ok!(set_attribute(b"hat", b""));
+		assert_noop!(set_attribute(b"mood", b""), Error::<Test>::TooManyAttributes);
+		// Changing an attribute does not count against the limit.
+		assert_ok!(set_attribute(b"eyes", b"red"));
+		let eyes = VulnModule::attribute(id, &bytes(b"eyes")).unwrap();
+		assert_eq!(eyes.into_inner(), b"red".to_vec());
+		assert_eq!(deposit(1), 13);
+
+		// The data stays with the collectible, and the next owner to change it takes over the
+		// deposit for all of it.
+		assert_ok!(VulnModule::transfer(RuntimeOrigin::signed(1), 2, id));
+		assert_ok!(VulnModule::clear_attribute(RuntimeOrigin::signed(2), id, bytes(b"hat")));
+		assert_eq!((deposit(1), deposit(2)), (0, 10));
+		assert_noop!(
+			VulnModule::clear_attribute(RuntimeOrigin::signed(2), id, bytes(b"hat")),
+			Error::<Test>::NoAttribute
+		);
+		assert_ok!(VulnModule::clear_metadata(RuntimeOrigin::signed(2), id));
+		assert_eq!(deposit(2), 7);
+
+		assert_ok!(VulnModule::burn(RuntimeOrigin::signed(2), id));
+		assert_eq!(deposit(2), 0);
+		assert_eq!(VulnModule::attribute(id, &bytes(b"eyes")), None);
+		assert_eq!(Balances::free_balance(1), 1_000);
+	});
+}
+
+#[test]
+fn owners_stake_and_freeze_origin_pledges_collectibles() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_noop!(VulnModule::freeze(RuntimeOrigin::signed(2), id), Error::<Test>::NotOwner);
+		assert_ok!(VulnModule::freeze(RuntimeOrigin::signed(1), id));
+		System::assert_last_event(
+			Event::CollectibleFrozen { collectible: id, reason: FreezeReason::Staked }.into(),
+		);
+		assert_noop!(
+			VulnModule::freeze(RuntimeOrigin::signed(1), id),
+			Error::<Test>::AlreadyFrozen
+		);
+		assert_noop!(VulnModule::transfer(RuntimeOrigin::signed(1), 2, id), Error::<Test>::Frozen);
+		assert_noop!(VulnModule::burn(RuntimeOrigin::signed(1), id), Error::<Test>::Frozen);
+
+		// Collateral is pledged on top of the stake, and only `FreezeOrigin` can release it.
+		assert_ok!(VulnModule::freeze(RuntimeOrigin::root(), id));
+		assert_ok!(VulnModule::thaw(RuntimeOrigin::signed(1), id));
+		assert!(VulnModule::is_frozen(id, FreezeReason::Collateral));
+		assert_noop!(VulnModule::thaw(RuntimeOrigin::signed(1), id), Error::<Test>::NotFrozen);
+		assert_noop!(VulnModule::transfer(RuntimeOrigin::signed(1), 2, id), Error::<Test>::Frozen);
+		assert_ok!(VulnModule::thaw(RuntimeOrigin::root(), id));
+		assert_ok!(VulnModule::transfer(RuntimeOrigin::signed(1), 2, id));
+	});
+}
+
+#[test]
+fn collectibles_changing_hands_can_be_neither_staked_nor_pledged() {
+	new_test_ext().execute_with(|| {
+		let (auctioned, sold) = (mint_to(1), mint_to(1));
+		assert_ok!(VulnModule::start_auction(RuntimeOrigin::signed(1), auctioned, 50, 5));
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, sold, Some(100)));
+		assert_ok!(VulnModule::buy_in_installments(RuntimeOrigin::signed(2), sold, 3));
+		for origin in [RuntimeOrigin::signed(1), RuntimeOrigin::root()] {
+			assert_noop!(
+				VulnModule::freeze(origin.clone(), auctioned),
+				Error::<Test>::InAuction
+			);
+			assert_noop!(VulnModule::freeze(origin, sold), Error::<Test>::InInstallments);
+		}
+
+		// Collateral can still be pledged on a staked collectible, but not the other way round.
+		let staked = mint_to(1);
+		assert_ok!(VulnModule::freeze(RuntimeOrigin::signed(1), staked));
+		assert_ok!(VulnModule::freeze(RuntimeOrigin::root(), staked));
+		let pledged = mint_to(1);
+		assert_ok!(VulnModule::freeze(RuntimeOrigin::root(), pledged));
+		assert_noop!(VulnModule::freeze(RuntimeOrigin::signed(1), pledged), Error::<Test>::Frozen);
+	});
+}
+
+#[test]
+fn swaps_exchange_collectibles_and_the_sweetener_or_expire() {
+	new_test_ext().execute_with(|| {
+		let (a, b, c) = (mint_to(1), mint_to(2), mint_to(3));
+		let held = |who| Balances::balance_on_hold(&HoldReason::Swap.into(), &who);
+		let owner = |id| CollectibleMap::<Test>::get(id).unwrap().owner;
+		assert_noop!(
+			VulnModule::create_swap(RuntimeOrigin::signed(1), a, b, None, 1),
+			Error::<Test>::InvalidSwapDeadline
+		);
+		assert_ok!(VulnModule::create_swap(RuntimeOrigin::signed(1), a, b, Some(100), 5));
+		System::assert_last_event(
+			Event::SwapCreated {
+				offered: a,
+				desired: b,
+				creator: 1,
+				sweetener: Some(100),
+				deadline: 5,
+			}
+			.into(),
+		);
+		assert_eq!(held(1), 100);
+		assert_noop!(VulnModule::transfer(RuntimeOrigin::signed(1), 4, a), Error::<Test>::InSwap);
+		assert_noop!(VulnModule::claim_swap(RuntimeOrigin::signed(3), a), Error::<Test>::NotOwner);
+
+		assert_ok!(VulnModule::claim_swap(RuntimeOrigin::signed(2), a));
+		assert_eq!((owner(a), owner(b)), (2, 1));
+		assert_eq!((Balances::free_balance(1), Balances::free_balance(2)), (900, 1_100));
+		assert_eq!(held(1), 0);
+		assert!(!VulnModule::is_frozen(a, FreezeReason::Swap));
+
+		// Only the creator can call a swap off before its deadline.
+		assert_ok!(VulnModule::create_swap(RuntimeOrigin::signed(2), a, c, Some(50), 10));
+		assert_noop!(VulnModule::cancel_swap(RuntimeOrigin::signed(3), a), Error::<Test>::NotOwner);
+		assert_ok!(VulnModule::cancel_swap(RuntimeOrigin::signed(2), a));
+		assert_eq!(held(2), 0);
+
+		// Unclaimed swaps can no longer be claimed at their deadline, and expire when idle.
+		assert_ok!(VulnModule::create_swap(RuntimeOrigin::signed(2), a, c, Some(50), 5));
+		System::set_block_number(5);
+		assert_noop!(
+			VulnModule::claim_swap(RuntimeOrigin::signed(3), a),
+			Error::<Test>::SwapExpired
+		);
+		VulnModule::on_idle(5, Weight::MAX);
+		System::assert_has_event(Event::SwapExpired { offered: a }.into());
+		assert_eq!(held(2), 0);
+		assert_eq!(VulnModule::swap(a), None);
+		assert_ok!(VulnModule::transfer(RuntimeOrigin::signed(2), 4, a));
+	});
+}
+
+#[test]
+fn buyers_pay_for_their_own_purchases_up_to_their_max_price() {
+	new_test_ext().execute_with(|| {
+		let id = mint_to(1);
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(100)));
+		// The listing was repriced above what the buyer agreed to.
+		assert_noop!(
+			VulnModule::buy_collectible(RuntimeOrigin::signed(2), id, 99),
+			Error::<Test>::AboveMaxPrice
+		);
+
+		// The caller pays the listed price and nothing on top, and no other account is charged.
+		assert_ok!(VulnModule::buy_collectible(RuntimeOrigin::signed(2), id, 150));
+		System::assert_has_event(
+			Event::Sold { seller: 1, buyer: 2, collectible: id, price: 100, charity: None }.into(),
+		);
+		assert_eq!(CollectibleMap::<Test>::get(id).unwrap().owner, 2);
+		let free = |who| Balances::free_balance(who);
+		assert_eq!((free(1), free(2), free(3)), (1_100, 900, 1_000));
+	});
+}
+
+#[test]
+fn burns_and_dropped_listings_are_reported() {
+	new_test_ext().execute_with(|| {
+		let (id, other) = (mint_to(1), mint_to(1));
+		assert_noop!(VulnModule::burn(RuntimeOrigin::signed(1), 99), Error::<Test>::NoCollectible);
+		assert_noop!(VulnModule::burn(RuntimeOrigin::signed(2), id), Error::<Test>::NotOwner);
+		assert_ok!(VulnModule::freeze(RuntimeOrigin::signed(1), id));
+		assert_noop!(VulnModule::burn(RuntimeOrigin::signed(1), id), Error::<Test>::Frozen);
+		assert_ok!(VulnModule::thaw(RuntimeOrigin::signed(1), id));
+
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, id, Some(10)));
+		assert_ok!(VulnModule::burn(RuntimeOrigin::signed(1), id));
+		System::assert_has_event(Event::PriceRemoved { collectible: id }.into());
+		System::assert_last_event(Event::CollectibleBurned { collectible: id, owner: 1 }.into());
+		assert_eq!(crate::OwnerOfCollectibles::<Test>::get(1).into_inner(), vec![other]);
+		assert_eq!(crate::CollectiblesCount::<Test>::get(), 1);
+		assert_eq!(Balances::free_balance(1), 1_000);
+
+		// A listing that goes away with a transfer is reported too.
+		assert_ok!(VulnModule::set_price(RuntimeOrigin::signed(1), 1, other, Some(10)));
+		System::reset_events();
+		assert_ok!(VulnModule::transfer(RuntimeOrigin::signed(1), 2, other));
+		System::assert_has_event(Event::PriceRemoved { collectible: other }.into());
+		assert_eq!(VulnModule::listing_price(other), None);
+	});
+}
@@ -0,0 +1,219 @@
+//! Traits through which the runtime plugs other pallets into vulntoken, and through which other
+//! pallets use the marketplace.
+
+use crate::{Color, Config, Pallet};
+use codec::{Decode, Encode};
+use frame_support::{
+	dispatch::DispatchResult,
+	sp_runtime::{traits::Zero, DispatchError},
+	traits::{Get, Randomness},
+	RuntimeDebug,
+};
+use scale_info::TypeInfo;
+use sp_std::marker::PhantomData;
+
+/// Tells whether an account holds a positive identity judgement.
+pub trait IdentityVerifier<AccountId> {
+	fn is_verified(who: &AccountId) -> bool;
+
+	/// Give `who` an identity that passes [`Self::is_verified`], so benchmarks can reach the
+	/// identity checks.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn verify(_who: &AccountId) {}
+}
+
+/// Rules a runtime places on minting, such as caps, fees, allowlists or mint phases.
+pub trait MintPolicy<AccountId> {
+	/// Allow or refuse `minter` minting a collectible to `owner`. Consulted before every mint,
+	/// it may also charge for the mint or count it; when it refuses, the mint fails with its error.
+	fn check_mint(minter: &AccountId, owner: &AccountId) -> DispatchResult;
+}
+
+/// Anybody may mint to anybody.
+impl<AccountId> MintPolicy<AccountId> for () {
+	fn check_mint(_minter: &AccountId, _owner: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+}
+
+/// Picks the id of each new collectible. Runtimes can number collectibles in mint order, make
+/// ids hard to predict, or take them from elsewhere.
+pub trait GenerateItemId {
+	/// Whether ids grow with the mint sequence, so that mint order is id order.
+	const ASCENDING: bool;
+
+	/// The id of the collectible minted `sequence`-th, counting from zero. Must never return
+	/// the same id twice.
+	fn item_id(sequence: u64) -> u64;
+}
+
+/// Ids in mint order, starting from zero.
+pub struct SequentialIds;
+impl GenerateItemId for SequentialIds {
+	const ASCENDING: bool = true;
+
+	fn item_id(sequence: u64) -> u64 {
+		sequence
+	}
+}
+
+/// Ids hashed from the mint sequence and the parent block hash, so nobody can tell which id
+/// their mint will get before the block it lands in.
+pub struct HashedIds<T>(PhantomData<T>);
+impl<T: frame_system::Config> GenerateItemId for HashedIds<T> {
+	const ASCENDING: bool = false;
+
+	fn item_id(sequence: u64) -> u64 {
+		let parent = frame_system::Pallet::<T>::parent_hash();
+		let hash = sp_io::hashing::blake2_256(&(b"vulntoken/id", parent, sequence).encode());
+		u64::from_le_bytes([hash[0], hash[1], hash[2], hash[3], hash[4], hash[5], hash[6], hash[7]])
+	}
+}
+
+/// Ids drawn from the pallet's `Randomness`, so they are spread over the whole `u64` range rather
+/// than counting up. Two mints colliding is unlikely before billions of collectibles exist, and
+/// a mint that would collide fails with `DuplicateCollectible` instead of replacing anything.
+/// The same caveat as for [`RandomColors`] applies.
+pub struct RandomIds<T>(PhantomData<T>);
+impl<T: Config> GenerateItemId for RandomIds<T> {
+	const ASCENDING: bool = false;
+
+	fn item_id(sequence: u64) -> u64 {
+		let (seed, _) = T::Randomness::random(&(b"vulntoken/id", sequence).encode());
+		let mut bytes = [0u8; 8];
+		for (byte, seed_byte) in bytes.iter_mut().zip(seed.as_ref()) {
+			*byte = *seed_byte;
+		}
+		u64::from_le_bytes(bytes)
+	}
+}
+
+/// Picks the color of each new collectible, which is where a runtime decides how rare each color
+/// is. Mystery boxes are minted hidden and pick their color when revealed instead.
+pub trait ColorStrategy {
+	/// The color of the collectible minted `sequence`-th, counting from zero, as `unique_id`.
+	fn color(sequence: u64, unique_id: u64) -> Color;
+}
+
+/// Red and yellow in turn, by mint sequence.
+pub struct ParityColors;
+impl ColorStrategy for ParityColors {
+	fn color(sequence: u64, _unique_id: u64) -> Color {
+		if sequence % 2 == 0 {
+			Color::Red
+		} else {
+			Color::Yellow
+		}
+	}
+}
+
+/// One of the four colors, uniformly at random. The randomness of the block a mint lands in may
+/// be known before it, so minters can choose when to mint; use mystery boxes when that matters.
+pub struct RandomColors<T>(PhantomData<T>);
+impl<T: Config> ColorStrategy for RandomColors<T> {
+	fn color(sequence: u64, _unique_id: u64) -> Color {
+		let (seed, _) = T::Randomness::random(&(b"vulntoken/color", sequence).encode());
+		Pallet::<T>::color_from_seed(&seed)
+	}
+}
+
+/// One of the four colors at random, each as likely as its weight in `Weights`, which lists
+/// red, yellow, blue and green in that order. With no weight at all, every collectible is red.
+/// The same caveat as for [`RandomColors`] applies.
+pub struct RarityTable<T, Weights>(PhantomData<(T, Weights)>);
+impl<T: Config, Weights: Get<[u32; 4]>> ColorStrategy for RarityTable<T, Weights> {
+	fn color(sequence: u64, _unique_id: u64) -> Color {
+		let weights = Weights::get();
+		let total = weights.iter().fold(0u64, |total, weight| total + u64::from(*weight));
+		if total == 0 {
+			return Color::Red
+		}
+		let (seed, _) = T::Randomness::random(&(b"vulntoken/color", sequence).encode());
+		let mut bytes = [0u8; 8];
+		for (byte, seed_byte) in bytes.iter_mut().zip(seed.as_ref()) {
+			*byte = *seed_byte;
+		}
+		let mut roll = u64::from_le_bytes(bytes) % total;
+		let colors = [Color::Red, Color::Yellow, Color::Blue, Color::Green];
+		for (color, weight) in colors.into_iter().zip(weights) {
+			match roll.checked_sub(u64::from(weight)) {
+				Some(rest) => roll = rest,
+				None => return color,
+			}
+		}
+		Color::Green
+	}
+}
+
+/// Where the price of a sale goes. The parts must add up to the price.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct SaleSplit<AccountId, Balance> {
+	pub seller: Balance,
+	/// Paid to the account that minted the collectible.
+	pub creator: Balance,
+	/// Taken from the buyer and handed to the pallet's `FeeSink`.
+	pub fee: Balance,
+	/// Paid to whoever referred the buyer, if anybody did.
+	pub referrer: Option<(AccountId, Balance)>,
+}
+
+/// Splits the price of a fixed-price sale between the seller, the creator, fees and a referrer.
+pub trait PricingPolicy<AccountId, Balance> {
+	fn split(
+		seller: &AccountId,
+		buyer: &AccountId,
+		creator: &AccountId,
+		price: Balance,
+	) -> SaleSplit<AccountId, Balance>;
+}
+
+/// The seller gets the whole price.
+impl<AccountId, Balance: Zero> PricingPolicy<AccountId, Balance> for () {
+	fn split(
+		_seller: &AccountId,
+		_buyer: &AccountId,
+		_creator: &AccountId,
+		price: Balance,
+	) -> SaleSplit<AccountId, Balance> {
+		SaleSplit { seller: price, creator: Zero::zero(), fee: Zero::zero(), referrer: None }
+	}
+}
+
+/// Fixed-price purchases, for pallets that buy collectibles on behalf of their own accounts.
+pub trait Marketplace<AccountId, Balance> {
+	/// The price `unique_id` can be bought at right now, if it is listed.
+	fn price(unique_id: u64) -> Option<Balance>;
+
+	/// Buy `unique_id` for `buyer` at its listed price, paid from `buyer`'s free balance.
+	fn buy(unique_id: u64, buyer: &AccountId) -> DispatchResult;
+
+	/// The account that owns `unique_id`.
+	fn owner(unique_id: u64) -> Option<AccountId>;
+
+	/// List a new collectible owned by `seller` at `price` and return its id, so benchmarks
+	/// have something to buy.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn list(seller: &AccountId, price: Balance) -> u64;
+}
+
+/// Moving collectibles between accounts, for pallets that keep their users' collectibles in
+/// accounts of their own, such as bridges.
+pub trait Custody<AccountId> {
+	/// The account that owns `unique_id`.
+	fn owner(unique_id: u64) -> Option<AccountId>;
+
+	/// Move `unique_id` from `from`, which must own it, to `to`. Collectibles that could not be
+	/// transferred, such as frozen or auctioned ones, cannot be moved either.
+	fn transfer(unique_id: u64, from: &AccountId, to: &AccountId) -> DispatchResult;
+
+	/// Mint a collectible to `owner` and return its id, so benchmarks have something to move.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn mint(owner: &AccountId) -> u64;
+}
+
+/// Minting for other pallets, such as those migrating collectibles from other chains.
+pub trait Issuer<AccountId> {
+	/// Mint `owner` a collectible of `color` and return its id. The mint passes the mint policy
+	/// and counts towards the per-block limit like any other. Only mystery boxes are hidden.
+	fn issue(owner: &AccountId, color: Color) -> Result<u64, DispatchError>;
+}
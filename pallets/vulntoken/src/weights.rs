@@ -0,0 +1,2666 @@
+
+//! Weights for vulntoken
+//!
+//! Estimated by hand from the storage each call touches in the worst case set up by
+//! `benchmarking.rs`, with `MaximumOwned = 100`. None of these figures were measured: replace
+//! them with the output of `benchmark pallet` on reference hardware before relying on them.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for vulntoken.
+pub trait WeightInfo {
+	fn create_collectible() -> Weight;
+	fn transfer() -> Weight;
+	fn burn() -> Weight;
+	fn set_price() -> Weight;
+	fn buy_collectible() -> Weight;
+	fn start_sealed_auction() -> Weight;
+	fn commit_bid() -> Weight;
+	fn reveal_bid() -> Weight;
+	fn settle_sealed_auction(b: u32, ) -> Weight;
+	fn start_raffle() -> Weight;
+	fn buy_tickets(c: u32, ) -> Weight;
+	fn draw_raffle(t: u32, ) -> Weight;
+	fn mint_mystery_box() -> Weight;
+	fn reveal() -> Weight;
+	fn set_royalty_enforcement() -> Weight;
+	fn approve_market() -> Weight;
+	fn remove_market() -> Weight;
+	fn set_transfer_cooldown() -> Weight;
+	fn buy_with_escrow() -> Weight;
+	fn release_escrow() -> Weight;
+	fn raise_dispute() -> Weight;
+	fn vote_dispute() -> Weight;
+	fn resolve_dispute() -> Weight;
+	fn cancel_listing() -> Weight;
+	fn clear_expired_listing() -> Weight;
+	fn flag_listing() -> Weight;
+	fn set_metadata_hash() -> Weight;
+	fn set_metadata_cid() -> Weight;
+	fn set_accept_mints() -> Weight;
+	fn claim_mints(c: u32, ) -> Weight;
+	fn confirm_pin() -> Weight;
+	fn offer_rental() -> Weight;
+	fn rent() -> Weight;
+	fn top_up_rental() -> Weight;
+	fn settle_rental() -> Weight;
+	fn end_rental() -> Weight;
+	fn buy_in_installments() -> Weight;
+	fn pay_installment() -> Weight;
+	fn enforce_installment_default() -> Weight;
+	fn place_sell_order() -> Weight;
+	fn place_buy_order(q: u32, ) -> Weight;
+	fn cancel_buy_order() -> Weight;
+	fn cancel_sell_order() -> Weight;
+	fn set_prices(n: u32, ) -> Weight;
+	fn rebuild_floor_index(n: u32, ) -> Weight;
+	fn reclaim_escrow(b: u32, ) -> Weight;
+	fn set_sale_receipts() -> Weight;
+	fn set_founder_badge_limit() -> Weight;
+	fn register_foreign() -> Weight;
+	fn redeem_foreign() -> Weight;
+	fn transfer_at() -> Weight;
+	fn cancel_transfer_at() -> Weight;
+	fn complete_timed_transfer() -> Weight;
+	fn set_heir() -> Weight;
+	fn start_inheritance() -> Weight;
+	fn veto_inheritance() -> Weight;
+	fn complete_inheritance(n: u32, ) -> Weight;
+	fn list_with_charity() -> Weight;
+	fn set_default_royalty() -> Weight;
+	fn set_item_royalty() -> Weight;
+	fn set_trading_limit() -> Weight;
+	fn impose_trading_limit() -> Weight;
+	fn create_licensed_collectible() -> Weight;
+	fn publish_metadata_version() -> Weight;
+	fn force_remap_owner(n: u32, ) -> Weight;
+	fn distribute(n: u32, ) -> Weight;
+	fn start_auction() -> Weight;
+	fn place_bid() -> Weight;
+	fn settle_auction() -> Weight;
+	fn create_collection() -> Weight;
+	fn set_collection_metadata() -> Weight;
+	fn set_collection_settings() -> Weight;
+	fn publish_collection_metadata_version() -> Weight;
+	fn destroy_collection() -> Weight;
+	fn destroy_collection_items(n: u32, ) -> Weight;
+	fn create_collectible_in() -> Weight;
+	fn approve() -> Weight;
+	fn cancel_approval() -> Weight;
+	fn set_approval_for_all() -> Weight;
+	fn make_offer() -> Weight;
+	fn accept_offer() -> Weight;
+	fn withdraw_offer() -> Weight;
+	fn transfer_many(n: u32, ) -> Weight;
+	fn mint_batch(n: u32, ) -> Weight;
+	fn set_metadata() -> Weight;
+	fn clear_metadata() -> Weight;
+	fn set_attribute() -> Weight;
+	fn clear_attribute() -> Weight;
+	fn freeze() -> Weight;
+	fn thaw() -> Weight;
+	fn create_swap() -> Weight;
+	fn claim_swap() -> Weight;
+	fn cancel_swap() -> Weight;
+	fn on_initialize() -> Weight;
+}
+
+/// Weights for vulntoken using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule OwnerOfCollectibles (r:1 w:1)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule AcceptsMints (r:1 w:0)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule FounderBadgeLimit (r:1 w:0)
+	/// Storage: VulnModule FounderBadgesIssued (r:1 w:1)
+	/// Storage: VulnModule FounderBadges (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:0 w:1)
+	/// Storage: VulnModule ItemIdAt (r:0 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule MintDeposits (r:0 w:1)
+	fn create_collectible() -> Weight {
+		Weight::from_parts(25_431_000, 4_339)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule RoyaltiesEnforced (r:1 w:0)
+	/// Storage: VulnModule ApprovedMarkets (r:2 w:0)
+	/// Storage: VulnModule Frozen (r:1 w:0)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule RentalRates (r:0 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule Approvals (r:1 w:1)
+	/// Storage: VulnModule OperatorApprovals (r:1 w:0)
+	/// Storage: VulnModule Collections (r:1 w:0)
+	fn transfer() -> Weight {
+		Weight::from_parts(46_210_000, 9_668)
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule OwnerOfCollectibles (r:1 w:1)
+	/// Storage: VulnModule MysteryBoxes (r:0 w:1)
+	/// Storage: VulnModule CooldownUntil (r:0 w:1)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule RevealRecords (r:0 w:1)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule MetadataCids (r:0 w:1)
+	/// Storage: VulnModule PinQueue (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule RentalRates (r:0 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule RecentSales (r:0 w:1)
+	/// Storage: VulnModule WashTraded (r:0 w:1)
+	/// Storage: VulnModule ForeignSources (r:1 w:0)
+	/// Storage: VulnModule Approvals (r:0 w:1)
+	/// Storage: VulnModule MintDeposits (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule MetadataDeposits (r:1 w:1)
+	/// Storage: VulnModule OnChainMetadata (r:0 w:1)
+	/// Storage: VulnModule Attributes (r:16 w:16)
+	/// Storage: VulnModule AttributeCounts (r:0 w:1)
+	/// Storage: VulnModule Offers (r:64 w:64)
+	/// Storage: System Account (r:64 w:64)
+	/// Storage: VulnModule OfferCounts (r:0 w:1)
+	fn burn() -> Weight {
+		Weight::from_parts(37_604_000, 4_339)
+			.saturating_add(T::DbWeight::get().reads(157_u64))
+			.saturating_add(T::DbWeight::get().writes(170_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:0)
+	/// Storage: VulnModule CooldownUntil (r:1 w:1)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule WashTraded (r:1 w:0)
+	/// Storage: VulnModule Approvals (r:1 w:0)
+	/// Storage: VulnModule OperatorApprovals (r:1 w:0)
+	fn set_price() -> Weight {
+		Weight::from_parts(24_512_000, 3_581)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:0)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule ListedAt (r:1 w:1)
+	/// Storage: System Account (r:6 w:6)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: Identity IdentityOf (r:2 w:0)
+	/// Storage: VulnModule CooldownUntil (r:0 w:1)
+	/// Storage: VulnModule ListingCharities (r:1 w:1)
+	/// Storage: VulnModule ItemRoyalties (r:1 w:0)
+	/// Storage: VulnModule DefaultRoyalties (r:1 w:0)
+	/// Storage: VulnModule PendingTradingLimits (r:2 w:0)
+	/// Storage: VulnModule SelfTradingLimits (r:2 w:0)
+	/// Storage: VulnModule ImposedTradingLimits (r:2 w:0)
+	/// Storage: VulnModule TradingWindows (r:2 w:2)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: VulnModule RentalRates (r:0 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule VolumeBuckets (r:1 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule RecentSales (r:1 w:1)
+	/// Storage: VulnModule WashTraded (r:1 w:1)
+	/// Storage: VulnModule IssuesReceipts (r:1 w:0)
+	/// Storage: VulnModule SaleReceipts (r:0 w:1)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule Approvals (r:0 w:1)
+	/// Storage: VulnModule Collections (r:1 w:0)
+	fn buy_collectible() -> Weight {
+		Weight::from_parts(121_806_000, 23_941)
+			.saturating_add(T::DbWeight::get().reads(37_u64))
+			.saturating_add(T::DbWeight::get().writes(28_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule SealedAuctions (r:0 w:1)
+	/// Storage: VulnModule CooldownUntil (r:1 w:0)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule SealedAuctionsEnding (r:1 w:1)
+	fn start_sealed_auction() -> Weight {
+		Weight::from_parts(26_307_000, 3_581)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: VulnModule SealedAuctions (r:1 w:1)
+	/// Storage: VulnModule SealedBids (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn commit_bid() -> Weight {
+		Weight::from_parts(40_118_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: VulnModule SealedAuctions (r:1 w:1)
+	/// Storage: VulnModule SealedBids (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn reveal_bid() -> Weight {
+		Weight::from_parts(55_364_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: VulnModule SealedAuctions (r:1 w:1)
+	/// Storage: VulnModule SealedBids (r:b w:b)
+	/// Storage: System Account (r:b+1 w:b+1)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: VulnModule ListingDeposits (r:1 w:0)
+	/// Storage: VulnModule RentalRates (r:0 w:1)
+	/// Storage: VulnModule VolumeBuckets (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule RecentSales (r:1 w:1)
+	/// Storage: VulnModule WashTraded (r:1 w:1)
+	/// Storage: VulnModule IssuesReceipts (r:1 w:0)
+	/// Storage: VulnModule SaleReceipts (r:0 w:1)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule Collections (r:1 w:0)
+	/// Storage: VulnModule ItemRoyalties (r:1 w:0)
+	/// Storage: VulnModule DefaultRoyalties (r:1 w:0)
+	/// Storage: VulnModule UnsettledAuctions (r:0 w:1)
+	/// The range of component `b` is `[1, MaxSealedBids]`.
+	fn settle_sealed_auction(b: u32, ) -> Weight {
+		Weight::from_parts(79_502_000, 9_668)
+			.saturating_add(Weight::from_parts(29_734_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(21_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(19_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 2_603).saturating_mul(b.into()))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule Raffles (r:0 w:1)
+	/// Storage: VulnModule CooldownUntil (r:1 w:0)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	fn start_raffle() -> Weight {
+		Weight::from_parts(26_118_000, 3_581)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: VulnModule Raffles (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule RaffleTickets (r:0 w:c)
+	/// The range of component `c` is `[1, MaxRaffleTickets]`.
+	fn buy_tickets(c: u32, ) -> Weight {
+		Weight::from_parts(35_240_000, 3_593)
+			.saturating_add(Weight::from_parts(4_102_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+	}
+	/// Storage: VulnModule Raffles (r:1 w:1)
+	/// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	/// Storage: VulnModule RaffleTickets (r:t w:t)
+	/// Storage: System Account (r:t+1 w:t+1)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: VulnModule ListingDeposits (r:1 w:0)
+	/// Storage: VulnModule RentalRates (r:0 w:1)
+	/// Storage: VulnModule VolumeBuckets (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule RecentSales (r:1 w:1)
+	/// Storage: VulnModule WashTraded (r:1 w:1)
+	/// Storage: VulnModule IssuesReceipts (r:1 w:0)
+	/// Storage: VulnModule SaleReceipts (r:0 w:1)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule Collections (r:1 w:0)
+	/// Storage: VulnModule ItemRoyalties (r:1 w:0)
+	/// Storage: VulnModule DefaultRoyalties (r:1 w:0)
+	/// The range of component `t` is `[1, MaxRaffleTickets]`.
+	fn draw_raffle(t: u32, ) -> Weight {
+		Weight::from_parts(61_675_000, 9_668)
+			.saturating_add(Weight::from_parts(28_410_000, 0).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(21_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().writes(17_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 2_603).saturating_mul(t.into()))
+	}
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule OwnerOfCollectibles (r:1 w:1)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: VulnModule MysteryBoxes (r:0 w:1)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule AcceptsMints (r:1 w:0)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule FounderBadgeLimit (r:1 w:0)
+	/// Storage: VulnModule FounderBadgesIssued (r:1 w:1)
+	/// Storage: VulnModule FounderBadges (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:0 w:1)
+	/// Storage: VulnModule ItemIdAt (r:0 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule MintDeposits (r:0 w:1)
+	fn mint_mystery_box() -> Weight {
+		Weight::from_parts(28_903_000, 4_339)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
+	/// Storage: VulnModule MysteryBoxes (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	/// Storage: VulnModule RevealRecords (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:2 w:2)
+	/// Storage: VulnModule ColorStatistics (r:2 w:2)
+	fn reveal() -> Weight {
+		Weight::from_parts(30_226_000, 3_581)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: VulnModule RoyaltiesEnforced (r:0 w:1)
+	fn set_royalty_enforcement() -> Weight {
+		Weight::from_parts(8_104_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule ApprovedMarkets (r:0 w:1)
+	fn approve_market() -> Weight {
+		Weight::from_parts(10_287_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule ApprovedMarkets (r:1 w:1)
+	fn remove_market() -> Weight {
+		Weight::from_parts(13_519_000, 3_509)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule TransferCooldown (r:0 w:1)
+	fn set_transfer_cooldown() -> Weight {
+		Weight::from_parts(8_051_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule Escrows (r:0 w:1)
+	/// Storage: VulnModule ListedAt (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	fn buy_with_escrow() -> Weight {
+		Weight::from_parts(45_733_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+	}
+	/// Storage: VulnModule Escrows (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:2 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: VulnModule ListingDeposits (r:1 w:0)
+	/// Storage: VulnModule RentalRates (r:0 w:1)
+	/// Storage: VulnModule VolumeBuckets (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule RecentSales (r:1 w:1)
+	/// Storage: VulnModule WashTraded (r:1 w:1)
+	/// Storage: VulnModule IssuesReceipts (r:1 w:0)
+	/// Storage: VulnModule SaleReceipts (r:0 w:1)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule Collections (r:1 w:0)
+	fn release_escrow() -> Weight {
+		Weight::from_parts(75_962_000, 9_668)
+			.saturating_add(T::DbWeight::get().reads(19_u64))
+			.saturating_add(T::DbWeight::get().writes(17_u64))
+	}
+	/// Storage: VulnModule Escrows (r:1 w:0)
+	/// Storage: VulnModule Disputes (r:0 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	fn raise_dispute() -> Weight {
+		Weight::from_parts(38_420_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: JurorMembership Members (r:1 w:0)
+	/// Storage: VulnModule Disputes (r:1 w:1)
+	/// Storage: VulnModule DisputeVotes (r:1 w:1)
+	fn vote_dispute() -> Weight {
+		Weight::from_parts(25_187_000, 3_509)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: VulnModule Disputes (r:1 w:1)
+	/// Storage: VulnModule Escrows (r:1 w:1)
+	/// Storage: JurorMembership Members (r:1 w:0)
+	/// Storage: VulnModule DisputeVotes (r:0 w:1)
+	/// Storage: System Account (r:3 w:3)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:2)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: VulnModule ListingDeposits (r:1 w:0)
+	/// Storage: VulnModule RentalRates (r:0 w:1)
+	/// Storage: VulnModule VolumeBuckets (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule RecentSales (r:1 w:1)
+	/// Storage: VulnModule WashTraded (r:1 w:1)
+	/// Storage: VulnModule IssuesReceipts (r:1 w:0)
+	/// Storage: VulnModule SaleReceipts (r:0 w:1)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule Collections (r:1 w:0)
+	fn resolve_dispute() -> Weight {
+		Weight::from_parts(112_645_000, 9_668)
+			.saturating_add(T::DbWeight::get().reads(22_u64))
+			.saturating_add(T::DbWeight::get().writes(21_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	fn cancel_listing() -> Weight {
+		Weight::from_parts(20_348_000, 3_581)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule ListedAt (r:1 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	fn clear_expired_listing() -> Weight {
+		Weight::from_parts(22_615_000, 3_581)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	fn flag_listing() -> Weight {
+		Weight::from_parts(31_204_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	fn set_metadata_hash() -> Weight {
+		Weight::from_parts(18_930_000, 3_581)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:0)
+	/// Storage: VulnModule MetadataCids (r:0 w:1)
+	/// Storage: VulnModule PinQueue (r:0 w:1)
+	fn set_metadata_cid() -> Weight {
+		Weight::from_parts(20_772_000, 3_581)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: VulnModule AcceptsMints (r:0 w:1)
+	fn set_accept_mints() -> Weight {
+		Weight::from_parts(10_114_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule PendingMints (r:1 w:1)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:c w:c)
+	/// Storage: VulnModule OwnerOfCollectibles (r:1 w:1)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: VulnModule ColorStatistics (r:c w:c)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule MintDeposits (r:0 w:c)
+	/// The range of component `c` is `[1, MaxMintsPerBlock]`.
+	fn claim_mints(c: u32, ) -> Weight {
+		Weight::from_parts(19_870_000, 4_339)
+			.saturating_add(Weight::from_parts(14_262_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2_581).saturating_mul(c.into()))
+	}
+	/// Storage: VulnModule PinQueue (r:1 w:1)
+	fn confirm_pin() -> Weight {
+		Weight::from_parts(12_406_000, 3_509)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:0)
+	/// Storage: VulnModule RentalRates (r:0 w:1)
+	fn offer_rental() -> Weight {
+		Weight::from_parts(17_402_000, 3_581)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:0)
+	/// Storage: VulnModule RentalRates (r:1 w:0)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: PaymentStreams NextStreamId (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: PaymentStreams Streams (r:0 w:1)
+	/// Storage: VulnModule Rentals (r:0 w:1)
+	fn rent() -> Weight {
+		Weight::from_parts(44_730_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: VulnModule Rentals (r:1 w:0)
+	/// Storage: PaymentStreams Streams (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn top_up_rental() -> Weight {
+		Weight::from_parts(36_284_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: VulnModule Rentals (r:1 w:1)
+	/// Storage: PaymentStreams Streams (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: VulnModule Frozen (r:0 w:1)
+	fn settle_rental() -> Weight {
+		Weight::from_parts(58_912_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: VulnModule Rentals (r:1 w:1)
+	/// Storage: PaymentStreams Streams (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: VulnModule Frozen (r:0 w:1)
+	fn end_rental() -> Weight {
+		Weight::from_parts(63_470_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule ListedAt (r:1 w:1)
+	/// Storage: Identity IdentityOf (r:2 w:0)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: VulnModule InstallmentDeadlines (r:1 w:1)
+	/// Storage: VulnModule InstallmentCursor (r:1 w:1)
+	/// Storage: VulnModule InstallmentPlans (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule RecentSales (r:1 w:1)
+	/// Storage: VulnModule WashTraded (r:1 w:1)
+	/// Storage: VulnModule IssuesReceipts (r:1 w:0)
+	/// Storage: VulnModule SaleReceipts (r:0 w:1)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule Collections (r:1 w:0)
+	fn buy_in_installments() -> Weight {
+		Weight::from_parts(52_840_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(20_u64))
+			.saturating_add(T::DbWeight::get().writes(19_u64))
+	}
+	/// Storage: VulnModule InstallmentPlans (r:1 w:1)
+	/// Storage: System Account (r:3 w:3)
+	/// Storage: VulnModule InstallmentDeadlines (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule RentalRates (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:0)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: VulnModule CooldownUntil (r:0 w:1)
+	/// Storage: VulnModule VolumeBuckets (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule RecentSales (r:1 w:1)
+	/// Storage: VulnModule WashTraded (r:1 w:1)
+	/// Storage: VulnModule IssuesReceipts (r:1 w:0)
+	/// Storage: VulnModule SaleReceipts (r:0 w:1)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule Collections (r:1 w:0)
+	fn pay_installment() -> Weight {
+		Weight::from_parts(81_377_000, 9_668)
+			.saturating_add(T::DbWeight::get().reads(20_u64))
+			.saturating_add(T::DbWeight::get().writes(20_u64))
+	}
+	/// Storage: VulnModule InstallmentPlans (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:0 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn enforce_installment_default() -> Weight {
+		Weight::from_parts(46_902_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:0)
+	/// Storage: VulnModule CooldownUntil (r:1 w:1)
+	/// Storage: VulnModule BuyBook (r:1 w:1)
+	/// Storage: Identity IdentityOf (r:2 w:0)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule RentalRates (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:0)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule VolumeBuckets (r:1 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule RecentSales (r:1 w:1)
+	/// Storage: VulnModule WashTraded (r:1 w:1)
+	/// Storage: VulnModule IssuesReceipts (r:1 w:0)
+	/// Storage: VulnModule SaleReceipts (r:0 w:1)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule Collections (r:1 w:0)
+	fn place_sell_order() -> Weight {
+		Weight::from_parts(71_208_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(23_u64))
+			.saturating_add(T::DbWeight::get().writes(20_u64))
+	}
+	/// Storage: System Account (r:q+1 w:q+1)
+	/// Storage: VulnModule SellBook (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:q w:q)
+	/// Storage: VulnModule CollectibleMap (r:q w:q)
+	/// Storage: Identity IdentityOf (r:2q w:0)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2q w:2q)
+	/// Storage: VulnModule ListedAt (r:0 w:q)
+	/// Storage: VulnModule RentalRates (r:0 w:q)
+	/// Storage: VulnModule ListingDeposits (r:q w:0)
+	/// Storage: VulnModule TransferCooldown (r:q w:0)
+	/// Storage: VulnModule CooldownUntil (r:0 w:q)
+	/// Storage: VulnModule NextOrderId (r:1 w:1)
+	/// Storage: VulnModule BuyBook (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:q w:q)
+	/// Storage: VulnModule FloorPrice (r:0 w:q)
+	/// Storage: VulnModule VolumeBuckets (r:q w:q)
+	/// Storage: VulnModule ColorFloorIndex (r:q w:q)
+	/// Storage: VulnModule ColorStatistics (r:q w:q)
+	/// Storage: VulnModule RecentSales (r:q w:q)
+	/// Storage: VulnModule WashTraded (r:q w:q)
+	/// Storage: VulnModule IssuesReceipts (r:q w:0)
+	/// Storage: VulnModule SaleReceipts (r:0 w:q)
+	/// Storage: VulnModule CollectiblesCount (r:q w:q)
+	/// Storage: VulnModule NextCollectibleId (r:q w:q)
+	/// Storage: VulnModule MintsThisBlock (r:q w:q)
+	/// Storage: VulnModule Collections (r:q w:0)
+	/// The range of component `q` is `[1, MaxBookDepth]`.
+	fn place_buy_order(q: u32, ) -> Weight {
+		Weight::from_parts(38_541_000, 6_196)
+			.saturating_add(Weight::from_parts(52_716_000, 0).saturating_mul(q.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((20_u64).saturating_mul(q.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((20_u64).saturating_mul(q.into())))
+			.saturating_add(Weight::from_parts(0, 2_603).saturating_mul(q.into()))
+	}
+	/// Storage: VulnModule BuyBook (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn cancel_buy_order() -> Weight {
+		Weight::from_parts(31_905_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: VulnModule SellBook (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:0 w:1)
+	fn cancel_sell_order() -> Weight {
+		Weight::from_parts(19_374_000, 1_489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:n w:n)
+	/// Storage: VulnModule Frozen (r:n w:0)
+	/// Storage: VulnModule CooldownUntil (r:n w:0)
+	/// Storage: VulnModule ListedAt (r:0 w:n)
+	/// Storage: VulnModule ListingDeposits (r:n w:n)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:n w:n)
+	/// Storage: VulnModule FloorPrice (r:0 w:n)
+	/// Storage: VulnModule ColorFloorIndex (r:n w:n)
+	/// Storage: VulnModule ColorStatistics (r:n w:n)
+	/// Storage: VulnModule WashTraded (r:n w:0)
+	/// The range of component `n` is `[1, MaximumOwned]`.
+	fn set_prices(n: u32, ) -> Weight {
+		Weight::from_parts(9_861_000, 3_581)
+			.saturating_add(Weight::from_parts(17_904_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((8_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2_549).saturating_mul(n.into()))
+	}
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:0)
+	/// Storage: VulnModule CollectibleMap (r:n w:0)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// The range of component `n` is `[1, 1000]`.
+	fn rebuild_floor_index(n: u32, ) -> Weight {
+		Weight::from_parts(11_207_000, 3_745)
+			.saturating_add(Weight::from_parts(4_318_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 2_581).saturating_mul(n.into()))
+	}
+	/// Storage: VulnModule SealedAuctions (r:1 w:1)
+	/// Storage: VulnModule SealedBids (r:b w:b)
+	/// Storage: System Account (r:b w:b)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule Escrows (r:1 w:1)
+	/// Storage: VulnModule Disputes (r:1 w:1)
+	/// Storage: VulnModule DisputeVotes (r:0 w:1)
+	/// Storage: VulnModule UnsettledAuctions (r:0 w:1)
+	/// The range of component `b` is `[1, MaxSealedBids]`.
+	fn reclaim_escrow(b: u32, ) -> Weight {
+		Weight::from_parts(41_236_000, 6_196)
+			.saturating_add(Weight::from_parts(24_310_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 2_603).saturating_mul(b.into()))
+	}
+	/// Storage: VulnModule IssuesReceipts (r:0 w:1)
+	fn set_sale_receipts() -> Weight {
+		Weight::from_parts(8_612_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule FounderBadgeLimit (r:0 w:1)
+	fn set_founder_badge_limit() -> Weight {
+		Weight::from_parts(7_904_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule ForeignDerivatives (r:1 w:1)
+	/// Storage: VulnModule ForeignSources (r:0 w:1)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule OwnerOfCollectibles (r:1 w:1)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule AcceptsMints (r:1 w:0)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule CooldownUntil (r:0 w:1)
+	/// Storage: VulnModule ItemIdAt (r:0 w:1)
+	fn register_foreign() -> Weight {
+		Weight::from_parts(24_187_000, 4_339)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule ForeignSources (r:1 w:1)
+	/// Storage: VulnModule ForeignDerivatives (r:0 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule OwnerOfCollectibles (r:1 w:1)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule Offers (r:64 w:64)
+	/// Storage: System Account (r:64 w:64)
+	/// Storage: VulnModule OfferCounts (r:0 w:1)
+	fn redeem_foreign() -> Weight {
+		Weight::from_parts(39_215_000, 4_339)
+			.saturating_add(T::DbWeight::get().reads(138_u64))
+			.saturating_add(T::DbWeight::get().writes(150_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule RoyaltiesEnforced (r:1 w:0)
+	/// Storage: VulnModule ApprovedMarkets (r:2 w:0)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule TimedTransfersDue (r:1 w:1)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule TimedTransfers (r:0 w:1)
+	/// Storage: VulnModule Collections (r:1 w:0)
+	fn transfer_at() -> Weight {
+		Weight::from_parts(43_587_000, 9_668)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: VulnModule TimedTransfers (r:1 w:1)
+	/// Storage: VulnModule TimedTransfersDue (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:0 w:1)
+	fn cancel_transfer_at() -> Weight {
+		Weight::from_parts(17_942_000, 5_293)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: VulnModule TimedTransfers (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:0)
+	/// Storage: VulnModule RentalRates (r:0 w:1)
+	fn complete_timed_transfer() -> Weight {
+		Weight::from_parts(28_406_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: VulnModule Inheritances (r:0 w:1)
+	/// Storage: System Account (r:1 w:0)
+	fn set_heir() -> Weight {
+		Weight::from_parts(14_822_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule Inheritances (r:1 w:1)
+	/// Storage: System Account (r:1 w:0)
+	fn start_inheritance() -> Weight {
+		Weight::from_parts(17_305_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule Inheritances (r:1 w:1)
+	/// Storage: System Account (r:1 w:0)
+	fn veto_inheritance() -> Weight {
+		Weight::from_parts(16_118_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule Inheritances (r:1 w:1)
+	/// Storage: System Account (r:1 w:0)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule CollectibleMap (r:n w:n)
+	/// Storage: VulnModule Frozen (r:n w:0)
+	/// Storage: VulnModule ListingDeposits (r:n w:0)
+	/// Storage: VulnModule ListedAt (r:0 w:n)
+	/// Storage: VulnModule RentalRates (r:0 w:n)
+	/// The range of component `n` is `[0, 100]`.
+	fn complete_inheritance(n: u32, ) -> Weight {
+		Weight::from_parts(21_460_000, 6_196)
+			.saturating_add(Weight::from_parts(19_874_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2_549).saturating_mul(n.into()))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:0)
+	/// Storage: VulnModule CooldownUntil (r:1 w:1)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule WashTraded (r:1 w:0)
+	/// Storage: VulnModule ListingCharities (r:0 w:1)
+	fn list_with_charity() -> Weight {
+		Weight::from_parts(27_036_000, 3_581)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: VulnModule DefaultRoyalties (r:0 w:1)
+	fn set_default_royalty() -> Weight {
+		Weight::from_parts(9_412_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:0)
+	/// Storage: VulnModule ItemRoyalties (r:0 w:1)
+	fn set_item_royalty() -> Weight {
+		Weight::from_parts(13_207_000, 3_581)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule PendingTradingLimits (r:1 w:1)
+	/// Storage: VulnModule SelfTradingLimits (r:1 w:1)
+	fn set_trading_limit() -> Weight {
+		Weight::from_parts(14_863_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: VulnModule ImposedTradingLimits (r:0 w:1)
+	fn impose_trading_limit() -> Weight {
+		Weight::from_parts(9_985_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule OwnerOfCollectibles (r:1 w:1)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule AcceptsMints (r:1 w:0)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule FounderBadgeLimit (r:1 w:0)
+	/// Storage: VulnModule FounderBadgesIssued (r:1 w:1)
+	/// Storage: VulnModule FounderBadges (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:0 w:1)
+	/// Storage: VulnModule ItemIdAt (r:0 w:1)
+	/// Storage: VulnModule Licenses (r:0 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule MintDeposits (r:0 w:1)
+	fn create_licensed_collectible() -> Weight {
+		Weight::from_parts(27_105_000, 4_339)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
+	/// Storage: VulnModule MetadataVersions (r:1 w:1)
+	fn publish_metadata_version() -> Weight {
+		Weight::from_parts(11_349_000, 3_505)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule BuyBook (r:1 w:1)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule SellBook (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:n w:0)
+	/// Storage: VulnModule CollectibleMap (r:n w:n)
+	/// Storage: VulnModule ListingDeposits (r:n w:n)
+	/// Storage: VulnModule MintDeposits (r:n w:n)
+	/// Storage: VulnModule MetadataDeposits (r:n w:n)
+	/// Storage: System Account (r:2 w:2)
+	/// The range of component `n` is `[0, 100]`.
+	fn force_remap_owner(n: u32, ) -> Weight {
+		Weight::from_parts(31_447_000, 32_116)
+			.saturating_add(Weight::from_parts(21_930_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2_597).saturating_mul(n.into()))
+	}
+	/// Storage: VulnModule CollectibleMap (r:n w:n)
+	/// Storage: VulnModule RoyaltiesEnforced (r:1 w:0)
+	/// Storage: VulnModule Frozen (r:n w:0)
+	/// Storage: VulnModule OwnerOfCollectibles (r:n+1 w:n+1)
+	/// Storage: VulnModule ListingDeposits (r:n w:0)
+	/// Storage: VulnModule ListedAt (r:0 w:n)
+	/// Storage: VulnModule RentalRates (r:0 w:n)
+	/// Storage: VulnModule Collections (r:n w:0)
+	/// The range of component `n` is `[1, 100]`.
+	fn distribute(n: u32, ) -> Weight {
+		Weight::from_parts(12_904_000, 3_505)
+			.saturating_add(Weight::from_parts(22_618_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5_098).saturating_mul(n.into()))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule CooldownUntil (r:1 w:0)
+	/// Storage: VulnModule AuctionsEnding (r:1 w:1)
+	/// Storage: VulnModule Auctions (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	fn start_auction() -> Weight {
+		Weight::from_parts(28_114_000, 3_581)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: VulnModule Auctions (r:1 w:1)
+	/// Storage: VulnModule PendingTradingLimits (r:2 w:0)
+	/// Storage: VulnModule SelfTradingLimits (r:2 w:0)
+	/// Storage: VulnModule ImposedTradingLimits (r:2 w:0)
+	/// Storage: System Account (r:2 w:2)
+	fn place_bid() -> Weight {
+		Weight::from_parts(46_902_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: VulnModule Auctions (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule ItemRoyalties (r:1 w:0)
+	/// Storage: VulnModule DefaultRoyalties (r:1 w:0)
+	/// Storage: System Account (r:3 w:3)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule CooldownUntil (r:0 w:1)
+	/// Storage: VulnModule VolumeBuckets (r:1 w:1)
+	/// Storage: VulnModule SaleReceipts (r:0 w:1)
+	/// Storage: VulnModule Collections (r:1 w:0)
+	/// Storage: VulnModule UnsettledAuctions (r:0 w:1)
+	fn settle_auction() -> Weight {
+		Weight::from_parts(71_380_000, 8_799)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
+	/// Storage: VulnModule NextCollectionId (r:1 w:1)
+	/// Storage: VulnModule Collections (r:0 w:1)
+	fn create_collection() -> Weight {
+		Weight::from_parts(11_402_000, 1_489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: VulnModule Collections (r:1 w:1)
+	fn set_collection_metadata() -> Weight {
+		Weight::from_parts(12_877_000, 3_706)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule Collections (r:1 w:1)
+	fn set_collection_settings() -> Weight {
+		Weight::from_parts(13_104_000, 3_706)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule Collections (r:1 w:1)
+	fn publish_collection_metadata_version() -> Weight {
+		Weight::from_parts(12_566_000, 3_706)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule Collections (r:1 w:1)
+	/// Storage: VulnModule CollectionFounders (r:1 w:0)
+	fn destroy_collection() -> Weight {
+		Weight::from_parts(12_211_000, 3_706)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule Collections (r:1 w:1)
+	/// Storage: VulnModule CollectionItems (r:n+1 w:n)
+	/// Storage: VulnModule CollectibleMap (r:n w:n)
+	/// Storage: VulnModule ForeignSources (r:n w:0)
+	/// Storage: VulnModule Frozen (r:n w:n)
+	/// Storage: VulnModule OwnerOfCollectibles (r:n w:n)
+	/// Storage: VulnModule ListingDeposits (r:n w:n)
+	/// Storage: VulnModule MintDeposits (r:n w:n)
+	/// Storage: System Account (r:n w:n)
+	/// Storage: VulnModule MetadataDeposits (r:n w:n)
+	/// Storage: VulnModule Attributes (r:16n w:16n)
+	/// Storage: VulnModule FloorIndex (r:n w:n)
+	/// Storage: VulnModule ColorFloorIndex (r:n w:n)
+	/// Storage: VulnModule ColorStatistics (r:n w:n)
+	/// Storage: VulnModule CollectiblesCount (r:n w:n)
+	/// Storage: VulnModule Offers (r:64n w:64n)
+	/// Storage: System Account (r:64n w:64n)
+	/// Storage: VulnModule OfferCounts (r:0 w:n)
+	/// Storage: VulnModule CollectionFounders (r:1 w:0)
+	/// The range of component `n` is `[1, 64]`.
+	fn destroy_collection_items(n: u32, ) -> Weight {
+		Weight::from_parts(12_604_000, 3_706)
+			.saturating_add(Weight::from_parts(39_115_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((158_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((171_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 4_339).saturating_mul(n.into()))
+	}
+	/// Storage: VulnModule Collections (r:1 w:1)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:3 w:3)
+	/// Storage: VulnModule OwnerOfCollectibles (r:1 w:1)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule AcceptsMints (r:1 w:0)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule FounderBadgeLimit (r:1 w:0)
+	/// Storage: VulnModule FounderBadgesIssued (r:1 w:1)
+	/// Storage: VulnModule FounderBadges (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:0 w:2)
+	/// Storage: VulnModule ItemIdAt (r:0 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule MintDeposits (r:0 w:1)
+	/// Storage: VulnModule CollectionItems (r:0 w:1)
+	/// Storage: VulnModule CollectionFounders (r:1 w:1)
+	fn create_collectible_in() -> Weight {
+		Weight::from_parts(30_684_000, 4_339)
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(18_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:0)
+	/// Storage: VulnModule OperatorApprovals (r:1 w:0)
+	/// Storage: VulnModule Approvals (r:0 w:1)
+	fn approve() -> Weight {
+		Weight::from_parts(14_930_000, 3_706)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:0)
+	/// Storage: VulnModule OperatorApprovals (r:1 w:0)
+	/// Storage: VulnModule Approvals (r:1 w:1)
+	fn cancel_approval() -> Weight {
+		Weight::from_parts(16_284_000, 3_706)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule OperatorApprovals (r:0 w:1)
+	fn set_approval_for_all() -> Weight {
+		Weight::from_parts(9_871_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:0)
+	/// Storage: VulnModule Offers (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule OfferCounts (r:1 w:1)
+	fn make_offer() -> Weight {
+		Weight::from_parts(41_563_000, 3_706)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Approvals (r:1 w:1)
+	/// Storage: VulnModule OperatorApprovals (r:1 w:0)
+	/// Storage: VulnModule Offers (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:0)
+	/// Storage: VulnModule CooldownUntil (r:1 w:1)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: VulnModule PendingTradingLimits (r:2 w:0)
+	/// Storage: VulnModule SelfTradingLimits (r:2 w:0)
+	/// Storage: VulnModule ImposedTradingLimits (r:2 w:0)
+	/// Storage: VulnModule ItemRoyalties (r:1 w:0)
+	/// Storage: VulnModule DefaultRoyalties (r:1 w:0)
+	/// Storage: System Account (r:3 w:3)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule VolumeBuckets (r:1 w:1)
+	/// Storage: VulnModule SaleReceipts (r:0 w:1)
+	/// Storage: VulnModule Collections (r:1 w:0)
+	/// Storage: VulnModule OfferCounts (r:1 w:1)
+	fn accept_offer() -> Weight {
+		Weight::from_parts(84_217_000, 8_799)
+			.saturating_add(T::DbWeight::get().reads(24_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+	}
+	/// Storage: VulnModule Offers (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule OfferCounts (r:1 w:1)
+	fn withdraw_offer() -> Weight {
+		Weight::from_parts(33_740_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:n w:n)
+	/// Storage: VulnModule Approvals (r:n w:n)
+	/// Storage: VulnModule OperatorApprovals (r:n w:0)
+	/// Storage: VulnModule RoyaltiesEnforced (r:1 w:0)
+	/// Storage: VulnModule ApprovedMarkets (r:1 w:0)
+	/// Storage: VulnModule Frozen (r:n w:0)
+	/// Storage: VulnModule OwnerOfCollectibles (r:n+1 w:n+1)
+	/// Storage: VulnModule ListingDeposits (r:n w:0)
+	/// Storage: VulnModule ListedAt (r:0 w:n)
+	/// Storage: VulnModule RentalRates (r:0 w:n)
+	/// Storage: VulnModule Collections (r:n w:0)
+	/// The range of component `n` is `[1, 100]`.
+	fn transfer_many(n: u32, ) -> Weight {
+		Weight::from_parts(13_215_000, 3_505)
+			.saturating_add(Weight::from_parts(25_904_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5_098).saturating_mul(n.into()))
+	}
+	/// Storage: VulnModule AcceptsMints (r:1 w:0)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:n w:n)
+	/// Storage: VulnModule OwnerOfCollectibles (r:1 w:1)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:n w:n)
+	/// Storage: VulnModule FounderBadgeLimit (r:1 w:0)
+	/// Storage: VulnModule FounderBadgesIssued (r:1 w:1)
+	/// Storage: VulnModule FounderBadges (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:0 w:1)
+	/// Storage: VulnModule ItemIdAt (r:0 w:n)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule MintDeposits (r:0 w:n)
+	/// The range of component `n` is `[1, MaxMintsPerBlock]`.
+	fn mint_batch(n: u32, ) -> Weight {
+		Weight::from_parts(20_546_000, 4_339)
+			.saturating_add(Weight::from_parts(15_108_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2_581).saturating_mul(n.into()))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:0)
+	/// Storage: VulnModule OnChainMetadata (r:1 w:1)
+	/// Storage: VulnModule MetadataDeposits (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn set_metadata() -> Weight {
+		Weight::from_parts(48_317_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:0)
+	/// Storage: VulnModule OnChainMetadata (r:1 w:1)
+	/// Storage: VulnModule MetadataDeposits (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn clear_metadata() -> Weight {
+		Weight::from_parts(45_902_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:0)
+	/// Storage: VulnModule Attributes (r:1 w:1)
+	/// Storage: VulnModule AttributeCounts (r:1 w:1)
+	/// Storage: VulnModule MetadataDeposits (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn set_attribute() -> Weight {
+		Weight::from_parts(51_066_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:0)
+	/// Storage: VulnModule Attributes (r:1 w:1)
+	/// Storage: VulnModule AttributeCounts (r:1 w:1)
+	/// Storage: VulnModule MetadataDeposits (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn clear_attribute() -> Weight {
+		Weight::from_parts(47_781_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:0)
+	/// Storage: VulnModule Frozen (r:2 w:1)
+	fn freeze() -> Weight {
+		Weight::from_parts(14_938_000, 3_581)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:0)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	fn thaw() -> Weight {
+		Weight::from_parts(14_612_000, 3_581)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:2 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule SwapDeadlines (r:1 w:1)
+	/// Storage: VulnModule SwapCursor (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule PendingSwaps (r:0 w:1)
+	fn create_swap() -> Weight {
+		Weight::from_parts(52_318_000, 9_668)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: VulnModule PendingSwaps (r:1 w:1)
+	/// Storage: VulnModule SwapDeadlines (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:2 w:2)
+	/// Storage: VulnModule RoyaltiesEnforced (r:1 w:0)
+	/// Storage: VulnModule ApprovedMarkets (r:2 w:0)
+	/// Storage: VulnModule Frozen (r:2 w:1)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule ListedAt (r:0 w:2)
+	/// Storage: VulnModule Approvals (r:0 w:2)
+	/// Storage: VulnModule RentalRates (r:0 w:2)
+	/// Storage: VulnModule ListingDeposits (r:2 w:0)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: VulnModule Collections (r:2 w:0)
+	fn claim_swap() -> Weight {
+		Weight::from_parts(71_904_000, 9_668)
+			.saturating_add(T::DbWeight::get().reads(18_u64))
+			.saturating_add(T::DbWeight::get().writes(15_u64))
+	}
+	/// Storage: VulnModule PendingSwaps (r:1 w:1)
+	/// Storage: VulnModule SwapDeadlines (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:0 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn cancel_swap() -> Weight {
+		Weight::from_parts(31_226_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: VulnModule MintsThisBlock (r:0 w:1)
+	fn on_initialize() -> Weight {
+		Weight::from_parts(1_874_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule OwnerOfCollectibles (r:1 w:1)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule AcceptsMints (r:1 w:0)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule FounderBadgeLimit (r:1 w:0)
+	/// Storage: VulnModule FounderBadgesIssued (r:1 w:1)
+	/// Storage: VulnModule FounderBadges (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:0 w:1)
+	/// Storage: VulnModule ItemIdAt (r:0 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule MintDeposits (r:0 w:1)
+	fn create_collectible() -> Weight {
+		Weight::from_parts(25_431_000, 4_339)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule RoyaltiesEnforced (r:1 w:0)
+	/// Storage: VulnModule ApprovedMarkets (r:2 w:0)
+	/// Storage: VulnModule Frozen (r:1 w:0)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule RentalRates (r:0 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule Approvals (r:1 w:1)
+	/// Storage: VulnModule OperatorApprovals (r:1 w:0)
+	/// Storage: VulnModule Collections (r:1 w:0)
+	fn transfer() -> Weight {
+		Weight::from_parts(46_210_000, 9_668)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule OwnerOfCollectibles (r:1 w:1)
+	/// Storage: VulnModule MysteryBoxes (r:0 w:1)
+	/// Storage: VulnModule CooldownUntil (r:0 w:1)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule RevealRecords (r:0 w:1)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule MetadataCids (r:0 w:1)
+	/// Storage: VulnModule PinQueue (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule RentalRates (r:0 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule RecentSales (r:0 w:1)
+	/// Storage: VulnModule WashTraded (r:0 w:1)
+	/// Storage: VulnModule ForeignSources (r:1 w:0)
+	/// Storage: VulnModule Approvals (r:0 w:1)
+	/// Storage: VulnModule MintDeposits (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule MetadataDeposits (r:1 w:1)
+	/// Storage: VulnModule OnChainMetadata (r:0 w:1)
+	/// Storage: VulnModule Attributes (r:16 w:16)
+	/// Storage: VulnModule AttributeCounts (r:0 w:1)
+	/// Storage: VulnModule Offers (r:64 w:64)
+	/// Storage: System Account (r:64 w:64)
+	/// Storage: VulnModule OfferCounts (r:0 w:1)
+	fn burn() -> Weight {
+		Weight::from_parts(37_604_000, 4_339)
+			.saturating_add(RocksDbWeight::get().reads(157_u64))
+			.saturating_add(RocksDbWeight::get().writes(170_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:0)
+	/// Storage: VulnModule CooldownUntil (r:1 w:1)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule WashTraded (r:1 w:0)
+	/// Storage: VulnModule Approvals (r:1 w:0)
+	/// Storage: VulnModule OperatorApprovals (r:1 w:0)
+	fn set_price() -> Weight {
+		Weight::from_parts(24_512_000, 3_581)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:0)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule ListedAt (r:1 w:1)
+	/// Storage: System Account (r:6 w:6)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: Identity IdentityOf (r:2 w:0)
+	/// Storage: VulnModule CooldownUntil (r:0 w:1)
+	/// Storage: VulnModule ListingCharities (r:1 w:1)
+	/// Storage: VulnModule ItemRoyalties (r:1 w:0)
+	/// Storage: VulnModule DefaultRoyalties (r:1 w:0)
+	/// Storage: VulnModule PendingTradingLimits (r:2 w:0)
+	/// Storage: VulnModule SelfTradingLimits (r:2 w:0)
+	/// Storage: VulnModule ImposedTradingLimits (r:2 w:0)
+	/// Storage: VulnModule TradingWindows (r:2 w:2)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: VulnModule RentalRates (r:0 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule VolumeBuckets (r:1 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule RecentSales (r:1 w:1)
+	/// Storage: VulnModule WashTraded (r:1 w:1)
+	/// Storage: VulnModule IssuesReceipts (r:1 w:0)
+	/// Storage: VulnModule SaleReceipts (r:0 w:1)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule Approvals (r:0 w:1)
+	/// Storage: VulnModule Collections (r:1 w:0)
+	fn buy_collectible() -> Weight {
+		Weight::from_parts(121_806_000, 23_941)
+			.saturating_add(RocksDbWeight::get().reads(37_u64))
+			.saturating_add(RocksDbWeight::get().writes(28_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule SealedAuctions (r:0 w:1)
+	/// Storage: VulnModule CooldownUntil (r:1 w:0)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule SealedAuctionsEnding (r:1 w:1)
+	fn start_sealed_auction() -> Weight {
+		Weight::from_parts(26_307_000, 3_581)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: VulnModule SealedAuctions (r:1 w:1)
+	/// Storage: VulnModule SealedBids (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn commit_bid() -> Weight {
+		Weight::from_parts(40_118_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: VulnModule SealedAuctions (r:1 w:1)
+	/// Storage: VulnModule SealedBids (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn reveal_bid() -> Weight {
+		Weight::from_parts(55_364_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: VulnModule SealedAuctions (r:1 w:1)
+	/// Storage: VulnModule SealedBids (r:b w:b)
+	/// Storage: System Account (r:b+1 w:b+1)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: VulnModule ListingDeposits (r:1 w:0)
+	/// Storage: VulnModule RentalRates (r:0 w:1)
+	/// Storage: VulnModule VolumeBuckets (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule RecentSales (r:1 w:1)
+	/// Storage: VulnModule WashTraded (r:1 w:1)
+	/// Storage: VulnModule IssuesReceipts (r:1 w:0)
+	/// Storage: VulnModule SaleReceipts (r:0 w:1)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule Collections (r:1 w:0)
+	/// Storage: VulnModule ItemRoyalties (r:1 w:0)
+	/// Storage: VulnModule DefaultRoyalties (r:1 w:0)
+	/// Storage: VulnModule UnsettledAuctions (r:0 w:1)
+	/// The range of component `b` is `[1, MaxSealedBids]`.
+	fn settle_sealed_auction(b: u32, ) -> Weight {
+		Weight::from_parts(79_502_000, 9_668)
+			.saturating_add(Weight::from_parts(29_734_000, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(21_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes(19_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 2_603).saturating_mul(b.into()))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule Raffles (r:0 w:1)
+	/// Storage: VulnModule CooldownUntil (r:1 w:0)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	fn start_raffle() -> Weight {
+		Weight::from_parts(26_118_000, 3_581)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: VulnModule Raffles (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule RaffleTickets (r:0 w:c)
+	/// The range of component `c` is `[1, MaxRaffleTickets]`.
+	fn buy_tickets(c: u32, ) -> Weight {
+		Weight::from_parts(35_240_000, 3_593)
+			.saturating_add(Weight::from_parts(4_102_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+	}
+	/// Storage: VulnModule Raffles (r:1 w:1)
+	/// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	/// Storage: VulnModule RaffleTickets (r:t w:t)
+	/// Storage: System Account (r:t+1 w:t+1)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: VulnModule ListingDeposits (r:1 w:0)
+	/// Storage: VulnModule RentalRates (r:0 w:1)
+	/// Storage: VulnModule VolumeBuckets (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule RecentSales (r:1 w:1)
+	/// Storage: VulnModule WashTraded (r:1 w:1)
+	/// Storage: VulnModule IssuesReceipts (r:1 w:0)
+	/// Storage: VulnModule SaleReceipts (r:0 w:1)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule Collections (r:1 w:0)
+	/// Storage: VulnModule ItemRoyalties (r:1 w:0)
+	/// Storage: VulnModule DefaultRoyalties (r:1 w:0)
+	/// The range of component `t` is `[1, MaxRaffleTickets]`.
+	fn draw_raffle(t: u32, ) -> Weight {
+		Weight::from_parts(61_675_000, 9_668)
+			.saturating_add(Weight::from_parts(28_410_000, 0).saturating_mul(t.into()))
+			.saturating_add(RocksDbWeight::get().reads(21_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(t.into())))
+			.saturating_add(RocksDbWeight::get().writes(17_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 2_603).saturating_mul(t.into()))
+	}
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule OwnerOfCollectibles (r:1 w:1)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: VulnModule MysteryBoxes (r:0 w:1)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule AcceptsMints (r:1 w:0)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule FounderBadgeLimit (r:1 w:0)
+	/// Storage: VulnModule FounderBadgesIssued (r:1 w:1)
+	/// Storage: VulnModule FounderBadges (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:0 w:1)
+	/// Storage: VulnModule ItemIdAt (r:0 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule MintDeposits (r:0 w:1)
+	fn mint_mystery_box() -> Weight {
+		Weight::from_parts(28_903_000, 4_339)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
+	/// Storage: VulnModule MysteryBoxes (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	/// Storage: VulnModule RevealRecords (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:2 w:2)
+	/// Storage: VulnModule ColorStatistics (r:2 w:2)
+	fn reveal() -> Weight {
+		Weight::from_parts(30_226_000, 3_581)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: VulnModule RoyaltiesEnforced (r:0 w:1)
+	fn set_royalty_enforcement() -> Weight {
+		Weight::from_parts(8_104_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule ApprovedMarkets (r:0 w:1)
+	fn approve_market() -> Weight {
+		Weight::from_parts(10_287_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule ApprovedMarkets (r:1 w:1)
+	fn remove_market() -> Weight {
+		Weight::from_parts(13_519_000, 3_509)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule TransferCooldown (r:0 w:1)
+	fn set_transfer_cooldown() -> Weight {
+		Weight::from_parts(8_051_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule Escrows (r:0 w:1)
+	/// Storage: VulnModule ListedAt (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	fn buy_with_escrow() -> Weight {
+		Weight::from_parts(45_733_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: VulnModule Escrows (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:2 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: VulnModule ListingDeposits (r:1 w:0)
+	/// Storage: VulnModule RentalRates (r:0 w:1)
+	/// Storage: VulnModule VolumeBuckets (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule RecentSales (r:1 w:1)
+	/// Storage: VulnModule WashTraded (r:1 w:1)
+	/// Storage: VulnModule IssuesReceipts (r:1 w:0)
+	/// Storage: VulnModule SaleReceipts (r:0 w:1)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule Collections (r:1 w:0)
+	fn release_escrow() -> Weight {
+		Weight::from_parts(75_962_000, 9_668)
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().writes(17_u64))
+	}
+	/// Storage: VulnModule Escrows (r:1 w:0)
+	/// Storage: VulnModule Disputes (r:0 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	fn raise_dispute() -> Weight {
+		Weight::from_parts(38_420_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: JurorMembership Members (r:1 w:0)
+	/// Storage: VulnModule Disputes (r:1 w:1)
+	/// Storage: VulnModule DisputeVotes (r:1 w:1)
+	fn vote_dispute() -> Weight {
+		Weight::from_parts(25_187_000, 3_509)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: VulnModule Disputes (r:1 w:1)
+	/// Storage: VulnModule Escrows (r:1 w:1)
+	/// Storage: JurorMembership Members (r:1 w:0)
+	/// Storage: VulnModule DisputeVotes (r:0 w:1)
+	/// Storage: System Account (r:3 w:3)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:2)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: VulnModule ListingDeposits (r:1 w:0)
+	/// Storage: VulnModule RentalRates (r:0 w:1)
+	/// Storage: VulnModule VolumeBuckets (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule RecentSales (r:1 w:1)
+	/// Storage: VulnModule WashTraded (r:1 w:1)
+	/// Storage: VulnModule IssuesReceipts (r:1 w:0)
+	/// Storage: VulnModule SaleReceipts (r:0 w:1)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule Collections (r:1 w:0)
+	fn resolve_dispute() -> Weight {
+		Weight::from_parts(112_645_000, 9_668)
+			.saturating_add(RocksDbWeight::get().reads(22_u64))
+			.saturating_add(RocksDbWeight::get().writes(21_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	fn cancel_listing() -> Weight {
+		Weight::from_parts(20_348_000, 3_581)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule ListedAt (r:1 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	fn clear_expired_listing() -> Weight {
+		Weight::from_parts(22_615_000, 3_581)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	fn flag_listing() -> Weight {
+		Weight::from_parts(31_204_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	fn set_metadata_hash() -> Weight {
+		Weight::from_parts(18_930_000, 3_581)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:0)
+	/// Storage: VulnModule MetadataCids (r:0 w:1)
+	/// Storage: VulnModule PinQueue (r:0 w:1)
+	fn set_metadata_cid() -> Weight {
+		Weight::from_parts(20_772_000, 3_581)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: VulnModule AcceptsMints (r:0 w:1)
+	fn set_accept_mints() -> Weight {
+		Weight::from_parts(10_114_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule PendingMints (r:1 w:1)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:c w:c)
+	/// Storage: VulnModule OwnerOfCollectibles (r:1 w:1)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: VulnModule ColorStatistics (r:c w:c)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule MintDeposits (r:0 w:c)
+	/// The range of component `c` is `[1, MaxMintsPerBlock]`.
+	fn claim_mints(c: u32, ) -> Weight {
+		Weight::from_parts(19_870_000, 4_339)
+			.saturating_add(Weight::from_parts(14_262_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2_581).saturating_mul(c.into()))
+	}
+	/// Storage: VulnModule PinQueue (r:1 w:1)
+	fn confirm_pin() -> Weight {
+		Weight::from_parts(12_406_000, 3_509)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:0)
+	/// Storage: VulnModule RentalRates (r:0 w:1)
+	fn offer_rental() -> Weight {
+		Weight::from_parts(17_402_000, 3_581)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:0)
+	/// Storage: VulnModule RentalRates (r:1 w:0)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: PaymentStreams NextStreamId (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: PaymentStreams Streams (r:0 w:1)
+	/// Storage: VulnModule Rentals (r:0 w:1)
+	fn rent() -> Weight {
+		Weight::from_parts(44_730_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: VulnModule Rentals (r:1 w:0)
+	/// Storage: PaymentStreams Streams (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn top_up_rental() -> Weight {
+		Weight::from_parts(36_284_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: VulnModule Rentals (r:1 w:1)
+	/// Storage: PaymentStreams Streams (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: VulnModule Frozen (r:0 w:1)
+	fn settle_rental() -> Weight {
+		Weight::from_parts(58_912_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: VulnModule Rentals (r:1 w:1)
+	/// Storage: PaymentStreams Streams (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: VulnModule Frozen (r:0 w:1)
+	fn end_rental() -> Weight {
+		Weight::from_parts(63_470_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule ListedAt (r:1 w:1)
+	/// Storage: Identity IdentityOf (r:2 w:0)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: VulnModule InstallmentDeadlines (r:1 w:1)
+	/// Storage: VulnModule InstallmentCursor (r:1 w:1)
+	/// Storage: VulnModule InstallmentPlans (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule RecentSales (r:1 w:1)
+	/// Storage: VulnModule WashTraded (r:1 w:1)
+	/// Storage: VulnModule IssuesReceipts (r:1 w:0)
+	/// Storage: VulnModule SaleReceipts (r:0 w:1)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule Collections (r:1 w:0)
+	fn buy_in_installments() -> Weight {
+		Weight::from_parts(52_840_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().writes(19_u64))
+	}
+	/// Storage: VulnModule InstallmentPlans (r:1 w:1)
+	/// Storage: System Account (r:3 w:3)
+	/// Storage: VulnModule InstallmentDeadlines (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule RentalRates (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:0)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: VulnModule CooldownUntil (r:0 w:1)
+	/// Storage: VulnModule VolumeBuckets (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule RecentSales (r:1 w:1)
+	/// Storage: VulnModule WashTraded (r:1 w:1)
+	/// Storage: VulnModule IssuesReceipts (r:1 w:0)
+	/// Storage: VulnModule SaleReceipts (r:0 w:1)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule Collections (r:1 w:0)
+	fn pay_installment() -> Weight {
+		Weight::from_parts(81_377_000, 9_668)
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().writes(20_u64))
+	}
+	/// Storage: VulnModule InstallmentPlans (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:0 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn enforce_installment_default() -> Weight {
+		Weight::from_parts(46_902_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:0)
+	/// Storage: VulnModule CooldownUntil (r:1 w:1)
+	/// Storage: VulnModule BuyBook (r:1 w:1)
+	/// Storage: Identity IdentityOf (r:2 w:0)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule RentalRates (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:0)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule VolumeBuckets (r:1 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule RecentSales (r:1 w:1)
+	/// Storage: VulnModule WashTraded (r:1 w:1)
+	/// Storage: VulnModule IssuesReceipts (r:1 w:0)
+	/// Storage: VulnModule SaleReceipts (r:0 w:1)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule Collections (r:1 w:0)
+	fn place_sell_order() -> Weight {
+		Weight::from_parts(71_208_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(23_u64))
+			.saturating_add(RocksDbWeight::get().writes(20_u64))
+	}
+	/// Storage: System Account (r:q+1 w:q+1)
+	/// Storage: VulnModule SellBook (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:q w:q)
+	/// Storage: VulnModule CollectibleMap (r:q w:q)
+	/// Storage: Identity IdentityOf (r:2q w:0)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2q w:2q)
+	/// Storage: VulnModule ListedAt (r:0 w:q)
+	/// Storage: VulnModule RentalRates (r:0 w:q)
+	/// Storage: VulnModule ListingDeposits (r:q w:0)
+	/// Storage: VulnModule TransferCooldown (r:q w:0)
+	/// Storage: VulnModule CooldownUntil (r:0 w:q)
+	/// Storage: VulnModule NextOrderId (r:1 w:1)
+	/// Storage: VulnModule BuyBook (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:q w:q)
+	/// Storage: VulnModule FloorPrice (r:0 w:q)
+	/// Storage: VulnModule VolumeBuckets (r:q w:q)
+	/// Storage: VulnModule ColorFloorIndex (r:q w:q)
+	/// Storage: VulnModule ColorStatistics (r:q w:q)
+	/// Storage: VulnModule RecentSales (r:q w:q)
+	/// Storage: VulnModule WashTraded (r:q w:q)
+	/// Storage: VulnModule IssuesReceipts (r:q w:0)
+	/// Storage: VulnModule SaleReceipts (r:0 w:q)
+	/// Storage: VulnModule CollectiblesCount (r:q w:q)
+	/// Storage: VulnModule NextCollectibleId (r:q w:q)
+	/// Storage: VulnModule MintsThisBlock (r:q w:q)
+	/// Storage: VulnModule Collections (r:q w:0)
+	/// The range of component `q` is `[1, MaxBookDepth]`.
+	fn place_buy_order(q: u32, ) -> Weight {
+		Weight::from_parts(38_541_000, 6_196)
+			.saturating_add(Weight::from_parts(52_716_000, 0).saturating_mul(q.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((20_u64).saturating_mul(q.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((20_u64).saturating_mul(q.into())))
+			.saturating_add(Weight::from_parts(0, 2_603).saturating_mul(q.into()))
+	}
+	/// Storage: VulnModule BuyBook (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn cancel_buy_order() -> Weight {
+		Weight::from_parts(31_905_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: VulnModule SellBook (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:0 w:1)
+	fn cancel_sell_order() -> Weight {
+		Weight::from_parts(19_374_000, 1_489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:n w:n)
+	/// Storage: VulnModule Frozen (r:n w:0)
+	/// Storage: VulnModule CooldownUntil (r:n w:0)
+	/// Storage: VulnModule ListedAt (r:0 w:n)
+	/// Storage: VulnModule ListingDeposits (r:n w:n)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:n w:n)
+	/// Storage: VulnModule FloorPrice (r:0 w:n)
+	/// Storage: VulnModule ColorFloorIndex (r:n w:n)
+	/// Storage: VulnModule ColorStatistics (r:n w:n)
+	/// Storage: VulnModule WashTraded (r:n w:0)
+	/// The range of component `n` is `[1, MaximumOwned]`.
+	fn set_prices(n: u32, ) -> Weight {
+		Weight::from_parts(9_861_000, 3_581)
+			.saturating_add(Weight::from_parts(17_904_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((8_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((8_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2_549).saturating_mul(n.into()))
+	}
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:0)
+	/// Storage: VulnModule CollectibleMap (r:n w:0)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// The range of component `n` is `[1, 1000]`.
+	fn rebuild_floor_index(n: u32, ) -> Weight {
+		Weight::from_parts(11_207_000, 3_745)
+			.saturating_add(Weight::from_parts(4_318_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 2_581).saturating_mul(n.into()))
+	}
+	/// Storage: VulnModule SealedAuctions (r:1 w:1)
+	/// Storage: VulnModule SealedBids (r:b w:b)
+	/// Storage: System Account (r:b w:b)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule Escrows (r:1 w:1)
+	/// Storage: VulnModule Disputes (r:1 w:1)
+	/// Storage: VulnModule DisputeVotes (r:0 w:1)
+	/// Storage: VulnModule UnsettledAuctions (r:0 w:1)
+	/// The range of component `b` is `[1, MaxSealedBids]`.
+	fn reclaim_escrow(b: u32, ) -> Weight {
+		Weight::from_parts(41_236_000, 6_196)
+			.saturating_add(Weight::from_parts(24_310_000, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 2_603).saturating_mul(b.into()))
+	}
+	/// Storage: VulnModule IssuesReceipts (r:0 w:1)
+	fn set_sale_receipts() -> Weight {
+		Weight::from_parts(8_612_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule FounderBadgeLimit (r:0 w:1)
+	fn set_founder_badge_limit() -> Weight {
+		Weight::from_parts(7_904_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule ForeignDerivatives (r:1 w:1)
+	/// Storage: VulnModule ForeignSources (r:0 w:1)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule OwnerOfCollectibles (r:1 w:1)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule AcceptsMints (r:1 w:0)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule CooldownUntil (r:0 w:1)
+	/// Storage: VulnModule ItemIdAt (r:0 w:1)
+	fn register_foreign() -> Weight {
+		Weight::from_parts(24_187_000, 4_339)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule ForeignSources (r:1 w:1)
+	/// Storage: VulnModule ForeignDerivatives (r:0 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule OwnerOfCollectibles (r:1 w:1)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule Offers (r:64 w:64)
+	/// Storage: System Account (r:64 w:64)
+	/// Storage: VulnModule OfferCounts (r:0 w:1)
+	fn redeem_foreign() -> Weight {
+		Weight::from_parts(39_215_000, 4_339)
+			.saturating_add(RocksDbWeight::get().reads(138_u64))
+			.saturating_add(RocksDbWeight::get().writes(150_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule RoyaltiesEnforced (r:1 w:0)
+	/// Storage: VulnModule ApprovedMarkets (r:2 w:0)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule TimedTransfersDue (r:1 w:1)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule TimedTransfers (r:0 w:1)
+	/// Storage: VulnModule Collections (r:1 w:0)
+	fn transfer_at() -> Weight {
+		Weight::from_parts(43_587_000, 9_668)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	/// Storage: VulnModule TimedTransfers (r:1 w:1)
+	/// Storage: VulnModule TimedTransfersDue (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:0 w:1)
+	fn cancel_transfer_at() -> Weight {
+		Weight::from_parts(17_942_000, 5_293)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: VulnModule TimedTransfers (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:0)
+	/// Storage: VulnModule RentalRates (r:0 w:1)
+	fn complete_timed_transfer() -> Weight {
+		Weight::from_parts(28_406_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: VulnModule Inheritances (r:0 w:1)
+	/// Storage: System Account (r:1 w:0)
+	fn set_heir() -> Weight {
+		Weight::from_parts(14_822_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule Inheritances (r:1 w:1)
+	/// Storage: System Account (r:1 w:0)
+	fn start_inheritance() -> Weight {
+		Weight::from_parts(17_305_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule Inheritances (r:1 w:1)
+	/// Storage: System Account (r:1 w:0)
+	fn veto_inheritance() -> Weight {
+		Weight::from_parts(16_118_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule Inheritances (r:1 w:1)
+	/// Storage: System Account (r:1 w:0)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule CollectibleMap (r:n w:n)
+	/// Storage: VulnModule Frozen (r:n w:0)
+	/// Storage: VulnModule ListingDeposits (r:n w:0)
+	/// Storage: VulnModule ListedAt (r:0 w:n)
+	/// Storage: VulnModule RentalRates (r:0 w:n)
+	/// The range of component `n` is `[0, 100]`.
+	fn complete_inheritance(n: u32, ) -> Weight {
+		Weight::from_parts(21_460_000, 6_196)
+			.saturating_add(Weight::from_parts(19_874_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2_549).saturating_mul(n.into()))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:0)
+	/// Storage: VulnModule CooldownUntil (r:1 w:1)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule WashTraded (r:1 w:0)
+	/// Storage: VulnModule ListingCharities (r:0 w:1)
+	fn list_with_charity() -> Weight {
+		Weight::from_parts(27_036_000, 3_581)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: VulnModule DefaultRoyalties (r:0 w:1)
+	fn set_default_royalty() -> Weight {
+		Weight::from_parts(9_412_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:0)
+	/// Storage: VulnModule ItemRoyalties (r:0 w:1)
+	fn set_item_royalty() -> Weight {
+		Weight::from_parts(13_207_000, 3_581)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule PendingTradingLimits (r:1 w:1)
+	/// Storage: VulnModule SelfTradingLimits (r:1 w:1)
+	fn set_trading_limit() -> Weight {
+		Weight::from_parts(14_863_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: VulnModule ImposedTradingLimits (r:0 w:1)
+	fn impose_trading_limit() -> Weight {
+		Weight::from_parts(9_985_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule OwnerOfCollectibles (r:1 w:1)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule AcceptsMints (r:1 w:0)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule FounderBadgeLimit (r:1 w:0)
+	/// Storage: VulnModule FounderBadgesIssued (r:1 w:1)
+	/// Storage: VulnModule FounderBadges (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:0 w:1)
+	/// Storage: VulnModule ItemIdAt (r:0 w:1)
+	/// Storage: VulnModule Licenses (r:0 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule MintDeposits (r:0 w:1)
+	fn create_licensed_collectible() -> Weight {
+		Weight::from_parts(27_105_000, 4_339)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
+	/// Storage: VulnModule MetadataVersions (r:1 w:1)
+	fn publish_metadata_version() -> Weight {
+		Weight::from_parts(11_349_000, 3_505)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule BuyBook (r:1 w:1)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule SellBook (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:n w:0)
+	/// Storage: VulnModule CollectibleMap (r:n w:n)
+	/// Storage: VulnModule ListingDeposits (r:n w:n)
+	/// Storage: VulnModule MintDeposits (r:n w:n)
+	/// Storage: VulnModule MetadataDeposits (r:n w:n)
+	/// Storage: System Account (r:2 w:2)
+	/// The range of component `n` is `[0, 100]`.
+	fn force_remap_owner(n: u32, ) -> Weight {
+		Weight::from_parts(31_447_000, 32_116)
+			.saturating_add(Weight::from_parts(21_930_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2_597).saturating_mul(n.into()))
+	}
+	/// Storage: VulnModule CollectibleMap (r:n w:n)
+	/// Storage: VulnModule RoyaltiesEnforced (r:1 w:0)
+	/// Storage: VulnModule Frozen (r:n w:0)
+	/// Storage: VulnModule OwnerOfCollectibles (r:n+1 w:n+1)
+	/// Storage: VulnModule ListingDeposits (r:n w:0)
+	/// Storage: VulnModule ListedAt (r:0 w:n)
+	/// Storage: VulnModule RentalRates (r:0 w:n)
+	/// Storage: VulnModule Collections (r:n w:0)
+	/// The range of component `n` is `[1, 100]`.
+	fn distribute(n: u32, ) -> Weight {
+		Weight::from_parts(12_904_000, 3_505)
+			.saturating_add(Weight::from_parts(22_618_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5_098).saturating_mul(n.into()))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule CooldownUntil (r:1 w:0)
+	/// Storage: VulnModule AuctionsEnding (r:1 w:1)
+	/// Storage: VulnModule Auctions (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	fn start_auction() -> Weight {
+		Weight::from_parts(28_114_000, 3_581)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: VulnModule Auctions (r:1 w:1)
+	/// Storage: VulnModule PendingTradingLimits (r:2 w:0)
+	/// Storage: VulnModule SelfTradingLimits (r:2 w:0)
+	/// Storage: VulnModule ImposedTradingLimits (r:2 w:0)
+	/// Storage: System Account (r:2 w:2)
+	fn place_bid() -> Weight {
+		Weight::from_parts(46_902_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: VulnModule Auctions (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule ItemRoyalties (r:1 w:0)
+	/// Storage: VulnModule DefaultRoyalties (r:1 w:0)
+	/// Storage: System Account (r:3 w:3)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule CooldownUntil (r:0 w:1)
+	/// Storage: VulnModule VolumeBuckets (r:1 w:1)
+	/// Storage: VulnModule SaleReceipts (r:0 w:1)
+	/// Storage: VulnModule Collections (r:1 w:0)
+	/// Storage: VulnModule UnsettledAuctions (r:0 w:1)
+	fn settle_auction() -> Weight {
+		Weight::from_parts(71_380_000, 8_799)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
+	/// Storage: VulnModule NextCollectionId (r:1 w:1)
+	/// Storage: VulnModule Collections (r:0 w:1)
+	fn create_collection() -> Weight {
+		Weight::from_parts(11_402_000, 1_489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: VulnModule Collections (r:1 w:1)
+	fn set_collection_metadata() -> Weight {
+		Weight::from_parts(12_877_000, 3_706)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule Collections (r:1 w:1)
+	fn set_collection_settings() -> Weight {
+		Weight::from_parts(13_104_000, 3_706)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule Collections (r:1 w:1)
+	fn publish_collection_metadata_version() -> Weight {
+		Weight::from_parts(12_566_000, 3_706)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule Collections (r:1 w:1)
+	/// Storage: VulnModule CollectionFounders (r:1 w:0)
+	fn destroy_collection() -> Weight {
+		Weight::from_parts(12_211_000, 3_706)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule Collections (r:1 w:1)
+	/// Storage: VulnModule CollectionItems (r:n+1 w:n)
+	/// Storage: VulnModule CollectibleMap (r:n w:n)
+	/// Storage: VulnModule ForeignSources (r:n w:0)
+	/// Storage: VulnModule Frozen (r:n w:n)
+	/// Storage: VulnModule OwnerOfCollectibles (r:n w:n)
+	/// Storage: VulnModule ListingDeposits (r:n w:n)
+	/// Storage: VulnModule MintDeposits (r:n w:n)
+	/// Storage: System Account (r:n w:n)
+	/// Storage: VulnModule MetadataDeposits (r:n w:n)
+	/// Storage: VulnModule Attributes (r:16n w:16n)
+	/// Storage: VulnModule FloorIndex (r:n w:n)
+	/// Storage: VulnModule ColorFloorIndex (r:n w:n)
+	/// Storage: VulnModule ColorStatistics (r:n w:n)
+	/// Storage: VulnModule CollectiblesCount (r:n w:n)
+	/// Storage: VulnModule Offers (r:64n w:64n)
+	/// Storage: System Account (r:64n w:64n)
+	/// Storage: VulnModule OfferCounts (r:0 w:n)
+	/// Storage: VulnModule CollectionFounders (r:1 w:0)
+	/// The range of component `n` is `[1, 64]`.
+	fn destroy_collection_items(n: u32, ) -> Weight {
+		Weight::from_parts(12_604_000, 3_706)
+			.saturating_add(Weight::from_parts(39_115_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((158_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((171_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 4_339).saturating_mul(n.into()))
+	}
+	/// Storage: VulnModule Collections (r:1 w:1)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:3 w:3)
+	/// Storage: VulnModule OwnerOfCollectibles (r:1 w:1)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule AcceptsMints (r:1 w:0)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule FounderBadgeLimit (r:1 w:0)
+	/// Storage: VulnModule FounderBadgesIssued (r:1 w:1)
+	/// Storage: VulnModule FounderBadges (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:0 w:2)
+	/// Storage: VulnModule ItemIdAt (r:0 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule MintDeposits (r:0 w:1)
+	/// Storage: VulnModule CollectionItems (r:0 w:1)
+	/// Storage: VulnModule CollectionFounders (r:1 w:1)
+	fn create_collectible_in() -> Weight {
+		Weight::from_parts(30_684_000, 4_339)
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(18_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:0)
+	/// Storage: VulnModule OperatorApprovals (r:1 w:0)
+	/// Storage: VulnModule Approvals (r:0 w:1)
+	fn approve() -> Weight {
+		Weight::from_parts(14_930_000, 3_706)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:0)
+	/// Storage: VulnModule OperatorApprovals (r:1 w:0)
+	/// Storage: VulnModule Approvals (r:1 w:1)
+	fn cancel_approval() -> Weight {
+		Weight::from_parts(16_284_000, 3_706)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule OperatorApprovals (r:0 w:1)
+	fn set_approval_for_all() -> Weight {
+		Weight::from_parts(9_871_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:0)
+	/// Storage: VulnModule Offers (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule OfferCounts (r:1 w:1)
+	fn make_offer() -> Weight {
+		Weight::from_parts(41_563_000, 3_706)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:1)
+	/// Storage: VulnModule Approvals (r:1 w:1)
+	/// Storage: VulnModule OperatorApprovals (r:1 w:0)
+	/// Storage: VulnModule Offers (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:0)
+	/// Storage: VulnModule CooldownUntil (r:1 w:1)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: VulnModule PendingTradingLimits (r:2 w:0)
+	/// Storage: VulnModule SelfTradingLimits (r:2 w:0)
+	/// Storage: VulnModule ImposedTradingLimits (r:2 w:0)
+	/// Storage: VulnModule ItemRoyalties (r:1 w:0)
+	/// Storage: VulnModule DefaultRoyalties (r:1 w:0)
+	/// Storage: System Account (r:3 w:3)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule VolumeBuckets (r:1 w:1)
+	/// Storage: VulnModule SaleReceipts (r:0 w:1)
+	/// Storage: VulnModule Collections (r:1 w:0)
+	/// Storage: VulnModule OfferCounts (r:1 w:1)
+	fn accept_offer() -> Weight {
+		Weight::from_parts(84_217_000, 8_799)
+			.saturating_add(RocksDbWeight::get().reads(24_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+	}
+	/// Storage: VulnModule Offers (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule OfferCounts (r:1 w:1)
+	fn withdraw_offer() -> Weight {
+		Weight::from_parts(33_740_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:n w:n)
+	/// Storage: VulnModule Approvals (r:n w:n)
+	/// Storage: VulnModule OperatorApprovals (r:n w:0)
+	/// Storage: VulnModule RoyaltiesEnforced (r:1 w:0)
+	/// Storage: VulnModule ApprovedMarkets (r:1 w:0)
+	/// Storage: VulnModule Frozen (r:n w:0)
+	/// Storage: VulnModule OwnerOfCollectibles (r:n+1 w:n+1)
+	/// Storage: VulnModule ListingDeposits (r:n w:0)
+	/// Storage: VulnModule ListedAt (r:0 w:n)
+	/// Storage: VulnModule RentalRates (r:0 w:n)
+	/// Storage: VulnModule Collections (r:n w:0)
+	/// The range of component `n` is `[1, 100]`.
+	fn transfer_many(n: u32, ) -> Weight {
+		Weight::from_parts(13_215_000, 3_505)
+			.saturating_add(Weight::from_parts(25_904_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((8_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5_098).saturating_mul(n.into()))
+	}
+	/// Storage: VulnModule AcceptsMints (r:1 w:0)
+	/// Storage: VulnModule CollectiblesCount (r:1 w:1)
+	/// Storage: VulnModule NextCollectibleId (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:n w:n)
+	/// Storage: VulnModule OwnerOfCollectibles (r:1 w:1)
+	/// Storage: VulnModule TransferCooldown (r:1 w:0)
+	/// Storage: VulnModule MintsThisBlock (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:n w:n)
+	/// Storage: VulnModule FounderBadgeLimit (r:1 w:0)
+	/// Storage: VulnModule FounderBadgesIssued (r:1 w:1)
+	/// Storage: VulnModule FounderBadges (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:0 w:1)
+	/// Storage: VulnModule ItemIdAt (r:0 w:n)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule MintDeposits (r:0 w:n)
+	/// The range of component `n` is `[1, MaxMintsPerBlock]`.
+	fn mint_batch(n: u32, ) -> Weight {
+		Weight::from_parts(20_546_000, 4_339)
+			.saturating_add(Weight::from_parts(15_108_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2_581).saturating_mul(n.into()))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:0)
+	/// Storage: VulnModule OnChainMetadata (r:1 w:1)
+	/// Storage: VulnModule MetadataDeposits (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn set_metadata() -> Weight {
+		Weight::from_parts(48_317_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:0)
+	/// Storage: VulnModule OnChainMetadata (r:1 w:1)
+	/// Storage: VulnModule MetadataDeposits (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn clear_metadata() -> Weight {
+		Weight::from_parts(45_902_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:0)
+	/// Storage: VulnModule Attributes (r:1 w:1)
+	/// Storage: VulnModule AttributeCounts (r:1 w:1)
+	/// Storage: VulnModule MetadataDeposits (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn set_attribute() -> Weight {
+		Weight::from_parts(51_066_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:0)
+	/// Storage: VulnModule Attributes (r:1 w:1)
+	/// Storage: VulnModule AttributeCounts (r:1 w:1)
+	/// Storage: VulnModule MetadataDeposits (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn clear_attribute() -> Weight {
+		Weight::from_parts(47_781_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:0)
+	/// Storage: VulnModule Frozen (r:2 w:1)
+	fn freeze() -> Weight {
+		Weight::from_parts(14_938_000, 3_581)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:1 w:0)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	fn thaw() -> Weight {
+		Weight::from_parts(14_612_000, 3_581)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VulnModule CollectibleMap (r:2 w:1)
+	/// Storage: VulnModule Frozen (r:1 w:1)
+	/// Storage: VulnModule SwapDeadlines (r:1 w:1)
+	/// Storage: VulnModule SwapCursor (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: VulnModule ListedAt (r:0 w:1)
+	/// Storage: VulnModule ListingDeposits (r:1 w:1)
+	/// Storage: VulnModule FloorIndex (r:1 w:1)
+	/// Storage: VulnModule FloorPrice (r:0 w:1)
+	/// Storage: VulnModule ColorFloorIndex (r:1 w:1)
+	/// Storage: VulnModule ColorStatistics (r:1 w:1)
+	/// Storage: VulnModule PendingSwaps (r:0 w:1)
+	fn create_swap() -> Weight {
+		Weight::from_parts(52_318_000, 9_668)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	/// Storage: VulnModule PendingSwaps (r:1 w:1)
+	/// Storage: VulnModule SwapDeadlines (r:1 w:1)
+	/// Storage: VulnModule CollectibleMap (r:2 w:2)
+	/// Storage: VulnModule RoyaltiesEnforced (r:1 w:0)
+	/// Storage: VulnModule ApprovedMarkets (r:2 w:0)
+	/// Storage: VulnModule Frozen (r:2 w:1)
+	/// Storage: VulnModule OwnerOfCollectibles (r:2 w:2)
+	/// Storage: VulnModule ListedAt (r:0 w:2)
+	/// Storage: VulnModule Approvals (r:0 w:2)
+	/// Storage: VulnModule RentalRates (r:0 w:2)
+	/// Storage: VulnModule ListingDeposits (r:2 w:0)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: VulnModule Collections (r:2 w:0)
+	fn claim_swap() -> Weight {
+		Weight::from_parts(71_904_000, 9_668)
+			.saturating_add(RocksDbWeight::get().reads(18_u64))
+			.saturating_add(RocksDbWeight::get().writes(15_u64))
+	}
+	/// Storage: VulnModule PendingSwaps (r:1 w:1)
+	/// Storage: VulnModule SwapDeadlines (r:1 w:1)
+	/// Storage: VulnModule Frozen (r:0 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn cancel_swap() -> Weight {
+		Weight::from_parts(31_226_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: VulnModule MintsThisBlock (r:0 w:1)
+	fn on_initialize() -> Weight {
+		Weight::from_parts(1_874_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -9,7 +9,7 @@ use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
-	traits::{BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify},
+	traits::{BlakeTwo256, Block as BlockT, ConvertInto, IdentifyAccount, NumberFor, One, Verify},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, MultiSignature,
 };
@@ -18,12 +18,15 @@ use sp_std::prelude::*;
 use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 
-use frame_support::genesis_builder_helper::{build_config, create_default_config};
+use frame_support::{
+	dispatch::DispatchClass,
+	genesis_builder_helper::{build_config, create_default_config},
+};
 pub use frame_support::{
 	construct_runtime, derive_impl, parameter_types,
 	traits::{
-		ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem, Randomness,
-		StorageInfo,
+		fungible, ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem,
+		OnUnbalanced, Randomness, StorageInfo, WithdrawReasons,
 	},
 	weights::{
 		constants::{
@@ -31,7 +34,7 @@ pub use frame_support::{
 		},
 		IdentityFee, Weight,
 	},
-	StorageValue,
+	PalletId, StorageValue,
 };
 pub use frame_system::Call as SystemCall;
 pub use pallet_balances::Call as BalancesCall;
@@ -101,10 +104,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 173,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -131,17 +134,38 @@ pub fn native_version() -> NativeVersion {
 	NativeVersion { runtime_version: VERSION, can_author_with: Default::default() }
 }
 
+/// Normal extrinsics may fill this share of a block. The rest is kept for operational ones, such
+/// as runtime upgrades and the marketplace's force calls, so a block full of mints and trades
+/// cannot shut them out.
 const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
+/// The share of a block expected to go to `on_initialize` hooks, which includes vulntoken's
+/// deadline processing.
+const AVERAGE_ON_INITIALIZE_RATIO: Perbill = Perbill::from_percent(10);
+/// We allow for 2 seconds of compute with a 6 second average block time.
+const MAXIMUM_BLOCK_WEIGHT: Weight =
+	Weight::from_parts(2u64 * WEIGHT_REF_TIME_PER_SECOND, u64::MAX);
 
 parameter_types! {
 	pub const BlockHashCount: BlockNumber = 2400;
 	pub const Version: RuntimeVersion = VERSION;
-	/// We allow for 2 seconds of compute with a 6 second average block time.
 	pub BlockWeights: frame_system::limits::BlockWeights =
-		frame_system::limits::BlockWeights::with_sensible_defaults(
-			Weight::from_parts(2u64 * WEIGHT_REF_TIME_PER_SECOND, u64::MAX),
-			NORMAL_DISPATCH_RATIO,
-		);
+		frame_system::limits::BlockWeights::builder()
+			.base_block(BlockExecutionWeight::get())
+			.for_class(DispatchClass::all(), |weights| {
+				weights.base_extrinsic = ExtrinsicBaseWeight::get();
+			})
+			.for_class(DispatchClass::Normal, |weights| {
+				weights.max_total = Some(NORMAL_DISPATCH_RATIO * MAXIMUM_BLOCK_WEIGHT);
+			})
+			.for_class(DispatchClass::Operational, |weights| {
+				weights.max_total = Some(MAXIMUM_BLOCK_WEIGHT);
+				// Operational extrinsics still fit once normal ones used up their share.
+				weights.reserved = Some(
+					MAXIMUM_BLOCK_WEIGHT - NORMAL_DISPATCH_RATIO * MAXIMUM_BLOCK_WEIGHT,
+				);
+			})
+			.avg_block_initialization(AVERAGE_ON_INITIALIZE_RATIO)
+			.build_or_panic();
 	pub BlockLength: frame_system::limits::BlockLength = frame_system::limits::BlockLength
 		::max_with_normal_ratio(5 * 1024 * 1024, NORMAL_DISPATCH_RATIO);
 	pub const SS58Prefix: u8 = 42;
@@ -224,7 +248,7 @@ impl pallet_balances::Config for Runtime {
 	type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
 	type FreezeIdentifier = ();
 	type MaxFreezes = ();
-	type RuntimeHoldReason = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = ();
 }
 
@@ -253,13 +277,342 @@ impl pallet_template::Config for Runtime {
 	type WeightInfo = pallet_template::weights::SubstrateWeight<Runtime>;
 }
 
-//impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
+impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
+
+/// The jurors deciding vulntoken escrow disputes.
+impl pallet_membership::Config<pallet_membership::Instance1> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AddOrigin = frame_system::EnsureRoot<AccountId>;
+	type RemoveOrigin = frame_system::EnsureRoot<AccountId>;
+	type SwapOrigin = frame_system::EnsureRoot<AccountId>;
+	type ResetOrigin = frame_system::EnsureRoot<AccountId>;
+	type PrimeOrigin = frame_system::EnsureRoot<AccountId>;
+	type MembershipInitialized = ();
+	type MembershipChanged = ();
+	type MaxMembers = ConstU32<32>;
+	type WeightInfo = pallet_membership::weights::SubstrateWeight<Runtime>;
+}
+
+/// The feeders appraising collections for the valuation oracle.
+impl pallet_membership::Config<pallet_membership::Instance2> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AddOrigin = frame_system::EnsureRoot<AccountId>;
+	type RemoveOrigin = frame_system::EnsureRoot<AccountId>;
+	type SwapOrigin = frame_system::EnsureRoot<AccountId>;
+	type ResetOrigin = frame_system::EnsureRoot<AccountId>;
+	type PrimeOrigin = frame_system::EnsureRoot<AccountId>;
+	type MembershipInitialized = ();
+	type MembershipChanged = ();
+	type MaxMembers = ConstU32<32>;
+	type WeightInfo = pallet_membership::weights::SubstrateWeight<Runtime>;
+}
+
+/// The creators vulntoken searches can be limited to.
+impl pallet_membership::Config<pallet_membership::Instance3> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AddOrigin = frame_system::EnsureRoot<AccountId>;
+	type RemoveOrigin = frame_system::EnsureRoot<AccountId>;
+	type SwapOrigin = frame_system::EnsureRoot<AccountId>;
+	type ResetOrigin = frame_system::EnsureRoot<AccountId>;
+	type PrimeOrigin = frame_system::EnsureRoot<AccountId>;
+	type MembershipInitialized = ();
+	type MembershipChanged = ();
+	type MaxMembers = ConstU32<256>;
+	type WeightInfo = pallet_membership::weights::SubstrateWeight<Runtime>;
+}
+
+/// The relayers the Ethereum bridge trusts with inbound messages.
+impl pallet_membership::Config<pallet_membership::Instance4> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AddOrigin = frame_system::EnsureRoot<AccountId>;
+	type RemoveOrigin = frame_system::EnsureRoot<AccountId>;
+	type SwapOrigin = frame_system::EnsureRoot<AccountId>;
+	type ResetOrigin = frame_system::EnsureRoot<AccountId>;
+	type PrimeOrigin = frame_system::EnsureRoot<AccountId>;
+	type MembershipInitialized = ();
+	type MembershipChanged = ();
+	type MaxMembers = ConstU32<16>;
+	type WeightInfo = pallet_membership::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_identity::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type BasicDeposit = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+	type ByteDeposit = ConstU128<EXISTENTIAL_DEPOSIT>;
+	type SubAccountDeposit = ConstU128<{ 20 * EXISTENTIAL_DEPOSIT }>;
+	type MaxSubAccounts = ConstU32<100>;
+	type IdentityInformation = pallet_identity::legacy::IdentityInfo<ConstU32<100>>;
+	type MaxRegistrars = ConstU32<20>;
+	type Slashed = ();
+	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	type RegistrarOrigin = frame_system::EnsureRoot<AccountId>;
+	type OffchainSignature = Signature;
+	type SigningPublicKey = <Signature as Verify>::Signer;
+	type UsernameAuthorityOrigin = frame_system::EnsureRoot<AccountId>;
+	type PendingUsernameExpiration = ConstU32<{ 7 * DAYS }>;
+	type MaxSuffixLength = ConstU32<7>;
+	type MaxUsernameLength = ConstU32<32>;
+	type WeightInfo = pallet_identity::weights::SubstrateWeight<Runtime>;
+}
+
+/// Accepts accounts that a registrar judged `Reasonable` or `KnownGood`.
+pub struct PositiveJudgement;
+impl vulntoken::IdentityVerifier<AccountId> for PositiveJudgement {
+	fn is_verified(who: &AccountId) -> bool {
+		Identity::identity(who).map_or(false, |(registration, _)| {
+			registration.judgements.iter().any(|(_, judgement)| {
+				matches!(
+					judgement,
+					pallet_identity::Judgement::Reasonable | pallet_identity::Judgement::KnownGood
+				)
+			})
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn verify(who: &AccountId) {
+		use sp_runtime::traits::Hash;
+
+		let registrar: AccountId = frame_benchmarking::account("registrar", 0, 0);
+		let index = match Identity::registrars()
+			.iter()
+			.position(|info| info.as_ref().map_or(false, |info| info.account == registrar))
+		{
+			Some(index) => index as u32,
+			None => {
+				Identity::add_registrar(RuntimeOrigin::root(), registrar.clone().into())
+					.expect("registrar limit is not reached in benchmarks");
+				Identity::registrars().len() as u32 - 1
+			},
+		};
+		let info = pallet_identity::legacy::IdentityInfo::<ConstU32<100>>::default();
+		Identity::set_identity(RuntimeOrigin::signed(who.clone()), Box::new(info.clone()))
+			.expect("benchmark accounts can pay the identity deposit");
+		Identity::request_judgement(RuntimeOrigin::signed(who.clone()), index, 0)
+			.expect("identity was just set");
+		Identity::provide_judgement(
+			RuntimeOrigin::signed(registrar),
+			index,
+			who.clone().into(),
+			pallet_identity::Judgement::KnownGood,
+			BlakeTwo256::hash_of(&info),
+		)
+		.expect("judgement was just requested");
+	}
+}
+
+/// Randomness used by vulntoken to draw raffles and reveal mystery boxes.
+///
+/// Collective flip mixes recent block hashes, which a block author can bias by withholding
+/// blocks. That is acceptable for the workshop chain, but Aura has no VRF to offer instead: a
+/// production deployment needs BABE and `pallet_babe::RandomnessFromOneEpochAgo`. Building
+/// without the `insecure-randomness` feature fails until such a source is wired in.
+#[cfg(feature = "insecure-randomness")]
+pub type VulntokenRandomness = RandomnessCollectiveFlip;
+
+#[cfg(not(feature = "insecure-randomness"))]
+compile_error!(
+	"vulntoken needs a randomness source: enable `insecure-randomness` for development chains \
+	 or configure a VRF-backed source such as BABE's."
+);
+
+/// Pays slashed marketplace deposits into the grants pallet's account, from which governance
+/// funds creators. Amounts too small to create the account while it is empty are burned.
+pub struct ToMarketplaceTreasury;
+impl OnUnbalanced<fungible::Credit<AccountId, Balances>> for ToMarketplaceTreasury {
+	fn on_nonzero_unbalanced(amount: fungible::Credit<AccountId, Balances>) {
+		let treasury = Grants::account_id();
+		let _ = <Balances as fungible::Balanced<AccountId>>::resolve(&treasury, amount);
+	}
+}
 
 impl vulntoken::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
-    //type CollectionRandomness = RandomnessCollectiveFlip;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RuntimeTask = RuntimeTask;
+    type Randomness = VulntokenRandomness;
     type MaximumOwned = frame_support::pallet_prelude::ConstU32<100>;
+    type SealedBidDeposit = ConstU128<{ 10 * EXISTENTIAL_DEPOSIT }>;
+    type MaxSealedBids = ConstU32<64>;
+    type MaxRaffleTickets = ConstU32<1_000>;
+    // Must exceed the 81 blocks of material collective flip mixes into its output.
+    type RevealDelay = ConstU32<{ 10 * MINUTES }>;
+    type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+    type EscrowDisputeWindow = ConstU32<{ 2 * DAYS }>;
+    type DisputeDeposit = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+    type DisputeVotingPeriod = ConstU32<{ 2 * DAYS }>;
+    type EscrowTimeout = ConstU32<{ 14 * DAYS }>;
+    type Jurors = JurorMembership;
+    type VerifiedCreators = CreatorMembership;
+    type ForeignOrigin = frame_system::EnsureRoot<AccountId>;
+    type FreezeOrigin = frame_system::EnsureRoot<AccountId>;
+    type IdentityVerifier = PositiveJudgement;
+    type MintPolicy = ();
+    type ItemIdGenerator = vulntoken::RandomIds<Runtime>;
+    type ColorStrategy = vulntoken::RarityTable<Runtime, ColorWeights>;
+    type HighValueThreshold = ConstU128<{ 1_000_000_000 * EXISTENTIAL_DEPOSIT }>;
+    type MaxListingDuration = ConstU32<{ 30 * DAYS }>;
+    // CIDv1 in base32 with a sha2-256 multihash is 59 characters.
+    type MaxCidLength = ConstU32<128>;
+    // Keeps a block of mints to a small part of the normal block weight, leaving the rest for
+    // trades and settlement.
+    type MaxMintsPerBlock = ConstU32<50>;
+    type CollectibleDeposit = ConstU128<{ 20 * EXISTENTIAL_DEPOSIT }>;
+    type ListingDeposit = ConstU128<{ 10 * EXISTENTIAL_DEPOSIT }>;
+    type MinListingPrice = ConstU128<EXISTENTIAL_DEPOSIT>;
+    type MaxPrice = ConstU128<{ 1_000_000_000_000 * EXISTENTIAL_DEPOSIT }>;
+    type Slashed = ToMarketplaceTreasury;
+    type PricingPolicy = ();
+    type FeeSink = ToMarketplaceTreasury;
+    type InstallmentPeriod = ConstU32<{ 7 * DAYS }>;
+    type MaxInstallments = ConstU32<12>;
+    type InstallmentForfeit = InstallmentForfeit;
+    type MaxDeadlinesPerBlock = ConstU32<64>;
+    type Streams = PaymentStreams;
+    type MaxBookDepth = ConstU32<64>;
+    type MaxFloorIndex = ConstU32<32>;
+    // Hourly buckets, so the rolling volume covers the last 24 hours and the current one.
+    type VolumeEpoch = ConstU32<HOURS>;
+    type VolumeEpochs = ConstU32<25>;
+    type FloorRebuildBatch = ConstU32<500>;
+    type SweepBatch = ConstU32<16>;
+    type WashTradeWindow = ConstU32<DAYS>;
+    type MaxRecentSales = ConstU32<16>;
+    type MinInactivityPeriod = ConstU32<{ 30 * DAYS }>;
+    type InheritanceGracePeriod = ConstU32<{ 7 * DAYS }>;
+    type MaxRoyalty = MaxRoyalty;
+    type TradingLimitWindow = ConstU32<DAYS>;
+    type MaxLicenseLength = ConstU32<64>;
+    type MetadataLimit = ConstU32<1_024>;
+    type AttributeKeyLimit = ConstU32<64>;
+    type AttributeValueLimit = ConstU32<256>;
+    type MaxAttributes = ConstU32<16>;
+    // A kilobyte of metadata holds about as much as minting the collectible.
+    type MetadataDepositPerByte = ConstU128<{ EXISTENTIAL_DEPOSIT / 50 }>;
+    type CollectionTeardownBatch = ConstU32<64>;
+    type MaxOffers = ConstU32<64>;
+    type WeightInfo = vulntoken::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const InstallmentForfeit: Perbill = Perbill::from_percent(20);
+	pub const MaxRoyalty: Perbill = Perbill::from_percent(25);
+	/// Red, yellow, blue and green, from common to rare.
+	pub const ColorWeights: [u32; 4] = [60, 25, 10, 5];
+}
+
+parameter_types! {
+	pub const GrantsPalletId: PalletId = PalletId(*b"vn/trsry");
+}
+
+impl pallet_grants::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type PalletId = GrantsPalletId;
+	type ApproveOrigin = frame_system::EnsureRoot<AccountId>;
+	type WeightInfo = pallet_grants::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_oracle::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = u32;
+	type Balance = Balance;
+	type Feeders = OracleFeeders;
+	type MaxFeeders = ConstU32<32>;
+	type StalenessWindow = ConstU32<DAYS>;
+	type MinAppraisals = ConstU32<3>;
+	type WeightInfo = pallet_oracle::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_payment_streams::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type WeightInfo = pallet_payment_streams::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const CrowdfundPalletId: PalletId = PalletId(*b"vn/crowd");
+}
+
+impl pallet_crowdfund::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type Marketplace = VulnModule;
+	type PalletId = CrowdfundPalletId;
+	type MaxDuration = ConstU32<{ 30 * DAYS }>;
+	type MinContribution = ConstU128<{ 10 * EXISTENTIAL_DEPOSIT }>;
+	type WeightInfo = pallet_crowdfund::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const EthBridgePalletId: PalletId = PalletId(*b"vn/ethbr");
+}
+
+/// Bridges collectibles to Ethereum. Until an Ethereum light client runs on chain, inbound
+/// messages are trusted when a member of `BridgeRelayers` submits them.
+impl pallet_eth_bridge::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Collectibles = VulnModule;
+	type Verifier = pallet_eth_bridge::TrustedRelayers<BridgeRelayers>;
+	type PalletId = EthBridgePalletId;
+	type MaxProofLength = ConstU32<16_384>;
+	type WeightInfo = pallet_eth_bridge::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub ClaimsPrefix: &'static [u8] = b"Claim vulntoken collectibles for:";
+}
+
+/// Lets holders from a snapshot of a previous chain or an Ethereum collection claim their
+/// collectibles here, once governance sets the snapshot's root.
+impl pallet_snapshot_claims::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Issuer = VulnModule;
+	type RootOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxProofDepth = ConstU32<32>;
+	type Prefix = ClaimsPrefix;
+	type WeightInfo = pallet_snapshot_claims::weights::SubstrateWeight<Runtime>;
+}
+
+/// Lets holders who lose their keys get their account, and the collectibles in it, back through
+/// friends they named in advance.
+impl pallet_recovery::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_recovery::weights::SubstrateWeight<Runtime>;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type ConfigDepositBase = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+	type FriendDepositFactor = ConstU128<{ 10 * EXISTENTIAL_DEPOSIT }>;
+	type MaxFriends = ConstU32<9>;
+	type RecoveryDeposit = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+}
+
+parameter_types! {
+	pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
+		WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
+}
+
+/// Releases team and creator allocations over time. Schedules are set in the chain spec or by
+/// `vested_transfer`.
+impl pallet_vesting::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type BlockNumberToBalance = ConvertInto;
+	type MinVestedTransfer = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+	type WeightInfo = pallet_vesting::weights::SubstrateWeight<Runtime>;
+	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+	const MAX_VESTING_SCHEDULES: u32 = 28;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+	RuntimeCall: From<C>,
+{
+	type Extrinsic = UncheckedExtrinsic;
+	type OverarchingCall = RuntimeCall;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -304,11 +657,50 @@ mod runtime {
 	#[runtime::pallet_index(7)]
 	pub type TemplateModule = pallet_template;
 
-	//#[runtime::pallet_index(8)]
-	//pub type RandomnessCollectiveFlip = pallet_insecure_randomness_collective_flip;
-
 	#[runtime::pallet_index(8)]
 	pub type VulnModule = vulntoken;
+
+	#[runtime::pallet_index(9)]
+	pub type RandomnessCollectiveFlip = pallet_insecure_randomness_collective_flip;
+
+	#[runtime::pallet_index(10)]
+	pub type JurorMembership = pallet_membership<Instance1>;
+
+	#[runtime::pallet_index(11)]
+	pub type Identity = pallet_identity;
+
+	#[runtime::pallet_index(12)]
+	pub type Grants = pallet_grants;
+
+	#[runtime::pallet_index(13)]
+	pub type OracleFeeders = pallet_membership<Instance2>;
+
+	#[runtime::pallet_index(14)]
+	pub type Oracle = pallet_oracle;
+
+	#[runtime::pallet_index(15)]
+	pub type PaymentStreams = pallet_payment_streams;
+
+	#[runtime::pallet_index(16)]
+	pub type Crowdfund = pallet_crowdfund;
+
+	#[runtime::pallet_index(17)]
+	pub type Recovery = pallet_recovery;
+
+	#[runtime::pallet_index(18)]
+	pub type CreatorMembership = pallet_membership<Instance3>;
+
+	#[runtime::pallet_index(19)]
+	pub type Vesting = pallet_vesting;
+
+	#[runtime::pallet_index(20)]
+	pub type BridgeRelayers = pallet_membership<Instance4>;
+
+	#[runtime::pallet_index(21)]
+	pub type EthBridge = pallet_eth_bridge;
+
+	#[runtime::pallet_index(22)]
+	pub type SnapshotClaims = pallet_snapshot_claims;
 }
 
 /// The address format for describing accounts.
@@ -333,7 +725,24 @@ pub type SignedExtra = (
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+	vulntoken::migrations::Checked<vulntoken::migrations::v1::MigrateToV1<Runtime>>,
+	vulntoken::migrations::Checked<
+		vulntoken::migrations::v2::MigrateReservesToHolds<Runtime, Balances>,
+	>,
+	vulntoken::migrations::Checked<vulntoken::migrations::v3::PopulateFreezes<Runtime>>,
+	vulntoken::migrations::Checked<vulntoken::migrations::v4::MoveRentalsToStreams<Runtime>>,
+	vulntoken::migrations::Checked<vulntoken::migrations::v5::RebuildFloorIndex<Runtime>>,
+	vulntoken::migrations::Checked<vulntoken::migrations::v6::PopulateColorStatistics<Runtime>>,
+	vulntoken::migrations::Checked<vulntoken::migrations::v7::RehashAccountKeys<Runtime>>,
+	vulntoken::migrations::Checked<vulntoken::migrations::v8::AddCollectionIds<Runtime>>,
+	vulntoken::migrations::Checked<vulntoken::migrations::v9::ScanHighestPrice<Runtime>>,
+	vulntoken::migrations::Checked<vulntoken::migrations::v10::RecordPendingMinters<Runtime>>,
+	vulntoken::migrations::Checked<vulntoken::migrations::v11::IndexCollectionItems<Runtime>>,
+	vulntoken::migrations::Checked<vulntoken::migrations::v12::AddCollectionSettings<Runtime>>,
+	vulntoken::migrations::Checked<vulntoken::migrations::v13::CountOffers<Runtime>>,
+	vulntoken::migrations::Checked<vulntoken::migrations::v14::IndexUnsettledAuctions<Runtime>>,
+);
 
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
@@ -359,6 +768,13 @@ mod benches {
 		[pallet_timestamp, Timestamp]
 		[pallet_sudo, Sudo]
 		[pallet_template, TemplateModule]
+		[vulntoken, VulnModule]
+		[pallet_grants, Grants]
+		[pallet_oracle, Oracle]
+		[pallet_payment_streams, PaymentStreams]
+		[pallet_crowdfund, Crowdfund]
+		[pallet_eth_bridge, EthBridge]
+		[pallet_snapshot_claims, SnapshotClaims]
 	);
 }
 
@@ -530,6 +946,71 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl vulntoken_runtime_api::VulntokenApi<Block, AccountId, Balance> for Runtime {
+		fn verify_metadata(unique_id: u64, blob: Vec<u8>) -> bool {
+			VulnModule::verify_metadata(unique_id, &blob)
+		}
+
+		fn holders(start_after: Option<AccountId>, limit: u32) -> Vec<(AccountId, u32)> {
+			VulnModule::holders(start_after, limit)
+		}
+
+		fn floor_price() -> Option<Balance> {
+			VulnModule::floor_price()
+		}
+
+		fn rolling_volume() -> Balance {
+			VulnModule::rolling_volume()
+		}
+
+		fn color_stats(color: vulntoken::Color) -> vulntoken::ColorStats<Balance> {
+			VulnModule::color_stats(color)
+		}
+
+		fn find_collectibles(
+			filter: vulntoken::CollectibleFilter<Balance>,
+			start_after: Option<u64>,
+			limit: u32,
+		) -> Vec<(u64, vulntoken::Color, Option<Balance>)> {
+			VulnModule::find_collectibles(filter, start_after, limit)
+		}
+
+		fn is_wash_traded(unique_id: u64) -> bool {
+			VulnModule::is_wash_traded(unique_id)
+		}
+
+		fn dry_run_buy(
+			buyer: AccountId,
+			unique_id: u64,
+			max_price: Balance,
+		) -> Result<vulntoken::SaleQuote<AccountId, Balance>, sp_runtime::DispatchError> {
+			VulnModule::dry_run_buy(buyer, unique_id, max_price)
+		}
+
+		fn collectible(unique_id: u64) -> Option<vulntoken::CollectibleView<AccountId, Balance>> {
+			VulnModule::collectible(unique_id)
+		}
+
+		fn collectibles_of(owner: AccountId) -> Vec<u64> {
+			VulnModule::collectibles_of(&owner)
+		}
+
+		fn prove_collectible(unique_id: u64) -> Vec<Vec<u8>> {
+			VulnModule::collectible_proof_keys(unique_id)
+		}
+
+		fn effective_royalty(unique_id: u64) -> Option<Perbill> {
+			VulnModule::effective_royalty(unique_id)
+		}
+	}
+
+	#[cfg(feature = "debug-api")]
+	impl vulntoken_runtime_api::VulntokenDebugApi<Block> for Runtime {
+		fn dump_storage(start_after: Option<Vec<u8>>, limit: u32) -> Vec<(Vec<u8>, Vec<u8>)> {
+			VulnModule::dump_storage(start_after, limit)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
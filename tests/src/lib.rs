@@ -0,0 +1,3 @@
+//! End-to-end tests driving the vulntoken marketplace through the node template runtime, so
+//! genesis, pallet configuration and dispatch are exactly what the node executes. The
+//! scenarios live under `tests/`.
@@ -0,0 +1,46 @@
+//! How the runtime shares block weight between marketplace traffic and operational calls.
+
+use frame_support::{
+	dispatch::{DispatchClass, GetDispatchInfo},
+	traits::Get,
+};
+use node_template_runtime::{
+	vulntoken::{self, WeightInfo},
+	AccountId, BlockWeights, Runtime, RuntimeCall,
+};
+
+type VulnCall = vulntoken::Call<Runtime>;
+type VulnWeights = <Runtime as vulntoken::Config>::WeightInfo;
+
+fn class(call: VulnCall) -> DispatchClass {
+	RuntimeCall::from(call).get_dispatch_info().class
+}
+
+#[test]
+fn force_calls_are_operational() {
+	assert_eq!(class(VulnCall::set_transfer_cooldown { blocks: 1 }), DispatchClass::Operational);
+	assert_eq!(class(VulnCall::flag_listing { unique_id: 0 }), DispatchClass::Operational);
+	assert_eq!(class(VulnCall::set_founder_badge_limit { limit: 1 }), DispatchClass::Operational);
+	let to = AccountId::new([1; 32]);
+	assert_eq!(class(VulnCall::create_collectible { to }), DispatchClass::Normal);
+}
+
+#[test]
+fn operational_calls_fit_into_a_block_full_of_normal_ones() {
+	let weights = BlockWeights::get();
+	let normal = weights.get(DispatchClass::Normal).max_total.unwrap();
+	let operational = weights.get(DispatchClass::Operational);
+	assert_eq!(operational.max_total, Some(weights.max_block));
+	assert_eq!(operational.reserved, Some(weights.max_block - normal));
+}
+
+#[test]
+fn a_block_of_mints_leaves_most_of_the_block_to_trades() {
+	let weights = BlockWeights::get();
+	let normal = weights.get(DispatchClass::Normal);
+	let mint = VulnWeights::create_collectible()
+		.max(VulnWeights::mint_mystery_box())
+		.saturating_add(normal.base_extrinsic);
+	let mints: u32 = <Runtime as vulntoken::Config>::MaxMintsPerBlock::get();
+	assert!(mint.saturating_mul(mints.into()).all_lte(normal.max_total.unwrap() / 10));
+}
@@ -0,0 +1,65 @@
+use codec::Encode;
+use frame_support::{dispatch::DispatchResultWithPostInfo, Hashable, StorageHasher, Twox64Concat};
+use node_template_runtime::{
+	vulntoken, AccountId, Balance, BalancesConfig, Runtime, RuntimeCall, RuntimeEvent,
+	RuntimeGenesisConfig, RuntimeOrigin, System, EXISTENTIAL_DEPOSIT,
+};
+use sp_runtime::{traits::Dispatchable, BuildStorage};
+
+pub const ALICE: AccountId = AccountId::new([1; 32]);
+pub const BOB: AccountId = AccountId::new([2; 32]);
+
+pub const INITIAL_BALANCE: Balance = 1_000_000 * EXISTENTIAL_DEPOSIT;
+
+/// Externalities built from the runtime's genesis config with funded test accounts.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let storage = RuntimeGenesisConfig {
+		balances: BalancesConfig { balances: vec![(ALICE, INITIAL_BALANCE), (BOB, INITIAL_BALANCE)] },
+		..Default::default()
+	}
+	.build_storage()
+	.unwrap();
+	let mut ext = sp_io::TestExternalities::new(storage);
+	// Events are not deposited in the genesis block.
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+pub fn dispatch(who: &AccountId, call: impl Into<RuntimeCall>) -> DispatchResultWithPostInfo {
+	call.into().dispatch(RuntimeOrigin::signed(who.clone()))
+}
+
+/// Events deposited since the previous call.
+pub fn take_events() -> Vec<RuntimeEvent> {
+	let events = System::events().into_iter().map(|record| record.event).collect();
+	System::reset_events();
+	events
+}
+
+/// The collectible as stored by the runtime. Pallet storage is private, so it is read back by
+/// key the way an external client would.
+pub fn collectible(unique_id: u64) -> Option<vulntoken::Collectible<Runtime>> {
+	frame_support::storage::migration::get_storage_value(
+		b"VulnModule",
+		b"CollectibleMap",
+		&Twox64Concat::hash(&unique_id.encode()),
+	)
+}
+
+/// Keys under the vulntoken pallet that still mention `unique_id`.
+pub fn keys_mentioning(unique_id: u64) -> Vec<Vec<u8>> {
+	let prefix = b"VulnModule".twox_128();
+	let needle = unique_id.encode();
+	let mut keys = Vec::new();
+	let mut key = prefix.to_vec();
+	while let Some(next) = sp_io::storage::next_key(&key) {
+		if !next.starts_with(&prefix) {
+			break
+		}
+		if next.windows(needle.len()).any(|w| w == needle) {
+			keys.push(next.clone());
+		}
+		key = next;
+	}
+	keys
+}
@@ -0,0 +1,89 @@
+//! A collectible's whole life on the marketplace: mint, list, buy, burn.
+//!
+//! Offers are not part of the marketplace yet; add the step here once they land.
+
+mod common;
+
+use common::*;
+use frame_support::{assert_noop, assert_ok};
+use node_template_runtime::{
+	vulntoken, Balances, Runtime, RuntimeEvent, VulnModule, EXISTENTIAL_DEPOSIT,
+};
+
+type VulnCall = vulntoken::Call<Runtime>;
+type VulnEvent = vulntoken::Event<Runtime>;
+
+#[test]
+fn mint_list_buy_burn() {
+	new_test_ext().execute_with(|| {
+		let price = 100 * EXISTENTIAL_DEPOSIT;
+
+		// Mint, taking the id from the event.
+		assert_ok!(dispatch(&ALICE, VulnCall::create_collectible { to: ALICE }));
+		let id = match take_events().as_slice() {
+			[RuntimeEvent::VulnModule(VulnEvent::CollectibleCreated { collectible, owner })]
+				if *owner == ALICE =>
+				*collectible,
+			other => panic!("unexpected events after mint: {other:?}"),
+		};
+		assert_eq!(collectible(id).map(|c| (c.owner, c.price)), Some((ALICE, None)));
+		assert_eq!(VulnModule::holders(None, 10), vec![(ALICE, 1)]);
+
+		// List
+		assert_ok!(dispatch(
+			&ALICE,
+			VulnCall::set_price { owner: ALICE, unique_id: id, new_price: Some(price) }
+		));
+		assert_eq!(
+			take_events(),
+			vec![RuntimeEvent::VulnModule(VulnEvent::PriceSet { collectible: id, price: Some(price) })]
+		);
+		assert_eq!(VulnModule::listing_price(id), Some(price));
+
+		// Buy
+		assert_ok!(dispatch(
+			&BOB,
+			VulnCall::buy_collectible { unique_id: id, max_price: price }
+		));
+		assert_eq!(
+			take_events(),
+			vec![
+				RuntimeEvent::Balances(pallet_balances::Event::Transfer {
+					from: BOB,
+					to: ALICE,
+					amount: price,
+				}),
+				RuntimeEvent::VulnModule(VulnEvent::Sold {
+					seller: ALICE,
+					buyer: BOB,
+					collectible: id,
+					price,
+					charity: None,
+				}),
+				RuntimeEvent::VulnModule(VulnEvent::TransferSucceeded {
+					from: ALICE,
+					to: BOB,
+					collectible: id,
+				}),
+			]
+		);
+		assert_eq!(Balances::free_balance(&ALICE), INITIAL_BALANCE + price);
+		assert_eq!(Balances::free_balance(&BOB), INITIAL_BALANCE - price);
+		assert_eq!(collectible(id).map(|c| (c.owner, c.price)), Some((BOB, None)));
+		assert_eq!(VulnModule::holders(None, 10), vec![(BOB, 1)]);
+		assert_eq!(VulnModule::listing_price(id), None);
+
+		// Burn. Only the owner may, and nothing about the collectible survives it.
+		assert_noop!(
+			dispatch(&ALICE, VulnCall::burn { unique_id: id }),
+			vulntoken::Error::<Runtime>::NotOwner
+		);
+		assert_ok!(dispatch(&BOB, VulnCall::burn { unique_id: id }));
+		let burned = VulnEvent::CollectibleBurned { collectible: id, owner: BOB };
+		assert_eq!(take_events().last(), Some(&RuntimeEvent::VulnModule(burned)));
+		assert!(collectible(id).is_none());
+		assert!(VulnModule::holders(None, 10).is_empty());
+		assert!(keys_mentioning(id).is_empty());
+		assert_eq!(Balances::free_balance(&BOB), INITIAL_BALANCE - price);
+	});
+}